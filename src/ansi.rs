@@ -74,6 +74,14 @@ impl TextParser for String {
     }
 }
 
+/// エスケープシーケンスを取り除いた文字列を返す
+pub fn strip_ansi(s: &str) -> String {
+    s.ansi_parse()
+        .filter(|p| p.ty == AnsiEscapeSequence::Chars)
+        .map(|p| p.chars)
+        .collect()
+}
+
 impl<'a> Iterator for TextIterator<'a> {
     type Item = Text<'a>;
     fn next(&mut self) -> Option<Self::Item> {
@@ -9,15 +9,16 @@ use crossbeam::channel::{bounded, Receiver, Sender};
 use crate::{
     cmd::Command,
     message::Message,
-    workers::{KubeWorker, Render, Tick, UserInput},
+    workers::{replay::load_records, KubeWorker, Render, Replay, Tick, UserInput},
 };
 
 pub struct App;
 
 impl App {
     pub fn run(cmd: Command) -> Result<()> {
-        let split_direction = cmd.split_direction();
         let kube_worker_config = cmd.kube_worker_config();
+        let render_config = cmd.render_config();
+        let records = cmd.replay.as_deref().map(load_records).transpose()?;
 
         let (tx_input, rx_main): (Sender<Message>, Receiver<Message>) = bounded(128);
         let (tx_main, rx_kube): (Sender<Message>, Receiver<Message>) = bounded(256);
@@ -35,6 +36,16 @@ impl App {
             kube_worker_config,
         );
 
+        // 再生時はクラスターに接続せず、記録したメッセージを送信する
+        let replay = records.map(|records| {
+            Replay::new(
+                tx_kube.clone(),
+                rx_kube.clone(),
+                is_terminated.clone(),
+                records,
+            )
+        });
+
         let tick = Tick::new(
             tx_tick.clone(),
             time::Duration::from_millis(200),
@@ -45,14 +56,20 @@ impl App {
             tx_main.clone(),
             rx_main.clone(),
             is_terminated.clone(),
-            split_direction,
+            render_config,
         );
 
         thread::scope(|s| {
-            let kube_handler = s.spawn(|| {
-                kube.set_panic_hook();
-                kube.start()
-            });
+            let kube_handler = match replay {
+                Some(replay) => s.spawn(move || {
+                    replay.set_panic_hook();
+                    replay.start()
+                }),
+                None => s.spawn(|| {
+                    kube.set_panic_hook();
+                    kube.start()
+                }),
+            };
 
             let tick_handler = s.spawn(move || {
                 tick.set_panic_hook();
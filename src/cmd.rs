@@ -1,4 +1,5 @@
 mod args;
 mod command;
 
+pub use self::args::{SnapshotFormat, SnapshotTab};
 pub use self::command::*;
@@ -1,5 +1,13 @@
 mod all_namespaces;
+mod byte_size;
+mod log_timestamp;
+mod since;
+mod snapshot;
 mod split_direction;
 
 pub use all_namespaces::*;
+pub use byte_size::*;
+pub use log_timestamp::*;
+pub use since::*;
+pub use snapshot::*;
 pub use split_direction::*;
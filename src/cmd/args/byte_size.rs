@@ -0,0 +1,39 @@
+use std::str::FromStr;
+
+use anyhow::{bail, Context as _};
+
+/// バイト数
+///
+/// 単位なしの数値、もしくは2の累乗の単位（K, Ki, KiB, M, Mi, MiB, G, Gi, GiB）を付けた数値を受け付ける
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByteSize(pub usize);
+
+impl FromStr for ByteSize {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        let pos = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+
+        let (value, unit) = s.split_at(pos);
+
+        let value: usize = value
+            .parse()
+            .with_context(|| format!("invalid byte size: {}", s))?;
+
+        let multiplier: usize = match unit.to_ascii_lowercase().as_str() {
+            "" | "b" => 1,
+            "k" | "ki" | "kib" => 1 << 10,
+            "m" | "mi" | "mib" => 1 << 20,
+            "g" | "gi" | "gib" => 1 << 30,
+            _ => bail!("invalid byte size unit: {}", unit),
+        };
+
+        let Some(bytes) = value.checked_mul(multiplier) else {
+            bail!("byte size is too large: {}", s);
+        };
+
+        Ok(Self(bytes))
+    }
+}
@@ -0,0 +1,28 @@
+use strum::EnumString;
+
+use crate::features::pod::kube::LogTimestampType;
+
+#[derive(Debug, Default, EnumString, Clone, Copy, PartialEq, Eq)]
+#[strum(ascii_case_insensitive)]
+pub enum LogTimestamp {
+    #[default]
+    #[strum(serialize = "hidden", serialize = "none")]
+    Hidden,
+    #[strum(serialize = "local")]
+    Local,
+    #[strum(serialize = "utc")]
+    Utc,
+    #[strum(serialize = "relative")]
+    Relative,
+}
+
+impl From<LogTimestamp> for LogTimestampType {
+    fn from(value: LogTimestamp) -> Self {
+        match value {
+            LogTimestamp::Hidden => LogTimestampType::Hidden,
+            LogTimestamp::Local => LogTimestampType::Local,
+            LogTimestamp::Utc => LogTimestampType::Utc,
+            LogTimestamp::Relative => LogTimestampType::Relative,
+        }
+    }
+}
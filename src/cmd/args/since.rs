@@ -0,0 +1,60 @@
+use std::{str::FromStr, time::Duration};
+
+use anyhow::{bail, Context as _};
+
+/// ログを取得する期間
+///
+/// 単位（h, m, s）を付けた数値を受け付け、1h30mのように組み合わせることもできる
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Since(pub Duration);
+
+impl FromStr for Since {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut rest = s.trim();
+
+        if rest.is_empty() {
+            bail!("invalid duration: {}", s);
+        }
+
+        let mut secs: u64 = 0;
+
+        while !rest.is_empty() {
+            let pos = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+
+            let (value, unit) = rest.split_at(pos);
+
+            let value: u64 = value
+                .parse()
+                .with_context(|| format!("invalid duration: {}", s))?;
+
+            let unit_len = unit
+                .find(|c: char| c.is_ascii_digit())
+                .unwrap_or(unit.len());
+
+            let (unit, remainder) = unit.split_at(unit_len);
+
+            let multiplier = match unit {
+                "h" => 3600,
+                "m" => 60,
+                "s" => 1,
+                _ => bail!("invalid duration unit: {}", unit),
+            };
+
+            let Some(total) = value
+                .checked_mul(multiplier)
+                .and_then(|value| secs.checked_add(value))
+            else {
+                bail!("duration is too large: {}", s);
+            };
+
+            secs = total;
+            rest = remainder;
+        }
+
+        Ok(Self(Duration::from_secs(secs)))
+    }
+}
@@ -0,0 +1,42 @@
+use strum::EnumString;
+
+use crate::features::label_selector::kube::LabelSelectorTarget;
+
+#[derive(Debug, EnumString, Clone, Copy, PartialEq, Eq)]
+#[strum(ascii_case_insensitive)]
+pub enum SnapshotTab {
+    #[strum(serialize = "pod", serialize = "pods")]
+    Pod,
+    #[strum(serialize = "config", serialize = "configs")]
+    Config,
+    #[strum(serialize = "event", serialize = "events")]
+    Event,
+    #[strum(serialize = "network", serialize = "networks")]
+    Network,
+}
+
+impl SnapshotTab {
+    /// ラベルセレクターを適用するテーブル
+    ///
+    /// Eventタブはラベルセレクターに対応していないため`None`を返す
+    pub fn label_selector_target(self) -> Option<LabelSelectorTarget> {
+        match self {
+            SnapshotTab::Pod => Some(LabelSelectorTarget::Pod),
+            SnapshotTab::Config => Some(LabelSelectorTarget::Config),
+            SnapshotTab::Network => Some(LabelSelectorTarget::Network),
+            SnapshotTab::Event => None,
+        }
+    }
+}
+
+#[derive(Debug, Default, EnumString, Clone, Copy, PartialEq, Eq)]
+#[strum(ascii_case_insensitive)]
+pub enum SnapshotFormat {
+    #[default]
+    #[strum(serialize = "text")]
+    Text,
+    #[strum(serialize = "json")]
+    Json,
+    #[strum(serialize = "yaml")]
+    Yaml,
+}
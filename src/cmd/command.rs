@@ -1,10 +1,19 @@
-use clap::Parser;
+use clap::{Args, Parser, Subcommand};
 use ratatui::layout::Direction;
-use std::path::PathBuf;
+use std::{path::PathBuf, time::Duration};
 
-use crate::workers::kube::KubeWorkerConfig;
+use crate::{
+    features::{impersonation::kube::Impersonation, pod::view::LogViewConfig},
+    history::History,
+    workers::{
+        kube::{KubeWorkerConfig, KUBECONFIG_SEPARATOR},
+        RenderConfig,
+    },
+};
 
-use super::args::{AllNamespaces, SplitDirection};
+use super::args::{
+    AllNamespaces, ByteSize, LogTimestamp, Since, SnapshotFormat, SnapshotTab, SplitDirection,
+};
 
 #[derive(Parser, Debug, Clone)]
 #[command(author, version, about, long_about = None)]
@@ -25,12 +34,13 @@ pub struct Command {
         long,
         conflicts_with = "all_namespaces",
         value_delimiter = ',',
+        global = true,
         display_order = 1000
     )]
     pub namespaces: Option<Vec<String>>,
 
     /// Context
-    #[arg(short, long, display_order = 1000)]
+    #[arg(short, long, global = true, display_order = 1000)]
     pub context: Option<String>,
 
     /// Select all namespaces
@@ -54,17 +64,149 @@ pub struct Command {
         default_missing_value = "true",
         hide_possible_values = true,
         value_enum,
+        global = true,
         display_order = 1000
     )]
     pub all_namespaces: AllNamespaces,
 
-    /// kubeconfig path
-    #[arg(short = 'C', long, display_order = 1000)]
-    pub kubeconfig: Option<PathBuf>,
+    /// kubeconfig paths merged in order (e.g. -C a.yaml:b.yaml | -C a.yaml -C b.yaml)
+    #[arg(
+        short = 'C',
+        long,
+        value_delimiter = KUBECONFIG_SEPARATOR,
+        global = true,
+        display_order = 1000
+    )]
+    pub kubeconfig: Vec<PathBuf>,
+
+    /// User to impersonate for all API requests
+    #[arg(long = "as", value_name = "USER", global = true, display_order = 1000)]
+    pub as_user: Option<String>,
+
+    /// Groups to impersonate (e.g. --as-group a,b | --as-group a --as-group b)
+    #[arg(
+        long,
+        value_name = "GROUP",
+        value_delimiter = ',',
+        requires = "as_user",
+        global = true,
+        display_order = 1000
+    )]
+    pub as_group: Vec<String>,
 
     /// Logging
     #[arg(short = 'l', long, display_order = 1000)]
     pub logging: bool,
+
+    /// Maximum number of lines kept in the log view (0 means unlimited)
+    #[arg(
+        long,
+        value_name = "LINES",
+        default_value_t = 100000,
+        display_order = 1000
+    )]
+    pub log_max_lines: usize,
+
+    /// Maximum size of lines kept in the log view (e.g. 512K, 64M, 1G)
+    #[arg(long, value_name = "SIZE", display_order = 1000)]
+    pub log_max_bytes: Option<ByteSize>,
+
+    /// Append lines dropped from the log view to this file
+    #[arg(long, value_name = "PATH", display_order = 1000)]
+    pub log_spill_file: Option<PathBuf>,
+
+    /// Initial timestamp display of the log view
+    #[arg(
+        long,
+        value_name = "hidden|local|utc|relative",
+        default_value = "hidden",
+        display_order = 1000
+    )]
+    pub log_timestamp: LogTimestamp,
+
+    /// File to store the history of log queries and filters [default: $XDG_STATE_HOME/kubetui/history.json]
+    #[arg(long, value_name = "PATH", display_order = 1000)]
+    pub history_file: Option<PathBuf>,
+
+    /// Maximum number of redraws per second
+    #[arg(
+        long,
+        value_name = "FPS",
+        default_value_t = 30,
+        value_parser = clap::value_parser!(u16).range(1..),
+        display_order = 1000
+    )]
+    pub max_fps: u16,
+
+    /// Record the data received from the cluster to this file (may contain Secret data)
+    #[arg(long, value_name = "PATH", display_order = 1000)]
+    pub record: Option<PathBuf>,
+
+    /// Replay a file recorded with --record instead of connecting to the cluster
+    #[arg(
+        long,
+        value_name = "PATH",
+        conflicts_with = "record",
+        display_order = 1000
+    )]
+    pub replay: Option<PathBuf>,
+
+    #[command(subcommand)]
+    pub subcommand: Option<SubCommand>,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum SubCommand {
+    /// Print the table of a tab once and exit without starting the TUI
+    Snapshot(SnapshotArgs),
+
+    /// Stream the logs matched by a log query to stdout without starting the TUI
+    Logs(LogsArgs),
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct SnapshotArgs {
+    /// Tab whose table to print (descriptions and YAML are not printed)
+    #[arg(short, long, value_name = "pod|config|event|network")]
+    pub tab: SnapshotTab,
+
+    /// Output format
+    #[arg(
+        short = 'o',
+        long,
+        value_name = "text|json|yaml",
+        default_value = "text"
+    )]
+    pub format: SnapshotFormat,
+
+    /// Label selector applied to the tab (e.g. -l app=foo,tier!=db)
+    #[arg(short = 'l', long, value_name = "SELECTOR")]
+    pub selector: Option<String>,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct LogsArgs {
+    /// Log query (e.g. 'pod:app container:nginx log:error')
+    #[arg(value_name = "QUERY")]
+    pub query: String,
+
+    /// Print lines without colors
+    #[arg(long)]
+    pub no_color: bool,
+
+    /// Print each line as a JSON object
+    #[arg(long)]
+    pub json: bool,
+
+    /// Only print logs newer than a relative duration (e.g. 30s, 5m, 1h30m)
+    #[arg(long, value_name = "DURATION")]
+    pub since: Option<Since>,
+}
+
+impl LogsArgs {
+    pub fn since(&self) -> Option<Duration> {
+        self.since.map(|Since(since)| since)
+    }
 }
 
 impl Command {
@@ -82,6 +224,8 @@ impl Command {
             context,
             all_namespaces,
             kubeconfig,
+            as_user,
+            as_group,
             ..
         } = self.clone();
 
@@ -90,6 +234,36 @@ impl Command {
             target_namespaces: namespaces,
             context,
             all_namespaces: all_namespaces.into(),
+            impersonation: as_user.map(|user| Impersonation::new(user, as_group)),
+        }
+    }
+
+    pub fn history_file(&self) -> Option<PathBuf> {
+        self.history_file.clone().or_else(History::default_path)
+    }
+
+    /// 描画の最小間隔
+    pub fn frame_interval(&self) -> Duration {
+        Duration::from_secs(1) / u32::from(self.max_fps)
+    }
+
+    pub fn render_config(&self) -> RenderConfig {
+        RenderConfig {
+            direction: self.split_direction(),
+            log_view_config: self.log_view_config(),
+            history_file: self.history_file(),
+            frame_interval: self.frame_interval(),
+            record_file: self.record.clone(),
+            replay: self.replay.is_some(),
+        }
+    }
+
+    pub fn log_view_config(&self) -> LogViewConfig {
+        LogViewConfig {
+            max_lines: (0 < self.log_max_lines).then_some(self.log_max_lines),
+            max_bytes: self.log_max_bytes.map(|ByteSize(bytes)| bytes),
+            spill_file: self.log_spill_file.clone(),
+            timestamp_type: self.log_timestamp.into(),
         }
     }
 }
@@ -158,6 +332,265 @@ mod tests {
             assert_eq!(cmd.unwrap_err().kind(), ErrorKind::ArgumentConflict)
         }
     }
+    mod log_view {
+        use clap::error::ErrorKind;
+        use pretty_assertions::assert_eq;
+        use rstest::rstest;
+
+        use crate::features::pod::kube::LogTimestampType;
+
+        use super::*;
+
+        #[test]
+        fn デフォルトでは行数のみ制限する() {
+            let cmd = Command::try_parse_from(["kubetui"]).unwrap();
+            let config = cmd.log_view_config();
+
+            assert_eq!(config.max_lines, Some(100000));
+            assert_eq!(config.max_bytes, None);
+            assert_eq!(config.spill_file, None);
+        }
+
+        #[test]
+        fn 行数に0を指定すると無制限になる() {
+            let cmd = Command::try_parse_from(["kubetui", "--log-max-lines", "0"]).unwrap();
+            assert_eq!(cmd.log_view_config().max_lines, None)
+        }
+
+        #[rstest]
+        #[case::default(None, LogTimestampType::Hidden)]
+        #[case::local(Some("local"), LogTimestampType::Local)]
+        #[case::utc(Some("UTC"), LogTimestampType::Utc)]
+        #[case::relative(Some("relative"), LogTimestampType::Relative)]
+        fn タイムスタンプの表示形式を指定できる(
+            #[case] value: Option<&str>,
+            #[case] expected: LogTimestampType,
+        ) {
+            let mut args = vec!["kubetui"];
+            if let Some(value) = value {
+                args.extend(["--log-timestamp", value]);
+            }
+
+            let cmd = Command::try_parse_from(args).unwrap();
+            assert_eq!(cmd.log_view_config().timestamp_type, expected)
+        }
+
+        #[rstest]
+        #[case::no_unit("1024", 1024)]
+        #[case::bytes("1024B", 1024)]
+        #[case::kilo("2K", 2 * 1024)]
+        #[case::kibi("2KiB", 2 * 1024)]
+        #[case::mebi("64Mi", 64 * 1024 * 1024)]
+        #[case::lowercase("1g", 1024 * 1024 * 1024)]
+        fn バイト数は単位付きで指定できる(
+            #[case] value: &str,
+            #[case] expected: usize,
+        ) {
+            let cmd = Command::try_parse_from(["kubetui", "--log-max-bytes", value]).unwrap();
+            assert_eq!(cmd.log_view_config().max_bytes, Some(expected))
+        }
+
+        #[rstest]
+        #[case::unknown_unit("10T")]
+        #[case::no_value("MiB")]
+        #[case::negative("-1")]
+        fn 不正なバイト数を指定するとエラーを返す(#[case] value: &str) {
+            let cmd = Command::try_parse_from(["kubetui", "--log-max-bytes", value]);
+            assert!(cmd.is_err_and(|err| matches!(
+                err.kind(),
+                ErrorKind::ValueValidation | ErrorKind::UnknownArgument
+            )))
+        }
+    }
+
+    mod max_fps {
+        use clap::error::ErrorKind;
+        use pretty_assertions::assert_eq;
+        use rstest::rstest;
+
+        use super::*;
+
+        #[rstest]
+        #[case::default(None, Duration::from_nanos(33_333_333))]
+        #[case::one(Some("1"), Duration::from_secs(1))]
+        #[case::sixty(Some("60"), Duration::from_nanos(16_666_666))]
+        fn fpsから描画の最小間隔を返す(
+            #[case] value: Option<&str>,
+            #[case] expected: Duration,
+        ) {
+            let mut args = vec!["kubetui"];
+            if let Some(value) = value {
+                args.extend(["--max-fps", value]);
+            }
+
+            let cmd = Command::try_parse_from(args).unwrap();
+            assert_eq!(cmd.frame_interval(), expected)
+        }
+
+        #[test]
+        fn ゼロを指定するとエラーを返す() {
+            let cmd = Command::try_parse_from(["kubetui", "--max-fps", "0"]);
+            assert_eq!(cmd.unwrap_err().kind(), ErrorKind::ValueValidation)
+        }
+    }
+
+    mod record {
+        use clap::error::ErrorKind;
+        use pretty_assertions::assert_eq;
+
+        use super::*;
+
+        #[test]
+        fn replayを指定すると再生モードになる() {
+            let cmd = Command::try_parse_from(["kubetui", "--replay", "session.jsonl"]).unwrap();
+
+            let config = cmd.render_config();
+
+            assert!(config.replay);
+            assert_eq!(config.record_file, None);
+        }
+
+        #[test]
+        fn recordとreplayを併用するとエラーを返す() {
+            let cmd =
+                Command::try_parse_from(["kubetui", "--record", "a.jsonl", "--replay", "b.jsonl"]);
+            assert_eq!(cmd.unwrap_err().kind(), ErrorKind::ArgumentConflict)
+        }
+    }
+
+    mod snapshot {
+        use clap::error::ErrorKind;
+        use pretty_assertions::assert_eq;
+        use rstest::rstest;
+
+        use super::*;
+
+        fn snapshot_args(cmd: Command) -> SnapshotArgs {
+            let Some(SubCommand::Snapshot(args)) = cmd.subcommand else {
+                panic!("snapshot subcommand is not set");
+            };
+
+            args
+        }
+
+        #[rstest]
+        #[case::singular("pod", SnapshotTab::Pod)]
+        #[case::plural("pods", SnapshotTab::Pod)]
+        #[case::config("configs", SnapshotTab::Config)]
+        #[case::event("events", SnapshotTab::Event)]
+        #[case::network("network", SnapshotTab::Network)]
+        fn タブを指定できる(#[case] value: &str, #[case] expected: SnapshotTab) {
+            let cmd = Command::try_parse_from(["kubetui", "snapshot", "--tab", value]).unwrap();
+
+            let args = snapshot_args(cmd);
+
+            assert_eq!(args.tab, expected);
+            assert_eq!(args.format, SnapshotFormat::Text);
+        }
+
+        #[test]
+        fn サブコマンドの後にnamespaceとフォーマットを指定できる() {
+            let cmd = Command::try_parse_from([
+                "kubetui", "snapshot", "--tab", "pods", "-n", "foo", "--format", "json", "-l",
+                "app=web",
+            ])
+            .unwrap();
+
+            assert_eq!(cmd.namespaces, Some(vec!["foo".to_string()]));
+
+            let args = snapshot_args(cmd);
+
+            assert_eq!(args.format, SnapshotFormat::Json);
+            assert_eq!(args.selector, Some("app=web".to_string()));
+        }
+
+        #[test]
+        fn サブコマンドの後にall_namespacesを指定できる() {
+            let cmd =
+                Command::try_parse_from(["kubetui", "snapshot", "-t", "events", "-A"]).unwrap();
+
+            assert_eq!(cmd.all_namespaces, AllNamespaces::True);
+        }
+
+        #[test]
+        fn タブを指定しないとエラーを返す() {
+            let cmd = Command::try_parse_from(["kubetui", "snapshot"]);
+            assert_eq!(cmd.unwrap_err().kind(), ErrorKind::MissingRequiredArgument)
+        }
+
+        #[test]
+        fn サブコマンドを指定しない場合はnoneを返す() {
+            let cmd = Command::try_parse_from(["kubetui"]).unwrap();
+            assert!(cmd.subcommand.is_none())
+        }
+    }
+
+    mod logs {
+        use clap::error::ErrorKind;
+        use pretty_assertions::assert_eq;
+        use rstest::rstest;
+
+        use super::*;
+
+        fn logs_args(cmd: Command) -> LogsArgs {
+            let Some(SubCommand::Logs(args)) = cmd.subcommand else {
+                panic!("logs subcommand is not set");
+            };
+
+            args
+        }
+
+        #[test]
+        fn クエリと出力形式を指定できる() {
+            let cmd = Command::try_parse_from([
+                "kubetui",
+                "logs",
+                "pod:app container:nginx",
+                "-n",
+                "foo",
+                "--no-color",
+            ])
+            .unwrap();
+
+            assert_eq!(cmd.namespaces, Some(vec!["foo".to_string()]));
+
+            let args = logs_args(cmd);
+
+            assert_eq!(args.query, "pod:app container:nginx");
+            assert!(args.no_color);
+            assert!(!args.json);
+            assert_eq!(args.since(), None);
+        }
+
+        #[rstest]
+        #[case::seconds("30s", Duration::from_secs(30))]
+        #[case::minutes("5m", Duration::from_secs(300))]
+        #[case::combined("1h30m", Duration::from_secs(5400))]
+        #[case::zero("0s", Duration::ZERO)]
+        fn sinceは単位付きで指定できる(#[case] value: &str, #[case] expected: Duration) {
+            let cmd =
+                Command::try_parse_from(["kubetui", "logs", "pod:app", "--since", value]).unwrap();
+
+            assert_eq!(logs_args(cmd).since(), Some(expected));
+        }
+
+        #[rstest]
+        #[case::no_unit("30")]
+        #[case::unknown_unit("1d")]
+        #[case::no_value("m")]
+        #[case::empty("")]
+        fn 不正なsinceを指定するとエラーを返す(#[case] value: &str) {
+            let cmd = Command::try_parse_from(["kubetui", "logs", "pod:app", "--since", value]);
+            assert_eq!(cmd.unwrap_err().kind(), ErrorKind::ValueValidation)
+        }
+
+        #[test]
+        fn クエリを指定しないとエラーを返す() {
+            let cmd = Command::try_parse_from(["kubetui", "logs"]);
+            assert_eq!(cmd.unwrap_err().kind(), ErrorKind::MissingRequiredArgument)
+        }
+    }
+
     mod all_namespace {
         use clap::error::ErrorKind;
         use pretty_assertions::assert_eq;
@@ -167,8 +600,9 @@ mod tests {
 
         #[test]
         fn equalがない構文のときエラーになる() {
+            // 値として扱われなかった`true`はサブコマンドとして解釈される
             let cmd = Command::try_parse_from(["kubetui", "--all-namespaces", "true"]);
-            assert_eq!(cmd.unwrap_err().kind(), ErrorKind::UnknownArgument)
+            assert_eq!(cmd.unwrap_err().kind(), ErrorKind::InvalidSubcommand)
         }
 
         #[rstest]
@@ -192,4 +626,76 @@ mod tests {
             assert_eq!(cmd.unwrap_err().kind(), ErrorKind::ArgumentConflict)
         }
     }
+
+    mod kubeconfig {
+        use pretty_assertions::assert_eq;
+
+        use super::*;
+
+        #[test]
+        fn 区切り文字と複数指定で複数のファイルを指定できる() {
+            let paths = format!("a.yaml{}b.yaml", KUBECONFIG_SEPARATOR);
+
+            let cmd = Command::try_parse_from(["kubetui", "-C", &paths, "-C", "c.yaml"]).unwrap();
+
+            assert_eq!(
+                cmd.kube_worker_config().kubeconfig,
+                vec![
+                    PathBuf::from("a.yaml"),
+                    PathBuf::from("b.yaml"),
+                    PathBuf::from("c.yaml")
+                ]
+            )
+        }
+
+        #[test]
+        fn 指定しないときは空になる() {
+            let cmd = Command::try_parse_from(["kubetui"]).unwrap();
+
+            assert!(cmd.kube_worker_config().kubeconfig.is_empty())
+        }
+    }
+
+    mod impersonation {
+        use clap::error::ErrorKind;
+        use pretty_assertions::assert_eq;
+
+        use super::*;
+
+        #[test]
+        fn ユーザーとグループを指定できる() {
+            let cmd = Command::try_parse_from([
+                "kubetui",
+                "--as",
+                "alice",
+                "--as-group",
+                "dev,ops",
+                "--as-group",
+                "qa",
+            ])
+            .unwrap();
+
+            assert_eq!(
+                cmd.kube_worker_config().impersonation,
+                Some(Impersonation::new(
+                    "alice",
+                    vec!["dev".to_string(), "ops".to_string(), "qa".to_string()]
+                ))
+            )
+        }
+
+        #[test]
+        fn 指定しないときは偽装しない() {
+            let cmd = Command::try_parse_from(["kubetui"]).unwrap();
+
+            assert_eq!(cmd.kube_worker_config().impersonation, None)
+        }
+
+        #[test]
+        fn ユーザーを指定せずにグループを指定するとエラーを返す() {
+            let cmd = Command::try_parse_from(["kubetui", "--as-group", "dev"]);
+
+            assert_eq!(cmd.unwrap_err().kind(), ErrorKind::MissingRequiredArgument)
+        }
+    }
 }
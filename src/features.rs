@@ -1,11 +1,17 @@
+pub mod access_review;
 pub mod api_resources;
 pub mod component_id;
 pub mod config;
 pub mod context;
+pub mod custom_resource;
 pub mod event;
 pub mod get;
 pub mod help;
+pub mod impersonation;
+pub mod label_selector;
 pub mod namespace;
 pub mod network;
+pub mod owner_tree;
 pub mod pod;
+pub mod resource_search;
 pub mod yaml;
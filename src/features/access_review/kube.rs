@@ -0,0 +1,824 @@
+mod binding;
+mod query;
+
+use anyhow::{anyhow, Result};
+use crossbeam::channel::Sender;
+use futures::{future::join_all, stream, StreamExt as _};
+use k8s_openapi::api::{
+    authentication::v1::SelfSubjectReview,
+    authorization::v1::{
+        ResourceAttributes, ResourceRule, SelfSubjectAccessReview, SelfSubjectAccessReviewSpec,
+        SelfSubjectRulesReview, SelfSubjectRulesReviewSpec, SubjectAccessReview,
+        SubjectAccessReviewSpec, SubjectAccessReviewStatus, SubjectRulesReviewStatus,
+    },
+    rbac::v1::PolicyRule,
+};
+use kube::{api::PostParams, Api};
+
+use crate::{
+    features::{
+        access_review::message::{AccessReviewRequest, AccessReviewResponse},
+        api_resources::kube::{ApiResource, ApiResources, SharedApiResources},
+    },
+    kube::KubeClientRequest,
+    logger,
+    message::Message,
+    workers::kube::AbortWorker,
+};
+
+use self::{
+    binding::{fetch_bindings, fetch_rules, rule_grants, Binding, Identity},
+    query::{Query, Subject, Target},
+};
+
+/// 一覧で確認する操作
+const MATRIX_VERBS: [&str; 7] = [
+    "get", "list", "watch", "create", "update", "patch", "delete",
+];
+
+/// ServiceAccountの一覧を確認するときに同時に送るSubjectAccessReviewの上限
+const MAX_CONCURRENT_REQUESTS: usize = 20;
+
+/// 一覧の1行（リソースと操作ごとの結果）
+type MatrixRow = (String, Vec<Result<bool>>);
+
+/// 入力に従って権限を確認する
+///
+/// API resourcesのロックは複製した時点で解放する
+#[derive(Clone)]
+pub struct AccessReviewWorker<C>
+where
+    C: KubeClientRequest,
+{
+    tx: Sender<Message>,
+    client: C,
+    shared_api_resources: SharedApiResources,
+    req: AccessReviewRequest,
+}
+
+impl<C: KubeClientRequest> AccessReviewWorker<C> {
+    pub fn new(
+        tx: Sender<Message>,
+        client: C,
+        shared_api_resources: SharedApiResources,
+        req: AccessReviewRequest,
+    ) -> Self {
+        Self {
+            tx,
+            client,
+            shared_api_resources,
+            req,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<C: KubeClientRequest> AbortWorker for AccessReviewWorker<C> {
+    async fn run(&self) {
+        let api_resources = self.shared_api_resources.read().await.clone();
+
+        let ret = review_access(&self.client, &api_resources, &self.req).await;
+
+        self.tx
+            .send(AccessReviewResponse(ret).into())
+            .expect("Failed to send AccessReviewResponse");
+    }
+}
+
+/// 権限を確認するリソース
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceRef {
+    pub group: String,
+    pub resource: String,
+    pub subresource: Option<String>,
+    /// クラスタースコープのリソースはnamespaceを指定せずに確認する
+    pub namespaced: bool,
+}
+
+impl ResourceRef {
+    fn from_api_resource(api_resource: &ApiResource) -> Self {
+        Self {
+            group: api_resource.group().to_string(),
+            resource: api_resource.name().to_string(),
+            subresource: None,
+            namespaced: api_resource.is_namespaced(),
+        }
+    }
+
+    /// `pods`、`deployments.apps`、`pods/log`の形式のリソースをAPIの一覧から探す
+    ///
+    /// グループを省略した場合はコアAPI、優先バージョンの順に探し、見つからない場合は入力のまま扱う
+    fn resolve(s: &str, api_resources: &ApiResources) -> Self {
+        let (name, subresource) = s
+            .split_once('/')
+            .map(|(name, sub)| (name, Some(sub.to_string())))
+            .unwrap_or((s, None));
+
+        let found = api_resources
+            .iter()
+            .filter(|r| r.name() == name)
+            .min_by_key(|r| (!r.is_api(), !r.is_preferred_version()))
+            .or_else(|| {
+                let (name, group) = name.split_once('.')?;
+
+                api_resources
+                    .iter()
+                    .filter(|r| r.name() == name && r.group() == group)
+                    .min_by_key(|r| !r.is_preferred_version())
+            });
+
+        match found {
+            Some(api_resource) => Self {
+                subresource,
+                ..Self::from_api_resource(api_resource)
+            },
+            None => {
+                let (name, group) = name.split_once('.').unwrap_or((name, ""));
+
+                Self {
+                    group: group.to_string(),
+                    resource: name.to_string(),
+                    subresource,
+                    namespaced: true,
+                }
+            }
+        }
+    }
+
+    /// Role、ClusterRoleのルールで指定する形式（`pods/log`）
+    fn rule_name(&self) -> String {
+        match &self.subresource {
+            Some(sub) => format!("{}/{}", self.resource, sub),
+            None => self.resource.to_string(),
+        }
+    }
+
+    fn attributes(&self, verb: &str, namespace: &str) -> ResourceAttributes {
+        ResourceAttributes {
+            group: Some(self.group.to_string()),
+            resource: Some(self.resource.to_string()),
+            subresource: self.subresource.clone(),
+            verb: Some(verb.to_string()),
+            namespace: self.namespaced.then(|| namespace.to_string()),
+            ..Default::default()
+        }
+    }
+}
+
+impl std::fmt::Display for ResourceRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.resource)?;
+
+        if !self.group.is_empty() {
+            write!(f, ".{}", self.group)?;
+        }
+
+        if let Some(sub) = &self.subresource {
+            write!(f, "/{}", sub)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// SelfSubjectAccessReview（ServiceAccountの場合はSubjectAccessReview）で操作が許可されているかを確認し、
+/// 許可しているRoleBinding、ClusterRoleBindingと合わせて結果を行ごとに返す
+async fn review_access<C: KubeClientRequest>(
+    client: &C,
+    api_resources: &ApiResources,
+    req: &AccessReviewRequest,
+) -> Result<Vec<String>> {
+    let query = Query::parse(&req.query, &req.namespace)?;
+
+    let identity = match &query.subject {
+        Subject::CurrentUser => current_identity(client).await,
+        Subject::ServiceAccount { namespace, name } => {
+            Ok(Identity::service_account(namespace, name))
+        }
+    };
+
+    match &query.target {
+        Target::Check { verb, resource } => {
+            let resource = ResourceRef::resolve(resource, api_resources);
+
+            let status = review(client, &query.subject, &query.namespace, verb, &resource).await?;
+
+            let granted_by = match &identity {
+                Ok(identity) => {
+                    granting_bindings(client, &query.namespace, identity, verb, &resource).await
+                }
+                Err(err) => Err(anyhow!("cannot determine the current user: {}", err)),
+            };
+
+            Ok(check_report(
+                &query,
+                identity.as_ref().ok(),
+                verb,
+                &resource,
+                &status,
+                granted_by,
+            ))
+        }
+        Target::Matrix { resources } => {
+            let resources: Vec<ResourceRef> = if resources.is_empty() {
+                api_resources
+                    .namespaced_kinds()
+                    .map(ResourceRef::from_api_resource)
+                    .collect()
+            } else {
+                resources
+                    .iter()
+                    .map(|r| ResourceRef::resolve(r, api_resources))
+                    .collect()
+            };
+
+            let (rows, incomplete) = match &query.subject {
+                Subject::CurrentUser => {
+                    let status = review_rules(client, &query.namespace).await?;
+
+                    let incomplete = status
+                        .incomplete
+                        .then(|| status.evaluation_error.unwrap_or_default());
+
+                    (matrix_rows(&status.resource_rules, &resources), incomplete)
+                }
+                Subject::ServiceAccount { .. } => {
+                    (review_matrix(client, &query, &resources).await, None)
+                }
+            };
+
+            let bindings = match &identity {
+                Ok(identity) => fetch_bindings(client, Some(&query.namespace), identity).await,
+                Err(err) => Err(anyhow!("cannot determine the current user: {}", err)),
+            };
+
+            Ok(matrix_report(
+                &query,
+                identity.as_ref().ok(),
+                &rows,
+                incomplete.as_deref(),
+                bindings,
+            ))
+        }
+    }
+}
+
+/// 接続に使用しているユーザー名とグループ
+///
+/// SelfSubjectReviewはKubernetes 1.28以降で使用できる
+async fn current_identity<C: KubeClientRequest>(client: &C) -> Result<Identity> {
+    let api: Api<SelfSubjectReview> = Api::all(client.client().clone());
+
+    let review = api
+        .create(&PostParams::default(), &SelfSubjectReview::default())
+        .await?;
+
+    let user_info = review
+        .status
+        .and_then(|status| status.user_info)
+        .ok_or_else(|| anyhow!("SelfSubjectReview returned no user info"))?;
+
+    Ok(Identity {
+        user: user_info.username.unwrap_or_default(),
+        groups: user_info.groups.unwrap_or_default(),
+    })
+}
+
+async fn review<C: KubeClientRequest>(
+    client: &C,
+    subject: &Subject,
+    namespace: &str,
+    verb: &str,
+    resource: &ResourceRef,
+) -> Result<SubjectAccessReviewStatus> {
+    let attributes = resource.attributes(verb, namespace);
+
+    let status = match subject {
+        Subject::CurrentUser => {
+            let api: Api<SelfSubjectAccessReview> = Api::all(client.client().clone());
+
+            let review = SelfSubjectAccessReview {
+                spec: SelfSubjectAccessReviewSpec {
+                    resource_attributes: Some(attributes),
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+
+            api.create(&PostParams::default(), &review).await?.status
+        }
+        Subject::ServiceAccount { namespace, name } => {
+            let api: Api<SubjectAccessReview> = Api::all(client.client().clone());
+
+            let Identity { user, groups } = Identity::service_account(namespace, name);
+
+            let review = SubjectAccessReview {
+                spec: SubjectAccessReviewSpec {
+                    resource_attributes: Some(attributes),
+                    user: Some(user),
+                    groups: Some(groups),
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+
+            api.create(&PostParams::default(), &review).await?.status
+        }
+    };
+
+    status.ok_or_else(|| anyhow!("access review returned no status"))
+}
+
+/// namespaceで接続中のユーザーに許可されているルール
+async fn review_rules<C: KubeClientRequest>(
+    client: &C,
+    namespace: &str,
+) -> Result<SubjectRulesReviewStatus> {
+    let api: Api<SelfSubjectRulesReview> = Api::all(client.client().clone());
+
+    let review = SelfSubjectRulesReview {
+        spec: SelfSubjectRulesReviewSpec {
+            namespace: Some(namespace.to_string()),
+        },
+        ..Default::default()
+    };
+
+    api.create(&PostParams::default(), &review)
+        .await?
+        .status
+        .ok_or_else(|| anyhow!("SelfSubjectRulesReview returned no status"))
+}
+
+/// SelfSubjectRulesReviewのルールから操作とリソースの組み合わせごとに許可されているかを判定する
+fn matrix_rows(rules: &[ResourceRule], resources: &[ResourceRef]) -> Vec<MatrixRow> {
+    let rules: Vec<PolicyRule> = rules
+        .iter()
+        .map(|rule| PolicyRule {
+            api_groups: rule.api_groups.clone(),
+            resource_names: rule.resource_names.clone(),
+            resources: rule.resources.clone(),
+            verbs: rule.verbs.clone(),
+            ..Default::default()
+        })
+        .collect();
+
+    resources
+        .iter()
+        .map(|resource| {
+            let cells = MATRIX_VERBS
+                .iter()
+                .map(|verb| Ok(rules.iter().any(|rule| rule_grants(rule, verb, resource))))
+                .collect();
+
+            (resource.to_string(), cells)
+        })
+        .collect()
+}
+
+/// 操作とリソースの組み合わせごとにSubjectAccessReviewで確認する
+async fn review_matrix<C: KubeClientRequest>(
+    client: &C,
+    query: &Query,
+    resources: &[ResourceRef],
+) -> Vec<MatrixRow> {
+    let reviews: Vec<_> = resources
+        .iter()
+        .flat_map(|resource| {
+            MATRIX_VERBS.iter().map(move |verb| async move {
+                review(client, &query.subject, &query.namespace, verb, resource)
+                    .await
+                    .map(|status| status.allowed)
+            })
+        })
+        .collect();
+
+    let cells: Vec<Result<bool>> = stream::iter(reviews)
+        .buffered(MAX_CONCURRENT_REQUESTS)
+        .collect()
+        .await;
+
+    let mut cells = cells.into_iter();
+
+    resources
+        .iter()
+        .map(|resource| {
+            (
+                resource.to_string(),
+                cells.by_ref().take(MATRIX_VERBS.len()).collect(),
+            )
+        })
+        .collect()
+}
+
+/// ユーザーを含むバインディングのうち、参照するロールのルールが操作を許可しているもの
+async fn granting_bindings<C: KubeClientRequest>(
+    client: &C,
+    namespace: &str,
+    identity: &Identity,
+    verb: &str,
+    resource: &ResourceRef,
+) -> Result<Vec<Binding>> {
+    let namespace = resource.namespaced.then_some(namespace);
+
+    let bindings = fetch_bindings(client, namespace, identity).await?;
+
+    let rules = join_all(bindings.iter().map(|binding| fetch_rules(client, binding))).await;
+
+    Ok(bindings
+        .into_iter()
+        .zip(rules)
+        .filter_map(|(binding, rules)| match rules {
+            Ok(rules) => rules
+                .iter()
+                .any(|rule| rule_grants(rule, verb, resource))
+                .then_some(binding),
+            Err(err) => {
+                logger!(error, "Failed to fetch the role of {}: {:?}", binding, err);
+                None
+            }
+        })
+        .collect())
+}
+
+fn subject_line(subject: &Subject, identity: Option<&Identity>) -> String {
+    match (subject, identity) {
+        (Subject::CurrentUser, Some(identity)) => {
+            format!("Subject:   {} ({})", subject, identity.user)
+        }
+        _ => format!("Subject:   {}", subject),
+    }
+}
+
+fn binding_lines(bindings: Result<Vec<Binding>>, none: &str) -> Vec<String> {
+    match bindings {
+        Ok(bindings) if bindings.is_empty() => vec![format!("  {}", none)],
+        Ok(bindings) => bindings.iter().map(|b| format!("  {}", b)).collect(),
+        Err(err) => vec![format!("  {:#}", err)],
+    }
+}
+
+fn check_report(
+    query: &Query,
+    identity: Option<&Identity>,
+    verb: &str,
+    resource: &ResourceRef,
+    status: &SubjectAccessReviewStatus,
+    granted_by: Result<Vec<Binding>>,
+) -> Vec<String> {
+    let result = if status.allowed { "yes" } else { "no" };
+
+    let namespace = if resource.namespaced {
+        query.namespace.as_str()
+    } else {
+        "(cluster-scoped)"
+    };
+
+    let mut lines = vec![
+        format!("Result: {}", result),
+        String::new(),
+        subject_line(&query.subject, identity),
+        format!("Verb:      {}", verb),
+        format!("Resource:  {}", resource),
+        format!("Namespace: {}", namespace),
+    ];
+
+    if let Some(reason) = status.reason.as_ref().filter(|r| !r.is_empty()) {
+        lines.push(format!("Reason:    {}", reason));
+    }
+
+    if let Some(err) = status.evaluation_error.as_ref().filter(|e| !e.is_empty()) {
+        lines.push(format!("Error:     {}", err));
+    }
+
+    lines.push(String::new());
+    lines.push("Granted by:".to_string());
+    lines.extend(binding_lines(
+        granted_by,
+        "(no RoleBinding or ClusterRoleBinding grants it)",
+    ));
+
+    lines
+}
+
+fn matrix_cell(result: &Result<bool>) -> &'static str {
+    match result {
+        Ok(true) => "\x1b[32m✓\x1b[39m",
+        Ok(false) => "\x1b[31m✗\x1b[39m",
+        Err(_) => "\x1b[33m?\x1b[39m",
+    }
+}
+
+fn matrix_report(
+    query: &Query,
+    identity: Option<&Identity>,
+    rows: &[MatrixRow],
+    incomplete: Option<&str>,
+    bindings: Result<Vec<Binding>>,
+) -> Vec<String> {
+    let name_width = rows
+        .iter()
+        .map(|(name, _)| name.len())
+        .chain(["RESOURCE".len()])
+        .max()
+        .unwrap_or_default();
+
+    let column_width = MATRIX_VERBS
+        .iter()
+        .map(|v| v.len())
+        .max()
+        .unwrap_or_default()
+        + 1;
+
+    let header = MATRIX_VERBS.iter().fold(
+        format!("{:<width$}", "RESOURCE", width = name_width + 2),
+        |header, verb| header + &format!("{:<width$}", verb.to_uppercase(), width = column_width),
+    );
+
+    let mut lines = vec![
+        subject_line(&query.subject, identity),
+        format!("Namespace: {}", query.namespace),
+    ];
+
+    // Webhookなどルールを列挙できない認可方式では、許可されている操作が✗になる場合がある
+    match incomplete {
+        Some(err) if !err.is_empty() => {
+            lines.push(format!("Note:      rules may be incomplete: {}", err))
+        }
+        Some(_) => lines.push("Note:      rules may be incomplete".to_string()),
+        None => {}
+    }
+
+    lines.push(String::new());
+    lines.push(header.trim_end().to_string());
+
+    for (name, cells) in rows {
+        let line = cells.iter().fold(
+            format!("{:<width$}", name, width = name_width + 2),
+            |line, cell| line + matrix_cell(cell) + &" ".repeat(column_width - 1),
+        );
+
+        lines.push(line.trim_end().to_string());
+
+        for err in cells.iter().filter_map(|cell| cell.as_ref().err()) {
+            logger!(error, "Failed to review access to {}: {:?}", name, err);
+        }
+    }
+
+    lines.push(String::new());
+    lines.push("Bindings:".to_string());
+    lines.extend(binding_lines(
+        bindings,
+        "(no RoleBinding or ClusterRoleBinding includes the subject)",
+    ));
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use k8s_openapi::api::rbac::v1::RoleRef;
+    use kube::discovery::Scope;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn api_resources() -> ApiResources {
+        ApiResources::from([
+            ApiResource::Api {
+                name: "pods".to_string(),
+                version: "v1".to_string(),
+                scope: Scope::Namespaced,
+            },
+            ApiResource::Api {
+                name: "nodes".to_string(),
+                version: "v1".to_string(),
+                scope: Scope::Cluster,
+            },
+            ApiResource::Apis {
+                name: "deployments".to_string(),
+                group: "apps".to_string(),
+                version: "v1".to_string(),
+                preferred_version: true,
+                scope: Scope::Namespaced,
+            },
+            ApiResource::Apis {
+                name: "events".to_string(),
+                group: "events.k8s.io".to_string(),
+                version: "v1".to_string(),
+                preferred_version: true,
+                scope: Scope::Namespaced,
+            },
+            ApiResource::Api {
+                name: "events".to_string(),
+                version: "v1".to_string(),
+                scope: Scope::Namespaced,
+            },
+        ])
+    }
+
+    fn resource(
+        group: &str,
+        name: &str,
+        subresource: Option<&str>,
+        namespaced: bool,
+    ) -> ResourceRef {
+        ResourceRef {
+            group: group.to_string(),
+            resource: name.to_string(),
+            subresource: subresource.map(String::from),
+            namespaced,
+        }
+    }
+
+    #[rstest]
+    #[case("pods", resource("", "pods", None, true))]
+    #[case("pods/log", resource("", "pods", Some("log"), true))]
+    #[case("deployments", resource("apps", "deployments", None, true))]
+    #[case(
+        "deployments.apps/scale",
+        resource("apps", "deployments", Some("scale"), true)
+    )]
+    #[case("nodes", resource("", "nodes", None, false))]
+    #[case("events", resource("", "events", None, true))]
+    #[case(
+        "events.events.k8s.io",
+        resource("events.k8s.io", "events", None, true)
+    )]
+    #[case("widgets.example.com", resource("example.com", "widgets", None, true))]
+    fn リソースをapiの一覧から解決する(
+        #[case] input: &str,
+        #[case] expected: ResourceRef,
+    ) {
+        assert_eq!(ResourceRef::resolve(input, &api_resources()), expected);
+    }
+
+    fn binding(namespace: Option<&str>, name: &str, kind: &str, role: &str) -> Binding {
+        Binding {
+            namespace: namespace.map(String::from),
+            name: name.to_string(),
+            role_ref: RoleRef {
+                api_group: "rbac.authorization.k8s.io".to_string(),
+                kind: kind.to_string(),
+                name: role.to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn 確認した結果と許可しているバインディングを表示する() {
+        let query = Query::parse("get pods --sa app", "dev").unwrap();
+
+        let status = SubjectAccessReviewStatus {
+            allowed: true,
+            reason: Some("RBAC: allowed by RoleBinding \"view\"".to_string()),
+            ..Default::default()
+        };
+
+        let actual = check_report(
+            &query,
+            None,
+            "get",
+            &resource("", "pods", None, true),
+            &status,
+            Ok(vec![
+                binding(Some("dev"), "view", "ClusterRole", "view"),
+                binding(None, "readers", "ClusterRole", "reader"),
+            ]),
+        );
+
+        assert_eq!(
+            actual.join("\n"),
+            indoc! {r#"
+                Result: yes
+
+                Subject:   serviceaccount dev/app
+                Verb:      get
+                Resource:  pods
+                Namespace: dev
+                Reason:    RBAC: allowed by RoleBinding "view"
+
+                Granted by:
+                  RoleBinding dev/view -> ClusterRole/view
+                  ClusterRoleBinding readers -> ClusterRole/reader"#
+            }
+        );
+    }
+
+    #[test]
+    fn 操作とリソースの組み合わせを一覧で表示する() {
+        let query = Query::parse("--list", "dev").unwrap();
+
+        let identity = Identity {
+            user: "alice".to_string(),
+            groups: vec![],
+        };
+
+        let allowed = |allowed: bool| Ok(allowed);
+
+        let rows = vec![
+            (
+                "pods".to_string(),
+                vec![
+                    allowed(true),
+                    allowed(true),
+                    allowed(true),
+                    allowed(false),
+                    allowed(false),
+                    allowed(false),
+                    Err(anyhow!("timeout")),
+                ],
+            ),
+            (
+                "deployments.apps".to_string(),
+                MATRIX_VERBS.iter().map(|_| allowed(false)).collect(),
+            ),
+        ];
+
+        let actual = matrix_report(&query, Some(&identity), &rows, None, Ok(vec![]));
+
+        let yes = "\x1b[32m✓\x1b[39m";
+        let no = "\x1b[31m✗\x1b[39m";
+        let error = "\x1b[33m?\x1b[39m";
+
+        assert_eq!(
+            actual,
+            vec![
+                "Subject:   current user (alice)".to_string(),
+                "Namespace: dev".to_string(),
+                String::new(),
+                "RESOURCE          GET    LIST   WATCH  CREATE UPDATE PATCH  DELETE".to_string(),
+                format!(
+                    "pods              {y}      {y}      {y}      {n}      {n}      {n}      {e}",
+                    y = yes,
+                    n = no,
+                    e = error
+                ),
+                format!(
+                    "deployments.apps  {n}      {n}      {n}      {n}      {n}      {n}      {n}",
+                    n = no
+                ),
+                String::new(),
+                "Bindings:".to_string(),
+                "  (no RoleBinding or ClusterRoleBinding includes the subject)".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn ルールから操作とリソースの組み合わせを判定する() {
+        let rules = vec![
+            ResourceRule {
+                api_groups: Some(vec!["".to_string()]),
+                resources: Some(vec!["pods".to_string()]),
+                verbs: vec!["get".to_string(), "list".to_string()],
+                ..Default::default()
+            },
+            ResourceRule {
+                api_groups: Some(vec!["apps".to_string()]),
+                resources: Some(vec!["deployments".to_string()]),
+                resource_names: Some(vec!["app".to_string()]),
+                verbs: vec!["*".to_string()],
+            },
+        ];
+
+        let actual: Vec<(String, Vec<bool>)> = matrix_rows(
+            &rules,
+            &[
+                resource("", "pods", None, true),
+                resource("apps", "deployments", None, true),
+            ],
+        )
+        .into_iter()
+        .map(|(name, cells)| (name, cells.into_iter().map(Result::unwrap).collect()))
+        .collect();
+
+        assert_eq!(
+            actual,
+            vec![
+                (
+                    "pods".to_string(),
+                    vec![true, true, false, false, false, false, false]
+                ),
+                ("deployments.apps".to_string(), vec![false; 7]),
+            ]
+        );
+    }
+
+    #[test]
+    fn ルールの一覧が不完全な場合は注記する() {
+        let query = Query::parse("--list", "dev").unwrap();
+
+        let actual = matrix_report(&query, None, &[], Some("webhook"), Ok(vec![]));
+
+        assert_eq!(
+            actual[..3],
+            [
+                "Subject:   current user".to_string(),
+                "Namespace: dev".to_string(),
+                "Note:      rules may be incomplete: webhook".to_string(),
+            ]
+        );
+    }
+}
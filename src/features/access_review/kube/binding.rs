@@ -0,0 +1,257 @@
+use anyhow::Result;
+use k8s_openapi::{
+    api::rbac::v1::{
+        ClusterRole, ClusterRoleBinding, PolicyRule, Role, RoleBinding, RoleRef,
+        Subject as RbacSubject,
+    },
+    List,
+};
+use kube::{Resource, ResourceExt as _};
+
+use crate::kube::KubeClientRequest;
+
+use super::{query::Subject, ResourceRef};
+
+/// 権限を確認するユーザー名と所属するグループ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Identity {
+    pub user: String,
+    pub groups: Vec<String>,
+}
+
+impl Identity {
+    /// ServiceAccountとして認証されたときのユーザー名とグループ
+    pub fn service_account(namespace: &str, name: &str) -> Self {
+        Self {
+            user: Subject::service_account_user(namespace, name),
+            groups: vec![
+                "system:serviceaccounts".to_string(),
+                format!("system:serviceaccounts:{}", namespace),
+                "system:authenticated".to_string(),
+            ],
+        }
+    }
+
+    /// バインディングのsubjectがこのユーザーを指しているか
+    ///
+    /// RoleBindingのServiceAccountでnamespaceを省略した場合はRoleBindingのnamespaceとして扱う
+    fn matches(&self, subject: &RbacSubject, binding_namespace: Option<&str>) -> bool {
+        match subject.kind.as_str() {
+            "User" => subject.name == self.user,
+            "Group" => self.groups.contains(&subject.name),
+            "ServiceAccount" => subject
+                .namespace
+                .as_deref()
+                .or(binding_namespace)
+                .is_some_and(|ns| Subject::service_account_user(ns, &subject.name) == self.user),
+            _ => false,
+        }
+    }
+}
+
+/// ユーザーにロールを割り当てているRoleBindingまたはClusterRoleBinding
+#[derive(Debug, Clone, PartialEq)]
+pub struct Binding {
+    /// ClusterRoleBindingの場合は`None`
+    pub namespace: Option<String>,
+    pub name: String,
+    pub role_ref: RoleRef,
+}
+
+impl std::fmt::Display for Binding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.namespace {
+            Some(ns) => write!(f, "RoleBinding {}/{}", ns, self.name)?,
+            None => write!(f, "ClusterRoleBinding {}", self.name)?,
+        }
+
+        write!(f, " -> {}/{}", self.role_ref.kind, self.role_ref.name)
+    }
+}
+
+/// namespaceのRoleBindingとClusterRoleBindingのうち、ユーザーを含むものを返す
+///
+/// namespaceが`None`の場合はClusterRoleBindingのみを対象とする
+pub async fn fetch_bindings<C: KubeClientRequest>(
+    client: &C,
+    namespace: Option<&str>,
+    identity: &Identity,
+) -> Result<Vec<Binding>> {
+    let role_bindings: Vec<RoleBinding> = match namespace {
+        Some(ns) => {
+            let list: List<RoleBinding> = client
+                .request(&RoleBinding::url_path(&(), Some(ns)))
+                .await?;
+
+            list.items
+        }
+        None => Vec::new(),
+    };
+
+    let cluster_role_bindings: List<ClusterRoleBinding> = client
+        .request(&ClusterRoleBinding::url_path(&(), None))
+        .await?;
+
+    let role_bindings = role_bindings.into_iter().map(|binding| {
+        (
+            binding.namespace().or_else(|| namespace.map(String::from)),
+            binding.name_any(),
+            binding.role_ref,
+            binding.subjects,
+        )
+    });
+
+    let cluster_role_bindings = cluster_role_bindings
+        .items
+        .into_iter()
+        .map(|binding| (None, binding.name_any(), binding.role_ref, binding.subjects));
+
+    Ok(role_bindings
+        .chain(cluster_role_bindings)
+        .filter(|(namespace, _, _, subjects)| {
+            subjects
+                .iter()
+                .flatten()
+                .any(|subject| identity.matches(subject, namespace.as_deref()))
+        })
+        .map(|(namespace, name, role_ref, _)| Binding {
+            namespace,
+            name,
+            role_ref,
+        })
+        .collect())
+}
+
+/// バインディングが参照するRoleまたはClusterRoleのルール
+pub async fn fetch_rules<C: KubeClientRequest>(
+    client: &C,
+    binding: &Binding,
+) -> Result<Vec<PolicyRule>> {
+    let RoleRef { kind, name, .. } = &binding.role_ref;
+
+    let rules = match (kind.as_str(), &binding.namespace) {
+        ("Role", Some(ns)) => {
+            let url = format!("{}/{}", Role::url_path(&(), Some(ns)), name);
+
+            let role: Role = client.request(&url).await?;
+
+            role.rules
+        }
+        _ => {
+            let url = format!("{}/{}", ClusterRole::url_path(&(), None), name);
+
+            let role: ClusterRole = client.request(&url).await?;
+
+            role.rules
+        }
+    };
+
+    Ok(rules.unwrap_or_default())
+}
+
+/// ルールが名前を限定せずにリソースへの操作を許可しているか
+pub fn rule_grants(rule: &PolicyRule, verb: &str, resource: &ResourceRef) -> bool {
+    let contains = |values: &Option<Vec<String>>, value: &str| {
+        values.iter().flatten().any(|v| v == "*" || v == value)
+    };
+
+    let resources = rule.resources.iter().flatten().any(|r| {
+        r == "*"
+            || *r == resource.rule_name()
+            || resource
+                .subresource
+                .as_ref()
+                .is_some_and(|sub| *r == format!("*/{}", sub))
+    });
+
+    rule.verbs.iter().any(|v| v == "*" || v == verb)
+        && contains(&rule.api_groups, &resource.group)
+        && resources
+        && rule.resource_names.iter().flatten().next().is_none()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn subject(kind: &str, name: &str, namespace: Option<&str>) -> RbacSubject {
+        RbacSubject {
+            kind: kind.to_string(),
+            name: name.to_string(),
+            namespace: namespace.map(String::from),
+            ..Default::default()
+        }
+    }
+
+    fn rule(verbs: &[&str], api_groups: &[&str], resources: &[&str]) -> PolicyRule {
+        let strings = |values: &[&str]| Some(values.iter().map(|v| v.to_string()).collect());
+
+        PolicyRule {
+            verbs: verbs.iter().map(|v| v.to_string()).collect(),
+            api_groups: strings(api_groups),
+            resources: strings(resources),
+            ..Default::default()
+        }
+    }
+
+    fn resource(group: &str, name: &str, subresource: Option<&str>) -> ResourceRef {
+        ResourceRef {
+            group: group.to_string(),
+            resource: name.to_string(),
+            subresource: subresource.map(String::from),
+            namespaced: true,
+        }
+    }
+
+    #[rstest]
+    #[case(subject("ServiceAccount", "app", Some("dev")), None, true)]
+    #[case(subject("ServiceAccount", "app", None), Some("dev"), true)]
+    #[case(subject("ServiceAccount", "app", None), Some("prod"), false)]
+    #[case(subject("ServiceAccount", "other", Some("dev")), None, false)]
+    #[case(subject("User", "system:serviceaccount:dev:app", None), None, true)]
+    #[case(subject("Group", "system:serviceaccounts:dev", None), None, true)]
+    #[case(subject("Group", "system:serviceaccounts:prod", None), None, false)]
+    fn serviceaccountを指すsubjectを判定する(
+        #[case] subject: RbacSubject,
+        #[case] binding_namespace: Option<&str>,
+        #[case] expected: bool,
+    ) {
+        let identity = Identity::service_account("dev", "app");
+
+        assert_eq!(identity.matches(&subject, binding_namespace), expected);
+    }
+
+    #[rstest]
+    #[case(rule(&["get", "list"], &[""], &["pods"]), "get", resource("", "pods", None), true)]
+    #[case(rule(&["get"], &[""], &["pods"]), "delete", resource("", "pods", None), false)]
+    #[case(rule(&["*"], &["*"], &["*"]), "delete", resource("apps", "deployments", None), true)]
+    #[case(rule(&["get"], &[""], &["deployments"]), "get", resource("apps", "deployments", None), false)]
+    #[case(rule(&["get"], &[""], &["pods"]), "get", resource("", "pods", Some("log")), false)]
+    #[case(rule(&["get"], &[""], &["pods/log"]), "get", resource("", "pods", Some("log")), true)]
+    #[case(rule(&["get"], &[""], &["*/log"]), "get", resource("", "pods", Some("log")), true)]
+    fn ルールが操作を許可しているか判定する(
+        #[case] rule: PolicyRule,
+        #[case] verb: &str,
+        #[case] resource: ResourceRef,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(rule_grants(&rule, verb, &resource), expected);
+    }
+
+    #[test]
+    fn 名前を限定したルールは許可しているとみなさない() {
+        let rule = PolicyRule {
+            resource_names: Some(vec!["app-config".to_string()]),
+            ..rule(&["get"], &[""], &["configmaps"])
+        };
+
+        assert!(!rule_grants(
+            &rule,
+            "get",
+            &resource("", "configmaps", None)
+        ));
+    }
+}
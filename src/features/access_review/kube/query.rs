@@ -0,0 +1,204 @@
+use anyhow::{bail, Result};
+
+const USAGE: &str =
+    "expected '<verb> <resource> | --list [<resource>...]' [-n <namespace>] [--sa [<namespace>/]<name>]";
+
+/// 権限を確認する対象
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Subject {
+    /// 接続に使用しているユーザー（偽装している場合は偽装したユーザー）
+    CurrentUser,
+    ServiceAccount {
+        namespace: String,
+        name: String,
+    },
+}
+
+impl Subject {
+    /// SubjectAccessReviewで指定するユーザー名
+    pub fn service_account_user(namespace: &str, name: &str) -> String {
+        format!("system:serviceaccount:{}:{}", namespace, name)
+    }
+}
+
+impl std::fmt::Display for Subject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CurrentUser => write!(f, "current user"),
+            Self::ServiceAccount { namespace, name } => {
+                write!(f, "serviceaccount {}/{}", namespace, name)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Target {
+    /// 1つの操作を確認する
+    Check { verb: String, resource: String },
+    /// 操作とリソースの組み合わせを一覧で確認する（空の場合はすべてのリソース）
+    Matrix { resources: Vec<String> },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Query {
+    pub subject: Subject,
+    pub namespace: String,
+    pub target: Target,
+}
+
+impl Query {
+    /// `kubectl auth can-i`と同様の形式の入力を解析する
+    ///
+    /// namespaceを省略した場合は`namespace`を使用し、ServiceAccountのnamespaceも同様に補完する
+    pub fn parse(query: &str, namespace: &str) -> Result<Self> {
+        let mut tokens = query.split_whitespace();
+
+        let mut namespace = namespace.to_string();
+        let mut service_account: Option<String> = None;
+        let mut is_list = false;
+        let mut args = Vec::new();
+
+        while let Some(token) = tokens.next() {
+            match token {
+                "-n" | "--namespace" => {
+                    let Some(value) = tokens.next() else {
+                        bail!("missing namespace: {}", USAGE);
+                    };
+
+                    namespace = value.to_string();
+                }
+                "--sa" | "--serviceaccount" => {
+                    let Some(value) = tokens.next() else {
+                        bail!("missing serviceaccount: {}", USAGE);
+                    };
+
+                    service_account = Some(value.to_string());
+                }
+                "--list" => is_list = true,
+                _ if token.starts_with('-') => bail!("unknown option '{}': {}", token, USAGE),
+                _ => args.push(token.to_string()),
+            }
+        }
+
+        let subject = match service_account {
+            Some(sa) => {
+                let (sa_namespace, name) = sa.split_once('/').unwrap_or((&namespace, &sa));
+
+                if sa_namespace.is_empty() || name.is_empty() || name.contains('/') {
+                    bail!("invalid serviceaccount '{}': {}", sa, USAGE);
+                }
+
+                Subject::ServiceAccount {
+                    namespace: sa_namespace.to_string(),
+                    name: name.to_string(),
+                }
+            }
+            None => Subject::CurrentUser,
+        };
+
+        let target = if is_list {
+            Target::Matrix { resources: args }
+        } else {
+            let mut args = args.into_iter();
+
+            match (args.next(), args.next(), args.next()) {
+                (Some(verb), Some(resource), None) => Target::Check { verb, resource },
+                (None, _, _) => bail!("missing verb and resource: {}", USAGE),
+                _ => bail!("expected a verb and a resource: {}", USAGE),
+            }
+        };
+
+        Ok(Self {
+            subject,
+            namespace,
+            target,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn service_account(namespace: &str, name: &str) -> Subject {
+        Subject::ServiceAccount {
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+        }
+    }
+
+    fn check(verb: &str, resource: &str) -> Target {
+        Target::Check {
+            verb: verb.to_string(),
+            resource: resource.to_string(),
+        }
+    }
+
+    #[rstest]
+    #[case("get pods", Subject::CurrentUser, "default", check("get", "pods"))]
+    #[case(
+        "delete deployments.apps -n kube-system",
+        Subject::CurrentUser,
+        "kube-system",
+        check("delete", "deployments.apps")
+    )]
+    #[case(
+        "get pods/log --sa app",
+        service_account("default", "app"),
+        "default",
+        check("get", "pods/log")
+    )]
+    #[case(
+        "--sa monitoring/prometheus list pods -n app",
+        service_account("monitoring", "prometheus"),
+        "app",
+        check("list", "pods")
+    )]
+    #[case(
+        "--list",
+        Subject::CurrentUser,
+        "default",
+        Target::Matrix { resources: vec![] }
+    )]
+    #[case(
+        "--list pods secrets --sa app -n dev",
+        service_account("dev", "app"),
+        "dev",
+        Target::Matrix { resources: vec!["pods".to_string(), "secrets".to_string()] }
+    )]
+    fn 操作とリソース対象を解析する(
+        #[case] query: &str,
+        #[case] subject: Subject,
+        #[case] namespace: &str,
+        #[case] target: Target,
+    ) {
+        let actual = Query::parse(query, "default").unwrap();
+
+        assert_eq!(
+            actual,
+            Query {
+                subject,
+                namespace: namespace.to_string(),
+                target,
+            }
+        );
+    }
+
+    #[rstest]
+    #[case("", "missing verb and resource")]
+    #[case("get", "expected a verb and a resource")]
+    #[case("get pods secrets", "expected a verb and a resource")]
+    #[case("get pods -n", "missing namespace")]
+    #[case("get pods --sa", "missing serviceaccount")]
+    #[case("get pods --sa a/b/c", "invalid serviceaccount 'a/b/c'")]
+    #[case("get pods --as admin", "unknown option '--as'")]
+    fn 不正な入力はエラーを返す(#[case] query: &str, #[case] expected: &str) {
+        let err = Query::parse(query, "default").unwrap_err().to_string();
+
+        assert!(err.starts_with(expected), "{}", err);
+    }
+}
@@ -0,0 +1,36 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    message::Message,
+    workers::{kube::message::Kube, replay::serde_result},
+};
+
+/// 権限を確認する入力（namespaceは入力で省略したときに使用する）
+#[derive(Debug, Clone)]
+pub struct AccessReviewRequest {
+    pub query: String,
+    pub namespace: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AccessReviewResponse(#[serde(with = "serde_result")] pub Result<Vec<String>>);
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum AccessReviewMessage {
+    #[serde(skip)]
+    Request(AccessReviewRequest),
+    Response(AccessReviewResponse),
+}
+
+impl From<AccessReviewRequest> for Message {
+    fn from(req: AccessReviewRequest) -> Self {
+        Message::Kube(Kube::AccessReview(AccessReviewMessage::Request(req)))
+    }
+}
+
+impl From<AccessReviewResponse> for Message {
+    fn from(res: AccessReviewResponse) -> Self {
+        Message::Kube(Kube::AccessReview(AccessReviewMessage::Response(res)))
+    }
+}
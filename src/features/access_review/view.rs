@@ -0,0 +1,3 @@
+mod popup;
+
+pub use popup::*;
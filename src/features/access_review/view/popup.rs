@@ -0,0 +1,110 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crossbeam::channel::Sender;
+use ratatui::crossterm::event::KeyCode;
+
+use crate::{
+    clipboard::Clipboard,
+    features::{
+        access_review::message::AccessReviewRequest,
+        component_id::{ACCESS_REVIEW_POPUP_ID, ACCESS_REVIEW_RESULT_POPUP_ID},
+    },
+    kube::context::Namespace,
+    message::{Message, UserEvent},
+    ui::{
+        event::EventResult,
+        widget::{
+            config::WidgetConfig, input::InputFormBuilder, SelectedItem, Text, Widget,
+            WidgetTrait as _,
+        },
+        Window,
+    },
+};
+
+pub struct AccessReviewPopup {
+    pub popup: Widget<'static>,
+    pub result_popup: Widget<'static>,
+}
+
+impl AccessReviewPopup {
+    pub fn new(
+        tx: &Sender<Message>,
+        clipboard: &Option<Rc<RefCell<Clipboard>>>,
+        namespaces: &Rc<RefCell<Namespace>>,
+    ) -> Self {
+        let popup = InputFormBuilder::default()
+            .id(ACCESS_REVIEW_POPUP_ID)
+            .widget_config(
+                WidgetConfig::builder()
+                    .title("Can I: <verb> <resource> | --list [<resource>...] [-n <namespace>] [--sa [<namespace>/]<name>]")
+                    .build(),
+            )
+            .actions(
+                UserEvent::from(KeyCode::Enter),
+                review_access(tx.clone(), namespaces.clone()),
+            )
+            .build()
+            .into();
+
+        let builder = Text::builder()
+            .id(ACCESS_REVIEW_RESULT_POPUP_ID)
+            .widget_config(&WidgetConfig::builder().title("Access Review").build())
+            .wrap();
+
+        let result_popup = if let Some(cb) = clipboard {
+            builder.clipboard(cb.clone())
+        } else {
+            builder
+        }
+        .build()
+        .into();
+
+        Self {
+            popup,
+            result_popup,
+        }
+    }
+}
+
+pub fn open_access_review_popup(w: &mut Window) -> EventResult {
+    w.open_popup(ACCESS_REVIEW_POPUP_ID);
+    EventResult::Nop
+}
+
+/// namespaceを省略した場合は選択しているnamespaceの先頭を使用する
+fn review_access(
+    tx: Sender<Message>,
+    namespaces: Rc<RefCell<Namespace>>,
+) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let Some(SelectedItem::Literal { item: query, .. }) =
+            w.find_widget(ACCESS_REVIEW_POPUP_ID).widget_item()
+        else {
+            return EventResult::Ignore;
+        };
+
+        if query.trim().is_empty() {
+            return EventResult::Nop;
+        }
+
+        let namespace = namespaces
+            .borrow()
+            .first()
+            .cloned()
+            .unwrap_or_else(|| "default".to_string());
+
+        w.close_popup();
+
+        *(w.find_widget_mut(ACCESS_REVIEW_RESULT_POPUP_ID)
+            .widget_config_mut()
+            .append_title_mut()) = Some(format!(" : {}", query.trim()).into());
+
+        w.widget_clear(ACCESS_REVIEW_RESULT_POPUP_ID);
+        w.open_popup(ACCESS_REVIEW_RESULT_POPUP_ID);
+
+        tx.send(AccessReviewRequest { query, namespace }.into())
+            .expect("Failed to send AccessReviewRequest");
+
+        EventResult::Nop
+    }
+}
@@ -1,4 +1,4 @@
-use std::{fmt::Display, hash::Hash, ops::Deref, sync::Arc, time};
+use std::{collections::BTreeMap, fmt::Display, hash::Hash, ops::Deref, sync::Arc, time};
 
 use anyhow::Result;
 use async_trait::async_trait;
@@ -16,14 +16,14 @@ use crate::{
     kube::{
         apis::{
             metrics::{NodeMetricsList, PodMetricsList},
-            v1_table::{Table, TableColumnDefinition, Value},
+            v1_table::{Table, TableColumnDefinition, TableRow, Value},
         },
         table::insert_ns,
         KubeClient, KubeClientRequest as _,
     },
     workers::kube::{
-        PollerBase, SharedTargetApiResources, TargetApiResources, TargetNamespaces, Worker,
-        WorkerResult,
+        is_connection_error, PollerBase, SharedTargetApiResources, TargetApiResources,
+        TargetNamespaces, Worker, WorkerResult,
     },
 };
 
@@ -45,6 +45,17 @@ impl ApiResources {
     pub fn shared() -> SharedApiResources {
         Arc::new(RwLock::new(Default::default()))
     }
+
+    /// 複数のリソースを横断して一覧を取得する際の対象
+    ///
+    /// namespaceスコープのリソースのうち、同じリソースを別のグループやバージョンで重複して取得しないように優先バージョンのみを返す
+    pub fn namespaced_kinds(&self) -> impl Iterator<Item = &ApiResource> {
+        self.inner.iter().filter(|kind| {
+            kind.is_namespaced()
+                && (kind.is_api() || kind.is_preferred_version())
+                && kind.name() != "events"
+        })
+    }
 }
 
 impl Deref for ApiResources {
@@ -165,6 +176,14 @@ impl ApiResource {
         }
     }
 
+    /// `pods`や`replicasets.apps`のようにkubectl getで指定する形式
+    pub fn kind_name(&self) -> String {
+        match self {
+            Self::Api { name, .. } => name.to_string(),
+            Self::Apis { name, group, .. } => format!("{}.{}", name, group),
+        }
+    }
+
     pub fn api_url_with_namespace(&self, ns: &str) -> String {
         format!(
             "{}/namespaces/{}/{}",
@@ -225,6 +244,86 @@ impl Display for ApiResource {
     }
 }
 
+/// Listタブの1行
+///
+/// テーブルの行の場合は、その行のリソースを保持する
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListLine {
+    pub content: String,
+    pub target: Option<ResourceTarget>,
+}
+
+/// API resourceとnamespace、名前で特定したリソース
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceTarget {
+    pub kind: ApiResource,
+    pub namespace: String,
+    pub name: String,
+}
+
+impl From<&str> for ListLine {
+    fn from(content: &str) -> Self {
+        Self {
+            content: content.to_string(),
+            target: None,
+        }
+    }
+}
+
+impl ResourceTarget {
+    /// サーバーサイドのテーブルの行に含まれるメタデータからリソースを特定する
+    pub fn from_row(kind: &ApiResource, row: &TableRow) -> Option<Self> {
+        let metadata = row.object.as_ref()?.0.get("metadata")?;
+
+        let name = metadata.get("name")?.as_str()?.to_string();
+
+        let namespace = metadata
+            .get("namespace")
+            .and_then(JsonValue::as_str)
+            .unwrap_or_default()
+            .to_string();
+
+        Some(Self {
+            kind: kind.clone(),
+            namespace,
+            name,
+        })
+    }
+
+    /// ウィジェットのアイテムに持たせるメタデータ
+    ///
+    /// Yamlタブの名前選択と同じく`namespace`、`name`、`key`（ApiResourceのJSON）で表す
+    pub fn to_metadata(&self) -> BTreeMap<String, String> {
+        let Ok(json) = serde_json::to_string(&self.kind) else {
+            unreachable!()
+        };
+
+        BTreeMap::from([
+            ("namespace".to_string(), self.namespace.clone()),
+            ("name".to_string(), self.name.clone()),
+            ("key".to_string(), json),
+        ])
+    }
+
+    pub fn from_metadata(metadata: &BTreeMap<String, String>) -> Option<Self> {
+        let (Some(namespace), Some(name), Some(key)) = (
+            metadata.get("namespace"),
+            metadata.get("name"),
+            metadata.get("key"),
+        ) else {
+            return None;
+        };
+
+        let kind = serde_json::from_str(key).ok()?;
+
+        Some(Self {
+            kind,
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+        })
+    }
+}
+
 #[derive(Clone)]
 pub struct ApiPoller {
     base: PollerBase,
@@ -258,37 +357,33 @@ impl Worker for ApiPoller {
                     tx,
                     shared_target_namespaces,
                     kube_client,
+                    connection,
+                    ..
                 },
             shared_target_api_resources,
             shared_api_resources,
         } = self;
 
-        match fetch_api_resources(kube_client).await {
-            Ok(fetched) => {
-                let mut api_resources = shared_api_resources.write().await;
-                *api_resources = fetched;
-            }
-            Err(err) => {
-                tx.send(ApiResponse::Poll(Err(err)).into())
-                    .expect("Failed to send ApiResponse::Poll");
-            }
-        }
-
         let mut interval = tokio::time::interval(time::Duration::from_millis(1000));
 
-        let mut last_tick = Instant::now();
+        let mut last_tick: Option<Instant> = None;
         let tick_rate = time::Duration::from_secs(10);
 
         let mut is_error = false;
 
         while !is_terminated.load(std::sync::atomic::Ordering::Relaxed) {
-            interval.tick().await;
+            connection.tick(&mut interval).await;
+
+            // 接続できずに取得できなかった場合は、再接続の間隔で取得し直す
+            if last_tick.is_none_or(|last_tick| tick_rate < last_tick.elapsed()) {
+                let result = fetch_api_resources(kube_client).await;
 
-            if tick_rate < last_tick.elapsed() {
-                last_tick = Instant::now();
+                connection.report(&result);
 
-                match fetch_api_resources(kube_client).await {
+                match result {
                     Ok(fetched) => {
+                        last_tick = Some(Instant::now());
+
                         let mut api_resources = shared_api_resources.write().await;
                         *api_resources = fetched;
 
@@ -300,6 +395,10 @@ impl Worker for ApiPoller {
                         }
                     }
                     Err(err) => {
+                        if !is_connection_error(&err) {
+                            last_tick = Some(Instant::now());
+                        }
+
                         tx.send(ApiResponse::Poll(Err(err)).into())
                             .expect("Failed to send ApiResponse::Poll");
                         is_error = true;
@@ -491,7 +590,7 @@ impl<'a> FetchTargetApiResources<'a> {
         }
     }
 
-    async fn fetch_table(&self) -> Result<Vec<String>> {
+    async fn fetch_table(&self) -> Result<Vec<ListLine>> {
         let mut ret = Vec::new();
         for api_resource in self.target_api_resources {
             let table = if api_resource.is_namespaced() {
@@ -501,14 +600,23 @@ impl<'a> FetchTargetApiResources<'a> {
                 get_table_cluster_resource(self.client, &api_resource.api_url()).await
             }?;
 
-            let data = if table.rows.is_empty() {
-                api_resource.to_table_header()
-            } else {
-                api_resource.to_table_header() + &table.to_print()
-            };
+            ret.extend(api_resource.to_table_header().lines().map(ListLine::from));
+
+            if !table.rows.is_empty() {
+                let data = table.to_print();
+
+                let mut lines = data.lines();
+
+                // 1行目はヘッダーで、以降はテーブルの行と同じ順番
+                ret.extend(lines.next().map(ListLine::from));
+
+                ret.extend(lines.zip(&table.rows).map(|(line, row)| ListLine {
+                    content: line.to_string(),
+                    target: ResourceTarget::from_row(api_resource, row),
+                }));
+            }
 
-            ret.extend(data.lines().map(ToString::to_string).collect::<Vec<_>>());
-            ret.push("".to_string());
+            ret.push(ListLine::from(""));
         }
 
         Ok(ret)
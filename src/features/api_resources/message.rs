@@ -1,8 +1,12 @@
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
 
-use crate::{message::Message, workers::kube::message::Kube};
+use crate::{
+    message::Message,
+    workers::{kube::message::Kube, replay::serde_result},
+};
 
-use super::kube::ApiResource;
+use super::kube::{ApiResource, ListLine};
 
 #[derive(Debug)]
 pub enum ApiRequest {
@@ -10,14 +14,15 @@ pub enum ApiRequest {
     Set(Vec<ApiResource>),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum ApiResponse {
-    Get(Result<Vec<ApiResource>>),
-    Poll(Result<Vec<String>>),
+    Get(#[serde(with = "serde_result")] Result<Vec<ApiResource>>),
+    Poll(#[serde(with = "serde_result")] Result<Vec<ListLine>>),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum ApiMessage {
+    #[serde(skip)]
     Request(ApiRequest),
     Response(ApiResponse),
 }
@@ -5,8 +5,9 @@ use crossbeam::channel::Sender;
 use crate::{
     clipboard::Clipboard,
     features::{
-        api_resources::message::ApiRequest,
+        api_resources::{kube::ResourceTarget, message::ApiRequest},
         component_id::{LIST_POPUP_ID, LIST_WIDGET_ID},
+        owner_tree::view::open_owner_tree_popup,
     },
     message::Message,
     ui::{
@@ -20,13 +21,14 @@ pub fn list_widget(
     tx: &Sender<Message>,
     clipboard: &Option<Rc<RefCell<Clipboard>>>,
 ) -> Widget<'static> {
-    let tx = tx.clone();
-
-    let open_subwin = move |w: &mut Window| {
-        tx.send(ApiRequest::Get.into())
-            .expect("Failed to send ApiRequest::Get");
-        w.open_popup(LIST_POPUP_ID);
-        EventResult::Nop
+    let open_subwin = {
+        let tx = tx.clone();
+        move |w: &mut Window| {
+            tx.send(ApiRequest::Get.into())
+                .expect("Failed to send ApiRequest::Get");
+            w.open_popup(LIST_POPUP_ID);
+            EventResult::Nop
+        }
     };
 
     let builder = Text::builder()
@@ -41,7 +43,8 @@ pub fn list_widget(
 
             config.render_block(text.can_activate() && is_active, is_mouse_over)
         })
-        .action('f', open_subwin);
+        .action('f', open_subwin)
+        .action('t', open_owner_tree(tx.clone()));
 
     if let Some(cb) = clipboard {
         builder.clipboard(cb.clone())
@@ -51,3 +54,22 @@ pub fn list_widget(
     .build()
     .into()
 }
+
+/// 選択中の行（画面の一番上の行か検索で選択中の行）のリソースの所有関係を表示する
+fn open_owner_tree(tx: Sender<Message>) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let Some(target) = w
+            .find_widget(LIST_WIDGET_ID)
+            .as_text()
+            .selected_literal_item()
+            .and_then(|item| item.metadata.as_ref())
+            .and_then(ResourceTarget::from_metadata)
+        else {
+            return EventResult::Ignore;
+        };
+
+        open_owner_tree_popup(w, &tx, target);
+
+        EventResult::Nop
+    }
+}
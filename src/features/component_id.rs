@@ -18,6 +18,8 @@ component_id!(
     list_tab,
     network_tab,
     yaml_tab,
+    custom_resource_tab,
+    namespace_tab,
     // widgets
     pod_widget,
     pod_log_widget,
@@ -29,8 +31,17 @@ component_id!(
     event_widget,
     list_widget,
     yaml_widget,
+    custom_resource_widget,
+    namespace_widget,
+    namespace_description_widget,
     // popups
     pod_log_query_help_popup,
+    pod_log_bookmark_popup,
+    pod_log_save_query_popup,
+    pod_log_saved_queries_popup,
+    network_graph_popup,
+    network_reachability_popup,
+    network_reachability_result_popup,
     context_popup,
     single_namespace_popup,
     multiple_namespaces_popup,
@@ -38,6 +49,15 @@ component_id!(
     yaml_kind_popup,
     yaml_name_popup,
     yaml_not_found_popup,
+    custom_resource_kind_popup,
+    custom_resource_explain_popup,
+    owner_tree_popup,
+    resource_search_popup,
+    resource_search_result_popup,
+    label_selector_popup,
+    impersonation_popup,
+    access_review_popup,
+    access_review_result_popup,
     help_popup,
     yaml_popup
 );
@@ -1,7 +1,7 @@
 use std::{collections::BTreeMap, time};
 
 use crate::{
-    features::config::message::ConfigResponse,
+    features::{config::message::ConfigResponse, label_selector::kube::LabelSelectorTarget},
     kube::{
         apis::v1_table::TableRow,
         table::{get_resource_per_namespace, insert_ns, KubeTable, KubeTableRow},
@@ -39,17 +39,17 @@ impl Worker for ConfigPoller {
                 PollerBase {
                     is_terminated,
                     tx,
-                    shared_target_namespaces,
-                    kube_client,
+                    connection,
+                    ..
                 },
         } = self;
 
         while !is_terminated.load(std::sync::atomic::Ordering::Relaxed) {
-            interval.tick().await;
+            connection.tick(&mut interval).await;
 
-            let target_namespaces = shared_target_namespaces.read().await;
+            let table = self.poll().await;
 
-            let table = fetch_configs(kube_client, &target_namespaces).await;
+            connection.report(&table);
 
             tx.send(ConfigResponse::Table(table).into())
                 .expect("Failed to send ConfigResponse::Table");
@@ -59,6 +59,28 @@ impl Worker for ConfigPoller {
     }
 }
 
+impl ConfigPoller {
+    /// 対象のnamespaceのConfigMapとSecretの一覧を取得する
+    pub async fn poll(&self) -> Result<KubeTable> {
+        let PollerBase {
+            shared_target_namespaces,
+            shared_label_selectors,
+            kube_client,
+            ..
+        } = &self.base;
+
+        let target_namespaces = shared_target_namespaces.read().await;
+
+        let label_selector = shared_label_selectors
+            .read()
+            .await
+            .get(&LabelSelectorTarget::Config)
+            .cloned();
+
+        fetch_configs(kube_client, &target_namespaces, label_selector.as_deref()).await
+    }
+}
+
 #[derive(Clone, Copy)]
 enum Configs {
     ConfigMap,
@@ -84,6 +106,7 @@ impl Configs {
 async fn fetch_configs_per_namespace(
     client: &KubeClient,
     namespaces: &[String],
+    label_selector: Option<&str>,
     ty: Configs,
 ) -> Result<Vec<KubeTableRow>> {
     let insert_ns = insert_ns(namespaces);
@@ -91,6 +114,7 @@ async fn fetch_configs_per_namespace(
         get_resource_per_namespace(
             client,
             format!("api/v1/namespaces/{}/{}", ns, ty.kind()),
+            label_selector,
             &["Name", r#"Data"#, "Age"],
             move |row: &TableRow, indexes: &[usize]| {
                 let mut row = vec![
@@ -121,7 +145,11 @@ async fn fetch_configs_per_namespace(
     Ok(jobs.into_iter().flatten().collect())
 }
 
-async fn fetch_configs(client: &KubeClient, namespaces: &[String]) -> Result<KubeTable> {
+async fn fetch_configs(
+    client: &KubeClient,
+    namespaces: &[String],
+    label_selector: Option<&str>,
+) -> Result<KubeTable> {
     let mut table = KubeTable {
         header: if namespaces.len() == 1 {
             ["KIND", "NAME", "DATA", "AGE"]
@@ -138,8 +166,8 @@ async fn fetch_configs(client: &KubeClient, namespaces: &[String]) -> Result<Kub
     };
 
     let jobs = try_join_all([
-        fetch_configs_per_namespace(client, namespaces, Configs::ConfigMap),
-        fetch_configs_per_namespace(client, namespaces, Configs::Secret),
+        fetch_configs_per_namespace(client, namespaces, label_selector, Configs::ConfigMap),
+        fetch_configs_per_namespace(client, namespaces, label_selector, Configs::Secret),
     ])
     .await?;
 
@@ -1,6 +1,11 @@
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
 
-use crate::{kube::table::KubeTable, message::Message, workers::kube::message::Kube};
+use crate::{
+    kube::table::KubeTable,
+    message::Message,
+    workers::{kube::message::Kube, replay::serde_result},
+};
 
 pub type ConfigData = Vec<String>;
 
@@ -10,8 +15,9 @@ pub struct RequestData {
     pub namespace: String,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum ConfigMessage {
+    #[serde(skip)]
     Request(ConfigRequest),
     Response(ConfigResponse),
 }
@@ -22,10 +28,10 @@ pub enum ConfigRequest {
     Secret(RequestData),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum ConfigResponse {
-    Table(Result<KubeTable>),
-    Data(Result<ConfigData>),
+    Table(#[serde(with = "serde_result")] Result<KubeTable>),
+    Data(#[serde(with = "serde_result")] Result<ConfigData>),
 }
 
 impl ConfigRequest {
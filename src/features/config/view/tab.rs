@@ -6,6 +6,7 @@ use ratatui::layout::{Constraint, Direction};
 use crate::{
     clipboard::Clipboard,
     features::component_id::CONFIG_TAB_ID,
+    history::HistoryHandle,
     message::Message,
     ui::{
         tab::{LayoutElement, NestedLayoutElement, NestedWidgetLayout},
@@ -25,8 +26,9 @@ impl ConfigTab {
         tx: &Sender<Message>,
         clipboard: &Option<Rc<RefCell<Clipboard>>>,
         split_direction: Direction,
+        history: &HistoryHandle,
     ) -> Self {
-        let config_widget = config_widget(tx);
+        let config_widget = config_widget(tx, history);
         let raw_data_widget = raw_data_widget(clipboard);
 
         let layout = layout(split_direction);
@@ -5,6 +5,7 @@ use crate::{
         component_id::{CONFIG_RAW_DATA_WIDGET_ID, CONFIG_WIDGET_ID},
         config::message::{ConfigRequest, RequestData},
     },
+    history::HistoryHandle,
     message::Message,
     ui::{
         event::EventResult,
@@ -13,13 +14,17 @@ use crate::{
     },
 };
 
-pub fn config_widget(tx: &Sender<Message>) -> Widget<'static> {
+pub fn config_widget(tx: &Sender<Message>, history: &HistoryHandle) -> Widget<'static> {
     let tx = tx.clone();
 
     Table::builder()
         .id(CONFIG_WIDGET_ID)
         .widget_config(&WidgetConfig::builder().title("Config").build())
         .filtered_key("NAME")
+        .filter_history(
+            history.loader("config_filter"),
+            history.saver("config_filter"),
+        )
         .block_injection(block_injection())
         .on_select(on_select(tx))
         .build()
@@ -1,7 +1,12 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
 use crate::{message::Message, workers::kube::message::Kube};
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum ContextMessage {
+    #[serde(skip)]
     Request(ContextRequest),
     Response(ContextResponse),
 }
@@ -12,9 +17,20 @@ pub enum ContextRequest {
     Set(String),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum ContextResponse {
-    Get(Vec<String>),
+    Get(Vec<ContextInfo>),
+}
+
+/// コンテキストの一覧に表示する情報
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContextInfo {
+    pub name: String,
+    pub cluster: String,
+    pub user: String,
+    pub namespace: Option<String>,
+    /// コンテキストを定義したkubeconfigのファイル
+    pub source: Option<PathBuf>,
 }
 
 impl From<ContextMessage> for Message {
@@ -1,3 +1,8 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::Path,
+};
+
 use crossbeam::channel::Sender;
 
 use crate::{
@@ -5,16 +10,21 @@ use crate::{
         component_id::{
             CONFIG_RAW_DATA_WIDGET_ID, CONFIG_WIDGET_ID, CONTEXT_POPUP_ID, EVENT_WIDGET_ID, LIST_POPUP_ID, LIST_WIDGET_ID, MULTIPLE_NAMESPACES_POPUP_ID, NETWORK_DESCRIPTION_WIDGET_ID, NETWORK_WIDGET_ID, POD_LOG_QUERY_WIDGET_ID, POD_LOG_WIDGET_ID, POD_WIDGET_ID, YAML_WIDGET_ID
         },
-        context::message::ContextRequest,
+        context::message::{ContextInfo, ContextRequest},
     },
     message::Message,
     ui::{
         event::EventResult,
-        widget::{config::WidgetConfig, LiteralItem, SingleSelect, Widget},
+        widget::{config::WidgetConfig, Item, LiteralItem, SingleSelect, Widget, WidgetTrait as _},
         Window,
     },
 };
 
+const CONTEXT_METADATA_KEY: &str = "context";
+
+/// 列の間の空白
+const COLUMN_SPACING: &str = "  ";
+
 pub struct ContextPopup {
     pub popup: Widget<'static>,
 }
@@ -38,9 +48,15 @@ fn popup(tx: Sender<Message>) -> Widget<'static> {
 
 fn on_select(tx: Sender<Message>) -> impl Fn(&mut Window, &LiteralItem) -> EventResult {
     move |w, v| {
-        let item = v.item.to_string();
+        let Some(item) = v
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get(CONTEXT_METADATA_KEY))
+        else {
+            return EventResult::Ignore;
+        };
 
-        tx.send(ContextRequest::Set(item).into())
+        tx.send(ContextRequest::Set(item.to_string()).into())
             .expect("Failed to send ContextRequest::Set");
 
         w.close_popup();
@@ -69,3 +85,173 @@ fn on_select(tx: Sender<Message>) -> impl Fn(&mut Window, &LiteralItem) -> Event
         EventResult::Nop
     }
 }
+
+/// コンテキストの一覧を更新する
+///
+/// 列名はタイトルに表示する
+pub fn update_context_popup(w: &mut Window, contexts: &[ContextInfo]) {
+    let (columns, items) = context_items(contexts);
+
+    let popup = w.find_widget_mut(CONTEXT_POPUP_ID);
+
+    *popup.widget_config_mut().append_title_mut() =
+        Some(format!(" [{}]", columns.join(" / ")).into());
+
+    popup.update_widget_item(Item::Array(items));
+}
+
+/// コンテキストの名前、クラスター、ユーザー、namespaceを列を揃えて並べる
+///
+/// 複数のkubeconfigを読み込んでいる場合は先頭にファイルの列を追加し、ファイルごとにまとめて表示する。
+/// フィルターは行全体に対して行うため、クラスターやユーザーでも絞り込める
+fn context_items(contexts: &[ContextInfo]) -> (Vec<&'static str>, Vec<LiteralItem>) {
+    let sources: BTreeSet<&Path> = contexts
+        .iter()
+        .filter_map(|ctx| ctx.source.as_deref())
+        .collect();
+
+    let grouped = 1 < sources.len();
+
+    let rows: Vec<Vec<String>> = contexts
+        .iter()
+        .map(|ctx| {
+            let mut row = Vec::new();
+
+            if grouped {
+                row.push(ctx.source.as_deref().map(display_path).unwrap_or_default());
+            }
+
+            row.extend([
+                ctx.name.to_string(),
+                ctx.cluster.to_string(),
+                ctx.user.to_string(),
+                ctx.namespace.clone().unwrap_or_default(),
+            ]);
+
+            row
+        })
+        .collect();
+
+    let mut widths = vec![0; rows.first().map(Vec::len).unwrap_or_default()];
+
+    for row in &rows {
+        for (width, column) in widths.iter_mut().zip(row) {
+            *width = (*width).max(column.chars().count());
+        }
+    }
+
+    let items = rows
+        .iter()
+        .zip(contexts)
+        .map(|(row, ctx)| {
+            let columns: Vec<String> = row
+                .iter()
+                .zip(&widths)
+                .enumerate()
+                .map(|(i, (column, width))| {
+                    let column = format!("{:<width$}", column, width = width);
+
+                    if grouped && i == 0 {
+                        format!("\x1b[90m{}\x1b[39m", column)
+                    } else {
+                        column
+                    }
+                })
+                .collect();
+
+            LiteralItem::new(
+                columns.join(COLUMN_SPACING).trim_end(),
+                Some(BTreeMap::from([(
+                    CONTEXT_METADATA_KEY.to_string(),
+                    ctx.name.to_string(),
+                )])),
+            )
+        })
+        .collect();
+
+    let mut columns = Vec::new();
+
+    if grouped {
+        columns.push("file");
+    }
+
+    columns.extend(["name", "cluster", "user", "namespace"]);
+
+    (columns, items)
+}
+
+/// ホームディレクトリを`~`に置き換える
+fn display_path(path: &Path) -> String {
+    let home = std::env::var_os("HOME").or_else(|| std::env::var_os("USERPROFILE"));
+
+    match home.and_then(|home| path.strip_prefix(home).ok().map(Path::to_path_buf)) {
+        Some(relative) => Path::new("~").join(relative).display().to_string(),
+        None => path.display().to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn context(name: &str, cluster: &str, source: &str) -> ContextInfo {
+        ContextInfo {
+            name: name.to_string(),
+            cluster: cluster.to_string(),
+            user: format!("{}-admin", cluster),
+            namespace: (name == "kind").then(|| "app".to_string()),
+            source: Some(PathBuf::from(source)),
+        }
+    }
+
+    #[test]
+    fn 列を揃えてクラスターとユーザーを表示する() {
+        let (columns, items) = context_items(&[
+            context("kind", "kind", "/kube/config"),
+            context("production", "eks-prod", "/kube/config"),
+        ]);
+
+        assert_eq!(columns, vec!["name", "cluster", "user", "namespace"]);
+
+        assert_eq!(
+            items
+                .iter()
+                .map(|item| item.item.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                "kind        kind      kind-admin      app",
+                "production  eks-prod  eks-prod-admin",
+            ]
+        );
+
+        assert_eq!(
+            items[1].metadata,
+            Some(BTreeMap::from([(
+                CONTEXT_METADATA_KEY.to_string(),
+                "production".to_string()
+            )]))
+        );
+    }
+
+    #[test]
+    fn 複数のkubeconfigを読み込んでいる場合はファイルの列を表示する() {
+        let (columns, items) = context_items(&[
+            context("kind", "kind", "/kube/config"),
+            context("production", "eks-prod", "/kube/eks.yaml"),
+        ]);
+
+        assert_eq!(
+            columns,
+            vec!["file", "name", "cluster", "user", "namespace"]
+        );
+
+        assert_eq!(
+            items[1].item,
+            "\x1b[90m/kube/eks.yaml\x1b[39m  production  eks-prod  eks-prod-admin"
+        );
+    }
+}
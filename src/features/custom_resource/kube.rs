@@ -0,0 +1,77 @@
+mod explain;
+mod table;
+mod worker;
+
+use std::collections::BTreeSet;
+
+use anyhow::{anyhow, Context as _, Result};
+use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition;
+use kube::{api::ListParams, Api, Resource as _, ResourceExt as _};
+
+use crate::{
+    features::api_resources::kube::{ApiResource, ApiResources},
+    kube::KubeClientRequest,
+};
+
+pub use worker::{
+    CustomResourceDefinitionsWorker, CustomResourceExplainWorker, CustomResourceWorker,
+};
+
+/// API resourcesのうちCRDで定義されているリソースを返す
+async fn fetch_custom_resources<C: KubeClientRequest>(
+    client: &C,
+    api_resources: &ApiResources,
+) -> Result<Vec<ApiResource>> {
+    let api = Api::<CustomResourceDefinition>::all(client.client().clone());
+
+    let crds: BTreeSet<String> = api
+        .list_metadata(&ListParams::default())
+        .await
+        .context("Failed to list CustomResourceDefinitions")?
+        .into_iter()
+        .map(|crd| crd.name_any())
+        .collect();
+
+    Ok(api_resources
+        .iter()
+        .filter(|api_resource| crds.contains(&crd_name(api_resource)))
+        .cloned()
+        .collect())
+}
+
+/// kubectl explainのようにカスタムリソースのスキーマを表示する
+async fn explain_custom_resource<C: KubeClientRequest>(
+    client: &C,
+    api_resource: &ApiResource,
+) -> Result<Vec<String>> {
+    let crd = fetch_custom_resource_definition(client, api_resource).await?;
+
+    explain::explain(&crd, api_resource.version())
+}
+
+async fn fetch_custom_resource_definition<C: KubeClientRequest>(
+    client: &C,
+    api_resource: &ApiResource,
+) -> Result<CustomResourceDefinition> {
+    if api_resource.is_api() {
+        return Err(anyhow!("{} is not a custom resource", api_resource));
+    }
+
+    let url = format!(
+        "{}/{}",
+        CustomResourceDefinition::url_path(&(), None),
+        crd_name(api_resource)
+    );
+
+    client.request(&url).await.with_context(|| {
+        format!(
+            "Failed to fetch CustomResourceDefinition {}",
+            crd_name(api_resource)
+        )
+    })
+}
+
+/// CRDの名前は`<plural>.<group>`
+fn crd_name(api_resource: &ApiResource) -> String {
+    format!("{}.{}", api_resource.name(), api_resource.group())
+}
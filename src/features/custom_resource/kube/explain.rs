@@ -0,0 +1,261 @@
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, Result};
+use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::{
+    CustomResourceDefinition, JSONSchemaProps, JSONSchemaPropsOrArray, JSONSchemaPropsOrBool,
+};
+
+const INDENT: &str = "  ";
+
+/// kubectl explain --recursiveのようにCRDのOpenAPIスキーマからフィールドの一覧を作成する
+///
+/// 各フィールドには型と説明を表示し、必須フィールドには`-required-`を付ける
+pub fn explain(crd: &CustomResourceDefinition, version: &str) -> Result<Vec<String>> {
+    let spec = &crd.spec;
+
+    let schema = spec
+        .versions
+        .iter()
+        .find(|v| v.name == version)
+        .ok_or_else(|| {
+            anyhow!(
+                "version {} not found in CustomResourceDefinition {}.{}",
+                version,
+                spec.names.plural,
+                spec.group
+            )
+        })?
+        .schema
+        .as_ref()
+        .and_then(|schema| schema.open_api_v3_schema.as_ref());
+
+    let mut lines = vec![
+        format!("KIND:     {}", spec.names.kind),
+        format!("GROUP:    {}", spec.group),
+        format!("VERSION:  {}", version),
+        String::new(),
+    ];
+
+    let Some(schema) = schema else {
+        lines.push("<no schema>".to_string());
+        return Ok(lines);
+    };
+
+    lines.push("DESCRIPTION:".to_string());
+
+    push_description(&mut lines, schema, 1);
+
+    if let Some(properties) = &schema.properties {
+        lines.push(String::new());
+        lines.push("FIELDS:".to_string());
+
+        push_fields(&mut lines, schema, properties, 1);
+    }
+
+    Ok(lines)
+}
+
+fn push_fields(
+    lines: &mut Vec<String>,
+    parent: &JSONSchemaProps,
+    properties: &BTreeMap<String, JSONSchemaProps>,
+    depth: usize,
+) {
+    let required = parent.required.as_deref().unwrap_or_default();
+
+    for (name, schema) in properties {
+        let required = if required.contains(name) {
+            " -required-"
+        } else {
+            ""
+        };
+
+        lines.push(format!(
+            "{}{}\t<{}>{}",
+            INDENT.repeat(depth),
+            name,
+            type_name(schema),
+            required
+        ));
+
+        push_description(lines, schema, depth + 1);
+
+        if let Some(values) = &schema.enum_ {
+            let values: Vec<String> = values.iter().map(|v| v.0.to_string()).collect();
+
+            lines.push(format!(
+                "{}ENUM: {}",
+                INDENT.repeat(depth + 1),
+                values.join(", ")
+            ));
+        }
+
+        if let Some((parent, properties)) = nested_properties(schema) {
+            push_fields(lines, parent, properties, depth + 1);
+        }
+    }
+}
+
+fn push_description(lines: &mut Vec<String>, schema: &JSONSchemaProps, depth: usize) {
+    if let Some(description) = &schema.description {
+        lines.extend(description.lines().map(|line| {
+            format!("{}{}", INDENT.repeat(depth), line)
+                .trim_end()
+                .to_string()
+        }));
+    }
+}
+
+/// オブジェクトやオブジェクトの配列が持つフィールド
+fn nested_properties(
+    schema: &JSONSchemaProps,
+) -> Option<(&JSONSchemaProps, &BTreeMap<String, JSONSchemaProps>)> {
+    let schema = match schema.type_.as_deref() {
+        Some("array") => item_schema(schema)?,
+        Some("object") => match &schema.additional_properties {
+            Some(JSONSchemaPropsOrBool::Schema(additional)) if schema.properties.is_none() => {
+                additional
+            }
+            _ => schema,
+        },
+        _ => schema,
+    };
+
+    schema
+        .properties
+        .as_ref()
+        .filter(|properties| !properties.is_empty())
+        .map(|properties| (schema, properties))
+}
+
+fn item_schema(schema: &JSONSchemaProps) -> Option<&JSONSchemaProps> {
+    match schema.items.as_ref()? {
+        JSONSchemaPropsOrArray::Schema(schema) => Some(schema),
+        JSONSchemaPropsOrArray::Schemas(schemas) => schemas.first(),
+    }
+}
+
+fn type_name(schema: &JSONSchemaProps) -> String {
+    if schema.x_kubernetes_int_or_string == Some(true) {
+        return "IntOrString".to_string();
+    }
+
+    match schema.type_.as_deref() {
+        Some("array") => {
+            let item = item_schema(schema)
+                .map(type_name)
+                .unwrap_or_else(|| "Object".to_string());
+
+            format!("[]{}", item)
+        }
+        Some("object") => match &schema.additional_properties {
+            Some(JSONSchemaPropsOrBool::Schema(additional)) if schema.properties.is_none() => {
+                format!("map[string]{}", type_name(additional))
+            }
+            _ => "Object".to_string(),
+        },
+        Some(type_) => type_.to_string(),
+        None => "Object".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn crd() -> CustomResourceDefinition {
+        serde_yaml::from_str(indoc! {r#"
+            apiVersion: apiextensions.k8s.io/v1
+            kind: CustomResourceDefinition
+            metadata:
+              name: foos.example.com
+            spec:
+              group: example.com
+              names:
+                kind: Foo
+                plural: foos
+              scope: Namespaced
+              versions:
+                - name: v1
+                  served: true
+                  storage: true
+                  schema:
+                    openAPIV3Schema:
+                      type: object
+                      description: Foo is an example resource.
+                      properties:
+                        spec:
+                          type: object
+                          description: Spec of Foo.
+                          required:
+                            - replicas
+                          properties:
+                            replicas:
+                              type: integer
+                              description: Number of replicas.
+                            port:
+                              x-kubernetes-int-or-string: true
+                            mode:
+                              type: string
+                              enum:
+                                - Active
+                                - Standby
+                            labels:
+                              type: object
+                              additionalProperties:
+                                type: string
+                            targets:
+                              type: array
+                              items:
+                                type: object
+                                properties:
+                                  name:
+                                    type: string
+                                    description: |
+                                      Name of the target.
+                                      Must be unique.
+        "#})
+        .unwrap()
+    }
+
+    #[test]
+    fn スキーマからフィールドの一覧を作成する() {
+        let actual = explain(&crd(), "v1").unwrap();
+
+        let expected = indoc! {"
+            KIND:     Foo
+            GROUP:    example.com
+            VERSION:  v1
+
+            DESCRIPTION:
+              Foo is an example resource.
+
+            FIELDS:
+              spec\t<Object>
+                Spec of Foo.
+                labels\t<map[string]string>
+                mode\t<string>
+                  ENUM: \"Active\", \"Standby\"
+                port\t<IntOrString>
+                replicas\t<integer> -required-
+                  Number of replicas.
+                targets\t<[]Object>
+                  name\t<string>
+                    Name of the target.
+                    Must be unique."
+        }
+        .lines()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn 存在しないバージョンはエラーになる() {
+        assert!(explain(&crd(), "v2").is_err());
+    }
+}
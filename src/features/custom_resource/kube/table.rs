@@ -0,0 +1,241 @@
+use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceColumnDefinition;
+use serde_json::Value;
+
+use crate::kube::{
+    apis::v1_table::TableRow,
+    table::{KubeTable, KubeTableRow},
+};
+
+/// サーバーが返すテーブルのうち表示する列
+///
+/// kubectl getと同様にpriorityが0のadditionalPrinterColumnsのみ表示し、
+/// additionalPrinterColumnsが定義されていない場合はAGEを表示する
+pub fn printer_columns(definitions: &[CustomResourceColumnDefinition]) -> Vec<String> {
+    let columns: Vec<String> = definitions
+        .iter()
+        .filter(|def| def.priority.unwrap_or_default() == 0)
+        .map(|def| def.name.clone())
+        .collect();
+
+    if columns.is_empty() {
+        return vec!["Name".to_string(), "Age".to_string()];
+    }
+
+    std::iter::once("Name".to_string()).chain(columns).collect()
+}
+
+/// テーブルの行を表示する列とstatus.conditionsのまとめに変換する
+///
+/// status.conditionsはincludeObject=Objectで取得したリソースから取り出す
+pub fn table_row(
+    row: &TableRow,
+    indexes: &[usize],
+    namespace: &str,
+    insert_ns: bool,
+) -> KubeTableRow {
+    let mut cells: Vec<String> = indexes
+        .iter()
+        .map(|i| match &row.cells[*i].0 {
+            Value::Null => String::new(),
+            _ => row.cells[*i].to_string(),
+        })
+        .collect();
+
+    let name = cells.first().cloned().unwrap_or_default();
+
+    if insert_ns {
+        cells.insert(0, namespace.to_string());
+    }
+
+    cells.push(
+        row.object
+            .as_ref()
+            .and_then(|object| conditions(&object.0))
+            .unwrap_or_default(),
+    );
+
+    KubeTableRow {
+        namespace: namespace.to_string(),
+        name,
+        metadata: None,
+        row: cells,
+    }
+}
+
+/// status.conditionsを持つリソースがある場合のみCONDITIONSを表示する
+pub fn custom_resource_table(
+    columns: &[String],
+    mut rows: Vec<KubeTableRow>,
+    insert_ns: bool,
+) -> KubeTable {
+    let has_conditions = rows
+        .iter()
+        .any(|row| row.row.last().is_some_and(|cell| !cell.is_empty()));
+
+    let mut header: Vec<String> = columns.iter().map(|column| column.to_uppercase()).collect();
+
+    if insert_ns {
+        header.insert(0, "NAMESPACE".to_string());
+    }
+
+    if has_conditions {
+        header.push("CONDITIONS".to_string());
+    } else {
+        rows.iter_mut().for_each(|row| {
+            row.row.pop();
+        });
+    }
+
+    KubeTable { header, rows }
+}
+
+/// status.conditionsを`Type=Status`の形式でまとめる
+fn conditions(object: &Value) -> Option<String> {
+    let conditions = object.pointer("/status/conditions")?.as_array()?;
+
+    let summary: Vec<String> = conditions
+        .iter()
+        .filter_map(|condition| {
+            let type_ = condition.get("type")?.as_str()?;
+            let status = condition.get("status")?.as_str()?;
+
+            Some(format!("{}={}", type_, status))
+        })
+        .collect();
+
+    Some(summary.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    fn definitions() -> Vec<CustomResourceColumnDefinition> {
+        serde_yaml::from_str(indoc! {r#"
+            - name: Ready
+              type: string
+              jsonPath: .status.conditions[?(@.type=="Ready")].status
+            - name: Replicas
+              type: integer
+              jsonPath: .spec.replicas
+            - name: Detail
+              type: string
+              priority: 1
+              jsonPath: .status.detail
+            - name: Age
+              type: date
+              jsonPath: .metadata.creationTimestamp
+        "#})
+        .unwrap()
+    }
+
+    fn row(cells: serde_json::Value, object: serde_json::Value) -> TableRow {
+        serde_json::from_value(json!({
+            "cells": cells,
+            "object": object,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn priorityが0のadditional_printer_columnsを表示する() {
+        assert_eq!(
+            printer_columns(&definitions()),
+            vec!["Name", "Ready", "Replicas", "Age"]
+        );
+    }
+
+    #[test]
+    fn additional_printer_columnsがない場合はageを表示する() {
+        assert_eq!(printer_columns(&[]), vec!["Name", "Age"]);
+    }
+
+    #[test]
+    fn status_conditionsをまとめて表示する() {
+        let rows = vec![
+            table_row(
+                &row(
+                    json!(["foo", "True", 2, "ok", "1h"]),
+                    json!({
+                        "metadata": {"name": "foo"},
+                        "status": {
+                            "conditions": [
+                                {"type": "Ready", "status": "True"},
+                                {"type": "Synced", "status": "False"}
+                            ]
+                        }
+                    }),
+                ),
+                &[0, 1, 2, 4],
+                "default",
+                false,
+            ),
+            table_row(
+                &row(
+                    json!(["bar", null, null, null, "2d"]),
+                    json!({"metadata": {"name": "bar"}}),
+                ),
+                &[0, 1, 2, 4],
+                "default",
+                false,
+            ),
+        ];
+
+        let actual = custom_resource_table(&printer_columns(&definitions()), rows, false);
+
+        assert_eq!(
+            actual.header,
+            vec!["NAME", "READY", "REPLICAS", "AGE", "CONDITIONS"]
+        );
+
+        assert_eq!(
+            actual
+                .rows
+                .iter()
+                .map(|r| r.row.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                vec!["foo", "True", "2", "1h", "Ready=True,Synced=False"],
+                vec!["bar", "", "", "2d", ""],
+            ]
+        );
+    }
+
+    #[test]
+    fn status_conditionsがない場合はconditionsを表示しない() {
+        let rows = vec![
+            table_row(
+                &row(json!(["foo", "30s"]), json!({"metadata": {"name": "foo"}})),
+                &[0, 1],
+                "app",
+                true,
+            ),
+            table_row(
+                &row(json!(["bar", "3d"]), json!({"metadata": {"name": "bar"}})),
+                &[0, 1],
+                "db",
+                true,
+            ),
+        ];
+
+        let actual = custom_resource_table(&printer_columns(&[]), rows, true);
+
+        assert_eq!(actual.header, vec!["NAMESPACE", "NAME", "AGE"]);
+
+        assert_eq!(
+            actual
+                .rows
+                .iter()
+                .map(|r| (r.name.as_str(), r.row.iter().map(String::as_str).collect()))
+                .collect::<Vec<(&str, Vec<&str>)>>(),
+            vec![
+                ("foo", vec!["app", "foo", "30s"]),
+                ("bar", vec!["db", "bar", "3d"]),
+            ]
+        );
+    }
+}
@@ -0,0 +1,382 @@
+use std::sync::{atomic::AtomicBool, Arc};
+
+use anyhow::{Context as _, Result};
+use crossbeam::channel::Sender;
+use futures::future::try_join_all;
+use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition;
+
+use crate::{
+    features::{
+        api_resources::kube::{ApiResource, SharedApiResources},
+        custom_resource::message::CustomResourceResponse,
+    },
+    kube::{
+        table::{get_resource_per_namespace, insert_ns, KubeTable, KubeTableRow},
+        KubeClientRequest,
+    },
+    message::Message,
+    workers::kube::{AbortWorker, SharedTargetNamespaces},
+};
+
+use super::{
+    explain_custom_resource, fetch_custom_resource_definition, fetch_custom_resources,
+    table::{custom_resource_table, printer_columns, table_row},
+};
+
+/// 選択されたカスタムリソースの一覧を定期的に取得する
+///
+/// namespaceの変更に追従するため、対象のnamespaceは取得のたびに読み込む
+#[derive(Clone)]
+pub struct CustomResourceWorker<C>
+where
+    C: KubeClientRequest,
+{
+    is_terminated: Arc<AtomicBool>,
+    tx: Sender<Message>,
+    client: C,
+    shared_target_namespaces: SharedTargetNamespaces,
+    api_resource: ApiResource,
+}
+
+impl<C: KubeClientRequest> CustomResourceWorker<C> {
+    pub fn new(
+        is_terminated: Arc<AtomicBool>,
+        tx: Sender<Message>,
+        client: C,
+        shared_target_namespaces: SharedTargetNamespaces,
+        api_resource: ApiResource,
+    ) -> Self {
+        Self {
+            is_terminated,
+            tx,
+            client,
+            shared_target_namespaces,
+            api_resource,
+        }
+    }
+
+    async fn fetch_table(&self, columns: &[String]) -> Result<KubeTable> {
+        let (rows, insert_ns) = if self.api_resource.is_namespaced() {
+            let namespaces = self.shared_target_namespaces.read().await.clone();
+
+            let insert_ns = insert_ns(&namespaces);
+
+            let rows = try_join_all(namespaces.iter().map(|ns| {
+                self.fetch_rows(
+                    self.api_resource.api_url_with_namespace(ns),
+                    columns,
+                    ns,
+                    insert_ns,
+                )
+            }))
+            .await?;
+
+            (rows.into_iter().flatten().collect(), insert_ns)
+        } else {
+            let rows = self
+                .fetch_rows(self.api_resource.api_url(), columns, "", false)
+                .await?;
+
+            (rows, false)
+        };
+
+        Ok(custom_resource_table(columns, rows, insert_ns))
+    }
+
+    /// status.conditionsを表示するためにリソース全体を含めてテーブルを取得する
+    async fn fetch_rows(
+        &self,
+        path: String,
+        columns: &[String],
+        namespace: &str,
+        insert_ns: bool,
+    ) -> Result<Vec<KubeTableRow>> {
+        let targets: Vec<&str> = columns.iter().map(String::as_str).collect();
+
+        get_resource_per_namespace(
+            &self.client,
+            format!("{}?includeObject=Object", path),
+            None,
+            &targets,
+            |row, indexes| table_row(row, indexes, namespace, insert_ns),
+        )
+        .await
+        .with_context(|| format!("Failed to list {}", self.api_resource))
+    }
+
+    async fn fetch_columns(&self) -> Result<Vec<String>> {
+        let crd: CustomResourceDefinition =
+            fetch_custom_resource_definition(&self.client, &self.api_resource).await?;
+
+        let columns = crd
+            .spec
+            .versions
+            .iter()
+            .find(|version| version.name == self.api_resource.version())
+            .and_then(|version| version.additional_printer_columns.as_deref())
+            .map(printer_columns)
+            .unwrap_or_else(|| printer_columns(&[]));
+
+        Ok(columns)
+    }
+}
+
+#[async_trait::async_trait]
+impl<C: KubeClientRequest> AbortWorker for CustomResourceWorker<C> {
+    async fn run(&self) {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(3));
+
+        let mut columns: Option<Vec<String>> = None;
+
+        while !self
+            .is_terminated
+            .load(std::sync::atomic::Ordering::Relaxed)
+        {
+            interval.tick().await;
+
+            // CRDの取得に失敗した場合は次の周期で再取得する
+            if columns.is_none() {
+                match self.fetch_columns().await {
+                    Ok(fetched) => columns = Some(fetched),
+                    Err(err) => {
+                        self.tx
+                            .send(CustomResourceResponse::Table(Err(err)).into())
+                            .expect("Failed to send CustomResourceResponse::Table");
+                        continue;
+                    }
+                }
+            }
+
+            let table = self
+                .fetch_table(columns.as_deref().unwrap_or_default())
+                .await;
+
+            self.tx
+                .send(CustomResourceResponse::Table(table).into())
+                .expect("Failed to send CustomResourceResponse::Table");
+        }
+    }
+}
+
+/// CRDで定義されているリソースの一覧を取得する
+#[derive(Clone)]
+pub struct CustomResourceDefinitionsWorker<C>
+where
+    C: KubeClientRequest,
+{
+    tx: Sender<Message>,
+    client: C,
+    shared_api_resources: SharedApiResources,
+}
+
+impl<C: KubeClientRequest> CustomResourceDefinitionsWorker<C> {
+    pub fn new(tx: Sender<Message>, client: C, shared_api_resources: SharedApiResources) -> Self {
+        Self {
+            tx,
+            client,
+            shared_api_resources,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<C: KubeClientRequest> AbortWorker for CustomResourceDefinitionsWorker<C> {
+    async fn run(&self) {
+        let api_resources = self.shared_api_resources.read().await.clone();
+
+        let ret = fetch_custom_resources(&self.client, &api_resources).await;
+
+        self.tx
+            .send(CustomResourceResponse::Definitions(ret).into())
+            .expect("Failed to send CustomResourceResponse::Definitions");
+    }
+}
+
+/// カスタムリソースのスキーマを取得する
+#[derive(Clone)]
+pub struct CustomResourceExplainWorker<C>
+where
+    C: KubeClientRequest,
+{
+    tx: Sender<Message>,
+    client: C,
+    api_resource: ApiResource,
+}
+
+impl<C: KubeClientRequest> CustomResourceExplainWorker<C> {
+    pub fn new(tx: Sender<Message>, client: C, api_resource: ApiResource) -> Self {
+        Self {
+            tx,
+            client,
+            api_resource,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<C: KubeClientRequest> AbortWorker for CustomResourceExplainWorker<C> {
+    async fn run(&self) {
+        let ret = explain_custom_resource(&self.client, &self.api_resource).await;
+
+        self.tx
+            .send(CustomResourceResponse::Explain(ret).into())
+            .expect("Failed to send CustomResourceResponse::Explain");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicBool;
+
+    use anyhow::bail;
+    use indoc::indoc;
+    use kube::discovery::Scope;
+    use mockall::predicate::eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+    use tokio::sync::RwLock;
+
+    use crate::{
+        kube::{apis::v1_table::Table, mock::MockTestKubeClient},
+        mock_expect,
+    };
+
+    use super::*;
+
+    fn api_resource(scope: Scope) -> ApiResource {
+        ApiResource::Apis {
+            name: "foos".into(),
+            group: "example.com".into(),
+            version: "v1".into(),
+            preferred_version: true,
+            scope,
+        }
+    }
+
+    fn crd() -> CustomResourceDefinition {
+        serde_yaml::from_str(indoc! {r#"
+            apiVersion: apiextensions.k8s.io/v1
+            kind: CustomResourceDefinition
+            metadata:
+              name: foos.example.com
+            spec:
+              group: example.com
+              names:
+                kind: Foo
+                plural: foos
+              scope: Namespaced
+              versions:
+                - name: v1
+                  served: true
+                  storage: true
+                  additionalPrinterColumns:
+                    - name: Phase
+                      type: string
+                      jsonPath: .status.phase
+        "#})
+        .unwrap()
+    }
+
+    fn table(names: &[&str]) -> Table {
+        serde_json::from_value(json!({
+            "columnDefinitions": [
+                {"name": "Name", "type": "string", "format": "name", "description": "", "priority": 0},
+                {"name": "Phase", "type": "string", "format": "", "description": "", "priority": 0}
+            ],
+            "rows": names
+                .iter()
+                .map(|name| json!({
+                    "cells": [name, "Running"],
+                    "object": {"metadata": {"name": name}}
+                }))
+                .collect::<Vec<_>>()
+        }))
+        .unwrap()
+    }
+
+    fn worker(
+        client: MockTestKubeClient,
+        namespaces: &[&str],
+        scope: Scope,
+    ) -> CustomResourceWorker<MockTestKubeClient> {
+        let (tx, _rx) = crossbeam::channel::unbounded();
+
+        CustomResourceWorker::new(
+            Arc::new(AtomicBool::new(false)),
+            tx,
+            client,
+            Arc::new(RwLock::new(
+                namespaces.iter().map(ToString::to_string).collect(),
+            )),
+            api_resource(scope),
+        )
+    }
+
+    #[tokio::test]
+    async fn 複数のnamespaceのリソースをprinter_columnsで表示する() {
+        let mut client = MockTestKubeClient::new();
+
+        mock_expect!(
+            client,
+            request,
+            [(
+                CustomResourceDefinition,
+                eq("/apis/apiextensions.k8s.io/v1/customresourcedefinitions/foos.example.com"),
+                Ok(crd())
+            )]
+        );
+
+        mock_expect!(
+            client,
+            table_request,
+            [
+                (
+                    Table,
+                    eq("apis/example.com/v1/namespaces/app/foos?includeObject=Object"),
+                    Ok(table(&["foo"]))
+                ),
+                (
+                    Table,
+                    eq("apis/example.com/v1/namespaces/db/foos?includeObject=Object"),
+                    Ok(table(&["bar"]))
+                )
+            ]
+        );
+
+        let worker = worker(client, &["app", "db"], Scope::Namespaced);
+
+        let columns = worker.fetch_columns().await.unwrap();
+
+        let actual = worker.fetch_table(&columns).await.unwrap();
+
+        assert_eq!(actual.header, vec!["NAMESPACE", "NAME", "PHASE"]);
+
+        assert_eq!(
+            actual
+                .rows
+                .iter()
+                .map(|r| r.row.clone())
+                .collect::<Vec<_>>(),
+            vec![vec!["app", "foo", "Running"], vec!["db", "bar", "Running"]]
+        );
+    }
+
+    #[tokio::test]
+    async fn 一覧の取得に失敗した場合はエラーを返す() {
+        let mut client = MockTestKubeClient::new();
+
+        mock_expect!(
+            client,
+            table_request,
+            [(
+                Table,
+                eq("apis/example.com/v1/foos?includeObject=Object"),
+                bail!("forbidden")
+            )]
+        );
+
+        let worker = worker(client, &["default"], Scope::Cluster);
+
+        assert!(worker.fetch_table(&[]).await.is_err());
+    }
+}
@@ -0,0 +1,57 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    features::api_resources::kube::ApiResource,
+    kube::table::KubeTable,
+    message::Message,
+    workers::{kube::message::Kube, replay::serde_result},
+};
+
+#[derive(Debug)]
+pub enum CustomResourceRequest {
+    /// CRDで定義されているリソースの一覧
+    Definitions,
+    /// 一覧を表示するリソース
+    Select(ApiResource),
+    /// スキーマを表示するリソース
+    Explain(ApiResource),
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum CustomResourceResponse {
+    Definitions(#[serde(with = "serde_result")] Result<Vec<ApiResource>>),
+    Table(#[serde(with = "serde_result")] Result<KubeTable>),
+    Explain(#[serde(with = "serde_result")] Result<Vec<String>>),
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum CustomResourceMessage {
+    #[serde(skip)]
+    Request(CustomResourceRequest),
+    Response(CustomResourceResponse),
+}
+
+impl From<CustomResourceRequest> for Message {
+    fn from(req: CustomResourceRequest) -> Self {
+        Message::Kube(Kube::CustomResource(CustomResourceMessage::Request(req)))
+    }
+}
+
+impl From<CustomResourceResponse> for Message {
+    fn from(res: CustomResourceResponse) -> Self {
+        Message::Kube(Kube::CustomResource(CustomResourceMessage::Response(res)))
+    }
+}
+
+impl From<CustomResourceMessage> for Kube {
+    fn from(m: CustomResourceMessage) -> Self {
+        Self::CustomResource(m)
+    }
+}
+
+impl From<CustomResourceMessage> for Message {
+    fn from(m: CustomResourceMessage) -> Self {
+        Self::Kube(m.into())
+    }
+}
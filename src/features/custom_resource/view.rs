@@ -0,0 +1,5 @@
+mod popups;
+mod tab;
+mod widget;
+
+pub use tab::*;
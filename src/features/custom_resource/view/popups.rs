@@ -0,0 +1,73 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crossbeam::channel::Sender;
+
+use crate::{
+    clipboard::Clipboard,
+    features::{
+        api_resources::kube::ApiResource,
+        component_id::{
+            CUSTOM_RESOURCE_EXPLAIN_POPUP_ID, CUSTOM_RESOURCE_KIND_POPUP_ID,
+            CUSTOM_RESOURCE_WIDGET_ID,
+        },
+        custom_resource::message::CustomResourceRequest,
+    },
+    message::Message,
+    ui::{
+        event::EventResult,
+        widget::{config::WidgetConfig, LiteralItem, SingleSelect, Text, Widget},
+        Window,
+    },
+};
+
+use super::widget::SharedCustomResource;
+
+pub fn kind_popup(tx: &Sender<Message>, selected: &SharedCustomResource) -> Widget<'static> {
+    SingleSelect::builder()
+        .id(CUSTOM_RESOURCE_KIND_POPUP_ID)
+        .widget_config(&WidgetConfig::builder().title("Custom Resource").build())
+        .on_select(on_select(tx.clone(), selected.clone()))
+        .build()
+        .into()
+}
+
+pub fn explain_popup(clipboard: &Option<Rc<RefCell<Clipboard>>>) -> Widget<'static> {
+    let builder = Text::builder()
+        .id(CUSTOM_RESOURCE_EXPLAIN_POPUP_ID)
+        .widget_config(&WidgetConfig::builder().title("Explain").build())
+        .wrap();
+
+    if let Some(cb) = clipboard {
+        builder.clipboard(cb.clone())
+    } else {
+        builder
+    }
+    .build()
+    .into()
+}
+
+fn on_select(
+    tx: Sender<Message>,
+    selected: SharedCustomResource,
+) -> impl Fn(&mut Window, &LiteralItem) -> EventResult {
+    move |w, v| {
+        let Some(key) = v.metadata.as_ref().and_then(|metadata| metadata.get("key")) else {
+            return EventResult::Ignore;
+        };
+
+        let Ok(kind) = serde_json::from_str::<ApiResource>(key) else {
+            return EventResult::Ignore;
+        };
+
+        w.close_popup();
+
+        *selected.borrow_mut() = Some(kind.clone());
+
+        w.widget_clear(CUSTOM_RESOURCE_WIDGET_ID);
+
+        tx.send(CustomResourceRequest::Select(kind).into())
+            .expect("Failed to send CustomResourceRequest::Select");
+
+        EventResult::Nop
+    }
+}
@@ -0,0 +1,56 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crossbeam::channel::Sender;
+use ratatui::prelude::Constraint;
+
+use crate::{
+    clipboard::Clipboard,
+    features::component_id::CUSTOM_RESOURCE_TAB_ID,
+    history::HistoryHandle,
+    message::Message,
+    ui::{
+        tab::{LayoutElement, NestedLayoutElement, NestedWidgetLayout},
+        widget::Widget,
+        Tab,
+    },
+};
+
+use super::{
+    popups::{explain_popup, kind_popup},
+    widget::{custom_resource_widget, SharedCustomResource},
+};
+
+pub struct CustomResourceTab {
+    pub tab: Tab<'static>,
+    pub kind_popup: Widget<'static>,
+    pub explain_popup: Widget<'static>,
+}
+
+impl CustomResourceTab {
+    pub fn new(
+        title: &'static str,
+        tx: &Sender<Message>,
+        clipboard: &Option<Rc<RefCell<Clipboard>>>,
+        history: &HistoryHandle,
+    ) -> Self {
+        let selected = SharedCustomResource::default();
+
+        let custom_resource_widget = custom_resource_widget(tx, history, &selected);
+
+        let layout = NestedWidgetLayout::default().nested_widget_layout([NestedLayoutElement(
+            Constraint::Percentage(100),
+            LayoutElement::WidgetIndex(0),
+        )]);
+
+        CustomResourceTab {
+            tab: Tab::new(
+                CUSTOM_RESOURCE_TAB_ID,
+                title,
+                [custom_resource_widget],
+                layout,
+            ),
+            kind_popup: kind_popup(tx, &selected),
+            explain_popup: explain_popup(clipboard),
+        }
+    }
+}
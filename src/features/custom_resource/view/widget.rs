@@ -0,0 +1,103 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crossbeam::channel::Sender;
+
+use crate::{
+    features::{
+        api_resources::kube::ApiResource,
+        component_id::{
+            CUSTOM_RESOURCE_EXPLAIN_POPUP_ID, CUSTOM_RESOURCE_KIND_POPUP_ID,
+            CUSTOM_RESOURCE_WIDGET_ID,
+        },
+        custom_resource::message::CustomResourceRequest,
+    },
+    history::HistoryHandle,
+    message::Message,
+    ui::{
+        event::EventResult,
+        widget::{config::WidgetConfig, Table, Widget, WidgetTrait as _},
+        Window,
+    },
+};
+
+/// 一覧に表示しているカスタムリソース
+pub type SharedCustomResource = Rc<RefCell<Option<ApiResource>>>;
+
+pub fn custom_resource_widget(
+    tx: &Sender<Message>,
+    history: &HistoryHandle,
+    selected: &SharedCustomResource,
+) -> Widget<'static> {
+    Table::builder()
+        .id(CUSTOM_RESOURCE_WIDGET_ID)
+        .widget_config(&WidgetConfig::builder().title("CRD").build())
+        .filtered_key("NAME")
+        .filter_history(
+            history.loader("custom_resource_filter"),
+            history.saver("custom_resource_filter"),
+        )
+        .block_injection(block_injection(selected.clone()))
+        .action('f', open_kind_popup(tx.clone()))
+        .action('e', open_explain_popup(tx.clone(), selected.clone()))
+        .build()
+        .into()
+}
+
+fn block_injection(selected: SharedCustomResource) -> impl Fn(&Table) -> WidgetConfig {
+    move |table: &Table| {
+        let index = if let Some(index) = table.state().selected() {
+            index + 1
+        } else {
+            0
+        };
+
+        let mut widget_config = table.widget_config().clone();
+
+        let kind = selected
+            .borrow()
+            .as_ref()
+            .map(|kind| format!(" : {}", kind))
+            .unwrap_or_default();
+
+        *widget_config.append_title_mut() =
+            Some(format!("{} [{}/{}]", kind, index, table.items().len()).into());
+
+        widget_config
+    }
+}
+
+fn open_kind_popup(tx: Sender<Message>) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        tx.send(CustomResourceRequest::Definitions.into())
+            .expect("Failed to send CustomResourceRequest::Definitions");
+
+        w.widget_clear(CUSTOM_RESOURCE_KIND_POPUP_ID);
+        w.open_popup(CUSTOM_RESOURCE_KIND_POPUP_ID);
+
+        EventResult::Nop
+    }
+}
+
+/// 一覧に表示しているカスタムリソースのスキーマを表示する
+fn open_explain_popup(
+    tx: Sender<Message>,
+    selected: SharedCustomResource,
+) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let Some(kind) = selected.borrow().clone() else {
+            return EventResult::Ignore;
+        };
+
+        *(w.find_widget_mut(CUSTOM_RESOURCE_EXPLAIN_POPUP_ID)
+            .widget_config_mut()
+            .append_title_mut()) = Some(format!(" : {}", kind).into());
+
+        w.widget_clear(CUSTOM_RESOURCE_EXPLAIN_POPUP_ID);
+        w.open_popup(CUSTOM_RESOURCE_EXPLAIN_POPUP_ID);
+
+        tx.send(CustomResourceRequest::Explain(kind).into())
+            .expect("Failed to send CustomResourceRequest::Explain");
+
+        EventResult::Nop
+    }
+}
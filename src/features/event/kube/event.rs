@@ -7,7 +7,7 @@ use futures::future::try_join_all;
 use crate::{
     kube::{
         apis::v1_table::{TableRow, ToTime as _},
-        table::{get_resource_per_namespace, insert_ns, KubeTableRow},
+        table::{get_resource_per_namespace, insert_ns, KubeTable, KubeTableRow},
         KubeClient,
     },
     message::Message,
@@ -34,17 +34,18 @@ impl Worker for EventPoller {
                 PollerBase {
                     is_terminated,
                     tx,
-                    shared_target_namespaces,
-                    kube_client,
+                    connection,
+                    ..
                 },
         } = self;
 
         let mut interval = tokio::time::interval(time::Duration::from_millis(1000));
         while !is_terminated.load(Ordering::Relaxed) {
-            interval.tick().await;
-            let target_namespaces = shared_target_namespaces.read().await;
+            connection.tick(&mut interval).await;
 
-            let event_list = get_event_table(kube_client, &target_namespaces).await;
+            let event_list = self.poll().await.map(|table| format_events(&table));
+
+            connection.report(&event_list);
 
             tx.send(Message::Kube(Kube::Event(event_list)))
                 .expect("Failed to send Kube::Event");
@@ -54,16 +55,32 @@ impl Worker for EventPoller {
     }
 }
 
+impl EventPoller {
+    /// 対象のnamespaceのイベントを発生した順に取得する
+    pub async fn poll(&self) -> Result<KubeTable> {
+        let PollerBase {
+            shared_target_namespaces,
+            kube_client,
+            ..
+        } = &self.base;
+
+        let target_namespaces = shared_target_namespaces.read().await;
+
+        fetch_events(kube_client, &target_namespaces).await
+    }
+}
+
 const TARGET_LEN: usize = 4;
 const TARGET: [&str; TARGET_LEN] = ["Last Seen", "Object", "Reason", "Message"];
 
-async fn get_event_table(client: &KubeClient, namespaces: &[String]) -> Result<Vec<String>> {
+async fn fetch_events(client: &KubeClient, namespaces: &[String]) -> Result<KubeTable> {
     let insert_ns = insert_ns(namespaces);
 
     let jobs = try_join_all(namespaces.iter().map(|ns| {
         get_resource_per_namespace(
             client,
             format!("api/v1/namespaces/{}/{}", ns, "events"),
+            None,
             &TARGET,
             move |row: &TableRow, indexes: &[usize]| {
                 let mut row: Vec<String> =
@@ -90,7 +107,22 @@ async fn get_event_table(client: &KubeClient, namespaces: &[String]) -> Result<V
 
     ok_only.sort_by_key(|row| row.row[0].to_time());
 
-    Ok(ok_only
+    let mut header: Vec<String> = TARGET.iter().map(|s| s.to_uppercase()).collect();
+
+    if insert_ns {
+        header.insert(1, "NAMESPACE".to_string());
+    }
+
+    Ok(KubeTable {
+        header,
+        rows: ok_only,
+    })
+}
+
+/// イベントをEventタブで表示する形式に変換する
+fn format_events(table: &KubeTable) -> Vec<String> {
+    table
+        .rows
         .iter()
         .flat_map(|v| {
             v.row
@@ -112,5 +144,5 @@ async fn get_event_table(client: &KubeClient, namespaces: &[String]) -> Result<V
                 .map(ToString::to_string)
                 .collect::<Vec<_>>()
         })
-        .collect())
+        .collect()
 }
@@ -15,10 +15,10 @@ use serde::{de::DeserializeOwned, Serialize};
 use crate::{
     features::{
         get::message::{GetRequest, GetResponse},
-        network::message::{GatewayVersion, HTTPRouteVersion},
+        network::message::{GRPCRouteVersion, GatewayVersion, HTTPRouteVersion},
     },
     kube::{
-        apis::networking::gateway::{v1, v1beta1},
+        apis::networking::gateway::{v1, v1alpha2, v1beta1},
         KubeClient,
     },
     logger,
@@ -36,6 +36,9 @@ pub enum GetYamlKind {
     NetworkPolicy,
     Gateway(GatewayVersion),
     HTTPRoute(HTTPRouteVersion),
+    GRPCRoute(GRPCRouteVersion),
+    TLSRoute,
+    TCPRoute,
 }
 
 impl std::fmt::Display for GetYamlKind {
@@ -55,6 +58,14 @@ impl std::fmt::Display for GetYamlKind {
                 HTTPRouteVersion::V1 => write!(f, "{}", v1::HTTPRoute::URL_PATH_SEGMENT),
                 HTTPRouteVersion::V1Beta1 => write!(f, "{}", v1beta1::HTTPRoute::URL_PATH_SEGMENT),
             },
+            Self::GRPCRoute(version) => match version {
+                GRPCRouteVersion::V1 => write!(f, "{}", v1::GRPCRoute::URL_PATH_SEGMENT),
+                GRPCRouteVersion::V1Alpha2 => {
+                    write!(f, "{}", v1alpha2::GRPCRoute::URL_PATH_SEGMENT)
+                }
+            },
+            Self::TLSRoute => write!(f, "{}", v1alpha2::TLSRoute::URL_PATH_SEGMENT),
+            Self::TCPRoute => write!(f, "{}", v1alpha2::TCPRoute::URL_PATH_SEGMENT),
         }
     }
 }
@@ -134,6 +145,21 @@ impl AbortWorker for GetYamlWorker {
                             .await
                     }
                 },
+                GetYamlKind::GRPCRoute(version) => match version {
+                    GRPCRouteVersion::V1 => {
+                        fetch_resource_yaml::<v1::GRPCRoute>(&self.client, name, namespace).await
+                    }
+                    GRPCRouteVersion::V1Alpha2 => {
+                        fetch_resource_yaml::<v1alpha2::GRPCRoute>(&self.client, name, namespace)
+                            .await
+                    }
+                },
+                GetYamlKind::TLSRoute => {
+                    fetch_resource_yaml::<v1alpha2::TLSRoute>(&self.client, name, namespace).await
+                }
+                GetYamlKind::TCPRoute => {
+                    fetch_resource_yaml::<v1alpha2::TCPRoute>(&self.client, name, namespace).await
+                }
             };
 
             self.tx
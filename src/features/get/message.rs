@@ -1,11 +1,16 @@
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
 
-use crate::{message::Message, workers::kube::message::Kube};
+use crate::{
+    message::Message,
+    workers::{kube::message::Kube, replay::serde_result},
+};
 
 pub use super::kube::yaml::GetYamlKind;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum GetMessage {
+    #[serde(skip)]
     Request(GetRequest),
     Response(GetResponse),
 }
@@ -17,10 +22,11 @@ pub struct GetRequest {
     pub kind: GetYamlKind,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct GetResponse {
     pub kind: String,
     pub name: String,
+    #[serde(with = "serde_result")]
     pub yaml: Result<Vec<String>>,
 }
 
@@ -11,7 +11,7 @@ const LEFT_HELP_TEXT: &[HelpBlock] = &[
         title: "General",
         bindings: &[
             KeyBindings {
-                keys: &["0~6"],
+                keys: &["0~8"],
                 desc: "switch tab",
             },
             KeyBindings {
@@ -38,6 +38,22 @@ const LEFT_HELP_TEXT: &[HelpBlock] = &[
                 keys: &["y"],
                 desc: "open yaml popup",
             },
+            KeyBindings {
+                keys: &["S"],
+                desc: "search resources by name or label selector",
+            },
+            KeyBindings {
+                keys: &["l"],
+                desc: "filter pod/config/network table by labels",
+            },
+            KeyBindings {
+                keys: &["I"],
+                desc: "impersonate user and groups",
+            },
+            KeyBindings {
+                keys: &["A"],
+                desc: "check RBAC permissions",
+            },
             KeyBindings {
                 keys: &["q", "Esc"],
                 desc: "quit",
@@ -152,14 +168,58 @@ const RIGHT_HELP_TEXT: &[HelpBlock] = &[
                 keys: &["Ctrl-k"],
                 desc: "delete the text from the cursor position to the end",
             },
+            KeyBindings {
+                keys: &["Up", "Down"],
+                desc: "recall prev/next history (log query / filter)",
+            },
+            KeyBindings {
+                keys: &["Ctrl-r"],
+                desc: "reverse search history",
+            },
         ],
     },
     HelpBlock {
         title: "List / Yaml Tab",
-        bindings: &[KeyBindings {
-            keys: &["f"],
-            desc: "open select popup",
-        }],
+        bindings: &[
+            KeyBindings {
+                keys: &["f"],
+                desc: "open select popup",
+            },
+            KeyBindings {
+                keys: &["t"],
+                desc: "open owner reference tree",
+            },
+        ],
+    },
+    HelpBlock {
+        title: "Search Result",
+        bindings: &[
+            KeyBindings {
+                keys: &["Enter"],
+                desc: "open yaml",
+            },
+            KeyBindings {
+                keys: &["d"],
+                desc: "open description (network resources)",
+            },
+            KeyBindings {
+                keys: &["l"],
+                desc: "open logs (pods)",
+            },
+        ],
+    },
+    HelpBlock {
+        title: "CRD Tab",
+        bindings: &[
+            KeyBindings {
+                keys: &["f"],
+                desc: "open custom resource select popup",
+            },
+            KeyBindings {
+                keys: &["e"],
+                desc: "explain the schema",
+            },
+        ],
     },
     HelpBlock {
         title: "Search (Only text view)",
@@ -199,12 +259,73 @@ const RIGHT_HELP_TEXT: &[HelpBlock] = &[
             },
         ],
     },
+    HelpBlock {
+        title: "Log Query",
+        bindings: &[
+            KeyBindings {
+                keys: &["Ctrl-s"],
+                desc: "save query as",
+            },
+            KeyBindings {
+                keys: &["Ctrl-o"],
+                desc: "open saved queries popup",
+            },
+            KeyBindings {
+                keys: &["Ctrl-x"],
+                desc: "delete saved query (saved queries popup)",
+            },
+        ],
+    },
     HelpBlock {
         title: "Log",
-        bindings: &[KeyBindings {
-            keys: &["Enter"],
-            desc: "insert blank line",
-        }],
+        bindings: &[
+            KeyBindings {
+                keys: &["Enter"],
+                desc: "insert blank line",
+            },
+            KeyBindings {
+                keys: &["m"],
+                desc: "toggle bookmark",
+            },
+            KeyBindings {
+                keys: &["[", "]"],
+                desc: "goto prev/next bookmark",
+            },
+            KeyBindings {
+                keys: &["M"],
+                desc: "open bookmark list popup",
+            },
+            KeyBindings {
+                keys: &["T"],
+                desc: "toggle timestamp (hidden/local/utc/relative)",
+            },
+        ],
+    },
+    HelpBlock {
+        title: "Network",
+        bindings: &[
+            KeyBindings {
+                keys: &["r"],
+                desc: "open relation graph popup",
+            },
+            KeyBindings {
+                keys: &["a"],
+                desc: "check reachability between pods",
+            },
+        ],
+    },
+    HelpBlock {
+        title: "Replay (Only --replay)",
+        bindings: &[
+            KeyBindings {
+                keys: &["P"],
+                desc: "pause/resume",
+            },
+            KeyBindings {
+                keys: &["<", ">"],
+                desc: "seek backward/forward 10 seconds",
+            },
+        ],
     },
 ];
 
@@ -0,0 +1,84 @@
+use std::fmt::Display;
+
+use kube::Config;
+use serde::{Deserialize, Serialize};
+
+/// APIリクエストで偽装するユーザーとグループ（`kubectl --as`、`--as-group`と同じ）
+///
+/// すべてのコンテキストの接続先に適用し、kubeconfigに`as`が定義されていても上書きする
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Impersonation {
+    pub user: String,
+    pub groups: Vec<String>,
+}
+
+impl Impersonation {
+    pub fn new(user: impl Into<String>, groups: Vec<String>) -> Self {
+        Self {
+            user: user.into(),
+            groups,
+        }
+    }
+
+    /// `<user> [<group>...]`の形式の入力を解析する（空の場合は`None`）
+    pub fn parse(input: &str) -> Option<Self> {
+        let mut tokens = input.split_whitespace();
+
+        let user = tokens.next()?;
+
+        Some(Self::new(user, tokens.map(String::from).collect()))
+    }
+
+    /// 接続先を生成する前の設定に偽装するユーザーとグループを反映する
+    pub fn apply(&self, config: &mut Config) {
+        config.auth_info.impersonate = Some(self.user.to_string());
+        config.auth_info.impersonate_groups =
+            (!self.groups.is_empty()).then(|| self.groups.clone());
+    }
+}
+
+impl Display for Impersonation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.user)?;
+
+        if !self.groups.is_empty() {
+            write!(f, " (groups: {})", self.groups.join(","))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("", None)]
+    #[case("   ", None)]
+    #[case("alice", Some(Impersonation::new("alice", vec![])))]
+    #[case(
+        " system:serviceaccount:default:foo  dev ops ",
+        Some(Impersonation::new(
+            "system:serviceaccount:default:foo",
+            vec!["dev".to_string(), "ops".to_string()]
+        ))
+    )]
+    fn 先頭をユーザー残りをグループとして解析する(
+        #[case] input: &str,
+        #[case] expected: Option<Impersonation>,
+    ) {
+        assert_eq!(Impersonation::parse(input), expected)
+    }
+
+    #[test]
+    fn グループがある場合はグループも表示する() {
+        let impersonation = Impersonation::new("alice", vec!["dev".to_string(), "ops".to_string()]);
+
+        assert_eq!(impersonation.to_string(), "alice (groups: dev,ops)");
+        assert_eq!(Impersonation::new("alice", vec![]).to_string(), "alice");
+    }
+}
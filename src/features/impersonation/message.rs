@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{message::Message, workers::kube::message::Kube};
+
+use super::kube::Impersonation;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum ImpersonationMessage {
+    #[serde(skip)]
+    Request(ImpersonationRequest),
+    Response(ImpersonationResponse),
+}
+
+/// 偽装するユーザーとグループを設定する（Noneの場合は解除する）
+#[derive(Debug)]
+pub struct ImpersonationRequest(pub Option<Impersonation>);
+
+/// 接続先に適用している偽装ユーザー
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ImpersonationResponse(pub Option<Impersonation>);
+
+impl From<ImpersonationMessage> for Message {
+    fn from(m: ImpersonationMessage) -> Self {
+        Message::Kube(Kube::Impersonation(m))
+    }
+}
+
+impl From<ImpersonationRequest> for Message {
+    fn from(m: ImpersonationRequest) -> Self {
+        Message::Kube(Kube::Impersonation(ImpersonationMessage::Request(m)))
+    }
+}
+
+impl From<ImpersonationResponse> for Message {
+    fn from(m: ImpersonationResponse) -> Self {
+        Message::Kube(Kube::Impersonation(ImpersonationMessage::Response(m)))
+    }
+}
@@ -0,0 +1,60 @@
+use crossbeam::channel::Sender;
+use ratatui::crossterm::event::KeyCode;
+
+use crate::{
+    features::{
+        component_id::IMPERSONATION_POPUP_ID,
+        impersonation::{kube::Impersonation, message::ImpersonationRequest},
+    },
+    message::{Message, UserEvent},
+    ui::{
+        event::EventResult,
+        widget::{
+            config::WidgetConfig, input::InputFormBuilder, SelectedItem, Widget, WidgetTrait as _,
+        },
+        Window,
+    },
+};
+
+pub struct ImpersonationPopup {
+    pub popup: Widget<'static>,
+}
+
+impl ImpersonationPopup {
+    pub fn new(tx: &Sender<Message>) -> Self {
+        let popup = InputFormBuilder::default()
+            .id(IMPERSONATION_POPUP_ID)
+            .widget_config(
+                WidgetConfig::builder()
+                    .title("Impersonate: <user> [<group>...] (empty to stop)")
+                    .build(),
+            )
+            .actions(UserEvent::from(KeyCode::Enter), impersonate(tx.clone()))
+            .build()
+            .into();
+
+        Self { popup }
+    }
+}
+
+pub fn open_impersonation_popup(w: &mut Window) -> EventResult {
+    w.open_popup(IMPERSONATION_POPUP_ID);
+    EventResult::Nop
+}
+
+fn impersonate(tx: Sender<Message>) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let Some(SelectedItem::Literal { item, .. }) =
+            w.find_widget(IMPERSONATION_POPUP_ID).widget_item()
+        else {
+            return EventResult::Ignore;
+        };
+
+        w.close_popup();
+
+        tx.send(ImpersonationRequest(Impersonation::parse(&item)).into())
+            .expect("Failed to send ImpersonationRequest");
+
+        EventResult::Nop
+    }
+}
@@ -0,0 +1,3 @@
+pub mod kube;
+pub mod message;
+pub mod view;
@@ -0,0 +1,17 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use tokio::sync::RwLock;
+
+/// ラベルセレクターを適用するテーブル
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LabelSelectorTarget {
+    Pod,
+    Config,
+    Network,
+}
+
+/// テーブルごとに適用しているラベルセレクター
+///
+/// コンテキストを切り替えても保持するため、コンテキストごとのループの外で作成する
+pub type LabelSelectors = BTreeMap<LabelSelectorTarget, String>;
+pub type SharedLabelSelectors = Arc<RwLock<LabelSelectors>>;
@@ -0,0 +1,22 @@
+use crate::{message::Message, workers::kube::message::Kube};
+
+use super::kube::LabelSelectorTarget;
+
+/// テーブルにラベルセレクターを適用する（Noneの場合は解除する）
+#[derive(Debug)]
+pub struct LabelSelectorRequest {
+    pub target: LabelSelectorTarget,
+    pub selector: Option<String>,
+}
+
+impl From<LabelSelectorRequest> for Kube {
+    fn from(req: LabelSelectorRequest) -> Self {
+        Self::LabelSelector(req)
+    }
+}
+
+impl From<LabelSelectorRequest> for Message {
+    fn from(req: LabelSelectorRequest) -> Self {
+        Self::Kube(req.into())
+    }
+}
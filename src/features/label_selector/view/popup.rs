@@ -0,0 +1,261 @@
+use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
+
+use crossbeam::channel::Sender;
+
+use crate::{
+    features::{
+        component_id::{
+            CONFIG_WIDGET_ID, LABEL_SELECTOR_POPUP_ID, NETWORK_WIDGET_ID, POD_WIDGET_ID,
+        },
+        label_selector::{kube::LabelSelectorTarget, message::LabelSelectorRequest},
+    },
+    kube::table::{ANNOTATIONS_METADATA_KEY, LABELS_METADATA_KEY},
+    message::Message,
+    ui::{
+        event::{CallbackFn, EventResult},
+        widget::{config::WidgetConfig, Item, List, LiteralItem, TableItem, Widget, WidgetTrait},
+        Window,
+    },
+};
+
+/// ラベルセレクターのポップアップを開いたテーブル
+pub type SharedLabelSelectorTarget = Rc<RefCell<Option<LabelSelectorTarget>>>;
+
+const SELECTOR_METADATA_KEY: &str = "selector";
+
+pub struct LabelSelectorPopup {
+    pub popup: Widget<'static>,
+}
+
+impl LabelSelectorPopup {
+    pub fn new(tx: &Sender<Message>, target: &SharedLabelSelectorTarget) -> Self {
+        let popup = List::builder()
+            .id(LABEL_SELECTOR_POPUP_ID)
+            .widget_config(&WidgetConfig::builder().title("Labels").build())
+            .on_select(on_select(tx.clone(), target.clone()))
+            .build()
+            .into();
+
+        Self { popup }
+    }
+}
+
+/// アクティブなテーブルの行からラベルを集計してポップアップを開く
+pub fn open_label_selector_popup(target: SharedLabelSelectorTarget) -> impl CallbackFn {
+    move |w: &mut Window| {
+        let widget = w.active_tab().active_widget();
+
+        let label_selector_target = match widget.id() {
+            POD_WIDGET_ID => LabelSelectorTarget::Pod,
+            CONFIG_WIDGET_ID => LabelSelectorTarget::Config,
+            NETWORK_WIDGET_ID => LabelSelectorTarget::Network,
+            _ => {
+                return EventResult::Ignore;
+            }
+        };
+
+        let items = label_items(widget.as_table().items());
+
+        *target.borrow_mut() = Some(label_selector_target);
+
+        let popup = w.find_widget_mut(LABEL_SELECTOR_POPUP_ID);
+
+        *popup.widget_config_mut().append_title_mut() =
+            Some(format!(" [{}]", table_title(label_selector_target)).into());
+
+        popup.update_widget_item(Item::Array(items));
+
+        w.open_popup(LABEL_SELECTOR_POPUP_ID);
+
+        EventResult::Nop
+    }
+}
+
+fn on_select(
+    tx: Sender<Message>,
+    target: SharedLabelSelectorTarget,
+) -> impl Fn(&mut Window, &LiteralItem) -> EventResult {
+    move |w: &mut Window, v: &LiteralItem| {
+        let Some(label_selector_target) = *target.borrow() else {
+            return EventResult::Ignore;
+        };
+
+        let Some(selector) = v
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get(SELECTOR_METADATA_KEY))
+        else {
+            return EventResult::Ignore;
+        };
+
+        let selector = (!selector.is_empty()).then(|| selector.to_string());
+
+        w.close_popup();
+
+        let title = match &selector {
+            Some(selector) => format!("{} (-l {})", table_title(label_selector_target), selector),
+            None => table_title(label_selector_target).to_string(),
+        };
+
+        let widget_id = table_widget_id(label_selector_target);
+
+        w.widget_clear(widget_id);
+
+        *w.find_widget_mut(widget_id).widget_config_mut().title_mut() = title.into();
+
+        tx.send(
+            LabelSelectorRequest {
+                target: label_selector_target,
+                selector,
+            }
+            .into(),
+        )
+        .expect("Failed to send LabelSelectorRequest");
+
+        EventResult::Nop
+    }
+}
+
+fn table_widget_id(target: LabelSelectorTarget) -> &'static str {
+    match target {
+        LabelSelectorTarget::Pod => POD_WIDGET_ID,
+        LabelSelectorTarget::Config => CONFIG_WIDGET_ID,
+        LabelSelectorTarget::Network => NETWORK_WIDGET_ID,
+    }
+}
+
+fn table_title(target: LabelSelectorTarget) -> &'static str {
+    match target {
+        LabelSelectorTarget::Pod => "Pod",
+        LabelSelectorTarget::Config => "Config",
+        LabelSelectorTarget::Network => "Network",
+    }
+}
+
+fn selector_item(item: String, selector: &str) -> LiteralItem {
+    LiteralItem::new(
+        item,
+        Some(BTreeMap::from([(
+            SELECTOR_METADATA_KEY.to_string(),
+            selector.to_string(),
+        )])),
+    )
+}
+
+fn header_item(item: &str) -> LiteralItem {
+    LiteralItem::new(format!("\x1b[90m{}\x1b[39m", item), None)
+}
+
+/// テーブルの行のメタデータからラベルのキーと値、アノテーションのキーを件数付きで集計する
+///
+/// ラベルの行を選択するとセレクター（キーのみの場合は存在チェック）を適用し、先頭の行で解除する
+/// アノテーションはセレクターで絞り込めないため、集計結果の表示のみ行う
+fn label_items(items: &[TableItem]) -> Vec<LiteralItem> {
+    let mut labels: BTreeMap<String, BTreeMap<String, usize>> = BTreeMap::new();
+
+    let mut annotations: BTreeMap<String, usize> = BTreeMap::new();
+
+    for metadata in items.iter().filter_map(|item| item.metadata.as_ref()) {
+        if let Some(map) = metadata
+            .get(LABELS_METADATA_KEY)
+            .and_then(|json| serde_json::from_str::<BTreeMap<String, String>>(json).ok())
+        {
+            for (key, value) in map {
+                *labels.entry(key).or_default().entry(value).or_default() += 1;
+            }
+        }
+
+        if let Some(keys) = metadata
+            .get(ANNOTATIONS_METADATA_KEY)
+            .and_then(|json| serde_json::from_str::<Vec<String>>(json).ok())
+        {
+            for key in keys {
+                *annotations.entry(key).or_default() += 1;
+            }
+        }
+    }
+
+    let mut ret = vec![selector_item("<clear label selector>".to_string(), "")];
+
+    ret.push(header_item("Labels"));
+
+    for (key, values) in &labels {
+        let count: usize = values.values().sum();
+
+        ret.push(selector_item(format!("{} ({})", key, count), key));
+
+        ret.extend(values.iter().map(|(value, count)| {
+            let selector = format!("{}={}", key, value);
+
+            selector_item(format!("  {} ({})", selector, count), &selector)
+        }));
+    }
+
+    if !annotations.is_empty() {
+        ret.push(header_item("Annotations"));
+
+        ret.extend(
+            annotations
+                .iter()
+                .map(|(key, count)| LiteralItem::new(format!("{} ({})", key, count), None)),
+        );
+    }
+
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn table_item(labels: &str, annotations: Option<&str>) -> TableItem {
+        let mut metadata = BTreeMap::from([(LABELS_METADATA_KEY.to_string(), labels.to_string())]);
+
+        if let Some(annotations) = annotations {
+            metadata.insert(
+                ANNOTATIONS_METADATA_KEY.to_string(),
+                annotations.to_string(),
+            );
+        }
+
+        TableItem::new(vec!["name".to_string()], Some(metadata))
+    }
+
+    #[test]
+    fn ラベルのキーと値を件数付きで集計する() {
+        let items = vec![
+            table_item(r#"{"app":"nginx","tier":"web"}"#, Some(r#"["note"]"#)),
+            table_item(r#"{"app":"nginx"}"#, None),
+            table_item(r#"{"app":"redis"}"#, Some(r#"["note"]"#)),
+            TableItem::new(vec!["no-labels".to_string()], None),
+        ];
+
+        let actual = label_items(&items);
+
+        assert_eq!(
+            actual
+                .iter()
+                .map(|item| (
+                    item.item.as_str(),
+                    item.metadata
+                        .as_ref()
+                        .and_then(|m| m.get(SELECTOR_METADATA_KEY))
+                        .map(String::as_str)
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                ("<clear label selector>", Some("")),
+                ("\x1b[90mLabels\x1b[39m", None),
+                ("app (3)", Some("app")),
+                ("  app=nginx (2)", Some("app=nginx")),
+                ("  app=redis (1)", Some("app=redis")),
+                ("tier (1)", Some("tier")),
+                ("  tier=web (1)", Some("tier=web")),
+                ("\x1b[90mAnnotations\x1b[39m", None),
+                ("note (2)", None),
+            ]
+        );
+    }
+}
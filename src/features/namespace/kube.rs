@@ -0,0 +1,6 @@
+mod description;
+mod overview;
+mod quota;
+
+pub use description::*;
+pub use overview::*;
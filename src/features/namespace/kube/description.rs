@@ -0,0 +1,315 @@
+use std::{
+    collections::BTreeMap,
+    sync::{atomic::AtomicBool, Arc},
+};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use crossbeam::channel::Sender;
+use k8s_openapi::{
+    api::core::v1::{LimitRange, Namespace, ResourceQuota},
+    apimachinery::pkg::api::resource::Quantity,
+    List,
+};
+
+use crate::{
+    features::namespace::message::NamespaceResponse,
+    kube::{KubeClient, KubeClientRequest},
+    message::Message,
+    workers::kube::AbortWorker,
+};
+
+use super::quota::{quota_usages, usage_bar};
+
+const INTERVAL: u64 = 3;
+
+const QUOTA_BAR_WIDTH: usize = 20;
+
+/// 選択されたnamespaceのラベル、ResourceQuotaの使用量、LimitRangeを定期的に取得する
+#[derive(Clone)]
+pub struct NamespaceDescriptionWorker {
+    is_terminated: Arc<AtomicBool>,
+    tx: Sender<Message>,
+    client: KubeClient,
+    namespace: String,
+}
+
+impl NamespaceDescriptionWorker {
+    pub fn new(
+        is_terminated: Arc<AtomicBool>,
+        tx: Sender<Message>,
+        client: KubeClient,
+        namespace: String,
+    ) -> Self {
+        Self {
+            is_terminated,
+            tx,
+            client,
+            namespace,
+        }
+    }
+}
+
+#[async_trait]
+impl AbortWorker for NamespaceDescriptionWorker {
+    async fn run(&self) {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(INTERVAL));
+
+        while !self
+            .is_terminated
+            .load(std::sync::atomic::Ordering::Relaxed)
+        {
+            interval.tick().await;
+
+            let description = fetch_namespace_description(&self.client, &self.namespace).await;
+
+            self.tx
+                .send(NamespaceResponse::Description(description).into())
+                .expect("Failed to send NamespaceResponse::Description");
+        }
+    }
+}
+
+pub async fn fetch_namespace_description<C: KubeClientRequest>(
+    client: &C,
+    namespace: &str,
+) -> Result<Vec<String>> {
+    let namespace_path = format!("api/v1/namespaces/{}", namespace);
+    let quotas_path = format!("api/v1/namespaces/{}/resourcequotas", namespace);
+    let limit_ranges_path = format!("api/v1/namespaces/{}/limitranges", namespace);
+
+    let (ns, quotas, limit_ranges) = futures::try_join!(
+        client.request::<Namespace>(&namespace_path),
+        client.request::<List<ResourceQuota>>(&quotas_path),
+        client.request::<List<LimitRange>>(&limit_ranges_path),
+    )?;
+
+    let mut lines = vec![format!(
+        "Status: {}",
+        ns.status
+            .as_ref()
+            .and_then(|status| status.phase.as_deref())
+            .unwrap_or("Unknown")
+    )];
+
+    lines.push("Labels:".to_string());
+
+    match &ns.metadata.labels {
+        Some(labels) if !labels.is_empty() => {
+            lines.extend(
+                labels
+                    .iter()
+                    .map(|(key, value)| format!("  {}={}", key, value)),
+            );
+        }
+        _ => lines.push("  <none>".to_string()),
+    }
+
+    lines.push(String::new());
+
+    lines.extend(quota_lines(&quotas.items));
+
+    lines.push(String::new());
+
+    lines.extend(limit_range_lines(&limit_ranges.items));
+
+    Ok(lines)
+}
+
+/// ResourceQuotaごとに`used/hard`の使用率をバーで表示する
+fn quota_lines(quotas: &[ResourceQuota]) -> Vec<String> {
+    if quotas.is_empty() {
+        return vec!["ResourceQuota: <none>".to_string()];
+    }
+
+    let mut lines = Vec::new();
+
+    for quota in quotas {
+        lines.push(format!(
+            "ResourceQuota: {}",
+            quota.metadata.name.as_deref().unwrap_or_default()
+        ));
+
+        let usages = quota_usages(quota);
+
+        let width = usages
+            .iter()
+            .map(|usage| usage.resource.len())
+            .max()
+            .unwrap_or_default();
+
+        lines.extend(usages.iter().map(|usage| {
+            let bar = usage
+                .ratio
+                .map(|ratio| usage_bar(ratio, QUOTA_BAR_WIDTH))
+                .unwrap_or_default();
+
+            format!(
+                "  {:<width$}  {}  {} / {}",
+                usage.resource,
+                bar,
+                usage.used,
+                usage.hard,
+                width = width
+            )
+        }));
+    }
+
+    lines
+}
+
+/// LimitRangeのtypeごとにデフォルト値や上限を表示する
+fn limit_range_lines(limit_ranges: &[LimitRange]) -> Vec<String> {
+    if limit_ranges.is_empty() {
+        return vec!["LimitRange: <none>".to_string()];
+    }
+
+    let mut lines = Vec::new();
+
+    for limit_range in limit_ranges {
+        lines.push(format!(
+            "LimitRange: {}",
+            limit_range.metadata.name.as_deref().unwrap_or_default()
+        ));
+
+        let Some(spec) = &limit_range.spec else {
+            continue;
+        };
+
+        for limit in &spec.limits {
+            lines.push(format!("  {}", limit.type_));
+
+            let values = [
+                ("Default", &limit.default),
+                ("Default Request", &limit.default_request),
+                ("Max", &limit.max),
+                ("Min", &limit.min),
+                ("Max Limit/Request Ratio", &limit.max_limit_request_ratio),
+            ];
+
+            lines.extend(values.iter().filter_map(|(name, value)| {
+                value
+                    .as_ref()
+                    .filter(|value| !value.is_empty())
+                    .map(|value| format!("    {}: {}", name, quantities(value)))
+            }));
+        }
+    }
+
+    lines
+}
+
+fn quantities(values: &BTreeMap<String, Quantity>) -> String {
+    values
+        .iter()
+        .map(|(key, value)| format!("{}={}", key, value.0))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use mockall::predicate::eq;
+    use pretty_assertions::assert_eq;
+
+    use crate::{kube::mock::MockTestKubeClient, mock_expect};
+
+    use super::*;
+
+    fn namespace() -> Namespace {
+        serde_yaml::from_str(indoc! {"
+            metadata:
+              name: app
+              labels:
+                team: a
+            status:
+              phase: Active
+        "})
+        .unwrap()
+    }
+
+    fn quotas() -> List<ResourceQuota> {
+        serde_yaml::from_str(indoc! {"
+            apiVersion: v1
+            kind: ResourceQuotaList
+            metadata: {}
+            items:
+              - metadata:
+                  name: compute
+                  namespace: app
+                status:
+                  hard:
+                    memory: 1Gi
+                    pods: '10'
+                  used:
+                    memory: 512Mi
+                    pods: '10'
+        "})
+        .unwrap()
+    }
+
+    fn limit_ranges() -> List<LimitRange> {
+        serde_yaml::from_str(indoc! {"
+            apiVersion: v1
+            kind: LimitRangeList
+            metadata: {}
+            items:
+              - metadata:
+                  name: limits
+                  namespace: app
+                spec:
+                  limits:
+                    - type: Container
+                      default:
+                        cpu: 500m
+                        memory: 512Mi
+                      defaultRequest:
+                        cpu: 250m
+        "})
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn ラベルとquotaの使用量とlimit_rangeを表示する() {
+        let mut client = MockTestKubeClient::new();
+
+        mock_expect!(
+            client,
+            request,
+            [
+                (Namespace, eq("api/v1/namespaces/app"), Ok(namespace())),
+                (
+                    List<ResourceQuota>,
+                    eq("api/v1/namespaces/app/resourcequotas"),
+                    Ok(quotas())
+                ),
+                (
+                    List<LimitRange>,
+                    eq("api/v1/namespaces/app/limitranges"),
+                    Ok(limit_ranges())
+                )
+            ]
+        );
+
+        let actual = fetch_namespace_description(&client, "app").await.unwrap();
+
+        assert_eq!(
+            actual,
+            vec![
+                "Status: Active",
+                "Labels:",
+                "  team=a",
+                "",
+                "ResourceQuota: compute",
+                "  memory  [##########----------]  50%  512Mi / 1Gi",
+                "  pods    \x1b[31m[####################] 100%\x1b[39m  10 / 10",
+                "",
+                "LimitRange: limits",
+                "  Container",
+                "    Default: cpu=500m memory=512Mi",
+                "    Default Request: cpu=250m",
+            ]
+        );
+    }
+}
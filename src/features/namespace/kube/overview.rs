@@ -0,0 +1,361 @@
+use std::{collections::BTreeMap, time};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use k8s_openapi::{api::core::v1::ResourceQuota, List};
+
+use crate::{
+    features::namespace::message::NamespaceResponse,
+    kube::{
+        apis::v1_table::{Table, TableRow},
+        table::{KubeTable, KubeTableRow},
+        KubeClientRequest,
+    },
+    logger,
+    workers::kube::{PollerBase, Worker, WorkerResult},
+};
+
+use super::quota::{quota_usages, usage_bar, QuotaUsage};
+
+const QUOTA_BAR_WIDTH: usize = 10;
+
+/// 全namespaceの状態、Podの数、ResourceQuotaの使用率を定期的に取得する
+#[derive(Clone)]
+pub struct NamespaceOverviewPoller {
+    base: PollerBase,
+}
+
+impl NamespaceOverviewPoller {
+    pub fn new(base: PollerBase) -> Self {
+        Self { base }
+    }
+}
+
+#[async_trait]
+impl Worker for NamespaceOverviewPoller {
+    type Output = WorkerResult;
+
+    async fn run(&self) -> Self::Output {
+        let mut interval = tokio::time::interval(time::Duration::from_secs(3));
+
+        let PollerBase {
+            is_terminated,
+            tx,
+            kube_client,
+            connection,
+            ..
+        } = &self.base;
+
+        while !is_terminated.load(std::sync::atomic::Ordering::Relaxed) {
+            connection.tick(&mut interval).await;
+
+            let table = fetch_namespace_overview(kube_client).await;
+
+            connection.report(&table);
+
+            tx.send(NamespaceResponse::Overview(table).into())
+                .expect("Failed to send NamespaceResponse::Overview");
+        }
+
+        WorkerResult::Terminated
+    }
+}
+
+/// namespaceの一覧に、ステータスごとのPodの数と最も使用率の高いResourceQuotaを加えたテーブルを作成する
+///
+/// PodとResourceQuotaは権限がない場合もあるため、取得に失敗した場合は空欄にする
+pub async fn fetch_namespace_overview<C: KubeClientRequest>(client: &C) -> Result<KubeTable> {
+    let (namespaces, pods, quotas) = futures::join!(
+        client.table_request::<Table>("api/v1/namespaces"),
+        client.table_request::<Table>("api/v1/pods"),
+        client.request::<List<ResourceQuota>>("api/v1/resourcequotas"),
+    );
+
+    let namespaces = namespaces?;
+
+    let pods = pods
+        .inspect_err(|e| logger!(error, "Failed to fetch pods: {:?}", e))
+        .ok()
+        .map(|pods| pod_counts(&pods));
+
+    let quotas = quotas
+        .inspect_err(|e| logger!(error, "Failed to fetch resourcequotas: {:?}", e))
+        .ok()
+        .map(|quotas| max_quota_usages(&quotas.items));
+
+    let indexes = namespaces.find_indexes(&["Name", "Status", "Age"]);
+
+    let rows = namespaces
+        .rows
+        .iter()
+        .map(|row| {
+            let name = row.cells[indexes[0]].to_string();
+
+            let pods = pods
+                .as_ref()
+                .map(|pods| {
+                    pods.get(&name)
+                        .map(|counts| {
+                            counts
+                                .iter()
+                                .map(|(status, count)| format!("{}:{}", status, count))
+                                .collect::<Vec<_>>()
+                                .join(" ")
+                        })
+                        .unwrap_or_else(|| "0".to_string())
+                })
+                .unwrap_or_default();
+
+            let quota = quotas
+                .as_ref()
+                .and_then(|quotas| quotas.get(&name))
+                .map(|usage| {
+                    format!(
+                        "{} {}",
+                        usage_bar(usage.ratio.unwrap_or_default(), QUOTA_BAR_WIDTH),
+                        usage.resource
+                    )
+                })
+                .unwrap_or_default();
+
+            KubeTableRow {
+                namespace: name.clone(),
+                name: name.clone(),
+                metadata: None,
+                row: vec![
+                    name,
+                    row.cells[indexes[1]].to_string(),
+                    pods,
+                    quota,
+                    row.cells[indexes[2]].to_string(),
+                    labels(row),
+                ],
+            }
+        })
+        .collect();
+
+    let mut table = KubeTable {
+        header: ["NAME", "STATUS", "PODS", "QUOTA", "AGE", "LABELS"]
+            .iter()
+            .map(ToString::to_string)
+            .collect(),
+        ..Default::default()
+    };
+
+    table.update_rows(rows);
+
+    Ok(table)
+}
+
+/// namespaceごとにPodのステータス（STATUSカラム）ごとの数を数える
+fn pod_counts(pods: &Table) -> BTreeMap<String, BTreeMap<String, usize>> {
+    let mut ret: BTreeMap<String, BTreeMap<String, usize>> = BTreeMap::new();
+
+    let Some(status_index) = pods.find_index("Status") else {
+        return ret;
+    };
+
+    for row in &pods.rows {
+        let Some(namespace) = object_metadata(row, "namespace") else {
+            continue;
+        };
+
+        *ret.entry(namespace.to_string())
+            .or_default()
+            .entry(row.cells[status_index].to_string())
+            .or_default() += 1;
+    }
+
+    ret
+}
+
+/// namespaceごとに最も使用率の高いResourceQuotaのリソースを返す
+fn max_quota_usages(quotas: &[ResourceQuota]) -> BTreeMap<String, QuotaUsage> {
+    let mut ret: BTreeMap<String, QuotaUsage> = BTreeMap::new();
+
+    for quota in quotas {
+        let Some(namespace) = &quota.metadata.namespace else {
+            continue;
+        };
+
+        for usage in quota_usages(quota) {
+            let Some(ratio) = usage.ratio else {
+                continue;
+            };
+
+            let is_max = ret
+                .get(namespace)
+                .and_then(|max| max.ratio)
+                .is_none_or(|max| max < ratio);
+
+            if is_max {
+                ret.insert(namespace.to_string(), usage);
+            }
+        }
+    }
+
+    ret
+}
+
+fn object_metadata<'a>(row: &'a TableRow, key: &str) -> Option<&'a str> {
+    row.object.as_ref()?.0.get("metadata")?.get(key)?.as_str()
+}
+
+fn labels(row: &TableRow) -> String {
+    row.object
+        .as_ref()
+        .and_then(|object| object.0.get("metadata")?.get("labels")?.as_object())
+        .map(|labels| {
+            labels
+                .iter()
+                .map(|(key, value)| format!("{}={}", key, value.as_str().unwrap_or_default()))
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::bail;
+    use indoc::indoc;
+    use mockall::predicate::eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use crate::{kube::mock::MockTestKubeClient, mock_expect};
+
+    use super::*;
+
+    fn namespaces() -> Table {
+        serde_json::from_value(json!({
+            "columnDefinitions": [
+                {"name": "Name", "type": "string", "format": "name", "description": "", "priority": 0},
+                {"name": "Status", "type": "string", "format": "", "description": "", "priority": 0},
+                {"name": "Age", "type": "string", "format": "", "description": "", "priority": 0}
+            ],
+            "rows": [
+                {
+                    "cells": ["app", "Active", "10d"],
+                    "object": {"metadata": {"name": "app", "labels": {"team": "a"}}}
+                },
+                {
+                    "cells": ["empty", "Terminating", "1d"],
+                    "object": {"metadata": {"name": "empty"}}
+                }
+            ]
+        }))
+        .unwrap()
+    }
+
+    fn pods() -> Table {
+        serde_json::from_value(json!({
+            "columnDefinitions": [
+                {"name": "Name", "type": "string", "format": "name", "description": "", "priority": 0},
+                {"name": "Status", "type": "string", "format": "", "description": "", "priority": 0}
+            ],
+            "rows": [
+                {"cells": ["a", "Running"], "object": {"metadata": {"name": "a", "namespace": "app"}}},
+                {"cells": ["b", "Running"], "object": {"metadata": {"name": "b", "namespace": "app"}}},
+                {"cells": ["c", "Pending"], "object": {"metadata": {"name": "c", "namespace": "app"}}}
+            ]
+        }))
+        .unwrap()
+    }
+
+    fn quotas() -> List<ResourceQuota> {
+        serde_yaml::from_str(indoc! {"
+            apiVersion: v1
+            kind: ResourceQuotaList
+            metadata: {}
+            items:
+              - metadata:
+                  name: compute
+                  namespace: app
+                status:
+                  hard:
+                    cpu: '2'
+                    pods: '10'
+                  used:
+                    cpu: 1500m
+                    pods: '3'
+        "})
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn namespaceごとのpodの数とquotaの使用率を表示する() {
+        let mut client = MockTestKubeClient::new();
+
+        mock_expect!(
+            client,
+            table_request,
+            [
+                (Table, eq("api/v1/namespaces"), Ok(namespaces())),
+                (Table, eq("api/v1/pods"), Ok(pods()))
+            ]
+        );
+
+        mock_expect!(
+            client,
+            request,
+            [(
+                List<ResourceQuota>,
+                eq("api/v1/resourcequotas"),
+                Ok(quotas())
+            )]
+        );
+
+        let actual = fetch_namespace_overview(&client).await.unwrap();
+
+        assert_eq!(
+            actual
+                .rows
+                .iter()
+                .map(|r| r.row.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                vec![
+                    "app",
+                    "Active",
+                    "Pending:1 Running:2",
+                    "\x1b[33m[########--]  75%\x1b[39m cpu",
+                    "10d",
+                    "team=a"
+                ],
+                vec!["empty", "Terminating", "0", "", "1d", ""]
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn podとquotaの取得に失敗した場合は空欄にする() {
+        let mut client = MockTestKubeClient::new();
+
+        mock_expect!(
+            client,
+            table_request,
+            [
+                (Table, eq("api/v1/namespaces"), Ok(namespaces())),
+                (Table, eq("api/v1/pods"), bail!("forbidden"))
+            ]
+        );
+
+        mock_expect!(
+            client,
+            request,
+            [(
+                List<ResourceQuota>,
+                eq("api/v1/resourcequotas"),
+                bail!("forbidden")
+            )]
+        );
+
+        let actual = fetch_namespace_overview(&client).await.unwrap();
+
+        assert_eq!(
+            actual.rows[0].row,
+            vec!["app", "Active", "", "", "10d", "team=a"]
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use std::collections::BTreeMap;
+
+use k8s_openapi::api::core::v1::ResourceQuota;
+
+/// 使用率が警告色になるしきい値
+const WARNING_RATIO: f64 = 0.7;
+
+/// 使用率が危険色になるしきい値
+const CRITICAL_RATIO: f64 = 0.9;
+
+/// ResourceQuotaのリソースごとの使用量
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuotaUsage {
+    pub resource: String,
+    pub used: String,
+    pub hard: String,
+    /// used/hard（数値として解釈できない場合はNone）
+    pub ratio: Option<f64>,
+}
+
+/// ResourceQuotaのhardに定義されているリソースの使用量をリソース名順に返す
+pub fn quota_usages(quota: &ResourceQuota) -> Vec<QuotaUsage> {
+    let Some(status) = &quota.status else {
+        return Vec::new();
+    };
+
+    let empty = BTreeMap::new();
+
+    let hard = status.hard.as_ref().unwrap_or(&empty);
+    let used = status.used.as_ref().unwrap_or(&empty);
+
+    hard.iter()
+        .map(|(resource, hard)| {
+            let used = used.get(resource).map(|q| q.0.as_str()).unwrap_or("0");
+
+            let ratio = match (parse_quantity(used), parse_quantity(&hard.0)) {
+                (Some(_), Some(0.0)) => Some(1.0),
+                (Some(used), Some(hard)) => Some(used / hard),
+                _ => None,
+            };
+
+            QuotaUsage {
+                resource: resource.to_string(),
+                used: used.to_string(),
+                hard: hard.0.to_string(),
+                ratio,
+            }
+        })
+        .collect()
+}
+
+/// Kubernetesのquantity（`500m`、`128Mi`、`1e3`など）を数値に変換する
+pub fn parse_quantity(quantity: &str) -> Option<f64> {
+    const SUFFIXES: [(&str, f64); 15] = [
+        ("Ki", 1024.0),
+        ("Mi", 1024.0 * 1024.0),
+        ("Gi", 1024.0 * 1024.0 * 1024.0),
+        ("Ti", 1024.0 * 1024.0 * 1024.0 * 1024.0),
+        ("Pi", 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0),
+        ("Ei", 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0),
+        ("n", 1e-9),
+        ("u", 1e-6),
+        ("m", 1e-3),
+        ("k", 1e3),
+        ("M", 1e6),
+        ("G", 1e9),
+        ("T", 1e12),
+        ("P", 1e15),
+        ("E", 1e18),
+    ];
+
+    let quantity = quantity.trim();
+
+    SUFFIXES
+        .iter()
+        .find_map(|(suffix, multiplier)| {
+            quantity
+                .strip_suffix(suffix)
+                .map(|number| number.parse::<f64>().ok().map(|n| n * multiplier))
+        })
+        .unwrap_or_else(|| quantity.parse::<f64>().ok())
+}
+
+/// 使用率を`[#####-----]  50%`の形式で描画する
+///
+/// 使用率が高い場合は黄色、上限に近い場合は赤色で表示する
+pub fn usage_bar(ratio: f64, width: usize) -> String {
+    let filled = ((ratio.clamp(0.0, 1.0) * width as f64).round() as usize).min(width);
+
+    let bar = format!(
+        "[{}{}] {:>3.0}%",
+        "#".repeat(filled),
+        "-".repeat(width - filled),
+        ratio * 100.0
+    );
+
+    if CRITICAL_RATIO <= ratio {
+        format!("\x1b[31m{}\x1b[39m", bar)
+    } else if WARNING_RATIO <= ratio {
+        format!("\x1b[33m{}\x1b[39m", bar)
+    } else {
+        bar
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("2", Some(2.0))]
+    #[case("500m", Some(0.5))]
+    #[case("128Mi", Some(128.0 * 1024.0 * 1024.0))]
+    #[case("1G", Some(1e9))]
+    #[case("1e3", Some(1000.0))]
+    #[case("1.5Gi", Some(1.5 * 1024.0 * 1024.0 * 1024.0))]
+    #[case("abc", None)]
+    fn quantityを数値に変換する(#[case] quantity: &str, #[case] expected: Option<f64>) {
+        assert_eq!(parse_quantity(quantity), expected);
+    }
+
+    #[rstest]
+    #[case(0.0, "[----------]   0%")]
+    #[case(0.5, "[#####-----]  50%")]
+    #[case(0.75, "\x1b[33m[########--]  75%\x1b[39m")]
+    #[case(1.2, "\x1b[31m[##########] 120%\x1b[39m")]
+    fn 使用率をバーで表示する(#[case] ratio: f64, #[case] expected: &str) {
+        assert_eq!(usage_bar(ratio, 10), expected);
+    }
+
+    #[test]
+    fn hardに定義されたリソースの使用率を計算する() {
+        let quota: ResourceQuota = serde_yaml::from_str(indoc! {"
+            metadata:
+              name: compute
+              namespace: default
+            status:
+              hard:
+                cpu: '2'
+                memory: 1Gi
+                pods: '10'
+              used:
+                cpu: 500m
+                memory: 512Mi
+        "})
+        .unwrap();
+
+        assert_eq!(
+            quota_usages(&quota),
+            vec![
+                QuotaUsage {
+                    resource: "cpu".into(),
+                    used: "500m".into(),
+                    hard: "2".into(),
+                    ratio: Some(0.25),
+                },
+                QuotaUsage {
+                    resource: "memory".into(),
+                    used: "512Mi".into(),
+                    hard: "1Gi".into(),
+                    ratio: Some(0.5),
+                },
+                QuotaUsage {
+                    resource: "pods".into(),
+                    used: "0".into(),
+                    hard: "10".into(),
+                    ratio: Some(0.0),
+                },
+            ]
+        );
+    }
+}
@@ -1,11 +1,14 @@
 use crate::{
+    kube::table::KubeTable,
     message::Message,
-    workers::{kube::message::Kube, kube::TargetNamespaces},
+    workers::{kube::message::Kube, kube::TargetNamespaces, replay::serde_result},
 };
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum NamespaceMessage {
+    #[serde(skip)]
     Request(NamespaceRequest),
     Response(NamespaceResponse),
 }
@@ -14,12 +17,16 @@ pub enum NamespaceMessage {
 pub enum NamespaceRequest {
     Get,
     Set(TargetNamespaces),
+    /// Namespaceタブで選択したnamespaceの詳細
+    Describe(String),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum NamespaceResponse {
-    Get(Result<TargetNamespaces>),
+    Get(#[serde(with = "serde_result")] Result<TargetNamespaces>),
     Set(TargetNamespaces),
+    Overview(#[serde(with = "serde_result")] Result<KubeTable>),
+    Description(#[serde(with = "serde_result")] Result<Vec<String>>),
 }
 
 impl From<NamespaceRequest> for Message {
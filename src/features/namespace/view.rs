@@ -1,5 +1,8 @@
 mod multiple_namespaces_popup;
 mod single_namespace_popup;
+mod tab;
+mod widgets;
 
 pub use multiple_namespaces_popup::*;
 pub use single_namespace_popup::*;
+pub use tab::*;
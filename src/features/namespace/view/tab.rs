@@ -0,0 +1,54 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crossbeam::channel::Sender;
+use ratatui::layout::{Constraint, Direction};
+
+use crate::{
+    clipboard::Clipboard,
+    features::component_id::NAMESPACE_TAB_ID,
+    history::HistoryHandle,
+    message::Message,
+    ui::{
+        tab::{LayoutElement, NestedLayoutElement, NestedWidgetLayout},
+        Tab,
+    },
+};
+
+use super::widgets::{namespace_description_widget, namespace_widget};
+
+pub struct NamespaceTab {
+    pub tab: Tab<'static>,
+}
+
+impl NamespaceTab {
+    pub fn new(
+        title: &'static str,
+        tx: &Sender<Message>,
+        clipboard: &Option<Rc<RefCell<Clipboard>>>,
+        split_direction: Direction,
+        history: &HistoryHandle,
+    ) -> Self {
+        let namespace_widget = namespace_widget(tx, history);
+        let description_widget = namespace_description_widget(clipboard);
+
+        let layout = layout(split_direction);
+
+        Self {
+            tab: Tab::new(
+                NAMESPACE_TAB_ID,
+                title,
+                [namespace_widget, description_widget],
+                layout,
+            ),
+        }
+    }
+}
+
+fn layout(split_direction: Direction) -> NestedWidgetLayout {
+    NestedWidgetLayout::default()
+        .direction(split_direction)
+        .nested_widget_layout([
+            NestedLayoutElement(Constraint::Percentage(50), LayoutElement::WidgetIndex(0)),
+            NestedLayoutElement(Constraint::Percentage(50), LayoutElement::WidgetIndex(1)),
+        ])
+}
@@ -0,0 +1,101 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crossbeam::channel::Sender;
+use ratatui::widgets::Block;
+
+use crate::{
+    clipboard::Clipboard,
+    features::{
+        component_id::{NAMESPACE_DESCRIPTION_WIDGET_ID, NAMESPACE_WIDGET_ID},
+        namespace::message::NamespaceRequest,
+    },
+    history::HistoryHandle,
+    message::Message,
+    ui::{
+        event::EventResult,
+        widget::{config::WidgetConfig, Table, TableItem, Text, Widget, WidgetTrait as _},
+        Window, WindowAction,
+    },
+};
+
+pub fn namespace_widget(tx: &Sender<Message>, history: &HistoryHandle) -> Widget<'static> {
+    Table::builder()
+        .id(NAMESPACE_WIDGET_ID)
+        .widget_config(&WidgetConfig::builder().title("Namespace").build())
+        .filtered_key("NAME")
+        .filter_history(
+            history.loader("namespace_filter"),
+            history.saver("namespace_filter"),
+        )
+        .block_injection(table_block_injection())
+        .on_select(on_select(tx.clone()))
+        .build()
+        .into()
+}
+
+pub fn namespace_description_widget(clipboard: &Option<Rc<RefCell<Clipboard>>>) -> Widget<'static> {
+    let builder = Text::builder()
+        .id(NAMESPACE_DESCRIPTION_WIDGET_ID)
+        .widget_config(&WidgetConfig::builder().title("Description").build())
+        .block_injection(text_block_injection());
+
+    if let Some(cb) = clipboard {
+        builder.clipboard(cb.clone())
+    } else {
+        builder
+    }
+    .build()
+    .into()
+}
+
+fn table_block_injection() -> impl Fn(&Table) -> WidgetConfig {
+    |table: &Table| {
+        let index = if let Some(index) = table.state().selected() {
+            index + 1
+        } else {
+            0
+        };
+
+        let mut widget_config = table.widget_config().clone();
+
+        *widget_config.append_title_mut() =
+            Some(format!(" [{}/{}]", index, table.items().len()).into());
+
+        widget_config
+    }
+}
+
+fn text_block_injection() -> impl Fn(&Text, bool, bool) -> Block<'static> {
+    |text: &Text, is_active: bool, is_mouse_over: bool| {
+        let (index, size) = text.state();
+
+        let mut config = text.widget_config().clone();
+
+        *config.title_mut() = format!("Description [{}/{}]", index, size).into();
+
+        config.render_block(text.can_activate() && is_active, is_mouse_over)
+    }
+}
+
+fn on_select(tx: Sender<Message>) -> impl Fn(&mut Window, &TableItem) -> EventResult {
+    move |w: &mut Window, v: &TableItem| {
+        w.widget_clear(NAMESPACE_DESCRIPTION_WIDGET_ID);
+
+        let Some(name) = v
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get("name"))
+        else {
+            return EventResult::Ignore;
+        };
+
+        *(w.find_widget_mut(NAMESPACE_DESCRIPTION_WIDGET_ID)
+            .widget_config_mut()
+            .append_title_mut()) = Some((format!(" : {}", name)).into());
+
+        tx.send(NamespaceRequest::Describe(name.to_string()).into())
+            .expect("Failed to send NamespaceRequest::Describe");
+
+        EventResult::WindowAction(WindowAction::Continue)
+    }
+}
@@ -1,20 +1,28 @@
 mod gateway;
+mod gateway_references;
+mod grpcroute;
 mod httproute;
 mod ingress;
 mod network_policy;
 mod pod;
+mod route_related_resources;
 mod service;
+mod tcproute;
+mod tlsroute;
 mod utils;
 
 #[allow(dead_code)]
-mod related_resources;
+pub(super) mod related_resources;
 
 use std::sync::{atomic::AtomicBool, Arc};
 
 use crate::{
     features::{
         api_resources::kube::SharedApiResources,
-        network::message::{NetworkRequest, NetworkRequestTargetParams, NetworkResponse},
+        network::message::{
+            NetworkRequest, NetworkRequestTargetParams, NetworkResponse, RelatedResource,
+            RelatedResourceGraph,
+        },
     },
     kube::KubeClientRequest,
     message::Message,
@@ -22,18 +30,47 @@ use crate::{
 };
 
 use self::{
-    gateway::GatewayDescriptionWorker, httproute::HTTPRouteDescriptionWorker,
-    ingress::IngressDescriptionWorker, network_policy::NetworkPolicyDescriptionWorker,
-    pod::PodDescriptionWorker, service::ServiceDescriptionWorker,
+    gateway::GatewayDescriptionWorker, grpcroute::GRPCRouteDescriptionWorker,
+    httproute::HTTPRouteDescriptionWorker, ingress::IngressDescriptionWorker,
+    network_policy::NetworkPolicyDescriptionWorker, pod::PodDescriptionWorker,
+    service::ServiceDescriptionWorker, tcproute::TCPRouteDescriptionWorker,
+    tlsroute::TLSRouteDescriptionWorker,
 };
 
 use anyhow::Result;
 use async_trait::async_trait;
 use crossbeam::channel::Sender;
+use k8s_openapi::{List, ListableResource};
+use kube::{Resource, ResourceExt as _};
 
 const INTERVAL: u64 = 3;
 
-type FetchedData = Vec<String>;
+#[derive(Debug, Default, PartialEq)]
+struct FetchedData {
+    /// Descriptionに表示する行
+    yaml: Vec<String>,
+
+    /// 関連リソースのグラフに使う関連リソース
+    related_resources: Vec<RelatedResource>,
+}
+
+/// 関連リソースの一覧をDescriptionのリソースに直接関連するものとして返す
+fn related_resources_from_list<K>(list: &List<K>, namespace: &str) -> Vec<RelatedResource>
+where
+    K: Resource<DynamicType = ()> + ListableResource,
+{
+    list.items
+        .iter()
+        .map(|item| {
+            RelatedResource::new(
+                K::kind(&()),
+                item.name_any(),
+                item.namespace().unwrap_or_else(|| namespace.to_string()),
+                K::version(&()),
+            )
+        })
+        .collect()
+}
 
 #[async_trait]
 trait Fetch<'a, C: KubeClientRequest> {
@@ -108,6 +145,18 @@ where
                 self.fetch_description::<HTTPRouteDescriptionWorker<C>>()
                     .await
             }
+            NetworkRequest::GRPCRoute(_) => {
+                self.fetch_description::<GRPCRouteDescriptionWorker<C>>()
+                    .await
+            }
+            NetworkRequest::TLSRoute(_) => {
+                self.fetch_description::<TLSRouteDescriptionWorker<C>>()
+                    .await
+            }
+            NetworkRequest::TCPRoute(_) => {
+                self.fetch_description::<TCPRouteDescriptionWorker<C>>()
+                    .await
+            }
         };
 
         if let Err(e) = ret {
@@ -140,15 +189,44 @@ where
         {
             interval.tick().await;
 
-            let fetched_data = worker.fetch().await;
-
-            self.tx
-                .send(NetworkResponse::Yaml(fetched_data).into())
-                .expect("Failed to send NetworkResponse::Yaml");
+            match worker.fetch().await {
+                Ok(FetchedData {
+                    yaml,
+                    related_resources,
+                }) => {
+                    self.tx
+                        .send(NetworkResponse::Yaml(Ok(yaml)).into())
+                        .expect("Failed to send NetworkResponse::Yaml");
+
+                    let graph = RelatedResourceGraph {
+                        root: self.root(),
+                        related_resources,
+                    };
+
+                    self.tx
+                        .send(NetworkResponse::Graph(graph).into())
+                        .expect("Failed to send NetworkResponse::Graph");
+                }
+                Err(err) => {
+                    self.tx
+                        .send(NetworkResponse::Yaml(Err(err)).into())
+                        .expect("Failed to send NetworkResponse::Yaml");
+                }
+            }
         }
 
         Ok(())
     }
+
+    fn root(&self) -> RelatedResource {
+        let NetworkRequestTargetParams {
+            name,
+            namespace,
+            version,
+        } = self.req.data();
+
+        RelatedResource::new(self.req.kind(), name, namespace, version)
+    }
 }
 
 #[cfg(test)]
@@ -400,6 +478,8 @@ mod tests {
 
             let event = rx.recv().unwrap();
 
+            let graph_event = rx.recv().unwrap();
+
             is_terminated.store(true, std::sync::atomic::Ordering::Relaxed);
 
             let _ret = handle.await;
@@ -436,6 +516,21 @@ mod tests {
             } else {
                 unreachable!()
             }
+
+            if let Message::Kube(Kube::Network(NetworkMessage::Response(NetworkResponse::Graph(
+                actual,
+            )))) = graph_event
+            {
+                assert_eq!(
+                    actual,
+                    RelatedResourceGraph {
+                        root: RelatedResource::new("Pod", "test", "default", "v1"),
+                        related_resources: vec![],
+                    }
+                )
+            } else {
+                unreachable!()
+            }
         }
 
         #[tokio::test(flavor = "multi_thread")]
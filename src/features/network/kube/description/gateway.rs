@@ -14,7 +14,7 @@ use crate::{
     },
 };
 
-use super::{Fetch, FetchedData};
+use super::{gateway_references::validate_gateway, Fetch, FetchedData};
 
 pub(super) struct GatewayDescriptionWorker<'a, C>
 where
@@ -71,6 +71,13 @@ async fn fetch_v1(client: Client, name: &str, namespace: &str) -> Result<Fetched
 
     let description = v1::Description::new(gateway.clone());
 
+    let validation = validate_gateway::<gateway::v1::Gateway, gateway::v1::HTTPRoute>(
+        client.clone(),
+        namespace,
+        &gateway,
+    )
+    .await;
+
     let related_resources =
         v1::discover_releated_resources(client, name, namespace, &gateway).await?;
 
@@ -79,6 +86,12 @@ async fn fetch_v1(client: Client, name: &str, namespace: &str) -> Result<Fetched
         .map(ToString::to_string)
         .collect::<Vec<String>>();
 
+    if !validation.is_empty() {
+        yaml.push("".into());
+
+        yaml.append(&mut validation.to_yaml_lines()?);
+    }
+
     let mut related_resources_yaml = serde_yaml::to_string(&related_resources)?
         .lines()
         .map(ToString::to_string)
@@ -90,7 +103,10 @@ async fn fetch_v1(client: Client, name: &str, namespace: &str) -> Result<Fetched
         yaml.append(&mut related_resources_yaml);
     }
 
-    Ok(yaml)
+    Ok(FetchedData {
+        yaml,
+        related_resources: related_resources.to_related_resources(),
+    })
 }
 
 async fn fetch_v1beta1(client: Client, name: &str, namespace: &str) -> Result<FetchedData> {
@@ -103,6 +119,13 @@ async fn fetch_v1beta1(client: Client, name: &str, namespace: &str) -> Result<Fe
 
     let description = v1beta1::Description::new(gateway.clone());
 
+    let validation = validate_gateway::<gateway::v1beta1::Gateway, gateway::v1beta1::HTTPRoute>(
+        client.clone(),
+        namespace,
+        &gateway,
+    )
+    .await;
+
     let related_resources =
         v1beta1::discover_releated_resources(client, name, namespace, &gateway).await?;
 
@@ -111,6 +134,12 @@ async fn fetch_v1beta1(client: Client, name: &str, namespace: &str) -> Result<Fe
         .map(ToString::to_string)
         .collect::<Vec<String>>();
 
+    if !validation.is_empty() {
+        yaml.push("".into());
+
+        yaml.append(&mut validation.to_yaml_lines()?);
+    }
+
     let mut related_resources_yaml = serde_yaml::to_string(&related_resources)?
         .lines()
         .map(ToString::to_string)
@@ -122,5 +151,100 @@ async fn fetch_v1beta1(client: Client, name: &str, namespace: &str) -> Result<Fe
         yaml.append(&mut related_resources_yaml);
     }
 
-    Ok(yaml)
+    Ok(FetchedData {
+        yaml,
+        related_resources: related_resources.to_related_resources(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        features::api_resources::kube::ApiResources, kube::fake_api_server::FakeApiServer,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn namespaceとsecretを取得できない場合も参照をunknownとして返す() {
+        let server = FakeApiServer::from_yaml(indoc! {r#"
+            resources:
+              - apiVersion: gateway.networking.k8s.io/v1
+                kind: Gateway
+                metadata:
+                  name: gateway
+                  namespace: infra
+                spec:
+                  gatewayClassName: example
+                  listeners:
+                    - name: https
+                      port: 443
+                      protocol: HTTPS
+                      allowedRoutes:
+                        namespaces:
+                          from: Selector
+                          selector:
+                            matchLabels:
+                              gateway: allowed
+                      tls:
+                        certificateRefs:
+                          - name: tls
+              - apiVersion: gateway.networking.k8s.io/v1
+                kind: HTTPRoute
+                metadata:
+                  name: route
+                  namespace: app
+                spec:
+                  parentRefs:
+                    - name: gateway
+                      namespace: infra
+            forbidden:
+              - namespaces
+              - secrets
+        "#})
+        .unwrap();
+
+        let client = server.client();
+
+        let worker = GatewayDescriptionWorker::new(
+            &client,
+            NetworkRequestTargetParams {
+                namespace: "infra".to_string(),
+                name: "gateway".to_string(),
+                version: "v1".to_string(),
+            },
+            ApiResources::shared(),
+        );
+
+        let yaml = worker.fetch().await.unwrap().yaml;
+
+        let validation: Vec<&str> = yaml
+            .iter()
+            .map(String::as_str)
+            .skip_while(|line| *line != "validation:")
+            .take_while(|line| !line.is_empty())
+            .filter(|line| !line.trim_start().starts_with("reason:"))
+            .collect();
+
+        assert_eq!(
+            validation,
+            vec![
+                "validation:",
+                "  parentRefs:",
+                "  - route: app/route",
+                "    kind: Gateway",
+                "    name: gateway",
+                "    namespace: infra",
+                "    status: Unknown",
+                "  certificateRefs:",
+                "  - kind: Secret",
+                "    name: tls",
+                "    namespace: infra",
+                "    status: Unknown",
+            ]
+        );
+    }
 }
@@ -6,7 +6,15 @@ use anyhow::{Context as _, Result};
 use kube::Client;
 use serde::{Deserialize, Serialize};
 
-use crate::kube::apis::networking::gateway::v1beta1::Gateway;
+use k8s_openapi::{
+    api::core::v1::{Pod, Service},
+    Resource as _,
+};
+
+use crate::{
+    features::network::message::RelatedResource,
+    kube::apis::networking::gateway::v1beta1::{Gateway, HTTPRoute},
+};
 
 use self::{
     httproute::{discover_httproutes, RelatedHTTPRoutes},
@@ -20,6 +28,52 @@ pub struct GatewayRelatedResources {
     related_resources: GatewayRelatedResourceItems,
 }
 
+impl GatewayRelatedResources {
+    /// 関連リソースのグラフに使う形式で返す
+    ///
+    /// ServiceはHTTPRouteの下に、PodはServiceの下に置く
+    pub fn to_related_resources(&self) -> Vec<RelatedResource> {
+        let GatewayRelatedResourceItems {
+            httproutes,
+            services,
+            pods,
+        } = &self.related_resources;
+
+        let httproutes = httproutes.iter().flatten().map(|httproute| {
+            RelatedResource::new(
+                HTTPRoute::KIND,
+                &httproute.name,
+                &httproute.namespace,
+                HTTPRoute::VERSION,
+            )
+        });
+
+        let services = services.iter().flatten().map(|service| {
+            RelatedResource::new(
+                Service::KIND,
+                &service.name,
+                &service.namespace,
+                Service::VERSION,
+            )
+            .parent(
+                HTTPRoute::KIND,
+                &service.httproute,
+                &service.httproute_namespace,
+            )
+        });
+
+        let pods = pods.iter().flatten().map(|pod| {
+            RelatedResource::new(Pod::KIND, &pod.name, &pod.namespace, Pod::VERSION).parent(
+                Service::KIND,
+                &pod.service,
+                &pod.namespace,
+            )
+        });
+
+        httproutes.chain(services).chain(pods).collect()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct GatewayRelatedResourceItems {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -30,14 +84,6 @@ struct GatewayRelatedResourceItems {
     pods: Option<RelatedPods>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-struct RelatedResource {
-    name: String,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    namespace: Option<String>,
-}
-
 pub async fn discover_releated_resources(
     client: Client,
     gateway_name: &str,
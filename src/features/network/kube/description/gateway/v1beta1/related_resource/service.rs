@@ -26,6 +26,10 @@ pub struct RelatedService {
     /// HTTPRoute Name
     pub httproute: String,
 
+    /// HTTPRoute Namespace
+    #[serde(skip)]
+    pub httproute_namespace: String,
+
     #[derivative(PartialEq = "ignore", PartialOrd = "ignore", Ord = "ignore")]
     #[serde(skip)]
     pub resource: Service,
@@ -131,6 +135,7 @@ async fn fetch_service(
             name: service.name_any(),
             namespace: service.extract_namespace(),
             httproute: httproute_name.to_string(),
+            httproute_namespace: httproute_namespace.to_string(),
             resource: service,
         }),
 
@@ -0,0 +1,1389 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    future::Future,
+};
+
+use anyhow::Result;
+use futures::{future, stream, StreamExt as _};
+use k8s_openapi::{
+    api::core::v1::{Namespace, Secret, Service},
+    apimachinery::pkg::apis::meta::v1::LabelSelector,
+    NamespaceResourceScope, Resource as _,
+};
+use kube::{api::ListParams, Api, Client};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{
+    kube::apis::networking::gateway::{self, v1beta1::ReferenceGrant},
+    logger,
+};
+
+use super::related_resources::label_selector::{
+    LabelSelectorExpression as _, LabelSelectorWrapper,
+};
+
+/// 検証のために参照先を取得するときの同時リクエスト数の上限
+const MAX_CONCURRENT_REQUESTS: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReferenceStatus {
+    Valid,
+    Invalid,
+    Missing,
+    NotPermitted,
+    /// 権限がないなどで参照先を取得できず、検証できない
+    Unknown,
+}
+
+/// 参照先の取得結果
+#[derive(Debug, Clone, PartialEq)]
+pub enum Lookup<T> {
+    Found(T),
+    NotFound,
+    /// 取得に失敗した理由
+    Failed(String),
+}
+
+impl<T> From<kube::Result<Option<T>>> for Lookup<T> {
+    fn from(result: kube::Result<Option<T>>) -> Self {
+        match result {
+            Ok(Some(value)) => Lookup::Found(value),
+            Ok(None) => Lookup::NotFound,
+            Err(err) => Lookup::Failed(err.to_string()),
+        }
+    }
+}
+
+impl<T> Lookup<T> {
+    fn as_ref(&self) -> Lookup<&T> {
+        match self {
+            Lookup::Found(value) => Lookup::Found(value),
+            Lookup::NotFound => Lookup::NotFound,
+            Lookup::Failed(err) => Lookup::Failed(err.clone()),
+        }
+    }
+
+    fn map<U>(self, f: impl FnOnce(T) -> U) -> Lookup<U> {
+        match self {
+            Lookup::Found(value) => Lookup::Found(f(value)),
+            Lookup::NotFound => Lookup::NotFound,
+            Lookup::Failed(err) => Lookup::Failed(err),
+        }
+    }
+}
+
+/// 参照元のリソース
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Referrer {
+    pub kind: String,
+    pub name: String,
+    pub namespace: String,
+}
+
+/// parentRefs, backendRefs, certificateRefsの参照先
+///
+/// バージョンごとに型が異なるため、この型に変換してから検証する
+/// namespaceは省略時に参照元のnamespaceで補完したもの
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Reference {
+    pub group: String,
+    pub kind: String,
+    pub name: String,
+    pub namespace: String,
+    pub port: Option<i32>,
+    pub section_name: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferenceValidation {
+    /// Gatewayの検証結果に含めるときの参照元のルート
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub route: Option<String>,
+
+    pub kind: String,
+
+    pub name: String,
+
+    pub namespace: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub section_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port: Option<i32>,
+
+    pub status: ReferenceStatus,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl ReferenceValidation {
+    fn new(reference: &Reference, status: ReferenceStatus, reason: Option<String>) -> Self {
+        Self {
+            route: None,
+            kind: reference.kind.clone(),
+            name: reference.name.clone(),
+            namespace: reference.namespace.clone(),
+            section_name: reference.section_name.clone(),
+            port: reference.port,
+            status,
+            reason,
+        }
+    }
+
+    fn valid(reference: &Reference) -> Self {
+        Self::new(reference, ReferenceStatus::Valid, None)
+    }
+
+    fn invalid(reference: &Reference, status: ReferenceStatus, reason: String) -> Self {
+        Self::new(reference, status, Some(reason))
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.status == ReferenceStatus::Valid
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Validation {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub parent_refs: Vec<ReferenceValidation>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub backend_refs: Vec<ReferenceValidation>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub certificate_refs: Vec<ReferenceValidation>,
+}
+
+impl Validation {
+    pub fn is_empty(&self) -> bool {
+        self.parent_refs.is_empty()
+            && self.backend_refs.is_empty()
+            && self.certificate_refs.is_empty()
+    }
+
+    /// `validation:`をキーとしたyamlの行
+    pub fn to_yaml_lines(&self) -> Result<Vec<String>> {
+        if self.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let root = BTreeMap::from([("validation", self)]);
+
+        Ok(serde_yaml::to_string(&root)?
+            .lines()
+            .map(ToString::to_string)
+            .collect())
+    }
+}
+
+/// Listenerが受け入れるルートのnamespace
+#[derive(Debug, Clone, PartialEq)]
+pub enum AllowedNamespaces {
+    Same,
+    All,
+    Selector(LabelSelector),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Listener {
+    pub name: String,
+    pub port: i32,
+    pub allowed_namespaces: AllowedNamespaces,
+    /// 未指定の場合はListenerのプロトコルに依存するため検証しない
+    pub allowed_kinds: Option<Vec<String>>,
+}
+
+/// バージョンごとのGatewayからListenerと証明書の参照を取り出す
+pub trait GatewayResource:
+    kube::Resource<DynamicType = (), Scope = NamespaceResourceScope>
+    + DeserializeOwned
+    + Clone
+    + std::fmt::Debug
+    + Send
+    + Sync
+    + 'static
+{
+    fn listeners(&self) -> Vec<Listener>;
+
+    fn certificate_refs(&self, namespace: &str) -> Vec<Reference>;
+}
+
+/// kind、バージョンごとのルートからparentRefsとbackendRefsを取り出す
+pub trait RouteResource:
+    kube::Resource<DynamicType = (), Scope = NamespaceResourceScope>
+    + DeserializeOwned
+    + Clone
+    + std::fmt::Debug
+    + Send
+    + Sync
+    + 'static
+{
+    fn parent_refs(&self, namespace: &str) -> Vec<Reference>;
+
+    fn backend_refs(&self, namespace: &str) -> Vec<Reference>;
+}
+
+/// 省略できるフィールド（`Option<Vec<T>>`）とできないフィールド（`Vec<T>`）を同じように走査する
+///
+/// TLSRoute、TCPRouteの`rules`は省略できない
+trait Items<T> {
+    fn items(&self) -> &[T];
+}
+
+impl<T> Items<T> for Vec<T> {
+    fn items(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T> Items<T> for Option<Vec<T>> {
+    fn items(&self) -> &[T] {
+        self.as_deref().unwrap_or_default()
+    }
+}
+
+macro_rules! impl_gateway_resources {
+    ($version:ident) => {
+        impl GatewayResource for gateway::$version::Gateway {
+            fn listeners(&self) -> Vec<Listener> {
+                use gateway::$version::{
+                    GatewayListenersAllowedRoutes, GatewayListenersAllowedRoutesNamespaces,
+                    GatewayListenersAllowedRoutesNamespacesFrom as From,
+                };
+
+                self.spec
+                    .listeners
+                    .iter()
+                    .map(|listener| {
+                        let (namespaces, kinds) = match &listener.allowed_routes {
+                            Some(GatewayListenersAllowedRoutes { kinds, namespaces }) => {
+                                (namespaces.clone(), kinds.clone())
+                            }
+                            None => (None, None),
+                        };
+
+                        let allowed_namespaces = match namespaces {
+                            Some(GatewayListenersAllowedRoutesNamespaces {
+                                from: Some(From::All),
+                                ..
+                            }) => AllowedNamespaces::All,
+                            Some(GatewayListenersAllowedRoutesNamespaces {
+                                from: Some(From::Selector),
+                                selector,
+                            }) => AllowedNamespaces::Selector(
+                                selector.map(LabelSelector::from).unwrap_or_default(),
+                            ),
+                            _ => AllowedNamespaces::Same,
+                        };
+
+                        let allowed_kinds = kinds
+                            .filter(|kinds| !kinds.is_empty())
+                            .map(|kinds| kinds.into_iter().map(|kind| kind.kind).collect());
+
+                        Listener {
+                            name: listener.name.clone(),
+                            port: listener.port,
+                            allowed_namespaces,
+                            allowed_kinds,
+                        }
+                    })
+                    .collect()
+            }
+
+            fn certificate_refs(&self, namespace: &str) -> Vec<Reference> {
+                let mut refs: Vec<Reference> = self
+                    .spec
+                    .listeners
+                    .iter()
+                    .filter_map(|listener| listener.tls.as_ref())
+                    .flat_map(|tls| tls.certificate_refs.iter().flatten())
+                    .map(|r| Reference {
+                        group: r.group.clone().unwrap_or_default(),
+                        kind: r.kind.clone().unwrap_or_else(|| Secret::KIND.to_string()),
+                        name: r.name.clone(),
+                        namespace: r.namespace.clone().unwrap_or_else(|| namespace.to_string()),
+                        port: None,
+                        section_name: None,
+                    })
+                    .collect();
+
+                refs.sort();
+                refs.dedup();
+
+                refs
+            }
+        }
+    };
+}
+
+macro_rules! impl_route_resource {
+    ($version:ident, $route:ident) => {
+        impl RouteResource for gateway::$version::$route {
+            fn parent_refs(&self, namespace: &str) -> Vec<Reference> {
+                self.spec
+                    .parent_refs
+                    .iter()
+                    .flatten()
+                    .map(|r| Reference {
+                        group: r
+                            .group
+                            .clone()
+                            .unwrap_or_else(|| gateway::v1::Gateway::GROUP.to_string()),
+                        kind: r
+                            .kind
+                            .clone()
+                            .unwrap_or_else(|| gateway::v1::Gateway::KIND.to_string()),
+                        name: r.name.clone(),
+                        namespace: r.namespace.clone().unwrap_or_else(|| namespace.to_string()),
+                        port: r.port,
+                        section_name: r.section_name.clone(),
+                    })
+                    .collect()
+            }
+
+            fn backend_refs(&self, namespace: &str) -> Vec<Reference> {
+                let mut refs: Vec<Reference> = self
+                    .spec
+                    .rules
+                    .items()
+                    .iter()
+                    .flat_map(|rule| rule.backend_refs.iter().flatten())
+                    .map(|r| Reference {
+                        group: r.group.clone().unwrap_or_default(),
+                        kind: r.kind.clone().unwrap_or_else(|| Service::KIND.to_string()),
+                        name: r.name.clone(),
+                        namespace: r.namespace.clone().unwrap_or_else(|| namespace.to_string()),
+                        port: r.port,
+                        section_name: None,
+                    })
+                    .collect();
+
+                refs.sort();
+                refs.dedup();
+
+                refs
+            }
+        }
+    };
+}
+
+impl_gateway_resources!(v1);
+impl_gateway_resources!(v1beta1);
+
+impl_route_resource!(v1, HTTPRoute);
+impl_route_resource!(v1beta1, HTTPRoute);
+impl_route_resource!(v1, GRPCRoute);
+impl_route_resource!(v1alpha2, GRPCRoute);
+impl_route_resource!(v1alpha2, TLSRoute);
+impl_route_resource!(v1alpha2, TCPRoute);
+
+/// 参照先のnamespaceにあるReferenceGrantが、参照元からの参照を許可しているか
+pub fn is_permitted(grants: &[ReferenceGrant], referrer: &Referrer, reference: &Reference) -> bool {
+    if referrer.namespace == reference.namespace {
+        return true;
+    }
+
+    grants
+        .iter()
+        .filter(|grant| grant.metadata.namespace.as_deref() == Some(reference.namespace.as_str()))
+        .any(|grant| {
+            let from = grant.spec.from.iter().any(|from| {
+                from.group == gateway::v1::Gateway::GROUP
+                    && from.kind == referrer.kind
+                    && from.namespace == referrer.namespace
+            });
+
+            let to = grant.spec.to.iter().any(|to| {
+                to.group == reference.group
+                    && to.kind == reference.kind
+                    && to.name.as_ref().is_none_or(|name| name == &reference.name)
+            });
+
+            from && to
+        })
+}
+
+fn not_permitted_reason(referrer: &Referrer, reference: &Reference) -> String {
+    format!(
+        "no ReferenceGrant in namespace {} allows {} from namespace {}",
+        reference.namespace, referrer.kind, referrer.namespace
+    )
+}
+
+/// backendRefの検証
+///
+/// serviceは参照先のServiceの取得結果
+pub fn check_backend_ref(
+    referrer: &Referrer,
+    reference: &Reference,
+    service: Lookup<&Service>,
+    grants: &[ReferenceGrant],
+) -> ReferenceValidation {
+    if !reference.group.is_empty() || reference.kind != Service::KIND {
+        return ReferenceValidation::invalid(
+            reference,
+            ReferenceStatus::Invalid,
+            format!("unsupported backend kind {}", group_kind(reference)),
+        );
+    }
+
+    if !is_permitted(grants, referrer, reference) {
+        return ReferenceValidation::invalid(
+            reference,
+            ReferenceStatus::NotPermitted,
+            not_permitted_reason(referrer, reference),
+        );
+    }
+
+    let service = match service {
+        Lookup::Found(service) => service,
+        Lookup::NotFound => {
+            return ReferenceValidation::invalid(
+                reference,
+                ReferenceStatus::Missing,
+                "service not found".to_string(),
+            );
+        }
+        Lookup::Failed(err) => {
+            return ReferenceValidation::invalid(
+                reference,
+                ReferenceStatus::Unknown,
+                format!("failed to get service: {}", err),
+            );
+        }
+    };
+
+    let Some(port) = reference.port else {
+        return ReferenceValidation::invalid(
+            reference,
+            ReferenceStatus::Invalid,
+            "port is required for Service backends".to_string(),
+        );
+    };
+
+    let has_port = service
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.ports.as_ref())
+        .is_some_and(|ports| ports.iter().any(|p| p.port == port));
+
+    if !has_port {
+        return ReferenceValidation::invalid(
+            reference,
+            ReferenceStatus::Invalid,
+            format!("service has no port {}", port),
+        );
+    }
+
+    ReferenceValidation::valid(reference)
+}
+
+/// parentRefの検証
+///
+/// listenersは参照先のGatewayのListenerの取得結果
+/// referrer_namespace_labelsは参照元のnamespaceのラベルで、取得できなかった場合はNone
+pub fn check_parent_ref(
+    referrer: &Referrer,
+    referrer_namespace_labels: Option<&BTreeMap<String, String>>,
+    reference: &Reference,
+    listeners: Lookup<&[Listener]>,
+) -> ReferenceValidation {
+    if reference.group != gateway::v1::Gateway::GROUP
+        || reference.kind != gateway::v1::Gateway::KIND
+    {
+        return ReferenceValidation::invalid(
+            reference,
+            ReferenceStatus::Invalid,
+            format!("unsupported parent kind {}", group_kind(reference)),
+        );
+    }
+
+    let listeners = match listeners {
+        Lookup::Found(listeners) => listeners,
+        Lookup::NotFound => {
+            return ReferenceValidation::invalid(
+                reference,
+                ReferenceStatus::Missing,
+                "gateway not found".to_string(),
+            );
+        }
+        Lookup::Failed(err) => {
+            return ReferenceValidation::invalid(
+                reference,
+                ReferenceStatus::Unknown,
+                format!("failed to get gateway: {}", err),
+            );
+        }
+    };
+
+    let listeners: Vec<&Listener> = listeners
+        .iter()
+        .filter(|listener| {
+            reference
+                .section_name
+                .as_ref()
+                .is_none_or(|name| name == &listener.name)
+                && reference.port.is_none_or(|port| port == listener.port)
+        })
+        .collect();
+
+    if listeners.is_empty() {
+        return ReferenceValidation::invalid(
+            reference,
+            ReferenceStatus::Invalid,
+            "no matching listener".to_string(),
+        );
+    }
+
+    let kind_allowed: Vec<&Listener> = listeners
+        .into_iter()
+        .filter(|listener| {
+            listener
+                .allowed_kinds
+                .as_ref()
+                .is_none_or(|kinds| kinds.contains(&referrer.kind))
+        })
+        .collect();
+
+    if kind_allowed.is_empty() {
+        return ReferenceValidation::invalid(
+            reference,
+            ReferenceStatus::NotPermitted,
+            format!(
+                "listener allowedRoutes does not allow kind {}",
+                referrer.kind
+            ),
+        );
+    }
+
+    let namespace_allowed =
+        kind_allowed
+            .iter()
+            .any(|listener| match &listener.allowed_namespaces {
+                AllowedNamespaces::Same => referrer.namespace == reference.namespace,
+                AllowedNamespaces::All => true,
+                AllowedNamespaces::Selector(selector) => {
+                    referrer_namespace_labels.is_some_and(|labels| {
+                        LabelSelectorWrapper::new(selector.clone()).expression(labels)
+                    })
+                }
+            });
+
+    let needs_labels = kind_allowed
+        .iter()
+        .any(|listener| matches!(listener.allowed_namespaces, AllowedNamespaces::Selector(_)));
+
+    if !namespace_allowed && needs_labels && referrer_namespace_labels.is_none() {
+        return ReferenceValidation::invalid(
+            reference,
+            ReferenceStatus::Unknown,
+            format!("failed to get labels of namespace {}", referrer.namespace),
+        );
+    }
+
+    if !namespace_allowed {
+        return ReferenceValidation::invalid(
+            reference,
+            ReferenceStatus::NotPermitted,
+            format!(
+                "listener allowedRoutes does not allow namespace {}",
+                referrer.namespace
+            ),
+        );
+    }
+
+    ReferenceValidation::valid(reference)
+}
+
+/// certificateRefの検証
+///
+/// secretは参照先のSecretの取得結果
+pub fn check_certificate_ref(
+    referrer: &Referrer,
+    reference: &Reference,
+    secret: Lookup<()>,
+    grants: &[ReferenceGrant],
+) -> ReferenceValidation {
+    if !reference.group.is_empty() || reference.kind != Secret::KIND {
+        return ReferenceValidation::invalid(
+            reference,
+            ReferenceStatus::Invalid,
+            format!("unsupported certificate kind {}", group_kind(reference)),
+        );
+    }
+
+    if !is_permitted(grants, referrer, reference) {
+        return ReferenceValidation::invalid(
+            reference,
+            ReferenceStatus::NotPermitted,
+            not_permitted_reason(referrer, reference),
+        );
+    }
+
+    match secret {
+        Lookup::Found(()) => ReferenceValidation::valid(reference),
+        Lookup::NotFound => ReferenceValidation::invalid(
+            reference,
+            ReferenceStatus::Missing,
+            "secret not found".to_string(),
+        ),
+        Lookup::Failed(err) => ReferenceValidation::invalid(
+            reference,
+            ReferenceStatus::Unknown,
+            format!("failed to get secret: {}", err),
+        ),
+    }
+}
+
+fn group_kind(reference: &Reference) -> String {
+    if reference.group.is_empty() {
+        reference.kind.clone()
+    } else {
+        format!("{}/{}", reference.group, reference.kind)
+    }
+}
+
+/// 参照先のnamespaceと名前
+type ReferenceKey = (String, String);
+
+fn reference_key(reference: &Reference) -> ReferenceKey {
+    (reference.namespace.clone(), reference.name.clone())
+}
+
+/// 参照先の取得結果
+///
+/// kindが検証対象外の参照先は取得しないためNotFoundとなるが、検証では使われない
+fn lookup<'a, T>(
+    targets: &'a BTreeMap<ReferenceKey, Lookup<T>>,
+    reference: &Reference,
+) -> Lookup<&'a T> {
+    targets
+        .get(&reference_key(reference))
+        .map_or(Lookup::NotFound, Lookup::as_ref)
+}
+
+/// 参照先をnamespaceと名前ごとに1度だけ取得する
+///
+/// 取得に失敗した参照先は、ログに出力してFailedとする
+async fn lookup_all<'a, T, F, Fut>(
+    kind: &str,
+    references: impl IntoIterator<Item = &'a Reference>,
+    fetch: F,
+) -> BTreeMap<ReferenceKey, Lookup<T>>
+where
+    F: Fn(String, String) -> Fut,
+    Fut: Future<Output = kube::Result<Option<T>>>,
+{
+    let keys: BTreeSet<ReferenceKey> = references.into_iter().map(reference_key).collect();
+
+    let futures: Vec<_> = keys
+        .into_iter()
+        .map(|(namespace, name)| {
+            let result = fetch(namespace.clone(), name.clone());
+
+            async move {
+                let result = result.await;
+
+                if let Err(err) = &result {
+                    logger!(
+                        error,
+                        "Failed to get {} {}/{} for validation: {}",
+                        kind,
+                        namespace,
+                        name,
+                        err
+                    );
+                }
+
+                ((namespace, name), Lookup::from(result))
+            }
+        })
+        .collect();
+
+    stream::iter(futures)
+        .buffer_unordered(MAX_CONCURRENT_REQUESTS)
+        .collect()
+        .await
+}
+
+async fn fetch_services<'a>(
+    client: Client,
+    references: impl IntoIterator<Item = &'a Reference>,
+) -> BTreeMap<ReferenceKey, Lookup<Service>> {
+    let references = references
+        .into_iter()
+        .filter(|r| r.group.is_empty() && r.kind == Service::KIND);
+
+    lookup_all(Service::KIND, references, |namespace, name| {
+        let api = Api::<Service>::namespaced(client.clone(), &namespace);
+
+        async move { api.get_opt(&name).await }
+    })
+    .await
+}
+
+async fn fetch_gateways<'a, G: GatewayResource>(
+    client: Client,
+    references: impl IntoIterator<Item = &'a Reference>,
+) -> BTreeMap<ReferenceKey, Lookup<Vec<Listener>>> {
+    let references = references
+        .into_iter()
+        .filter(|r| r.group == gateway::v1::Gateway::GROUP && r.kind == gateway::v1::Gateway::KIND);
+
+    lookup_all(gateway::v1::Gateway::KIND, references, |namespace, name| {
+        let api = Api::<G>::namespaced(client.clone(), &namespace);
+
+        async move {
+            api.get_opt(&name)
+                .await
+                .map(|gateway| gateway.map(|gateway| gateway.listeners()))
+        }
+    })
+    .await
+}
+
+/// Secretの中身は不要なのでメタデータのみ取得する
+async fn fetch_secrets<'a>(
+    client: Client,
+    references: impl IntoIterator<Item = &'a Reference>,
+) -> BTreeMap<ReferenceKey, Lookup<()>> {
+    let references = references
+        .into_iter()
+        .filter(|r| r.group.is_empty() && r.kind == Secret::KIND);
+
+    lookup_all(Secret::KIND, references, |namespace, name| {
+        let api = Api::<Secret>::namespaced(client.clone(), &namespace);
+
+        async move {
+            api.get_metadata_opt(&name)
+                .await
+                .map(|secret| secret.map(|_| ()))
+        }
+    })
+    .await
+}
+
+/// namespaceごとのラベル
+///
+/// 取得に失敗したnamespaceはログに出力して含めない
+async fn fetch_namespace_labels(
+    client: Client,
+    namespaces: BTreeSet<String>,
+) -> BTreeMap<String, BTreeMap<String, String>> {
+    let futures: Vec<_> = namespaces
+        .into_iter()
+        .map(|namespace| {
+            let api = Api::<Namespace>::all(client.clone());
+
+            async move {
+                match api.get_opt(&namespace).await {
+                    Ok(ns) => {
+                        let labels = ns.and_then(|ns| ns.metadata.labels).unwrap_or_default();
+
+                        Some((namespace, labels))
+                    }
+                    Err(err) => {
+                        logger!(
+                            error,
+                            "Failed to get Namespace {} for validation: {}",
+                            namespace,
+                            err
+                        );
+
+                        None
+                    }
+                }
+            }
+        })
+        .collect();
+
+    stream::iter(futures)
+        .buffer_unordered(MAX_CONCURRENT_REQUESTS)
+        .filter_map(future::ready)
+        .collect()
+        .await
+}
+
+/// ReferenceGrantのCRDがない場合なども考慮して、取得に失敗した場合は空として扱う
+async fn fetch_reference_grants(client: Client, namespace: String) -> Vec<ReferenceGrant> {
+    let api = Api::<ReferenceGrant>::namespaced(client, &namespace);
+
+    match api.list(&ListParams::default()).await {
+        Ok(list) => list.items,
+        Err(err) => {
+            logger!(
+                error,
+                "Failed to list ReferenceGrants in {}: {}",
+                namespace,
+                err
+            );
+            Vec::new()
+        }
+    }
+}
+
+/// namespaceごとにReferenceGrantを取得する
+async fn fetch_reference_grants_in(
+    client: Client,
+    namespaces: BTreeSet<String>,
+) -> Vec<ReferenceGrant> {
+    let futures: Vec<_> = namespaces
+        .into_iter()
+        .map(|namespace| fetch_reference_grants(client.clone(), namespace))
+        .collect();
+
+    stream::iter(futures)
+        .buffer_unordered(MAX_CONCURRENT_REQUESTS)
+        .concat()
+        .await
+}
+
+/// 参照元と異なる参照先のnamespace（ReferenceGrantが必要なnamespace）
+fn cross_namespaces(referrer: &Referrer, references: &[Reference]) -> BTreeSet<String> {
+    references
+        .iter()
+        .filter(|reference| reference.namespace != referrer.namespace)
+        .map(|reference| reference.namespace.clone())
+        .collect()
+}
+
+/// ルートのparentRefsとbackendRefsを検証する
+///
+/// 参照先を取得できない場合は、その参照のみをUnknownとする
+pub async fn validate_route<G: GatewayResource, R: RouteResource>(
+    client: Client,
+    namespace: &str,
+    route: &R,
+) -> Validation {
+    let referrer = Referrer {
+        kind: R::kind(&()).to_string(),
+        name: route.meta().name.clone().unwrap_or_default(),
+        namespace: namespace.to_string(),
+    };
+
+    let parent_refs = route.parent_refs(namespace);
+
+    let backend_refs = route.backend_refs(namespace);
+
+    let (namespace_labels, gateways, services, grants) = futures::join!(
+        fetch_namespace_labels(client.clone(), BTreeSet::from([namespace.to_string()])),
+        fetch_gateways::<G>(client.clone(), &parent_refs),
+        fetch_services(client.clone(), &backend_refs),
+        fetch_reference_grants_in(client, cross_namespaces(&referrer, &backend_refs)),
+    );
+
+    let parent_refs = parent_refs
+        .iter()
+        .map(|reference| {
+            check_parent_ref(
+                &referrer,
+                namespace_labels.get(namespace),
+                reference,
+                lookup(&gateways, reference).map(Vec::as_slice),
+            )
+        })
+        .collect();
+
+    let backend_refs = backend_refs
+        .iter()
+        .map(|reference| {
+            check_backend_ref(&referrer, reference, lookup(&services, reference), &grants)
+        })
+        .collect();
+
+    Validation {
+        parent_refs,
+        backend_refs,
+        ..Default::default()
+    }
+}
+
+/// Gatewayを参照しているルートとその参照
+struct RouteReferences {
+    referrer: Referrer,
+    parent_refs: Vec<Reference>,
+    backend_refs: Vec<Reference>,
+}
+
+impl RouteReferences {
+    fn label(&self) -> Option<String> {
+        Some(format!(
+            "{}/{}",
+            self.referrer.namespace, self.referrer.name
+        ))
+    }
+}
+
+/// Gatewayのcertificate_refsと、Gatewayを参照しているルートの問題のある参照を検証する
+///
+/// ルートはGatewayへの参照が有効な場合のみbackendRefsを検証する。
+/// 参照先のNamespace、Service、ReferenceGrantはルートごとではなくまとめて取得する。
+pub async fn validate_gateway<G: GatewayResource, R: RouteResource>(
+    client: Client,
+    namespace: &str,
+    gateway: &G,
+) -> Validation {
+    let name = gateway.meta().name.clone().unwrap_or_default();
+
+    let referrer = Referrer {
+        kind: G::kind(&()).to_string(),
+        name: name.clone(),
+        namespace: namespace.to_string(),
+    };
+
+    let certificate_refs = gateway.certificate_refs(namespace);
+
+    let (secrets, grants) = futures::join!(
+        fetch_secrets(client.clone(), &certificate_refs),
+        fetch_reference_grants_in(
+            client.clone(),
+            cross_namespaces(&referrer, &certificate_refs)
+        ),
+    );
+
+    let certificate_refs = certificate_refs
+        .iter()
+        .map(|reference| {
+            check_certificate_ref(
+                &referrer,
+                reference,
+                lookup(&secrets, reference).map(|_| ()),
+                &grants,
+            )
+        })
+        .collect();
+
+    let mut validation = Validation {
+        certificate_refs,
+        ..Default::default()
+    };
+
+    // 全namespaceのルートを取得できない場合はGatewayのみを検証する
+    let routes = match Api::<R>::all(client.clone())
+        .list(&ListParams::default())
+        .await
+    {
+        Ok(routes) => routes.items,
+        Err(err) => {
+            logger!(error, "Failed to list routes for validation: {}", err);
+            return validation;
+        }
+    };
+
+    let routes: Vec<RouteReferences> = routes
+        .iter()
+        .filter_map(|route| {
+            let route_namespace = route.meta().namespace.clone().unwrap_or_default();
+
+            let parent_refs: Vec<Reference> = route
+                .parent_refs(&route_namespace)
+                .into_iter()
+                .filter(|r| {
+                    r.group == referrer_group()
+                        && r.kind == referrer.kind
+                        && r.name == name
+                        && r.namespace == namespace
+                })
+                .collect();
+
+            if parent_refs.is_empty() {
+                return None;
+            }
+
+            Some(RouteReferences {
+                referrer: Referrer {
+                    kind: R::kind(&()).to_string(),
+                    name: route.meta().name.clone().unwrap_or_default(),
+                    namespace: route_namespace.clone(),
+                },
+                parent_refs,
+                backend_refs: route.backend_refs(&route_namespace),
+            })
+        })
+        .collect();
+
+    // parentRefsはすべてこのGatewayを参照しているため、取得し直さない
+    let listeners = gateway.listeners();
+
+    let namespace_labels = fetch_namespace_labels(
+        client.clone(),
+        routes
+            .iter()
+            .map(|route| route.referrer.namespace.clone())
+            .collect(),
+    )
+    .await;
+
+    let mut valid_routes = Vec::new();
+
+    for route in routes {
+        let invalid_parent_refs: Vec<ReferenceValidation> = route
+            .parent_refs
+            .iter()
+            .map(|reference| {
+                check_parent_ref(
+                    &route.referrer,
+                    namespace_labels.get(&route.referrer.namespace),
+                    reference,
+                    Lookup::Found(&listeners),
+                )
+            })
+            .filter(|v| !v.is_valid())
+            .map(|v| ReferenceValidation {
+                route: route.label(),
+                ..v
+            })
+            .collect();
+
+        if invalid_parent_refs.is_empty() {
+            valid_routes.push(route);
+        } else {
+            validation.parent_refs.extend(invalid_parent_refs);
+        }
+    }
+
+    let backend_refs: Vec<&Reference> = valid_routes
+        .iter()
+        .flat_map(|route| route.backend_refs.iter())
+        .collect();
+
+    let grant_namespaces: BTreeSet<String> = valid_routes
+        .iter()
+        .flat_map(|route| cross_namespaces(&route.referrer, &route.backend_refs))
+        .collect();
+
+    let (services, grants) = futures::join!(
+        fetch_services(client.clone(), backend_refs),
+        fetch_reference_grants_in(client, grant_namespaces),
+    );
+
+    for route in valid_routes {
+        let invalid_backend_refs = route
+            .backend_refs
+            .iter()
+            .map(|reference| {
+                check_backend_ref(
+                    &route.referrer,
+                    reference,
+                    lookup(&services, reference),
+                    &grants,
+                )
+            })
+            .filter(|v| !v.is_valid())
+            .map(|v| ReferenceValidation {
+                route: route.label(),
+                ..v
+            });
+
+        validation.backend_refs.extend(invalid_backend_refs);
+    }
+
+    validation
+}
+
+fn referrer_group() -> &'static str {
+    gateway::v1::Gateway::GROUP
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn route() -> Referrer {
+        Referrer {
+            kind: "HTTPRoute".to_string(),
+            name: "route".to_string(),
+            namespace: "app".to_string(),
+        }
+    }
+
+    fn service_ref(namespace: &str, port: Option<i32>) -> Reference {
+        Reference {
+            group: String::new(),
+            kind: "Service".to_string(),
+            name: "backend".to_string(),
+            namespace: namespace.to_string(),
+            port,
+            section_name: None,
+        }
+    }
+
+    fn gateway_ref(section_name: Option<&str>) -> Reference {
+        Reference {
+            group: "gateway.networking.k8s.io".to_string(),
+            kind: "Gateway".to_string(),
+            name: "gateway".to_string(),
+            namespace: "infra".to_string(),
+            port: None,
+            section_name: section_name.map(ToString::to_string),
+        }
+    }
+
+    fn service() -> Service {
+        serde_yaml::from_str(indoc! {"
+            metadata:
+              name: backend
+            spec:
+              ports:
+              - port: 80
+        "})
+        .unwrap()
+    }
+
+    fn grants(to_name: Option<&str>) -> Vec<ReferenceGrant> {
+        let mut grant: ReferenceGrant = serde_yaml::from_str(indoc! {"
+            metadata:
+              name: allow-app
+              namespace: backend
+            spec:
+              from:
+              - group: gateway.networking.k8s.io
+                kind: HTTPRoute
+                namespace: app
+              to:
+              - group: \"\"
+                kind: Service
+        "})
+        .unwrap();
+
+        grant.spec.to[0].name = to_name.map(ToString::to_string);
+
+        vec![grant]
+    }
+
+    fn listener(name: &str, allowed_namespaces: AllowedNamespaces) -> Listener {
+        Listener {
+            name: name.to_string(),
+            port: 80,
+            allowed_namespaces,
+            allowed_kinds: None,
+        }
+    }
+
+    #[rstest]
+    #[case::same_namespace(service_ref("app", Some(80)), vec![], ReferenceStatus::Valid)]
+    #[case::granted(service_ref("backend", Some(80)), grants(None), ReferenceStatus::Valid)]
+    #[case::granted_by_name(
+        service_ref("backend", Some(80)),
+        grants(Some("backend")),
+        ReferenceStatus::Valid
+    )]
+    #[case::granted_other_name(
+        service_ref("backend", Some(80)),
+        grants(Some("other")),
+        ReferenceStatus::NotPermitted
+    )]
+    #[case::no_grant(service_ref("backend", Some(80)), vec![], ReferenceStatus::NotPermitted)]
+    #[case::missing_port(service_ref("app", None), vec![], ReferenceStatus::Invalid)]
+    #[case::unknown_port(service_ref("app", Some(8080)), vec![], ReferenceStatus::Invalid)]
+    fn backend_refを検証する(
+        #[case] reference: Reference,
+        #[case] grants: Vec<ReferenceGrant>,
+        #[case] expected: ReferenceStatus,
+    ) {
+        let actual = check_backend_ref(&route(), &reference, Lookup::Found(&service()), &grants);
+
+        assert_eq!(actual.status, expected);
+    }
+
+    #[test]
+    fn 省略できないrulesのbackend_refsも取り出す() {
+        let route: gateway::v1alpha2::TCPRoute = serde_yaml::from_str(indoc! {"
+            metadata:
+              name: route
+            spec:
+              parentRefs:
+              - name: gateway
+                namespace: infra
+              rules:
+              - backendRefs:
+                - name: backend
+                  namespace: backend
+                  port: 80
+              - backendRefs:
+                - name: backend
+                  namespace: backend
+                  port: 80
+        "})
+        .unwrap();
+
+        assert_eq!(route.parent_refs("app"), vec![gateway_ref(None)]);
+        assert_eq!(
+            route.backend_refs("app"),
+            vec![service_ref("backend", Some(80))]
+        );
+    }
+
+    #[test]
+    fn 存在しないserviceはmissingになる() {
+        let actual = check_backend_ref(
+            &route(),
+            &service_ref("app", Some(80)),
+            Lookup::NotFound,
+            &[],
+        );
+
+        assert_eq!(
+            actual,
+            ReferenceValidation {
+                route: None,
+                kind: "Service".to_string(),
+                name: "backend".to_string(),
+                namespace: "app".to_string(),
+                section_name: None,
+                port: Some(80),
+                status: ReferenceStatus::Missing,
+                reason: Some("service not found".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn 取得できなかったserviceはunknownになる() {
+        let actual = check_backend_ref(
+            &route(),
+            &service_ref("app", Some(80)),
+            Lookup::Failed("forbidden".to_string()),
+            &[],
+        );
+
+        assert_eq!(actual.status, ReferenceStatus::Unknown);
+        assert_eq!(
+            actual.reason,
+            Some("failed to get service: forbidden".to_string())
+        );
+    }
+
+    #[rstest]
+    #[case::all(gateway_ref(None), AllowedNamespaces::All, ReferenceStatus::Valid)]
+    #[case::same(
+        gateway_ref(None),
+        AllowedNamespaces::Same,
+        ReferenceStatus::NotPermitted
+    )]
+    #[case::selector(
+        gateway_ref(None),
+        AllowedNamespaces::Selector(LabelSelector {
+            match_labels: Some(BTreeMap::from([("gateway".to_string(), "allowed".to_string())])),
+            ..Default::default()
+        }),
+        ReferenceStatus::Valid
+    )]
+    #[case::section_name(
+        gateway_ref(Some("http")),
+        AllowedNamespaces::All,
+        ReferenceStatus::Valid
+    )]
+    #[case::unknown_section_name(
+        gateway_ref(Some("https")),
+        AllowedNamespaces::All,
+        ReferenceStatus::Invalid
+    )]
+    fn parent_refを検証する(
+        #[case] reference: Reference,
+        #[case] allowed_namespaces: AllowedNamespaces,
+        #[case] expected: ReferenceStatus,
+    ) {
+        let labels = BTreeMap::from([("gateway".to_string(), "allowed".to_string())]);
+
+        let listeners = vec![listener("http", allowed_namespaces)];
+
+        let actual = check_parent_ref(
+            &route(),
+            Some(&labels),
+            &reference,
+            Lookup::Found(&listeners),
+        );
+
+        assert_eq!(actual.status, expected);
+    }
+
+    #[test]
+    fn 許可されていないkindのルートはnot_permittedになる() {
+        let listeners = vec![Listener {
+            allowed_kinds: Some(vec!["GRPCRoute".to_string()]),
+            ..listener("http", AllowedNamespaces::All)
+        }];
+
+        let actual = check_parent_ref(
+            &route(),
+            Some(&BTreeMap::new()),
+            &gateway_ref(None),
+            Lookup::Found(&listeners),
+        );
+
+        assert_eq!(actual.status, ReferenceStatus::NotPermitted);
+    }
+
+    #[test]
+    fn namespaceのラベルを取得できない場合はselectorの検証がunknownになる() {
+        let listeners = vec![listener(
+            "http",
+            AllowedNamespaces::Selector(LabelSelector {
+                match_labels: Some(BTreeMap::from([(
+                    "gateway".to_string(),
+                    "allowed".to_string(),
+                )])),
+                ..Default::default()
+            }),
+        )];
+
+        let actual = check_parent_ref(
+            &route(),
+            None,
+            &gateway_ref(None),
+            Lookup::Found(&listeners),
+        );
+
+        assert_eq!(actual.status, ReferenceStatus::Unknown);
+    }
+
+    #[test]
+    fn 存在しないgatewayはmissingになる() {
+        let actual = check_parent_ref(
+            &route(),
+            Some(&BTreeMap::new()),
+            &gateway_ref(None),
+            Lookup::NotFound,
+        );
+
+        assert_eq!(actual.status, ReferenceStatus::Missing);
+    }
+
+    #[test]
+    fn 別のnamespaceのsecretはreference_grantが必要() {
+        let gateway = Referrer {
+            kind: "Gateway".to_string(),
+            name: "gateway".to_string(),
+            namespace: "infra".to_string(),
+        };
+
+        let reference = Reference {
+            group: String::new(),
+            kind: "Secret".to_string(),
+            name: "tls".to_string(),
+            namespace: "certs".to_string(),
+            port: None,
+            section_name: None,
+        };
+
+        let actual = check_certificate_ref(&gateway, &reference, Lookup::Found(()), &[]);
+
+        assert_eq!(
+            actual.reason,
+            Some(
+                "no ReferenceGrant in namespace certs allows Gateway from namespace infra"
+                    .to_string()
+            )
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition;
+use kube::ResourceExt;
+use serde::{Deserialize, Serialize};
+
+use crate::kube::apis::networking::gateway::v1::{
+    GRPCRoute, GRPCRouteSpec, GRPCRouteStatus, GRPCRouteStatusParents,
+    GRPCRouteStatusParentsParentRef,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Description {
+    grpcroute: DescriptionGRPCRoute,
+}
+
+impl Description {
+    pub fn new(grpcroute: GRPCRoute) -> Self {
+        Self {
+            grpcroute: DescriptionGRPCRoute::new(grpcroute),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct MetadataName {
+    name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DescriptionGRPCRoute {
+    metadata: MetadataName,
+
+    spec: GRPCRouteSpec,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<GRPCRouteStatusWrapper>,
+}
+
+impl DescriptionGRPCRoute {
+    fn new(grpcroute: GRPCRoute) -> Self {
+        let name = grpcroute.name_any();
+
+        let GRPCRoute {
+            metadata: _,
+            spec,
+            status,
+        } = grpcroute;
+
+        let status_wrapper = status.map(GRPCRouteStatusWrapper::new);
+
+        Self {
+            metadata: MetadataName { name },
+            spec,
+            status: status_wrapper,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct GRPCRouteStatusWrapper {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    parents: Vec<RouteParentStatusWrapper>,
+}
+
+impl GRPCRouteStatusWrapper {
+    fn new(status: GRPCRouteStatus) -> Self {
+        let parents = status
+            .parents
+            .into_iter()
+            .map(RouteParentStatusWrapper::new)
+            .collect();
+
+        Self { parents }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RouteParentStatusWrapper {
+    #[serde(flatten)]
+    #[serde(with = "GRPCRouteStatusParentsDef")]
+    status: GRPCRouteStatusParents,
+}
+
+impl RouteParentStatusWrapper {
+    fn new(status: GRPCRouteStatusParents) -> Self {
+        Self { status }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "GRPCRouteStatusParents")]
+#[serde(rename_all = "camelCase")]
+struct GRPCRouteStatusParentsDef {
+    #[serde(skip)]
+    conditions: Option<Vec<Condition>>,
+
+    controller_name: String,
+
+    parent_ref: GRPCRouteStatusParentsParentRef,
+}
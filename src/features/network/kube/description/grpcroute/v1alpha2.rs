@@ -0,0 +1,2 @@
+mod description;
+pub use description::Description;
@@ -11,7 +11,10 @@ use crate::{
     kube::{apis::networking::gateway, KubeClientRequest},
 };
 
-use super::{Fetch, FetchedData};
+use super::{
+    gateway_references::validate_route, route_related_resources::discover_related_resources, Fetch,
+    FetchedData,
+};
 
 pub(super) struct HTTPRouteDescriptionWorker<'a, C>
 where
@@ -68,13 +71,26 @@ async fn fetch_v1(client: Client, name: &str, namespace: &str) -> Result<Fetched
 
     let description = v1::Description::new(httproute.clone());
 
-    let related_resources = v1::discover_releated_resources(client, namespace, &httproute).await?;
+    let validation = validate_route::<gateway::v1::Gateway, gateway::v1::HTTPRoute>(
+        client.clone(),
+        namespace,
+        &httproute,
+    )
+    .await;
+
+    let related_resources = discover_related_resources(client, namespace, &httproute).await?;
 
     let mut yaml = serde_yaml::to_string(&description)?
         .lines()
         .map(ToString::to_string)
         .collect::<Vec<String>>();
 
+    if !validation.is_empty() {
+        yaml.push("".into());
+
+        yaml.append(&mut validation.to_yaml_lines()?);
+    }
+
     let mut related_resources_yaml = serde_yaml::to_string(&related_resources)?
         .lines()
         .map(ToString::to_string)
@@ -86,7 +102,10 @@ async fn fetch_v1(client: Client, name: &str, namespace: &str) -> Result<Fetched
         yaml.append(&mut related_resources_yaml);
     }
 
-    Ok(yaml)
+    Ok(FetchedData {
+        yaml,
+        related_resources: related_resources.to_related_resources(),
+    })
 }
 
 async fn fetch_v1beat1(client: Client, name: &str, namespace: &str) -> Result<FetchedData> {
@@ -99,14 +118,26 @@ async fn fetch_v1beat1(client: Client, name: &str, namespace: &str) -> Result<Fe
 
     let description = v1beta1::Description::new(httproute.clone());
 
-    let related_resources =
-        v1beta1::discover_releated_resources(client, namespace, &httproute).await?;
+    let validation = validate_route::<gateway::v1beta1::Gateway, gateway::v1beta1::HTTPRoute>(
+        client.clone(),
+        namespace,
+        &httproute,
+    )
+    .await;
+
+    let related_resources = discover_related_resources(client, namespace, &httproute).await?;
 
     let mut yaml = serde_yaml::to_string(&description)?
         .lines()
         .map(ToString::to_string)
         .collect::<Vec<String>>();
 
+    if !validation.is_empty() {
+        yaml.push("".into());
+
+        yaml.append(&mut validation.to_yaml_lines()?);
+    }
+
     let mut related_resources_yaml = serde_yaml::to_string(&related_resources)?
         .lines()
         .map(ToString::to_string)
@@ -118,5 +149,8 @@ async fn fetch_v1beat1(client: Client, name: &str, namespace: &str) -> Result<Fe
         yaml.append(&mut related_resources_yaml);
     }
 
-    Ok(yaml)
+    Ok(FetchedData {
+        yaml,
+        related_resources: related_resources.to_related_resources(),
+    })
 }
@@ -1,5 +1,2 @@
 mod description;
 pub use description::Description;
-
-mod related_resource;
-pub use related_resource::discover_releated_resources;
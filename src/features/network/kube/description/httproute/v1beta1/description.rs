@@ -90,7 +90,7 @@ impl RouteParentStatusWrapper {
 #[serde(remote = "HTTPRouteStatusParents")]
 #[serde(rename_all = "camelCase")]
 struct HTTPRouteStatusParentsDef {
-    #[serde(skip)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     conditions: Option<Vec<Condition>>,
 
     controller_name: String,
@@ -17,7 +17,7 @@ use crate::{
 
 use super::{
     related_resources::{to_list_value::ToListValue, RelatedClient},
-    Fetch, FetchedData,
+    related_resources_from_list, Fetch, FetchedData,
 };
 
 use extract::Extract;
@@ -84,6 +84,19 @@ where
             None
         };
 
+        let related = [
+            related_services
+                .as_ref()
+                .map(|list| related_resources_from_list(list, &self.namespace)),
+            related_pods
+                .as_ref()
+                .map(|list| related_resources_from_list(list, &self.namespace)),
+        ]
+        .into_iter()
+        .flatten()
+        .flatten()
+        .collect();
+
         let mut related_resources = Mapping::new();
 
         if let Some(services) = related_services {
@@ -120,7 +133,10 @@ where
             value.extend(related_resources);
         }
 
-        Ok(value)
+        Ok(FetchedData {
+            yaml: value,
+            related_resources: related,
+        })
     }
 }
 
@@ -372,7 +388,7 @@ mod tests {
         .map(ToString::to_string)
         .collect();
 
-        assert_eq!(result.unwrap(), expected);
+        assert_eq!(result.unwrap().yaml, expected);
     }
 
     #[tokio::test]
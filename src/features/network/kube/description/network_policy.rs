@@ -22,7 +22,7 @@ use super::{
     related_resources::{
         label_selector::LabelSelectorWrapper, to_list_value::ToListValue, RelatedClient,
     },
-    Fetch, FetchedData,
+    related_resources_from_list, Fetch, FetchedData,
 };
 
 pub(super) struct NetworkPolicyDescriptionWorker<'a, C>
@@ -70,6 +70,11 @@ where
                 None
             };
 
+        let related = related_pods
+            .as_ref()
+            .map(|list| related_resources_from_list(list, &self.namespace))
+            .unwrap_or_default();
+
         let mut related_resources = Mapping::new();
 
         if let Some(pods) = related_pods {
@@ -100,7 +105,10 @@ where
             value.extend(related_resources);
         }
 
-        Ok(value)
+        Ok(FetchedData {
+            yaml: value,
+            related_resources: related,
+        })
     }
 }
 
@@ -351,7 +359,7 @@ mod tests {
         .map(ToString::to_string)
         .collect();
 
-        assert_eq!(result.unwrap(), expected);
+        assert_eq!(result.unwrap().yaml, expected);
     }
 
     #[tokio::test]
@@ -12,7 +12,7 @@ use self::{extract::Extract, to_value::ToValue};
 
 use super::{
     related_resources::{to_list_value::ToListValue, RelatedClient},
-    Fetch, FetchedData, Result,
+    related_resources_from_list, Fetch, FetchedData, Result,
 };
 
 use crate::{
@@ -85,6 +85,22 @@ impl<'a, C: KubeClientRequest> Fetch<'a, C> for PodDescriptionWorker<'a, C> {
 
         let mut value = pod;
 
+        let related = [
+            related_services
+                .as_ref()
+                .map(|list| related_resources_from_list(list, &self.namespace)),
+            related_ingresses
+                .as_ref()
+                .map(|list| related_resources_from_list(list, &self.namespace)),
+            related_networkpolicies
+                .as_ref()
+                .map(|list| related_resources_from_list(list, &self.namespace)),
+        ]
+        .into_iter()
+        .flatten()
+        .flatten()
+        .collect();
+
         let mut related_resources = Mapping::new();
 
         if let Some(services) = related_services {
@@ -117,7 +133,10 @@ impl<'a, C: KubeClientRequest> Fetch<'a, C> for PodDescriptionWorker<'a, C> {
             value.extend(vec);
         }
 
-        Ok(value)
+        Ok(FetchedData {
+            yaml: value,
+            related_resources: related,
+        })
     }
 }
 
@@ -126,7 +145,9 @@ mod tests {
     use super::*;
 
     use crate::{
-        features::api_resources::kube::ApiResources, kube::mock::MockTestKubeClient, mock_expect,
+        features::{api_resources::kube::ApiResources, network::message::RelatedResource},
+        kube::mock::MockTestKubeClient,
+        mock_expect,
     };
     use indoc::indoc;
     use k8s_openapi::{
@@ -556,7 +577,21 @@ mod tests {
         .map(ToString::to_string)
         .collect();
 
-        assert_eq!(result.unwrap(), expected)
+        let result = result.unwrap();
+
+        assert_eq!(result.yaml, expected);
+
+        assert_eq!(
+            result.related_resources,
+            vec![
+                RelatedResource::new("Service", "service-1", "default", "v1"),
+                RelatedResource::new("Service", "service-2", "default", "v1"),
+                RelatedResource::new("Ingress", "ingress-1", "default", "v1"),
+                RelatedResource::new("Ingress", "ingress-2", "default", "v1"),
+                RelatedResource::new("NetworkPolicy", "allow-all-egress", "default", "v1"),
+                RelatedResource::new("NetworkPolicy", "allow-all-ingress", "default", "v1"),
+            ]
+        );
     }
 
     #[tokio::test(flavor = "multi_thread")]
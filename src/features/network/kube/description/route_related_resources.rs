@@ -0,0 +1,226 @@
+mod gateway;
+mod pod;
+mod service;
+
+use anyhow::{Context as _, Result};
+use k8s_openapi::{
+    api::core::v1::{Pod, Service},
+    Resource as _,
+};
+use kube::{Api, Client};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    features::network::message::RelatedResource, kube::apis::networking::gateway::v1::Gateway,
+};
+
+use super::{gateway_references::RouteResource, FetchedData};
+
+use self::{
+    gateway::{discover_gateways, RelatedGateways},
+    pod::{discover_pods, RelatedPods},
+    service::{discover_services, RelatedServices},
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RouteRelatedResources {
+    related_resources: RouteRelatedResourceItems,
+
+    /// parentRefsのGatewayを参照するときのバージョン
+    #[serde(skip)]
+    gateway_version: String,
+}
+
+impl RouteRelatedResources {
+    /// 関連リソースのグラフに使う形式で返す
+    ///
+    /// PodはServiceの下に置く
+    pub fn to_related_resources(&self) -> Vec<RelatedResource> {
+        let RouteRelatedResourceItems {
+            gateways,
+            services,
+            pods,
+        } = &self.related_resources;
+
+        let gateways = gateways.iter().flatten().map(|gateway| {
+            RelatedResource::new(
+                Gateway::KIND,
+                &gateway.name,
+                &gateway.namespace,
+                &self.gateway_version,
+            )
+        });
+
+        let services = services.iter().flatten().map(|service| {
+            RelatedResource::new(
+                Service::KIND,
+                &service.name,
+                &service.namespace,
+                Service::VERSION,
+            )
+        });
+
+        let pods = pods.iter().flatten().map(|pod| {
+            RelatedResource::new(Pod::KIND, &pod.name, &pod.namespace, Pod::VERSION).parent(
+                Service::KIND,
+                &pod.service,
+                &pod.namespace,
+            )
+        });
+
+        gateways.chain(services).chain(pods).collect()
+    }
+}
+
+/// Gatewayはv1とv1beta1のみのため、それ以外のバージョンのルートからはv1のGatewayを参照する
+fn gateway_version(route_version: &str) -> String {
+    match route_version {
+        "v1beta1" => "v1beta1".to_string(),
+        _ => Gateway::VERSION.to_string(),
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RouteRelatedResourceItems {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    gateways: Option<RelatedGateways>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    services: Option<RelatedServices>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pods: Option<RelatedPods>,
+}
+
+/// parentRefsのGateway、backendRefsのServiceとServiceが選択するPodを探す
+///
+/// HTTPRoute、GRPCRoute、TLSRoute、TCPRouteで共通
+pub async fn discover_related_resources<R: RouteResource>(
+    client: Client,
+    route_namespace: &str,
+    route: &R,
+) -> Result<RouteRelatedResources> {
+    let kind = R::kind(&()).to_lowercase();
+
+    let gateways = discover_gateways(route.parent_refs(route_namespace));
+
+    let services = discover_services(client.clone(), route.backend_refs(route_namespace))
+        .await
+        .with_context(|| format!("discover services for {}", kind))?;
+
+    let pods = if let Some(services) = services.as_ref() {
+        discover_pods(client.clone(), services)
+            .await
+            .with_context(|| format!("discover pods for {}", kind))?
+    } else {
+        None
+    };
+
+    let related_resources = RouteRelatedResources {
+        related_resources: RouteRelatedResourceItems {
+            gateways,
+            services,
+            pods,
+        },
+        gateway_version: gateway_version(&R::version(&())),
+    };
+
+    Ok(related_resources)
+}
+
+/// ルートを取得し、ルートの内容と関連リソースを返す
+///
+/// GRPCRoute、TLSRoute、TCPRouteで共通
+pub async fn fetch_route<R, D>(
+    client: Client,
+    name: &str,
+    namespace: &str,
+    description: impl FnOnce(R) -> D,
+) -> Result<FetchedData>
+where
+    R: RouteResource,
+    D: Serialize,
+{
+    let api = Api::<R>::namespaced(client.clone(), namespace);
+
+    let route = api.get(name).await.context(format!(
+        "Failed to fetch {}: namespace={}, name={}",
+        R::kind(&()),
+        namespace,
+        name
+    ))?;
+
+    let related_resources = discover_related_resources(client, namespace, &route).await?;
+
+    let mut yaml = serde_yaml::to_string(&description(route))?
+        .lines()
+        .map(ToString::to_string)
+        .collect::<Vec<String>>();
+
+    let mut related_resources_yaml = serde_yaml::to_string(&related_resources)?
+        .lines()
+        .map(ToString::to_string)
+        .collect::<Vec<String>>();
+
+    if !related_resources_yaml.is_empty() {
+        yaml.push("".into());
+
+        yaml.append(&mut related_resources_yaml);
+    }
+
+    Ok(FetchedData {
+        yaml,
+        related_resources: related_resources.to_related_resources(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::{gateway::RelatedGateway, pod::RelatedPod, service::RelatedService, *};
+
+    #[rstest]
+    #[case("v1", "v1")]
+    #[case("v1beta1", "v1beta1")]
+    #[case("v1alpha2", "v1")]
+    fn ルートのバージョンに対応するgatewayのバージョンを返す(
+        #[case] route_version: &str,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(gateway_version(route_version), expected);
+    }
+
+    #[test]
+    fn podはserviceの下に置く() {
+        let related_resources = RouteRelatedResources {
+            related_resources: RouteRelatedResourceItems {
+                gateways: Some(vec![RelatedGateway {
+                    name: "gateway".to_string(),
+                    namespace: "infra".to_string(),
+                }]),
+                services: Some(vec![RelatedService {
+                    name: "backend".to_string(),
+                    namespace: "app".to_string(),
+                    resource: Default::default(),
+                }]),
+                pods: Some(vec![RelatedPod {
+                    name: "backend-0".to_string(),
+                    namespace: "app".to_string(),
+                    service: "backend".to_string(),
+                }]),
+            },
+            gateway_version: "v1".to_string(),
+        };
+
+        assert_eq!(
+            related_resources.to_related_resources(),
+            vec![
+                RelatedResource::new("Gateway", "gateway", "infra", "v1"),
+                RelatedResource::new("Service", "backend", "app", "v1"),
+                RelatedResource::new("Pod", "backend-0", "app", "v1")
+                    .parent("Service", "backend", "app"),
+            ]
+        );
+    }
+}
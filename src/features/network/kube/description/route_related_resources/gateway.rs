@@ -0,0 +1,53 @@
+use k8s_openapi::Resource as _;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    features::network::kube::description::gateway_references::Reference,
+    kube::apis::networking::gateway::v1::Gateway, logger,
+};
+
+pub type RelatedGateways = Vec<RelatedGateway>;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct RelatedGateway {
+    /// Gateway Name
+    pub name: String,
+
+    /// Gateway Namespace
+    pub namespace: String,
+}
+
+pub fn discover_gateways(parent_refs: Vec<Reference>) -> Option<RelatedGateways> {
+    let mut result: Vec<_> = parent_refs
+        .into_iter()
+        .filter_map(
+            |Reference {
+                 group,
+                 kind,
+                 name,
+                 namespace,
+                 ..
+             }| {
+                if group != Gateway::GROUP || kind != Gateway::KIND {
+                    logger!(
+                        warn,
+                        "ParentRef is not a Gateway, skipping. Group: {group}, Kind: {kind} namespace: {namespace} name: {name}",
+                    );
+
+                    return None;
+                }
+
+                Some(RelatedGateway { name, namespace })
+            },
+        )
+        .collect();
+
+    result.sort();
+    result.dedup();
+
+    if result.is_empty() {
+        None
+    } else {
+        Some(result)
+    }
+}
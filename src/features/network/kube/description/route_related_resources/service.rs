@@ -6,8 +6,9 @@ use kube::{Api, Client, ResourceExt};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    features::network::kube::description::utils::ExtractNamespace as _,
-    kube::apis::networking::gateway::v1::{HTTPRoute, HTTPRouteRulesBackendRefs},
+    features::network::kube::description::{
+        gateway_references::Reference, utils::ExtractNamespace as _,
+    },
     logger,
 };
 
@@ -33,44 +34,17 @@ impl PartialOrd for RelatedService {
     }
 }
 
-struct BackendRefs<'a> {
-    refs: Vec<&'a HTTPRouteRulesBackendRefs>,
-}
-
-impl<'a> From<&'a HTTPRoute> for BackendRefs<'a> {
-    fn from(value: &'a HTTPRoute) -> Self {
-        let rules = value.spec.rules.as_ref();
-
-        let refs: Vec<&HTTPRouteRulesBackendRefs> = rules
-            .map(|rules| {
-                rules
-                    .iter()
-                    .filter_map(|rule| rule.backend_refs.as_ref())
-                    .flat_map(|backend_refs| backend_refs.iter())
-                    .collect::<Vec<_>>()
-            })
-            .unwrap_or_default();
-
-        BackendRefs { refs }
-    }
-}
-
+/// backendRefsのうちServiceを参照しているものを取得する
+///
+/// backendRefsのnamespaceは省略時にルートのnamespaceで補完済み
 pub async fn discover_services(
     client: Client,
-    httproute_namespace: &str,
-    httproute: &HTTPRoute,
+    backend_refs: Vec<Reference>,
 ) -> Result<Option<RelatedServices>> {
-    let BackendRefs { refs } = BackendRefs::from(httproute);
-
-    let fetch_service_args: Vec<_> = refs
-        .into_iter()
-        .map(move |r| (httproute_namespace.to_string(), r.clone()))
-        .collect();
-
     let task = tokio::spawn(async move {
-        let futures = fetch_service_args.into_iter().map(|args| {
+        let futures = backend_refs.into_iter().map(|r| {
             let client = client.clone();
-            async move { fetch_service(client, args.0, args.1).await }
+            async move { fetch_service(client, r).await }
         });
 
         let stream = futures::stream::iter(futures).buffer_unordered(20);
@@ -91,24 +65,12 @@ pub async fn discover_services(
     }
 }
 
-async fn fetch_service(
-    client: Client,
-    httproute_namespace: String,
-    r: HTTPRouteRulesBackendRefs,
-) -> Option<RelatedService> {
-    if r.group.as_ref().is_some_and(|g| !g.is_empty())
-        || r.kind.as_ref().is_some_and(|k| k != Service::KIND)
-    {
+async fn fetch_service(client: Client, r: Reference) -> Option<RelatedService> {
+    if !r.group.is_empty() || r.kind != Service::KIND {
         return None;
     }
 
-    let namespace = if let Some(namespace) = r.namespace.as_ref() {
-        namespace
-    } else {
-        &httproute_namespace
-    };
-
-    let api = Api::<Service>::namespaced(client, namespace);
+    let api = Api::<Service>::namespaced(client, &r.namespace);
 
     match api.get(&r.name).await {
         Ok(service) => Some(RelatedService {
@@ -121,6 +83,7 @@ async fn fetch_service(
             logger!(
                 error,
                 "failed to get service {namespace}/{name}: {err}",
+                namespace = r.namespace,
                 name = r.name
             );
 
@@ -2,6 +2,7 @@ use anyhow::Result;
 use k8s_openapi::{
     api::{
         core::v1::{Pod, Service, ServiceSpec},
+        discovery::v1::EndpointSlice,
         networking::v1::Ingress,
     },
     List,
@@ -14,13 +15,14 @@ use crate::{
         api_resources::kube::SharedApiResources, network::message::NetworkRequestTargetParams,
     },
     kube::KubeClientRequest,
+    logger,
 };
 
-use self::to_value::ToValue;
+use self::{endpoints::endpoints_value, to_value::ToValue};
 
 use super::{
     related_resources::{to_list_value::ToListValue, RelatedClient},
-    Fetch, FetchedData,
+    related_resources_from_list, Fetch, FetchedData,
 };
 
 use extract::Extract;
@@ -78,6 +80,47 @@ where
             None
         };
 
+        let url = format!(
+            "{}?labelSelector=kubernetes.io%2Fservice-name%3D{}",
+            EndpointSlice::url_path(&(), Some(&self.namespace)),
+            self.name
+        );
+
+        // EndpointSliceを取得できない場合もServiceの内容は表示するため、endpointsとexcludedPodsを省略する
+        let endpoints = match self.client.request::<List<EndpointSlice>>(&url).await {
+            Ok(endpoint_slices) => endpoints_value(
+                &endpoint_slices.items,
+                related_pods
+                    .as_ref()
+                    .map(|pods| pods.items.as_slice())
+                    .unwrap_or_default(),
+            )?,
+            Err(err) => {
+                logger!(
+                    error,
+                    "Failed to fetch EndpointSlices for service {}/{}: {}",
+                    self.namespace,
+                    self.name,
+                    err
+                );
+
+                None
+            }
+        };
+
+        let related = [
+            related_ingresses
+                .as_ref()
+                .map(|list| related_resources_from_list(list, &self.namespace)),
+            related_pods
+                .as_ref()
+                .map(|list| related_resources_from_list(list, &self.namespace)),
+        ]
+        .into_iter()
+        .flatten()
+        .flatten()
+        .collect();
+
         let mut related_resources = Mapping::new();
 
         if let Some(ingresses) = related_ingresses {
@@ -99,6 +142,16 @@ where
 
         let mut value = service;
 
+        if let Some(endpoints) = endpoints {
+            value.push(Default::default());
+
+            value.extend(
+                serde_yaml::to_string(&endpoints)?
+                    .lines()
+                    .map(ToString::to_string),
+            );
+        }
+
         if !related_resources.is_empty() {
             let mut root = Mapping::new();
 
@@ -114,7 +167,10 @@ where
             value.extend(related_resources);
         }
 
-        Ok(value)
+        Ok(FetchedData {
+            yaml: value,
+            related_resources: related,
+        })
     }
 }
 
@@ -123,7 +179,7 @@ mod tests {
     use anyhow::bail;
     use indoc::indoc;
     use k8s_openapi::{
-        api::{core::v1::Pod, networking::v1::Ingress},
+        api::{core::v1::Pod, discovery::v1::EndpointSlice, networking::v1::Ingress},
         List,
     };
     use mockall::predicate::eq;
@@ -218,6 +274,29 @@ mod tests {
         .unwrap()
     }
 
+    fn endpoint_slices() -> List<EndpointSlice> {
+        serde_yaml::from_str(indoc! {
+            "
+            items:
+            - addressType: IPv4
+              metadata:
+                name: service-abcde
+              ports:
+              - port: 80
+                protocol: TCP
+              endpoints:
+              - addresses:
+                - 10.1.0.1
+                conditions:
+                  ready: true
+                targetRef:
+                  kind: Pod
+                  name: pod-1
+            "
+        })
+        .unwrap()
+    }
+
     #[tokio::test]
     async fn yamlデータを返す() {
         let mut client = MockTestKubeClient::new();
@@ -239,6 +318,11 @@ mod tests {
                     List<Pod>,
                     eq("/api/v1/namespaces/default/pods"),
                     Ok(pods())
+                ),
+                (
+                    List<EndpointSlice>,
+                    eq("/apis/discovery.k8s.io/v1/namespaces/default/endpointslices?labelSelector=kubernetes.io%2Fservice-name%3Dservice"),
+                    Ok(endpoint_slices())
                 )
             ]
         );
@@ -274,6 +358,18 @@ mod tests {
                 sessionAffinity: None
                 type: ClusterIP
 
+            endpoints:
+            - address: 10.1.0.1
+              ports:
+              - 80/TCP
+              targetRef: Pod/pod-1
+              ready: true
+              serving: true
+              terminating: false
+            excludedPods:
+            - name: pod-2
+              reason: pod has no IP address
+
             relatedResources:
               ingresses:
               - ingress-1
@@ -286,7 +382,83 @@ mod tests {
         .map(ToString::to_string)
         .collect();
 
-        assert_eq!(result.unwrap(), expected);
+        assert_eq!(result.unwrap().yaml, expected);
+    }
+
+    #[tokio::test]
+    async fn endpoint_sliceの取得に失敗した場合はendpointsを省略して返す() {
+        let mut client = MockTestKubeClient::new();
+        mock_expect!(
+            client,
+            request,
+            [
+                (
+                    Service,
+                    eq("/api/v1/namespaces/default/services/service"),
+                    Ok(service())
+                ),
+                (
+                    List<Ingress>,
+                    eq("/apis/networking.k8s.io/v1/namespaces/default/ingresses"),
+                    Ok(ingresses())
+                ),
+                (
+                    List<Pod>,
+                    eq("/api/v1/namespaces/default/pods"),
+                    Ok(pods())
+                ),
+                (
+                    List<EndpointSlice>,
+                    eq("/apis/discovery.k8s.io/v1/namespaces/default/endpointslices?labelSelector=kubernetes.io%2Fservice-name%3Dservice"),
+                    bail!("forbidden")
+                )
+            ]
+        );
+
+        let target_params = NetworkRequestTargetParams {
+            namespace: "default".to_string(),
+            name: "service".to_string(),
+            version: "v1".to_string(),
+        };
+
+        let worker = ServiceDescriptionWorker::new(&client, target_params, ApiResources::shared());
+
+        let result = worker.fetch().await;
+
+        let expected: Vec<String> = indoc! {
+            "
+            service:
+              metadata:
+                name: service
+              spec:
+                clusterIP: 10.101.97.182
+                clusterIPs:
+                - 10.101.97.182
+                ipFamilies:
+                - IPv4
+                ipFamilyPolicy: SingleStack
+                ports:
+                - port: 80
+                  protocol: TCP
+                  targetPort: 80
+                selector:
+                  version: v1
+                sessionAffinity: None
+                type: ClusterIP
+
+            relatedResources:
+              ingresses:
+              - ingress-1
+              pods:
+              - pod-1
+              - pod-2
+            "
+        }
+        .lines()
+        .map(ToString::to_string)
+        .collect();
+
+        assert_eq!(result.unwrap().yaml, expected);
     }
 
     #[tokio::test]
@@ -310,6 +482,11 @@ mod tests {
                     List<Pod>,
                     eq("/api/v1/namespaces/default/pods"),
                     bail!("error")
+                ),
+                (
+                    List<EndpointSlice>,
+                    eq("/apis/discovery.k8s.io/v1/namespaces/default/endpointslices?labelSelector=kubernetes.io%2Fservice-name%3Dtest"),
+                    bail!("error")
                 )
 
             ]
@@ -367,6 +544,303 @@ mod to_value {
     }
 }
 
+mod endpoints {
+    use anyhow::Result;
+    use k8s_openapi::api::{
+        core::v1::Pod,
+        discovery::v1::{Endpoint, EndpointSlice},
+    };
+    use serde::Serialize;
+    use serde_yaml::{Mapping, Value};
+
+    #[derive(Debug, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct EndpointValue {
+        address: String,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        ports: Vec<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        target_ref: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        node_name: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        zone: Option<String>,
+        ready: bool,
+        serving: bool,
+        terminating: bool,
+    }
+
+    /// セレクタに一致するが、トラフィックを受け付けないPod
+    #[derive(Debug, Serialize)]
+    struct ExcludedPod {
+        name: String,
+        reason: String,
+    }
+
+    /// 条件が未設定の場合、readyはtrue、servingはreadyと同じ、terminatingはfalseとして扱う
+    fn conditions(endpoint: &Endpoint) -> (bool, bool, bool) {
+        let conditions = endpoint.conditions.clone().unwrap_or_default();
+
+        let ready = conditions.ready.unwrap_or(true);
+        let serving = conditions.serving.unwrap_or(ready);
+        let terminating = conditions.terminating.unwrap_or(false);
+
+        (ready, serving, terminating)
+    }
+
+    fn pod_endpoint<'a>(slices: &'a [EndpointSlice], name: &str) -> Option<&'a Endpoint> {
+        slices
+            .iter()
+            .flat_map(|slice| slice.endpoints.iter())
+            .find(|endpoint| {
+                endpoint.target_ref.as_ref().is_some_and(|target_ref| {
+                    target_ref.kind.as_deref() == Some("Pod")
+                        && target_ref.name.as_deref() == Some(name)
+                })
+            })
+    }
+
+    /// Podがエンドポイントに含まれない、またはreadyでない理由
+    fn excluded_reason(pod: &Pod, endpoint: Option<&Endpoint>) -> Option<String> {
+        let status = pod.status.clone().unwrap_or_default();
+
+        if pod.metadata.deletion_timestamp.is_some() {
+            return Some("pod is terminating".to_string());
+        }
+
+        if let Some(endpoint) = endpoint {
+            let (ready, _, terminating) = conditions(endpoint);
+
+            if ready {
+                return None;
+            }
+
+            if terminating {
+                return Some("endpoint is terminating".to_string());
+            }
+
+            let condition = status
+                .conditions
+                .iter()
+                .flatten()
+                .find(|condition| condition.type_ == "Ready" && condition.status != "True");
+
+            return Some(match condition {
+                Some(condition) => format!(
+                    "endpoint is not ready ({}: {})",
+                    condition.reason.as_deref().unwrap_or("Unknown"),
+                    condition.message.as_deref().unwrap_or_default()
+                ),
+                None => "endpoint is not ready".to_string(),
+            });
+        }
+
+        let reason = match status.phase.as_deref() {
+            Some(phase @ ("Succeeded" | "Failed")) => format!("pod phase is {}", phase),
+            _ if status.pod_ip.is_none() => "pod has no IP address".to_string(),
+            _ => "not found in EndpointSlices".to_string(),
+        };
+
+        Some(reason)
+    }
+
+    /// EndpointSliceのエンドポイントと、セレクタに一致するがトラフィックを受け付けないPodを返す
+    pub fn endpoints_value(slices: &[EndpointSlice], pods: &[Pod]) -> Result<Option<Value>> {
+        let endpoints: Vec<EndpointValue> = slices
+            .iter()
+            .flat_map(|slice| {
+                let ports: Vec<String> = slice
+                    .ports
+                    .iter()
+                    .flatten()
+                    .map(|port| {
+                        let port_number = format!(
+                            "{}/{}",
+                            port.port.unwrap_or_default(),
+                            port.protocol.as_deref().unwrap_or("TCP")
+                        );
+
+                        match port.name.as_deref() {
+                            Some(name) if !name.is_empty() => format!("{} {}", name, port_number),
+                            _ => port_number,
+                        }
+                    })
+                    .collect();
+
+                slice.endpoints.iter().map(move |endpoint| {
+                    let (ready, serving, terminating) = conditions(endpoint);
+
+                    EndpointValue {
+                        address: endpoint.addresses.join(", "),
+                        ports: ports.clone(),
+                        target_ref: endpoint.target_ref.as_ref().map(|target_ref| {
+                            format!(
+                                "{}/{}",
+                                target_ref.kind.as_deref().unwrap_or_default(),
+                                target_ref.name.as_deref().unwrap_or_default()
+                            )
+                        }),
+                        node_name: endpoint.node_name.clone(),
+                        zone: endpoint.zone.clone(),
+                        ready,
+                        serving,
+                        terminating,
+                    }
+                })
+            })
+            .collect();
+
+        let excluded_pods: Vec<ExcludedPod> = pods
+            .iter()
+            .filter_map(|pod| {
+                let name = pod.metadata.name.clone().unwrap_or_default();
+
+                let reason = excluded_reason(pod, pod_endpoint(slices, &name))?;
+
+                Some(ExcludedPod { name, reason })
+            })
+            .collect();
+
+        let mut value = Mapping::new();
+
+        if !endpoints.is_empty() {
+            value.insert("endpoints".into(), serde_yaml::to_value(endpoints)?);
+        }
+
+        if !excluded_pods.is_empty() {
+            value.insert("excludedPods".into(), serde_yaml::to_value(excluded_pods)?);
+        }
+
+        if value.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(value.into()))
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use indoc::indoc;
+        use pretty_assertions::assert_eq;
+
+        use super::*;
+
+        fn endpoint_slices() -> Vec<EndpointSlice> {
+            serde_yaml::from_str(indoc! {
+                "
+                - addressType: IPv4
+                  metadata:
+                    name: service-abcde
+                  ports:
+                  - name: http
+                    port: 8080
+                    protocol: TCP
+                  endpoints:
+                  - addresses:
+                    - 10.1.0.1
+                    conditions:
+                      ready: true
+                      serving: true
+                      terminating: false
+                    nodeName: node-1
+                    zone: zone-a
+                    targetRef:
+                      kind: Pod
+                      name: pod-1
+                  - addresses:
+                    - 10.1.0.2
+                    conditions:
+                      ready: false
+                      serving: false
+                      terminating: false
+                    nodeName: node-2
+                    targetRef:
+                      kind: Pod
+                      name: pod-2
+                "
+            })
+            .unwrap()
+        }
+
+        fn pods() -> Vec<Pod> {
+            serde_yaml::from_str(indoc! {
+                "
+                - metadata:
+                    name: pod-1
+                  status:
+                    phase: Running
+                    podIP: 10.1.0.1
+                - metadata:
+                    name: pod-2
+                  status:
+                    phase: Running
+                    podIP: 10.1.0.2
+                    conditions:
+                    - type: Ready
+                      status: \"False\"
+                      reason: ContainersNotReady
+                      message: \"containers with unready status: [app]\"
+                - metadata:
+                    name: pod-3
+                  status:
+                    phase: Pending
+                - metadata:
+                    name: pod-4
+                  status:
+                    phase: Failed
+                    podIP: 10.1.0.4
+                "
+            })
+            .unwrap()
+        }
+
+        #[test]
+        fn エンドポイントと除外されたpodを返す() {
+            let actual = endpoints_value(&endpoint_slices(), &pods()).unwrap();
+
+            let expected: Value = serde_yaml::from_str(indoc! {
+                "
+                endpoints:
+                - address: 10.1.0.1
+                  ports:
+                  - http 8080/TCP
+                  targetRef: Pod/pod-1
+                  nodeName: node-1
+                  zone: zone-a
+                  ready: true
+                  serving: true
+                  terminating: false
+                - address: 10.1.0.2
+                  ports:
+                  - http 8080/TCP
+                  targetRef: Pod/pod-2
+                  nodeName: node-2
+                  ready: false
+                  serving: false
+                  terminating: false
+                excludedPods:
+                - name: pod-2
+                  reason: \"endpoint is not ready (ContainersNotReady: containers with unready status: [app])\"
+                - name: pod-3
+                  reason: pod has no IP address
+                - name: pod-4
+                  reason: pod phase is Failed
+                "
+            })
+            .unwrap();
+
+            assert_eq!(actual, Some(expected));
+        }
+
+        #[test]
+        fn エンドポイントもpodもない場合はnoneを返す() {
+            let actual = endpoints_value(&[], &[]).unwrap();
+
+            assert_eq!(actual, None);
+        }
+    }
+}
+
 mod extract {
     use k8s_openapi::api::core::v1::Service;
     use kube::api::ObjectMeta;
@@ -0,0 +1,98 @@
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition;
+use kube::ResourceExt;
+use serde::{Deserialize, Serialize};
+
+use crate::kube::apis::networking::gateway::v1alpha2::{
+    TCPRoute, TCPRouteSpec, TCPRouteStatus, TCPRouteStatusParents, TCPRouteStatusParentsParentRef,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Description {
+    tcproute: DescriptionTCPRoute,
+}
+
+impl Description {
+    pub fn new(tcproute: TCPRoute) -> Self {
+        Self {
+            tcproute: DescriptionTCPRoute::new(tcproute),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct MetadataName {
+    name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DescriptionTCPRoute {
+    metadata: MetadataName,
+
+    spec: TCPRouteSpec,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<TCPRouteStatusWrapper>,
+}
+
+impl DescriptionTCPRoute {
+    fn new(tcproute: TCPRoute) -> Self {
+        let name = tcproute.name_any();
+
+        let TCPRoute {
+            metadata: _,
+            spec,
+            status,
+        } = tcproute;
+
+        let status_wrapper = status.map(TCPRouteStatusWrapper::new);
+
+        Self {
+            metadata: MetadataName { name },
+            spec,
+            status: status_wrapper,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TCPRouteStatusWrapper {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    parents: Vec<RouteParentStatusWrapper>,
+}
+
+impl TCPRouteStatusWrapper {
+    fn new(status: TCPRouteStatus) -> Self {
+        let parents = status
+            .parents
+            .into_iter()
+            .map(RouteParentStatusWrapper::new)
+            .collect();
+
+        Self { parents }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RouteParentStatusWrapper {
+    #[serde(flatten)]
+    #[serde(with = "TCPRouteStatusParentsDef")]
+    status: TCPRouteStatusParents,
+}
+
+impl RouteParentStatusWrapper {
+    fn new(status: TCPRouteStatusParents) -> Self {
+        Self { status }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "TCPRouteStatusParents")]
+#[serde(rename_all = "camelCase")]
+struct TCPRouteStatusParentsDef {
+    #[serde(skip)]
+    conditions: Option<Vec<Condition>>,
+
+    controller_name: String,
+
+    parent_ref: TCPRouteStatusParentsParentRef,
+}
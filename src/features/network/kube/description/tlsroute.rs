@@ -0,0 +1,61 @@
+mod v1alpha2;
+
+use anyhow::Result;
+
+use crate::{
+    features::{
+        api_resources::kube::SharedApiResources, network::message::NetworkRequestTargetParams,
+    },
+    kube::{apis::networking::gateway, KubeClientRequest},
+};
+
+use super::{route_related_resources::fetch_route, Fetch, FetchedData};
+
+pub(super) struct TLSRouteDescriptionWorker<'a, C>
+where
+    C: KubeClientRequest,
+{
+    client: &'a C,
+    namespace: String,
+    name: String,
+    version: String,
+}
+
+#[async_trait::async_trait]
+impl<'a, C> Fetch<'a, C> for TLSRouteDescriptionWorker<'a, C>
+where
+    C: KubeClientRequest,
+{
+    fn new(client: &'a C, params: NetworkRequestTargetParams, _: SharedApiResources) -> Self {
+        let NetworkRequestTargetParams {
+            namespace,
+            name,
+            version,
+        } = params;
+
+        Self {
+            client,
+            namespace,
+            name,
+            version,
+        }
+    }
+
+    async fn fetch(&self) -> Result<FetchedData> {
+        match self.version.as_str() {
+            "v1alpha2" => {
+                fetch_route::<gateway::v1alpha2::TLSRoute, _>(
+                    self.client.client().clone(),
+                    &self.name,
+                    &self.namespace,
+                    v1alpha2::Description::new,
+                )
+                .await
+            }
+
+            _ => {
+                unreachable!()
+            }
+        }
+    }
+}
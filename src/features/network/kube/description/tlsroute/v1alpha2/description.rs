@@ -0,0 +1,98 @@
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition;
+use kube::ResourceExt;
+use serde::{Deserialize, Serialize};
+
+use crate::kube::apis::networking::gateway::v1alpha2::{
+    TLSRoute, TLSRouteSpec, TLSRouteStatus, TLSRouteStatusParents, TLSRouteStatusParentsParentRef,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Description {
+    tlsroute: DescriptionTLSRoute,
+}
+
+impl Description {
+    pub fn new(tlsroute: TLSRoute) -> Self {
+        Self {
+            tlsroute: DescriptionTLSRoute::new(tlsroute),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct MetadataName {
+    name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DescriptionTLSRoute {
+    metadata: MetadataName,
+
+    spec: TLSRouteSpec,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<TLSRouteStatusWrapper>,
+}
+
+impl DescriptionTLSRoute {
+    fn new(tlsroute: TLSRoute) -> Self {
+        let name = tlsroute.name_any();
+
+        let TLSRoute {
+            metadata: _,
+            spec,
+            status,
+        } = tlsroute;
+
+        let status_wrapper = status.map(TLSRouteStatusWrapper::new);
+
+        Self {
+            metadata: MetadataName { name },
+            spec,
+            status: status_wrapper,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TLSRouteStatusWrapper {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    parents: Vec<RouteParentStatusWrapper>,
+}
+
+impl TLSRouteStatusWrapper {
+    fn new(status: TLSRouteStatus) -> Self {
+        let parents = status
+            .parents
+            .into_iter()
+            .map(RouteParentStatusWrapper::new)
+            .collect();
+
+        Self { parents }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RouteParentStatusWrapper {
+    #[serde(flatten)]
+    #[serde(with = "TLSRouteStatusParentsDef")]
+    status: TLSRouteStatusParents,
+}
+
+impl RouteParentStatusWrapper {
+    fn new(status: TLSRouteStatusParents) -> Self {
+        Self { status }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "TLSRouteStatusParents")]
+#[serde(rename_all = "camelCase")]
+struct TLSRouteStatusParentsDef {
+    #[serde(skip)]
+    conditions: Option<Vec<Condition>>,
+
+    controller_name: String,
+
+    parent_ref: TLSRouteStatusParentsParentRef,
+}
@@ -14,14 +14,15 @@ use k8s_openapi::{
 use crate::{
     features::{
         api_resources::kube::{ApiResource, ApiResources, SharedApiResources},
-        network::message::{GatewayVersion, HTTPRouteVersion, NetworkResponse},
+        label_selector::kube::LabelSelectorTarget,
+        network::message::{GRPCRouteVersion, GatewayVersion, HTTPRouteVersion, NetworkResponse},
     },
     kube::{
         apis::{
-            networking::gateway::{v1, v1beta1},
+            networking::gateway::{v1, v1alpha2, v1beta1},
             v1_table::Table,
         },
-        table::{insert_ns, KubeTable, KubeTableRow},
+        table::{insert_ns, label_metadata, KubeTable, KubeTableRow},
         KubeClient, KubeClientRequest,
     },
     logger,
@@ -35,6 +36,7 @@ pub struct NetworkTableRow {
     version: String,
     name: String,
     age: String,
+    labels: BTreeMap<String, String>,
 }
 
 impl NetworkTableRow {
@@ -51,13 +53,17 @@ impl NetworkTableRow {
                 .collect()
         };
 
+        let mut metadata = BTreeMap::from([
+            ("kind".to_string(), self.kind.to_string()),
+            ("version".to_string(), self.version.to_string()),
+        ]);
+
+        metadata.extend(self.labels.clone());
+
         KubeTableRow {
             namespace: self.namespace.to_string(),
             name: self.name.to_string(),
-            metadata: Some(BTreeMap::from([
-                ("kind".to_string(), self.kind.to_string()),
-                ("version".to_string(), self.version.to_string()),
-            ])),
+            metadata: Some(metadata),
             row,
         }
     }
@@ -114,6 +120,9 @@ enum TargetResource {
     NetworkPolicy,
     Gateway(GatewayVersion),
     HTTPRoute(HTTPRouteVersion),
+    GRPCRoute(GRPCRouteVersion),
+    TLSRoute,
+    TCPRoute,
 }
 
 impl TargetResource {
@@ -131,6 +140,12 @@ impl TargetResource {
                 HTTPRouteVersion::V1 => v1::HTTPRoute::KIND,
                 HTTPRouteVersion::V1Beta1 => v1beta1::HTTPRoute::KIND,
             },
+            Self::GRPCRoute(version) => match version {
+                GRPCRouteVersion::V1 => v1::GRPCRoute::KIND,
+                GRPCRouteVersion::V1Alpha2 => v1alpha2::GRPCRoute::KIND,
+            },
+            Self::TLSRoute => v1alpha2::TLSRoute::KIND,
+            Self::TCPRoute => v1alpha2::TCPRoute::KIND,
         }
     }
 
@@ -144,24 +159,67 @@ impl TargetResource {
             Self::Gateway(GatewayVersion::V1Beta1) => v1beta1::Gateway::VERSION,
             Self::HTTPRoute(HTTPRouteVersion::V1) => v1::HTTPRoute::VERSION,
             Self::HTTPRoute(HTTPRouteVersion::V1Beta1) => v1beta1::HTTPRoute::VERSION,
+            Self::GRPCRoute(GRPCRouteVersion::V1) => v1::GRPCRoute::VERSION,
+            Self::GRPCRoute(GRPCRouteVersion::V1Alpha2) => v1alpha2::GRPCRoute::VERSION,
+            Self::TLSRoute => v1alpha2::TLSRoute::VERSION,
+            Self::TCPRoute => v1alpha2::TCPRoute::VERSION,
         }
     }
 
-    async fn fetch_table(&self, client: &KubeClient, ns: &str) -> Result<Table> {
+    async fn fetch_table(
+        &self,
+        client: &KubeClient,
+        ns: &str,
+        label_selector: Option<String>,
+    ) -> Result<Table> {
         match self {
-            Self::Ingress => client.table_namespaced::<Ingress>(ns).await,
-            Self::Service => client.table_namespaced::<Service>(ns).await,
-            Self::Pod => client.table_namespaced::<Pod>(ns).await,
-            Self::NetworkPolicy => client.table_namespaced::<NetworkPolicy>(ns).await,
-            Self::Gateway(GatewayVersion::V1) => client.table_namespaced::<v1::Gateway>(ns).await,
+            Self::Ingress => client.table_namespaced::<Ingress>(ns, label_selector).await,
+            Self::Service => client.table_namespaced::<Service>(ns, label_selector).await,
+            Self::Pod => client.table_namespaced::<Pod>(ns, label_selector).await,
+            Self::NetworkPolicy => {
+                client
+                    .table_namespaced::<NetworkPolicy>(ns, label_selector)
+                    .await
+            }
+            Self::Gateway(GatewayVersion::V1) => {
+                client
+                    .table_namespaced::<v1::Gateway>(ns, label_selector)
+                    .await
+            }
             Self::Gateway(GatewayVersion::V1Beta1) => {
-                client.table_namespaced::<v1beta1::Gateway>(ns).await
+                client
+                    .table_namespaced::<v1beta1::Gateway>(ns, label_selector)
+                    .await
             }
             Self::HTTPRoute(HTTPRouteVersion::V1) => {
-                client.table_namespaced::<v1::HTTPRoute>(ns).await
+                client
+                    .table_namespaced::<v1::HTTPRoute>(ns, label_selector)
+                    .await
             }
             Self::HTTPRoute(HTTPRouteVersion::V1Beta1) => {
-                client.table_namespaced::<v1beta1::HTTPRoute>(ns).await
+                client
+                    .table_namespaced::<v1beta1::HTTPRoute>(ns, label_selector)
+                    .await
+            }
+            Self::GRPCRoute(GRPCRouteVersion::V1) => {
+                client
+                    .table_namespaced::<v1::GRPCRoute>(ns, label_selector)
+                    .await
+            }
+            Self::GRPCRoute(GRPCRouteVersion::V1Alpha2) => {
+                client
+                    .table_namespaced::<v1alpha2::GRPCRoute>(ns, label_selector)
+                    .await
+            }
+            Self::TLSRoute => {
+                client
+                    .table_namespaced::<v1alpha2::TLSRoute>(ns, label_selector)
+                    .await
+            }
+            Self::TCPRoute => {
+                client
+                    .table_namespaced::<v1alpha2::TCPRoute>(ns, label_selector)
+                    .await
             }
         }
         .with_context(|| {
@@ -246,6 +304,63 @@ fn target_resources(api_resources: &ApiResources) -> Vec<TargetResource> {
         }
     }
 
+    match find_api_resource(
+        api_resources,
+        v1::GRPCRoute::GROUP,
+        v1::GRPCRoute::URL_PATH_SEGMENT,
+    )
+    .map(|api| api.version())
+    {
+        Some("v1") => {
+            targets.push(TargetResource::GRPCRoute(GRPCRouteVersion::V1));
+        }
+        Some("v1alpha2") => {
+            targets.push(TargetResource::GRPCRoute(GRPCRouteVersion::V1Alpha2));
+        }
+        Some(v) => {
+            logger!(warn, "GRPCRoute is not support: {}", v);
+        }
+        None => {
+            logger!(warn, "GRPCRoute is not found.");
+        }
+    }
+
+    match find_api_resource(
+        api_resources,
+        v1alpha2::TLSRoute::GROUP,
+        v1alpha2::TLSRoute::URL_PATH_SEGMENT,
+    )
+    .map(|api| api.version())
+    {
+        Some("v1alpha2") => {
+            targets.push(TargetResource::TLSRoute);
+        }
+        Some(v) => {
+            logger!(warn, "TLSRoute is not support: {}", v);
+        }
+        None => {
+            logger!(warn, "TLSRoute is not found.");
+        }
+    }
+
+    match find_api_resource(
+        api_resources,
+        v1alpha2::TCPRoute::GROUP,
+        v1alpha2::TCPRoute::URL_PATH_SEGMENT,
+    )
+    .map(|api| api.version())
+    {
+        Some("v1alpha2") => {
+            targets.push(TargetResource::TCPRoute);
+        }
+        Some(v) => {
+            logger!(warn, "TCPRoute is not support: {}", v);
+        }
+        None => {
+            logger!(warn, "TCPRoute is not found.");
+        }
+    }
+
     targets
 }
 
@@ -258,16 +373,14 @@ impl Worker for NetworkPoller {
 
         let is_terminated = &self.base.is_terminated;
         let tx = &self.base.tx;
+        let connection = &self.base.connection;
 
         while !is_terminated.load(std::sync::atomic::Ordering::Relaxed) {
-            interval.tick().await;
+            connection.tick(&mut interval).await;
 
-            let target_resources = {
-                let apis = self.api_resources.read().await;
-                target_resources(&apis)
-            };
+            let table = self.poll().await;
 
-            let table = self.polling(&target_resources).await;
+            connection.report(&table);
 
             tx.send(NetworkResponse::List(table).into())
                 .expect("Failed to send NetworkResponse::List");
@@ -280,23 +393,40 @@ impl Worker for NetworkPoller {
 const TARGET_COLUMNS: [&str; 2] = ["Name", "Age"];
 
 impl NetworkPoller {
+    /// 対象のnamespaceのネットワーク関連リソースの一覧を取得する
+    pub async fn poll(&self) -> Result<KubeTable> {
+        let target_resources = {
+            let apis = self.api_resources.read().await;
+            target_resources(&apis)
+        };
+
+        self.polling(&target_resources).await
+    }
+
     async fn polling(&self, target_resources: &[TargetResource]) -> Result<KubeTable> {
         let target_namespaces = self.base.shared_target_namespaces.read().await;
 
-        let rows: Vec<_> = join_all(
-            target_resources
-                .iter()
-                .map(|kind| self.fetch_resource(kind, &target_namespaces)),
-        )
-        .await
-        .into_iter()
-        .inspect(|res| {
-            if let Err(e) = res {
-                logger!(error, "Failed to fetch resource: {:?}", e);
-            }
-        })
-        .filter_map(|res| res.ok())
-        .collect();
+        let label_selector = self
+            .base
+            .shared_label_selectors
+            .read()
+            .await
+            .get(&LabelSelectorTarget::Network)
+            .cloned();
+
+        let rows: Vec<_> =
+            join_all(target_resources.iter().map(|kind| {
+                self.fetch_resource(kind, &target_namespaces, label_selector.as_deref())
+            }))
+            .await
+            .into_iter()
+            .inspect(|res| {
+                if let Err(e) = res {
+                    logger!(error, "Failed to fetch resource: {:?}", e);
+                }
+            })
+            .filter_map(|res| res.ok())
+            .collect();
 
         let table = NetworkTable::new(
             insert_ns(&target_namespaces),
@@ -310,14 +440,13 @@ impl NetworkPoller {
         &self,
         kind: &TargetResource,
         namespaces: &[String],
+        label_selector: Option<&str>,
     ) -> Result<Vec<NetworkTableRow>> {
         let client = &self.base.kube_client;
 
-        let jobs = try_join_all(
-            namespaces
-                .iter()
-                .map(|ns| fetch_resource_per_namespace(client, kind, ns, &TARGET_COLUMNS)),
-        )
+        let jobs = try_join_all(namespaces.iter().map(|ns| {
+            fetch_resource_per_namespace(client, kind, ns, label_selector, &TARGET_COLUMNS)
+        }))
         .await?;
 
         Ok(jobs.into_iter().flatten().collect())
@@ -328,9 +457,12 @@ async fn fetch_resource_per_namespace(
     client: &KubeClient,
     kind: &TargetResource,
     ns: &str,
+    label_selector: Option<&str>,
     target_columns: &[&str],
 ) -> Result<Vec<NetworkTableRow>> {
-    let table = kind.fetch_table(client, ns).await?;
+    let table = kind
+        .fetch_table(client, ns, label_selector.map(ToString::to_string))
+        .await?;
 
     let indexes = table.find_indexes(target_columns);
 
@@ -343,6 +475,7 @@ async fn fetch_resource_per_namespace(
             version: kind.version().to_string(),
             name: row.cells[indexes[0]].to_string(),
             age: row.cells[indexes[1]].to_string(),
+            labels: label_metadata(row),
         })
         .collect();
 
@@ -375,6 +508,49 @@ fn find_api_resource<'a>(
 mod tests {
     use super::*;
 
+    mod target_resources {
+        use super::*;
+
+        use kube::discovery::Scope;
+
+        fn api_resource(name: &str, version: &str) -> ApiResource {
+            ApiResource::Apis {
+                group: "gateway.networking.k8s.io".to_string(),
+                name: name.to_string(),
+                version: version.to_string(),
+                preferred_version: true,
+                scope: Scope::Namespaced,
+            }
+        }
+
+        #[test]
+        fn 見つかったルートのバージョンに応じて対象に追加する() {
+            let api_resources = ApiResources::from([
+                api_resource("grpcroutes", "v1alpha2"),
+                api_resource("tlsroutes", "v1alpha2"),
+                api_resource("tcproutes", "v1alpha2"),
+            ]);
+
+            let actual: Vec<_> = target_resources(&api_resources)
+                .iter()
+                .map(|target| (target.as_str(), target.version()))
+                .collect();
+
+            assert_eq!(
+                actual,
+                vec![
+                    ("Ingress", "v1"),
+                    ("Service", "v1"),
+                    ("Pod", "v1"),
+                    ("NetworkPolicy", "v1"),
+                    ("GRPCRoute", "v1alpha2"),
+                    ("TLSRoute", "v1alpha2"),
+                    ("TCPRoute", "v1alpha2"),
+                ]
+            );
+        }
+    }
+
     mod find_api_resource {
         use super::*;
 
@@ -0,0 +1,195 @@
+mod policy;
+mod query;
+
+use anyhow::{anyhow, Result};
+use k8s_openapi::{
+    api::{
+        core::v1::{Namespace, Pod},
+        networking::v1::NetworkPolicy,
+    },
+    apimachinery::pkg::util::intstr::IntOrString,
+    List,
+};
+use kube::Resource;
+
+use crate::{features::network::message::ReachabilityRequest, kube::KubeClientRequest};
+
+use self::{
+    policy::{evaluate, Direction, DirectionVerdict, Endpoint, Port},
+    query::{PodRef, Query},
+};
+
+/// NetworkPolicyによってsourceからdestinationへの通信が許可されるかを判定し、結果を行ごとに返す
+pub async fn check_reachability<C: KubeClientRequest>(
+    client: &C,
+    req: ReachabilityRequest,
+) -> Result<Vec<String>> {
+    let query = Query::parse(&req.query, &req.namespace)?;
+
+    let source = fetch_endpoint(client, &query.source).await?;
+    let destination = fetch_endpoint(client, &query.destination).await?;
+
+    let port = match &query.port {
+        IntOrString::Int(number) => *number,
+        IntOrString::String(name) => {
+            destination
+                .named_port(name, &query.protocol)
+                .ok_or_else(|| {
+                    anyhow!(
+                        "port '{}/{}' not found in pod {}",
+                        name,
+                        query.protocol,
+                        query.destination
+                    )
+                })?
+        }
+    };
+
+    let port = Port {
+        number: port,
+        protocol: query.protocol.clone(),
+    };
+
+    let egress_policies = fetch_policies(client, &query.source.namespace).await?;
+    let ingress_policies = fetch_policies(client, &query.destination.namespace).await?;
+
+    let egress = evaluate(
+        Direction::Egress,
+        &egress_policies,
+        &source,
+        &destination,
+        &port,
+    );
+
+    let ingress = evaluate(
+        Direction::Ingress,
+        &ingress_policies,
+        &source,
+        &destination,
+        &port,
+    );
+
+    Ok(report(
+        &query,
+        &source,
+        &destination,
+        &port,
+        &[egress, ingress],
+    ))
+}
+
+async fn fetch_endpoint<C: KubeClientRequest>(client: &C, pod: &PodRef) -> Result<Endpoint> {
+    let url = format!("{}/{}", Pod::url_path(&(), Some(&pod.namespace)), pod.name);
+
+    let pod_resource: Pod = client.request(&url).await?;
+
+    let url = format!("{}/{}", Namespace::url_path(&(), None), pod.namespace);
+
+    let namespace: Namespace = client.request(&url).await?;
+
+    Ok(Endpoint {
+        pod: pod_resource,
+        namespace_labels: namespace.metadata.labels.unwrap_or_default(),
+    })
+}
+
+async fn fetch_policies<C: KubeClientRequest>(
+    client: &C,
+    namespace: &str,
+) -> Result<Vec<NetworkPolicy>> {
+    let url = NetworkPolicy::url_path(&(), Some(namespace));
+
+    let list: List<NetworkPolicy> = client.request(&url).await?;
+
+    Ok(list.items)
+}
+
+fn report(
+    query: &Query,
+    source: &Endpoint,
+    destination: &Endpoint,
+    port: &Port,
+    verdicts: &[DirectionVerdict],
+) -> Vec<String> {
+    let pod_ip = |endpoint: &Endpoint| {
+        endpoint
+            .pod
+            .status
+            .as_ref()
+            .and_then(|status| status.pod_ip.clone())
+            .unwrap_or_else(|| "no IP".to_string())
+    };
+
+    let port_name = match &query.port {
+        IntOrString::Int(_) => format!("{}/{}", port.number, port.protocol),
+        IntOrString::String(name) => format!("{} ({}/{})", name, port.number, port.protocol),
+    };
+
+    let result = if verdicts.iter().all(DirectionVerdict::is_allowed) {
+        "Allowed"
+    } else {
+        "Denied"
+    };
+
+    let mut lines = vec![
+        format!("Result: {}", result),
+        String::new(),
+        format!("Source:      {} ({})", query.source, pod_ip(source)),
+        format!(
+            "Destination: {} ({})",
+            query.destination,
+            pod_ip(destination)
+        ),
+        format!("Port:        {}", port_name),
+    ];
+
+    for verdict in verdicts {
+        let (title, pod) = match verdict.direction {
+            Direction::Egress => ("Egress from", &query.source),
+            Direction::Ingress => ("Ingress to", &query.destination),
+        };
+
+        lines.push(String::new());
+
+        lines.push(format!(
+            "{} {}: {}",
+            title,
+            pod,
+            if verdict.is_allowed() {
+                "Allowed"
+            } else {
+                "Denied"
+            }
+        ));
+
+        if !verdict.is_isolated() {
+            lines.push(format!(
+                "  no NetworkPolicy selects this pod for {}",
+                verdict.direction
+            ));
+        }
+
+        for policy in &verdict.policies {
+            if policy.matched_rules.is_empty() {
+                lines.push(format!(
+                    "  {}: no {} rule matches",
+                    policy.name, verdict.direction
+                ));
+            } else {
+                let rules: Vec<String> = policy
+                    .matched_rules
+                    .iter()
+                    .map(|i| format!("{}[{}]", verdict.direction, i))
+                    .collect();
+
+                lines.push(format!(
+                    "  {}: allowed by {}",
+                    policy.name,
+                    rules.join(", ")
+                ));
+            }
+        }
+    }
+
+    lines
+}
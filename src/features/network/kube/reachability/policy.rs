@@ -0,0 +1,553 @@
+use std::{collections::BTreeMap, net::IpAddr};
+
+use k8s_openapi::{
+    api::{
+        core::v1::Pod,
+        networking::v1::{IPBlock, NetworkPolicy, NetworkPolicyPeer, NetworkPolicyPort},
+    },
+    apimachinery::pkg::{apis::meta::v1::LabelSelector, util::intstr::IntOrString},
+};
+
+use crate::features::network::kube::description::related_resources::label_selector::{
+    LabelSelectorExpression as _, LabelSelectorWrapper,
+};
+
+/// 通信の向き
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Ingress,
+    Egress,
+}
+
+impl std::fmt::Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ingress => write!(f, "ingress"),
+            Self::Egress => write!(f, "egress"),
+        }
+    }
+}
+
+/// 通信の端点となるPodとそのnamespaceのラベル
+#[derive(Debug, Clone)]
+pub struct Endpoint {
+    pub pod: Pod,
+    pub namespace_labels: BTreeMap<String, String>,
+}
+
+impl Endpoint {
+    fn namespace(&self) -> &str {
+        self.pod.metadata.namespace.as_deref().unwrap_or_default()
+    }
+
+    fn labels(&self) -> BTreeMap<String, String> {
+        self.pod.metadata.labels.clone().unwrap_or_default()
+    }
+
+    fn ip(&self) -> Option<IpAddr> {
+        self.pod
+            .status
+            .as_ref()
+            .and_then(|status| status.pod_ip.as_ref())
+            .and_then(|ip| ip.parse().ok())
+    }
+
+    /// コンテナのポート名に対応するポート番号
+    pub fn named_port(&self, name: &str, protocol: &str) -> Option<i32> {
+        self.pod
+            .spec
+            .as_ref()?
+            .containers
+            .iter()
+            .flat_map(|container| container.ports.iter().flatten())
+            .find(|port| {
+                port.name.as_deref() == Some(name)
+                    && port.protocol.as_deref().unwrap_or("TCP") == protocol
+            })
+            .map(|port| port.container_port)
+    }
+}
+
+/// 宛先のポート
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Port {
+    pub number: i32,
+    pub protocol: String,
+}
+
+/// 対象のPodを選択しているNetworkPolicyと、通信に一致したルールの番号
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolicyVerdict {
+    pub name: String,
+    pub matched_rules: Vec<usize>,
+}
+
+/// 片方向の判定結果
+///
+/// 対象のPodを選択しているNetworkPolicyがない場合は隔離されておらず、すべての通信を許可する
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirectionVerdict {
+    pub direction: Direction,
+    pub policies: Vec<PolicyVerdict>,
+}
+
+impl DirectionVerdict {
+    pub fn is_isolated(&self) -> bool {
+        !self.policies.is_empty()
+    }
+
+    pub fn is_allowed(&self) -> bool {
+        !self.is_isolated()
+            || self
+                .policies
+                .iter()
+                .any(|policy| !policy.matched_rules.is_empty())
+    }
+}
+
+/// ルールの通信相手とポートの条件
+type Rule<'a> = (
+    Option<&'a Vec<NetworkPolicyPeer>>,
+    Option<&'a Vec<NetworkPolicyPort>>,
+);
+
+/// sourceからdestinationのportへの通信をpoliciesで判定する
+///
+/// Ingressの場合はdestinationのnamespaceの、Egressの場合はsourceのnamespaceのNetworkPolicyを渡す
+pub fn evaluate(
+    direction: Direction,
+    policies: &[NetworkPolicy],
+    source: &Endpoint,
+    destination: &Endpoint,
+    port: &Port,
+) -> DirectionVerdict {
+    let (target, peer) = match direction {
+        Direction::Ingress => (destination, source),
+        Direction::Egress => (source, destination),
+    };
+
+    let policies = policies
+        .iter()
+        .filter_map(|policy| {
+            let spec = policy.spec.as_ref()?;
+
+            if !has_policy_type(policy, direction)
+                || !selector_matches(&spec.pod_selector, &target.labels())
+            {
+                return None;
+            }
+
+            let policy_namespace = policy
+                .metadata
+                .namespace
+                .as_deref()
+                .unwrap_or(target.namespace());
+
+            let rules: Vec<Rule> = match direction {
+                Direction::Ingress => spec
+                    .ingress
+                    .iter()
+                    .flatten()
+                    .map(|rule| (rule.from.as_ref(), rule.ports.as_ref()))
+                    .collect(),
+                Direction::Egress => spec
+                    .egress
+                    .iter()
+                    .flatten()
+                    .map(|rule| (rule.to.as_ref(), rule.ports.as_ref()))
+                    .collect(),
+            };
+
+            let matched_rules = rules
+                .into_iter()
+                .enumerate()
+                .filter(|(_, (peers, ports))| {
+                    let peer_matched = peers.is_none_or(|peers| {
+                        peers.is_empty()
+                            || peers
+                                .iter()
+                                .any(|p| peer_matches(p, peer, policy_namespace))
+                    });
+
+                    let port_matched = ports.is_none_or(|ports| {
+                        ports.is_empty() || ports.iter().any(|p| port_matches(p, port, destination))
+                    });
+
+                    peer_matched && port_matched
+                })
+                .map(|(i, _)| i)
+                .collect();
+
+            Some(PolicyVerdict {
+                name: format!(
+                    "{}/{}",
+                    policy_namespace,
+                    policy.metadata.name.as_deref().unwrap_or_default()
+                ),
+                matched_rules,
+            })
+        })
+        .collect();
+
+    DirectionVerdict {
+        direction,
+        policies,
+    }
+}
+
+/// policyTypesが未指定の場合はIngressを、egressがある場合はEgressも含める
+fn has_policy_type(policy: &NetworkPolicy, direction: Direction) -> bool {
+    let Some(spec) = &policy.spec else {
+        return false;
+    };
+
+    if let Some(policy_types) = &spec.policy_types {
+        let direction = match direction {
+            Direction::Ingress => "Ingress",
+            Direction::Egress => "Egress",
+        };
+
+        return policy_types.iter().any(|t| t == direction);
+    }
+
+    match direction {
+        Direction::Ingress => true,
+        Direction::Egress => spec.egress.is_some(),
+    }
+}
+
+fn selector_matches(selector: &LabelSelector, labels: &BTreeMap<String, String>) -> bool {
+    LabelSelectorWrapper::new(selector.clone()).expression(labels)
+}
+
+fn peer_matches(peer: &NetworkPolicyPeer, endpoint: &Endpoint, policy_namespace: &str) -> bool {
+    if let Some(ip_block) = &peer.ip_block {
+        return endpoint
+            .ip()
+            .is_some_and(|ip| ip_block_contains(ip_block, ip));
+    }
+
+    let namespace_matched = match &peer.namespace_selector {
+        Some(selector) => selector_matches(selector, &endpoint.namespace_labels),
+        None => endpoint.namespace() == policy_namespace,
+    };
+
+    let pod_matched = peer
+        .pod_selector
+        .as_ref()
+        .is_none_or(|selector| selector_matches(selector, &endpoint.labels()));
+
+    namespace_matched && pod_matched
+}
+
+/// ポート名は宛先のPodのコンテナポートで解決する
+fn port_matches(policy_port: &NetworkPolicyPort, port: &Port, destination: &Endpoint) -> bool {
+    let protocol = policy_port.protocol.as_deref().unwrap_or("TCP");
+
+    if protocol != port.protocol {
+        return false;
+    }
+
+    match &policy_port.port {
+        None => true,
+        Some(IntOrString::Int(number)) => match policy_port.end_port {
+            Some(end_port) => (*number..=end_port).contains(&port.number),
+            None => *number == port.number,
+        },
+        Some(IntOrString::String(name)) => {
+            destination.named_port(name, protocol) == Some(port.number)
+        }
+    }
+}
+
+fn ip_block_contains(ip_block: &IPBlock, ip: IpAddr) -> bool {
+    cidr_contains(&ip_block.cidr, ip)
+        && !ip_block
+            .except
+            .iter()
+            .flatten()
+            .any(|except| cidr_contains(except, ip))
+}
+
+fn cidr_contains(cidr: &str, ip: IpAddr) -> bool {
+    let Some((addr, prefix)) = cidr.split_once('/') else {
+        return false;
+    };
+
+    let (Ok(addr), Ok(prefix)) = (addr.parse::<IpAddr>(), prefix.parse::<u32>()) else {
+        return false;
+    };
+
+    let (addr, ip, bits) = match (addr, ip) {
+        (IpAddr::V4(addr), IpAddr::V4(ip)) => (u32::from(addr) as u128, u32::from(ip) as u128, 32),
+        (IpAddr::V6(addr), IpAddr::V6(ip)) => (u128::from(addr), u128::from(ip), 128),
+        _ => return false,
+    };
+
+    if bits < prefix {
+        return false;
+    }
+
+    if prefix == 0 {
+        return true;
+    }
+
+    let shift = bits - prefix;
+
+    addr >> shift == ip >> shift
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::{formatdoc, indoc};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn endpoint(namespace: &str, name: &str, ip: &str, labels: &str) -> Endpoint {
+        let pod = serde_yaml::from_str(&formatdoc! {"
+                metadata:
+                  name: {}
+                  namespace: {}
+                  labels: {}
+                spec:
+                  containers:
+                  - name: app
+                    ports:
+                    - name: http
+                      containerPort: 8080
+                status:
+                  podIP: {}
+            ",
+            name, namespace, labels, ip
+        })
+        .unwrap();
+
+        Endpoint {
+            pod,
+            namespace_labels: BTreeMap::from([(
+                "kubernetes.io/metadata.name".to_string(),
+                namespace.to_string(),
+            )]),
+        }
+    }
+
+    fn client() -> Endpoint {
+        endpoint("app", "client", "10.0.1.5", "{app: client}")
+    }
+
+    fn server() -> Endpoint {
+        endpoint("db", "server", "10.0.2.5", "{app: server}")
+    }
+
+    fn policies(yaml: &str) -> Vec<NetworkPolicy> {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    fn tcp(number: i32) -> Port {
+        Port {
+            number,
+            protocol: "TCP".to_string(),
+        }
+    }
+
+    #[test]
+    fn ポリシーに選択されていない場合は許可する() {
+        let policies = policies(indoc! {"
+            - metadata:
+                name: other
+                namespace: db
+              spec:
+                podSelector:
+                  matchLabels:
+                    app: other
+        "});
+
+        let verdict = evaluate(
+            Direction::Ingress,
+            &policies,
+            &client(),
+            &server(),
+            &tcp(8080),
+        );
+
+        assert!(!verdict.is_isolated());
+        assert!(verdict.is_allowed());
+    }
+
+    #[test]
+    fn ルールのないポリシーに選択されている場合は拒否する() {
+        let policies = policies(indoc! {"
+            - metadata:
+                name: deny-all
+                namespace: db
+              spec:
+                podSelector: {}
+        "});
+
+        let verdict = evaluate(
+            Direction::Ingress,
+            &policies,
+            &client(),
+            &server(),
+            &tcp(8080),
+        );
+
+        assert_eq!(
+            verdict,
+            DirectionVerdict {
+                direction: Direction::Ingress,
+                policies: vec![PolicyVerdict {
+                    name: "db/deny-all".to_string(),
+                    matched_rules: vec![],
+                }],
+            }
+        );
+        assert!(!verdict.is_allowed());
+    }
+
+    #[rstest]
+    #[case::namespace_selector(
+        "[{namespaceSelector: {matchLabels: {kubernetes.io/metadata.name: app}}}]",
+        true
+    )]
+    #[case::namespace_and_pod_selector(
+        "[{namespaceSelector: {}, podSelector: {matchLabels: {app: client}}}]",
+        true
+    )]
+    #[case::pod_selector_in_other_namespace("[{podSelector: {matchLabels: {app: client}}}]", false)]
+    #[case::ip_block("[{ipBlock: {cidr: 10.0.1.0/24}}]", true)]
+    #[case::ip_block_except("[{ipBlock: {cidr: 10.0.0.0/16, except: [10.0.1.0/24]}}]", false)]
+    fn 送信元の条件で判定する(#[case] from: &str, #[case] expected: bool) {
+        let policies = policies(&formatdoc! {"
+                - metadata:
+                    name: allow
+                    namespace: db
+                  spec:
+                    podSelector:
+                      matchLabels:
+                        app: server
+                    ingress:
+                    - from: {}
+            ",
+            from
+        });
+
+        let verdict = evaluate(
+            Direction::Ingress,
+            &policies,
+            &client(),
+            &server(),
+            &tcp(8080),
+        );
+
+        assert_eq!(verdict.is_allowed(), expected);
+    }
+
+    #[rstest]
+    #[case::number("[{port: 8080}]", tcp(8080), true)]
+    #[case::other_number("[{port: 80}]", tcp(8080), false)]
+    #[case::named_port("[{port: http}]", tcp(8080), true)]
+    #[case::range("[{port: 8000, endPort: 9000}]", tcp(8080), true)]
+    #[case::protocol("[{port: 8080, protocol: UDP}]", tcp(8080), false)]
+    #[case::protocol_only(
+        "[{protocol: UDP}]",
+        Port { number: 53, protocol: "UDP".to_string() },
+        true
+    )]
+    fn ポートの条件で判定する(
+        #[case] ports: &str,
+        #[case] port: Port,
+        #[case] expected: bool,
+    ) {
+        let policies = policies(&formatdoc! {"
+                - metadata:
+                    name: allow
+                    namespace: app
+                  spec:
+                    podSelector: {{}}
+                    policyTypes: [Egress]
+                    egress:
+                    - ports: {}
+            ",
+            ports
+        });
+
+        let verdict = evaluate(Direction::Egress, &policies, &client(), &server(), &port);
+
+        assert_eq!(verdict.is_allowed(), expected);
+    }
+
+    #[test]
+    fn 一致したルールの番号を返す() {
+        let policies = policies(indoc! {"
+            - metadata:
+                name: allow
+                namespace: app
+              spec:
+                podSelector:
+                  matchLabels:
+                    app: client
+                egress:
+                - ports: [{port: 53, protocol: UDP}]
+                - to: [{podSelector: {}, namespaceSelector: {}}]
+                  ports: [{port: 8080}]
+                - to: [{ipBlock: {cidr: 10.0.0.0/8}}]
+        "});
+
+        let verdict = evaluate(
+            Direction::Egress,
+            &policies,
+            &client(),
+            &server(),
+            &tcp(8080),
+        );
+
+        assert_eq!(
+            verdict.policies,
+            vec![PolicyVerdict {
+                name: "app/allow".to_string(),
+                matched_rules: vec![1, 2],
+            }]
+        );
+    }
+
+    #[test]
+    fn policy_typesにない方向は判定しない() {
+        let policies = policies(indoc! {"
+            - metadata:
+                name: ingress-only
+                namespace: app
+              spec:
+                podSelector: {}
+                policyTypes: [Ingress]
+        "});
+
+        let verdict = evaluate(
+            Direction::Egress,
+            &policies,
+            &client(),
+            &server(),
+            &tcp(8080),
+        );
+
+        assert!(!verdict.is_isolated());
+    }
+
+    #[rstest]
+    #[case("10.0.0.0/8", "10.1.2.3", true)]
+    #[case("10.0.0.0/16", "10.1.2.3", false)]
+    #[case("0.0.0.0/0", "192.168.0.1", true)]
+    #[case("10.0.0.1/32", "10.0.0.1", true)]
+    #[case("fd00::/8", "fd12::1", true)]
+    #[case("fd00::/8", "10.0.0.1", false)]
+    #[case("invalid", "10.0.0.1", false)]
+    fn cidrにipが含まれるか判定する(
+        #[case] cidr: &str,
+        #[case] ip: &str,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(cidr_contains(cidr, ip.parse().unwrap()), expected);
+    }
+}
@@ -0,0 +1,154 @@
+use anyhow::{bail, Result};
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+
+const USAGE: &str = "expected '<source> -> <destination>:<port>[/<protocol>]'";
+
+const PROTOCOLS: [&str; 3] = ["TCP", "UDP", "SCTP"];
+
+/// namespaceと名前で指定されたPod
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PodRef {
+    pub namespace: String,
+    pub name: String,
+}
+
+impl std::fmt::Display for PodRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.namespace, self.name)
+    }
+}
+
+impl PodRef {
+    /// `<namespace>/<name>`または`<name>`
+    fn parse(s: &str, namespace: &str) -> Result<Self> {
+        let s = s.trim();
+
+        let (namespace, name) = s.split_once('/').unwrap_or((namespace, s));
+
+        if namespace.is_empty() || name.is_empty() || name.contains('/') {
+            bail!("invalid pod '{}': {}", s, USAGE);
+        }
+
+        Ok(Self {
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Query {
+    pub source: PodRef,
+    pub destination: PodRef,
+    /// ポート番号または宛先Podのポート名
+    pub port: IntOrString,
+    pub protocol: String,
+}
+
+impl Query {
+    pub fn parse(query: &str, namespace: &str) -> Result<Self> {
+        let Some((source, destination)) = query.split_once("->") else {
+            bail!("missing '->': {}", USAGE);
+        };
+
+        let Some((destination, port)) = destination.trim().rsplit_once(':') else {
+            bail!("missing destination port: {}", USAGE);
+        };
+
+        let (port, protocol) = port
+            .split_once('/')
+            .map(|(port, protocol)| (port, protocol.to_ascii_uppercase()))
+            .unwrap_or((port, "TCP".to_string()));
+
+        if !PROTOCOLS.contains(&protocol.as_str()) {
+            bail!(
+                "unsupported protocol '{}': expected one of {}",
+                protocol,
+                PROTOCOLS.join(", ")
+            );
+        }
+
+        let port = match port.parse::<i32>() {
+            Ok(port) if (1..=65535).contains(&port) => IntOrString::Int(port),
+            Ok(port) => bail!("invalid port '{}': must be between 1 and 65535", port),
+            Err(_) if !port.is_empty() => IntOrString::String(port.to_string()),
+            Err(_) => bail!("missing destination port: {}", USAGE),
+        };
+
+        Ok(Self {
+            source: PodRef::parse(source, namespace)?,
+            destination: PodRef::parse(destination, namespace)?,
+            port,
+            protocol,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn pod(namespace: &str, name: &str) -> PodRef {
+        PodRef {
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+        }
+    }
+
+    #[rstest]
+    #[case::namespace_omitted(
+        "client -> server:8080",
+        pod("default", "client"),
+        pod("default", "server"),
+        IntOrString::Int(8080),
+        "TCP"
+    )]
+    #[case::with_namespace_and_protocol(
+        "app/client->db/server:53/udp",
+        pod("app", "client"),
+        pod("db", "server"),
+        IntOrString::Int(53),
+        "UDP"
+    )]
+    #[case::named_port(
+        "client -> db/server:http",
+        pod("default", "client"),
+        pod("db", "server"),
+        IntOrString::String("http".to_string()),
+        "TCP"
+    )]
+    fn クエリをパースする(
+        #[case] query: &str,
+        #[case] source: PodRef,
+        #[case] destination: PodRef,
+        #[case] port: IntOrString,
+        #[case] protocol: &str,
+    ) {
+        let actual = Query::parse(query, "default").unwrap();
+
+        assert_eq!(
+            actual,
+            Query {
+                source,
+                destination,
+                port,
+                protocol: protocol.to_string(),
+            }
+        );
+    }
+
+    #[rstest]
+    #[case::missing_arrow("client server:80")]
+    #[case::missing_port("client -> server")]
+    #[case::empty_port("client -> server:")]
+    #[case::out_of_range_port("client -> server:70000")]
+    #[case::unknown_protocol("client -> server:80/icmp")]
+    #[case::empty_source(" -> server:80")]
+    #[case::too_many_slashes("a/b/c -> server:80")]
+    fn 不正なクエリはエラーになる(#[case] query: &str) {
+        assert!(Query::parse(query, "default").is_err());
+    }
+}
@@ -1,7 +1,25 @@
 use anyhow::Result;
+use k8s_openapi::{
+    api::{
+        core::v1::{Pod, Service},
+        networking::v1::{Ingress, NetworkPolicy},
+    },
+    Resource as _,
+};
+use serde::{Deserialize, Serialize};
 use strum::EnumString;
 
-use crate::{kube::table::KubeTable, message::Message, workers::kube::message::Kube};
+use crate::{
+    kube::{
+        apis::networking::gateway::{
+            v1::{GRPCRoute, Gateway, HTTPRoute},
+            v1alpha2::{TCPRoute, TLSRoute},
+        },
+        table::KubeTable,
+    },
+    message::Message,
+    workers::{kube::message::Kube, replay::serde_result},
+};
 
 #[derive(Copy, Clone, Default, Debug, EnumString)]
 #[strum(serialize_all = "lowercase")]
@@ -19,6 +37,14 @@ pub enum HTTPRouteVersion {
     V1Beta1,
 }
 
+#[derive(Copy, Clone, Default, Debug, EnumString)]
+#[strum(serialize_all = "lowercase")]
+pub enum GRPCRouteVersion {
+    #[default]
+    V1,
+    V1Alpha2,
+}
+
 #[derive(Debug, Clone)]
 pub struct NetworkRequestTargetParams {
     pub name: String,
@@ -34,21 +60,101 @@ pub enum NetworkRequest {
     NetworkPolicy(NetworkRequestTargetParams),
     Gateway(NetworkRequestTargetParams),
     HTTPRoute(NetworkRequestTargetParams),
+    GRPCRoute(NetworkRequestTargetParams),
+    TLSRoute(NetworkRequestTargetParams),
+    TCPRoute(NetworkRequestTargetParams),
+}
+
+/// Pod間の通信可否の確認
+///
+/// queryは`<source> -> <destination>:<port>[/<protocol>]`の形式で、
+/// namespaceを省略したPodはnamespaceに属するものとして扱う
+#[derive(Debug, Clone)]
+pub struct ReachabilityRequest {
+    pub query: String,
+    pub namespace: String,
+}
+
+/// Descriptionに表示しているリソースと関連するリソース
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RelatedResource {
+    pub kind: String,
+    pub name: String,
+    pub namespace: String,
+    pub version: String,
+    /// 関連元のkind、名前、namespace（例: Podに対するService）
+    ///
+    /// `None`の場合はDescriptionに表示しているリソースに直接関連する
+    pub parent: Option<(String, String, String)>,
 }
 
-#[derive(Debug)]
+impl RelatedResource {
+    pub fn new(
+        kind: impl Into<String>,
+        name: impl Into<String>,
+        namespace: impl Into<String>,
+        version: impl Into<String>,
+    ) -> Self {
+        Self {
+            kind: kind.into(),
+            name: name.into(),
+            namespace: namespace.into(),
+            version: version.into(),
+            parent: None,
+        }
+    }
+
+    pub fn parent(
+        mut self,
+        kind: impl Into<String>,
+        name: impl Into<String>,
+        namespace: impl Into<String>,
+    ) -> Self {
+        self.parent = Some((kind.into(), name.into(), namespace.into()));
+        self
+    }
+}
+
+/// 関連リソースのグラフ
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RelatedResourceGraph {
+    /// Descriptionに表示しているリソース
+    pub root: RelatedResource,
+    pub related_resources: Vec<RelatedResource>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
 pub enum NetworkResponse {
-    List(Result<KubeTable>),
-    Yaml(Result<Vec<String>>),
+    List(#[serde(with = "serde_result")] Result<KubeTable>),
+    Yaml(#[serde(with = "serde_result")] Result<Vec<String>>),
+    Graph(RelatedResourceGraph),
+    Reachability(#[serde(with = "serde_result")] Result<Vec<String>>),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum NetworkMessage {
+    #[serde(skip)]
     Request(NetworkRequest),
     Response(NetworkResponse),
+    #[serde(skip)]
+    Reachability(ReachabilityRequest),
 }
 
 impl NetworkRequest {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Pod(_) => Pod::KIND,
+            Self::Service(_) => Service::KIND,
+            Self::Ingress(_) => Ingress::KIND,
+            Self::NetworkPolicy(_) => NetworkPolicy::KIND,
+            Self::Gateway(_) => Gateway::KIND,
+            Self::HTTPRoute(_) => HTTPRoute::KIND,
+            Self::GRPCRoute(_) => GRPCRoute::KIND,
+            Self::TLSRoute(_) => TLSRoute::KIND,
+            Self::TCPRoute(_) => TCPRoute::KIND,
+        }
+    }
+
     pub fn data(&self) -> &NetworkRequestTargetParams {
         match self {
             Self::Pod(data) => data,
@@ -57,6 +163,9 @@ impl NetworkRequest {
             Self::NetworkPolicy(data) => data,
             Self::Gateway(data) => data,
             Self::HTTPRoute(data) => data,
+            Self::GRPCRoute(data) => data,
+            Self::TLSRoute(data) => data,
+            Self::TCPRoute(data) => data,
         }
     }
 }
@@ -79,6 +188,12 @@ impl From<NetworkRequest> for Message {
     }
 }
 
+impl From<ReachabilityRequest> for Message {
+    fn from(req: ReachabilityRequest) -> Self {
+        NetworkMessage::Reachability(req).into()
+    }
+}
+
 impl From<NetworkResponse> for Message {
     fn from(res: NetworkResponse) -> Self {
         NetworkMessage::Response(res).into()
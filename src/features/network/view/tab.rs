@@ -7,17 +7,25 @@ use crate::{
     clipboard::Clipboard,
     features::{
         component_id::NETWORK_TAB_ID,
-        network::view::widgets::{description_widget, network_widget},
+        network::view::widgets::{
+            description_widget, network_graph_widget, network_reachability_result_widget,
+            network_reachability_widget, network_widget, SharedDescriptionTarget,
+        },
     },
+    history::HistoryHandle,
     message::Message,
     ui::{
         tab::{LayoutElement, NestedLayoutElement, NestedWidgetLayout},
+        widget::Widget,
         Tab,
     },
 };
 
 pub struct NetworkTab {
     pub tab: Tab<'static>,
+    pub graph_popup: Widget<'static>,
+    pub reachability_popup: Widget<'static>,
+    pub reachability_result_popup: Widget<'static>,
 }
 
 impl NetworkTab {
@@ -26,9 +34,14 @@ impl NetworkTab {
         tx: &Sender<Message>,
         clipboard: &Option<Rc<RefCell<Clipboard>>>,
         split_mode: Direction,
+        history: &HistoryHandle,
+        target: &SharedDescriptionTarget,
     ) -> Self {
-        let network_widget = network_widget(tx);
-        let description_widget = description_widget(clipboard);
+        let network_widget = network_widget(tx, history, target);
+        let description_widget = description_widget(clipboard, target);
+        let graph_widget = network_graph_widget(tx, target);
+        let reachability_widget = network_reachability_widget(tx, target);
+        let reachability_result_widget = network_reachability_result_widget(clipboard);
 
         let layout = layout(split_mode);
 
@@ -39,6 +52,9 @@ impl NetworkTab {
                 [network_widget, description_widget],
                 layout,
             ),
+            graph_popup: graph_widget,
+            reachability_popup: reachability_widget,
+            reachability_result_popup: reachability_result_widget,
         }
     }
 }
@@ -1,5 +1,12 @@
 mod description;
+mod graph;
 mod network;
+mod reachability;
 
 pub(super) use description::*;
+pub(super) use graph::*;
 pub(super) use network::*;
+pub(super) use reachability::*;
+
+pub use graph::graph_items;
+pub use network::{request_description, DescriptionTarget, SharedDescriptionTarget};
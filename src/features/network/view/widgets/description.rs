@@ -8,11 +8,18 @@ use crate::{
     ui::widget::{config::WidgetConfig, Text, Widget, WidgetTrait as _},
 };
 
-pub fn description_widget(clipboard: &Option<Rc<RefCell<Clipboard>>>) -> Widget<'static> {
+use super::{open_network_graph_popup, open_network_reachability_popup, SharedDescriptionTarget};
+
+pub fn description_widget(
+    clipboard: &Option<Rc<RefCell<Clipboard>>>,
+    target: &SharedDescriptionTarget,
+) -> Widget<'static> {
     let builder = Text::builder()
         .id(NETWORK_DESCRIPTION_WIDGET_ID)
         .widget_config(&WidgetConfig::builder().title("Description").build())
-        .block_injection(block_injection());
+        .block_injection(block_injection())
+        .action('r', open_network_graph_popup(target.clone()))
+        .action('a', open_network_reachability_popup(target.clone()));
 
     if let Some(cb) = clipboard {
         builder.clipboard(cb.clone())
@@ -0,0 +1,279 @@
+use std::collections::BTreeMap;
+
+use crossbeam::channel::Sender;
+
+use crate::{
+    features::{
+        component_id::{NETWORK_DESCRIPTION_WIDGET_ID, NETWORK_GRAPH_POPUP_ID},
+        network::message::{RelatedResource, RelatedResourceGraph},
+    },
+    message::Message,
+    ui::{
+        event::EventResult,
+        widget::{config::WidgetConfig, List, LiteralItem, Widget},
+        Window,
+    },
+};
+
+use super::{request_description, DescriptionTarget, SharedDescriptionTarget};
+
+/// ネストの上限（循環参照の対策）
+const MAX_DEPTH: usize = 8;
+
+pub fn network_graph_widget(
+    tx: &Sender<Message>,
+    target: &SharedDescriptionTarget,
+) -> Widget<'static> {
+    List::builder()
+        .id(NETWORK_GRAPH_POPUP_ID)
+        .widget_config(&WidgetConfig::builder().title("Graph").build())
+        .on_select(on_select(tx.clone(), target.clone()))
+        .build()
+        .into()
+}
+
+/// Descriptionに表示しているリソースと関連リソースのグラフのポップアップを開く
+///
+/// グラフはDescriptionの取得と合わせて更新される
+pub fn open_network_graph_popup(
+    target: SharedDescriptionTarget,
+) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        if target.borrow().is_none() {
+            return EventResult::Ignore;
+        }
+
+        w.open_popup(NETWORK_GRAPH_POPUP_ID);
+
+        EventResult::Nop
+    }
+}
+
+fn on_select(
+    tx: Sender<Message>,
+    target: SharedDescriptionTarget,
+) -> impl Fn(&mut Window, &LiteralItem) -> EventResult {
+    move |w: &mut Window, v: &LiteralItem| {
+        let Some(metadata) = v.metadata.as_ref() else {
+            return EventResult::Ignore;
+        };
+
+        let (Some(kind), Some(name), Some(namespace), Some(version)) = (
+            metadata.get("kind"),
+            metadata.get("name"),
+            metadata.get("namespace"),
+            metadata.get("version"),
+        ) else {
+            return EventResult::Ignore;
+        };
+
+        w.close_popup();
+
+        request_description(
+            w,
+            &tx,
+            &target,
+            DescriptionTarget {
+                kind: kind.to_string(),
+                name: name.to_string(),
+                namespace: namespace.to_string(),
+                version: version.to_string(),
+            },
+        );
+
+        w.activate_widget_by_id(NETWORK_DESCRIPTION_WIDGET_ID);
+
+        EventResult::Nop
+    }
+}
+
+/// rootを頂点とした木を罫線で描画する
+///
+/// 関連元が見つからないリソースはrootの直下に置く
+pub fn graph_items(graph: &RelatedResourceGraph) -> Vec<LiteralItem> {
+    let RelatedResourceGraph {
+        root,
+        related_resources: nodes,
+    } = graph;
+
+    let has_parent = |node: &RelatedResource| {
+        node.parent.as_ref().is_some_and(|(kind, name, namespace)| {
+            nodes
+                .iter()
+                .any(|n| &n.kind == kind && &n.name == name && &n.namespace == namespace)
+        })
+    };
+
+    let children: Vec<&RelatedResource> = nodes.iter().filter(|node| !has_parent(node)).collect();
+
+    let mut items = vec![graph_item(String::new(), root, root)];
+
+    push_children(&mut items, root, nodes, &children, "", 0);
+
+    items
+}
+
+fn push_children(
+    items: &mut Vec<LiteralItem>,
+    root: &RelatedResource,
+    nodes: &[RelatedResource],
+    children: &[&RelatedResource],
+    indent: &str,
+    depth: usize,
+) {
+    if MAX_DEPTH <= depth {
+        return;
+    }
+
+    for (i, child) in children.iter().enumerate() {
+        let is_last = i + 1 == children.len();
+
+        let branch = if is_last { "└─ " } else { "├─ " };
+
+        items.push(graph_item(format!("{}{}", indent, branch), child, root));
+
+        let grandchildren: Vec<&RelatedResource> = nodes
+            .iter()
+            .filter(|node| {
+                node.parent.as_ref().is_some_and(|(kind, name, namespace)| {
+                    kind == &child.kind && name == &child.name && namespace == &child.namespace
+                })
+            })
+            .collect();
+
+        let indent = format!("{}{}", indent, if is_last { "   " } else { "│  " });
+
+        push_children(items, root, nodes, &grandchildren, &indent, depth + 1);
+    }
+}
+
+fn graph_item(prefix: String, node: &RelatedResource, root: &RelatedResource) -> LiteralItem {
+    let namespace = if node.namespace == root.namespace {
+        String::new()
+    } else {
+        format!(" ({})", node.namespace)
+    };
+
+    LiteralItem::new(
+        format!("{}{}/{}{}", prefix, node.kind, node.name, namespace),
+        Some(BTreeMap::from([
+            ("kind".to_string(), node.kind.clone()),
+            ("name".to_string(), node.name.clone()),
+            ("namespace".to_string(), node.namespace.clone()),
+            ("version".to_string(), node.version.clone()),
+        ])),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn node(kind: &str, name: &str, namespace: &str, version: &str) -> RelatedResource {
+        RelatedResource::new(kind, name, namespace, version)
+    }
+
+    fn lines(items: &[LiteralItem]) -> Vec<&str> {
+        items.iter().map(|item| item.item.as_str()).collect()
+    }
+
+    #[test]
+    fn 関連元のないリソースはrootの直下に並べる() {
+        let graph = RelatedResourceGraph {
+            root: node("Ingress", "ingress", "default", "v1"),
+            related_resources: vec![
+                node("Service", "service-1", "default", "v1"),
+                node("Service", "service-2", "default", "v1"),
+                node("Pod", "pod-1", "default", "v1"),
+            ],
+        };
+
+        assert_eq!(
+            lines(&graph_items(&graph)),
+            vec![
+                "Ingress/ingress",
+                "├─ Service/service-1",
+                "├─ Service/service-2",
+                "└─ Pod/pod-1",
+            ]
+        );
+    }
+
+    #[test]
+    fn 関連元を持つリソースはその下に並べる() {
+        let graph = RelatedResourceGraph {
+            root: node("Gateway", "gateway", "default", "v1beta1"),
+            related_resources: vec![
+                node("HTTPRoute", "route-1", "default", "v1beta1"),
+                node("HTTPRoute", "route-2", "app", "v1beta1"),
+                node("Service", "service-1", "default", "v1").parent(
+                    "HTTPRoute",
+                    "route-1",
+                    "default",
+                ),
+                node("Pod", "pod-1", "default", "v1").parent("Service", "service-1", "default"),
+                node("Pod", "pod-2", "default", "v1").parent("Service", "service-1", "default"),
+            ],
+        };
+
+        let items = graph_items(&graph);
+
+        assert_eq!(
+            lines(&items),
+            vec![
+                "Gateway/gateway",
+                "├─ HTTPRoute/route-1",
+                "│  └─ Service/service-1",
+                "│     ├─ Pod/pod-1",
+                "│     └─ Pod/pod-2",
+                "└─ HTTPRoute/route-2 (app)",
+            ]
+        );
+
+        let version = items[5]
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get("version"))
+            .cloned();
+
+        assert_eq!(version, Some("v1beta1".to_string()));
+    }
+
+    #[test]
+    fn 同じ名前の関連元が複数のnamespaceにある場合はnamespaceも一致するものの下に並べる() {
+        let graph = RelatedResourceGraph {
+            root: node("Gateway", "gateway", "default", "v1"),
+            related_resources: vec![
+                node("HTTPRoute", "route", "app", "v1"),
+                node("HTTPRoute", "route", "db", "v1"),
+                node("Service", "backend", "app", "v1").parent("HTTPRoute", "route", "app"),
+                node("Service", "backend", "db", "v1").parent("HTTPRoute", "route", "db"),
+                node("Pod", "backend-0", "db", "v1").parent("Service", "backend", "db"),
+            ],
+        };
+
+        assert_eq!(
+            lines(&graph_items(&graph)),
+            vec![
+                "Gateway/gateway",
+                "├─ HTTPRoute/route (app)",
+                "│  └─ Service/backend (app)",
+                "└─ HTTPRoute/route (db)",
+                "   └─ Service/backend (db)",
+                "      └─ Pod/backend-0 (db)",
+            ]
+        );
+    }
+
+    #[test]
+    fn 関連リソースがない場合はrootのみ() {
+        let graph = RelatedResourceGraph {
+            root: node("Pod", "pod", "default", "v1"),
+            related_resources: vec![],
+        };
+
+        assert_eq!(lines(&graph_items(&graph)), vec!["Pod/pod"]);
+    }
+}
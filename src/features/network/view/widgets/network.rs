@@ -1,3 +1,5 @@
+use std::{cell::RefCell, rc::Rc};
+
 use crossbeam::channel::Sender;
 use k8s_openapi::{
     api::{
@@ -9,10 +11,15 @@ use k8s_openapi::{
 
 use crate::{
     features::{
-        component_id::{NETWORK_DESCRIPTION_WIDGET_ID, NETWORK_WIDGET_ID},
+        api_resources::kube::ApiResource,
+        component_id::{NETWORK_DESCRIPTION_WIDGET_ID, NETWORK_GRAPH_POPUP_ID, NETWORK_WIDGET_ID},
         network::message::{NetworkRequest, NetworkRequestTargetParams},
     },
-    kube::apis::networking::gateway::v1::{Gateway, HTTPRoute},
+    history::HistoryHandle,
+    kube::apis::networking::gateway::{
+        v1::{GRPCRoute, Gateway, HTTPRoute},
+        v1alpha2::{TCPRoute, TLSRoute},
+    },
     message::Message,
     ui::{
         event::EventResult,
@@ -21,15 +28,27 @@ use crate::{
     },
 };
 
-pub fn network_widget(tx: &Sender<Message>) -> Widget<'static> {
+use super::{open_network_graph_popup, open_network_reachability_popup};
+
+pub fn network_widget(
+    tx: &Sender<Message>,
+    history: &HistoryHandle,
+    target: &SharedDescriptionTarget,
+) -> Widget<'static> {
     let tx = tx.clone();
 
     Table::builder()
         .id(NETWORK_WIDGET_ID)
         .widget_config(&WidgetConfig::builder().title("Network").build())
         .filtered_key("NAME")
+        .filter_history(
+            history.loader("network_filter"),
+            history.saver("network_filter"),
+        )
         .block_injection(block_injection())
-        .on_select(on_select(tx))
+        .on_select(on_select(tx, target.clone()))
+        .action('r', open_network_graph_popup(target.clone()))
+        .action('a', open_network_reachability_popup(target.clone()))
         .build()
         .into()
 }
@@ -51,10 +70,47 @@ fn block_injection() -> impl Fn(&Table) -> WidgetConfig {
     }
 }
 
-fn on_select(tx: Sender<Message>) -> impl Fn(&mut Window, &TableItem) -> EventResult {
-    move |w: &mut Window, v: &TableItem| {
-        w.widget_clear(NETWORK_DESCRIPTION_WIDGET_ID);
+/// Descriptionに表示しているリソース
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DescriptionTarget {
+    pub kind: String,
+    pub name: String,
+    pub namespace: String,
+    pub version: String,
+}
+
+pub type SharedDescriptionTarget = Rc<RefCell<Option<DescriptionTarget>>>;
+
+impl DescriptionTarget {
+    /// Networkタブで説明を表示できるリソースの場合に作成する
+    pub fn from_api_resource(kind: &ApiResource, namespace: &str, name: &str) -> Option<Self> {
+        let kind_name = match (kind.group(), kind.name(), kind.version()) {
+            ("", "pods", _) => Pod::KIND,
+            ("", "services", _) => Service::KIND,
+            ("networking.k8s.io", "ingresses", _) => Ingress::KIND,
+            ("networking.k8s.io", "networkpolicies", _) => NetworkPolicy::KIND,
+            ("gateway.networking.k8s.io", "gateways", "v1" | "v1beta1") => Gateway::KIND,
+            ("gateway.networking.k8s.io", "httproutes", "v1" | "v1beta1") => HTTPRoute::KIND,
+            ("gateway.networking.k8s.io", "grpcroutes", "v1" | "v1alpha2") => GRPCRoute::KIND,
+            ("gateway.networking.k8s.io", "tlsroutes", "v1alpha2") => TLSRoute::KIND,
+            ("gateway.networking.k8s.io", "tcproutes", "v1alpha2") => TCPRoute::KIND,
+            _ => return None,
+        };
+
+        Some(Self {
+            kind: kind_name.to_string(),
+            name: name.to_string(),
+            namespace: namespace.to_string(),
+            version: kind.version().to_string(),
+        })
+    }
+}
 
+fn on_select(
+    tx: Sender<Message>,
+    target: SharedDescriptionTarget,
+) -> impl Fn(&mut Window, &TableItem) -> EventResult {
+    move |w: &mut Window, v: &TableItem| {
         let Some(metadata) = v.metadata.as_ref() else {
             return EventResult::Ignore;
         };
@@ -75,46 +131,59 @@ fn on_select(tx: Sender<Message>) -> impl Fn(&mut Window, &TableItem) -> EventRe
             return EventResult::Ignore;
         };
 
-        *(w.find_widget_mut(NETWORK_DESCRIPTION_WIDGET_ID)
-            .widget_config_mut()
-            .append_title_mut()) = Some((format!(" : {}", name)).into());
+        request_description(
+            w,
+            &tx,
+            &target,
+            DescriptionTarget {
+                kind: kind.to_string(),
+                name: name.to_string(),
+                namespace: namespace.to_string(),
+                version: version.to_string(),
+            },
+        );
 
-        let request_data = NetworkRequestTargetParams {
-            namespace: namespace.to_string(),
-            name: name.to_string(),
-            version: version.to_string(),
-        };
+        EventResult::WindowAction(WindowAction::Continue)
+    }
+}
 
-        match kind.as_str() {
-            Pod::KIND => {
-                tx.send(NetworkRequest::Pod(request_data).into())
-                    .expect("Failed to send NetworkRequest::Pod");
-            }
-            Service::KIND => {
-                tx.send(NetworkRequest::Service(request_data).into())
-                    .expect("Failed to send NetworkRequest::Service");
-            }
-            Ingress::KIND => {
-                tx.send(NetworkRequest::Ingress(request_data).into())
-                    .expect("Failed to send NetworkRequest::Ingress");
-            }
-            NetworkPolicy::KIND => {
-                tx.send(NetworkRequest::NetworkPolicy(request_data).into())
-                    .expect("Failed to send NetworkRequest::NetworkPolicy");
-            }
-            Gateway::KIND => {
-                tx.send(NetworkRequest::Gateway(request_data).into())
-                    .expect("Failed to send NetworkRequest::Gateway");
-            }
-            HTTPRoute::KIND => {
-                tx.send(NetworkRequest::HTTPRoute(request_data).into())
-                    .expect("Failed to send NetworkRequest::HTTPRoute");
-            }
-            _ => {
-                unreachable!()
-            }
+/// リソースの説明の取得を要求し、Descriptionに表示するリソースを切り替える
+pub fn request_description(
+    w: &mut Window,
+    tx: &Sender<Message>,
+    shared_target: &SharedDescriptionTarget,
+    target: DescriptionTarget,
+) {
+    w.widget_clear(NETWORK_DESCRIPTION_WIDGET_ID);
+    w.widget_clear(NETWORK_GRAPH_POPUP_ID);
+
+    *(w.find_widget_mut(NETWORK_DESCRIPTION_WIDGET_ID)
+        .widget_config_mut()
+        .append_title_mut()) = Some((format!(" : {}", target.name)).into());
+
+    let request_data = NetworkRequestTargetParams {
+        namespace: target.namespace.clone(),
+        name: target.name.clone(),
+        version: target.version.clone(),
+    };
+
+    let request = match target.kind.as_str() {
+        Pod::KIND => NetworkRequest::Pod(request_data),
+        Service::KIND => NetworkRequest::Service(request_data),
+        Ingress::KIND => NetworkRequest::Ingress(request_data),
+        NetworkPolicy::KIND => NetworkRequest::NetworkPolicy(request_data),
+        Gateway::KIND => NetworkRequest::Gateway(request_data),
+        HTTPRoute::KIND => NetworkRequest::HTTPRoute(request_data),
+        GRPCRoute::KIND => NetworkRequest::GRPCRoute(request_data),
+        TLSRoute::KIND => NetworkRequest::TLSRoute(request_data),
+        TCPRoute::KIND => NetworkRequest::TCPRoute(request_data),
+        _ => {
+            unreachable!()
         }
+    };
 
-        EventResult::WindowAction(WindowAction::Continue)
-    }
+    tx.send(request.into())
+        .expect("Failed to send NetworkRequest");
+
+    *shared_target.borrow_mut() = Some(target);
 }
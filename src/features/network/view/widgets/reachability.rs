@@ -0,0 +1,120 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crossbeam::channel::Sender;
+use k8s_openapi::{api::core::v1::Pod, Resource as _};
+use ratatui::crossterm::event::KeyCode;
+
+use crate::{
+    clipboard::Clipboard,
+    features::{
+        component_id::{NETWORK_REACHABILITY_POPUP_ID, NETWORK_REACHABILITY_RESULT_POPUP_ID},
+        network::message::ReachabilityRequest,
+    },
+    message::{Message, UserEvent},
+    ui::{
+        event::EventResult,
+        widget::{
+            config::WidgetConfig, input::InputFormBuilder, Item, LiteralItem, SelectedItem, Text,
+            Widget, WidgetTrait as _,
+        },
+        Window,
+    },
+};
+
+use super::SharedDescriptionTarget;
+
+/// 通信可否を確認するPodとポートを入力するポップアップ
+pub fn network_reachability_widget(
+    tx: &Sender<Message>,
+    target: &SharedDescriptionTarget,
+) -> Widget<'static> {
+    InputFormBuilder::default()
+        .id(NETWORK_REACHABILITY_POPUP_ID)
+        .widget_config(
+            WidgetConfig::builder()
+                .title("Reachability: <source> -> <destination>:<port>[/<protocol>]")
+                .build(),
+        )
+        .actions(
+            UserEvent::from(KeyCode::Enter),
+            check_reachability(tx.clone(), target.clone()),
+        )
+        .build()
+        .into()
+}
+
+pub fn network_reachability_result_widget(
+    clipboard: &Option<Rc<RefCell<Clipboard>>>,
+) -> Widget<'static> {
+    let builder = Text::builder()
+        .id(NETWORK_REACHABILITY_RESULT_POPUP_ID)
+        .widget_config(&WidgetConfig::builder().title("Reachability").build())
+        .wrap();
+
+    if let Some(cb) = clipboard {
+        builder.clipboard(cb.clone())
+    } else {
+        builder
+    }
+    .build()
+    .into()
+}
+
+/// Descriptionに表示しているリソースがPodの場合は送信元として入力した状態で開く
+pub fn open_network_reachability_popup(
+    target: SharedDescriptionTarget,
+) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let source = target
+            .borrow()
+            .as_ref()
+            .filter(|target| target.kind == Pod::KIND)
+            .map(|target| format!("{}/{} -> ", target.namespace, target.name))
+            .unwrap_or_default();
+
+        w.find_widget_mut(NETWORK_REACHABILITY_POPUP_ID)
+            .update_widget_item(Item::Single(LiteralItem::new(source, None)));
+
+        w.open_popup(NETWORK_REACHABILITY_POPUP_ID);
+
+        EventResult::Nop
+    }
+}
+
+/// namespaceを省略したPodはDescriptionに表示しているリソースのnamespaceとして扱う
+fn check_reachability(
+    tx: Sender<Message>,
+    target: SharedDescriptionTarget,
+) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let Some(SelectedItem::Literal { item: query, .. }) =
+            w.find_widget(NETWORK_REACHABILITY_POPUP_ID).widget_item()
+        else {
+            return EventResult::Ignore;
+        };
+
+        if query.trim().is_empty() {
+            return EventResult::Nop;
+        }
+
+        let namespace = target
+            .borrow()
+            .as_ref()
+            .map(|target| target.namespace.clone())
+            .unwrap_or_else(|| "default".to_string());
+
+        w.close_popup();
+
+        *(w.find_widget_mut(NETWORK_REACHABILITY_RESULT_POPUP_ID)
+            .widget_config_mut()
+            .append_title_mut()) = Some(format!(" : {}", query.trim()).into());
+
+        w.widget_clear(NETWORK_REACHABILITY_RESULT_POPUP_ID);
+        w.open_popup(NETWORK_REACHABILITY_RESULT_POPUP_ID);
+
+        tx.send(ReachabilityRequest { query, namespace }.into())
+            .expect("Failed to send ReachabilityRequest");
+
+        EventResult::Nop
+    }
+}
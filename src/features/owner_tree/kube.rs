@@ -0,0 +1,259 @@
+mod tree;
+
+use anyhow::{anyhow, Context as _, Result};
+use crossbeam::channel::Sender;
+use futures::{stream, StreamExt as _};
+
+use crate::{
+    features::{
+        api_resources::kube::{ApiResource, ResourceTarget, SharedApiResources},
+        owner_tree::message::OwnerTreeResponse,
+    },
+    kube::{apis::v1_table::Table, KubeClientRequest},
+    logger,
+    message::Message,
+    workers::kube::AbortWorker,
+};
+
+use tree::{build_tree, OwnedObject};
+
+pub use tree::OwnerTreeLine;
+
+/// 同時に一覧を取得するリソースの種類の上限
+const MAX_CONCURRENT_REQUESTS: usize = 20;
+
+/// rootが所有しているリソースを取得する
+///
+/// API resourcesのロックは対象のリソースの種類を複製した時点で解放する
+#[derive(Clone)]
+pub struct OwnerTreeWorker<C>
+where
+    C: KubeClientRequest,
+{
+    tx: Sender<Message>,
+    client: C,
+    shared_api_resources: SharedApiResources,
+    root: ResourceTarget,
+}
+
+impl<C: KubeClientRequest> OwnerTreeWorker<C> {
+    pub fn new(
+        tx: Sender<Message>,
+        client: C,
+        shared_api_resources: SharedApiResources,
+        root: ResourceTarget,
+    ) -> Self {
+        Self {
+            tx,
+            client,
+            shared_api_resources,
+            root,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<C: KubeClientRequest> AbortWorker for OwnerTreeWorker<C> {
+    async fn run(&self) {
+        let kinds: Vec<ApiResource> = self
+            .shared_api_resources
+            .read()
+            .await
+            .namespaced_kinds()
+            .cloned()
+            .collect();
+
+        let ret = fetch_owner_tree(&self.client, &kinds, &self.root).await;
+
+        self.tx
+            .send(OwnerTreeResponse(ret).into())
+            .expect("Failed to send OwnerTreeResponse");
+    }
+}
+
+/// kubectl treeのようにrootが所有しているリソースを再帰的に辿る
+///
+/// `kinds`のリソースの一覧から所有者を辿り、一覧を取得できないリソースは無視する
+async fn fetch_owner_tree<C: KubeClientRequest>(
+    client: &C,
+    kinds: &[ApiResource],
+    root: &ResourceTarget,
+) -> Result<Vec<OwnerTreeLine>> {
+    let root_url = if root.namespace.is_empty() {
+        format!("{}/{}", root.kind.api_url(), root.name)
+    } else {
+        format!(
+            "{}/{}",
+            root.kind.api_url_with_namespace(&root.namespace),
+            root.name
+        )
+    };
+
+    let table: Table = client
+        .table_request(&root_url)
+        .await
+        .with_context(|| format!("Failed to fetch {} {}", root.kind, root.name))?;
+
+    let root_object = OwnedObject::from_table(&root.kind, &table)
+        .into_iter()
+        .next()
+        .ok_or_else(|| anyhow!("{} {} not found", root.kind, root.name))?;
+
+    let futures: Vec<_> = kinds
+        .iter()
+        .map(|kind| async move {
+            // クラスタースコープのリソースは全namespaceのリソースを所有できる
+            let url = if root.namespace.is_empty() {
+                kind.api_url()
+            } else {
+                kind.api_url_with_namespace(&root.namespace)
+            };
+
+            match client.table_request::<Table>(&url).await {
+                Ok(table) => OwnedObject::from_table(kind, &table),
+                Err(err) => {
+                    logger!(error, "Failed to list {} for owner tree: {}", kind, err);
+                    Vec::new()
+                }
+            }
+        })
+        .collect();
+
+    let objects: Vec<Vec<OwnedObject>> = stream::iter(futures)
+        .buffer_unordered(MAX_CONCURRENT_REQUESTS)
+        .collect()
+        .await;
+
+    let objects: Vec<OwnedObject> = objects.into_iter().flatten().collect();
+
+    Ok(build_tree(&root_object, &objects))
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::bail;
+    use kube::discovery::Scope;
+    use mockall::predicate::eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use crate::{
+        features::api_resources::kube::ApiResources, kube::mock::MockTestKubeClient, mock_expect,
+    };
+
+    use super::*;
+
+    fn table(objects: &[(&str, &str, &[&str])]) -> Table {
+        serde_json::from_value(json!({
+            "columnDefinitions": [
+                {"name": "Name", "type": "string", "format": "name", "description": "", "priority": 0}
+            ],
+            "rows": objects.iter().map(|(name, uid, owners)| json!({
+                "cells": [name],
+                "object": {
+                    "metadata": {
+                        "name": name,
+                        "namespace": "default",
+                        "uid": uid,
+                        "ownerReferences": owners.iter().map(|uid| json!({"uid": uid})).collect::<Vec<_>>()
+                    }
+                }
+            })).collect::<Vec<_>>()
+        }))
+        .unwrap()
+    }
+
+    fn api_resources() -> ApiResources {
+        ApiResources::from(vec![
+            ApiResource::Api {
+                name: "pods".into(),
+                version: "v1".into(),
+                scope: Scope::Namespaced,
+            },
+            ApiResource::Api {
+                name: "events".into(),
+                version: "v1".into(),
+                scope: Scope::Namespaced,
+            },
+            ApiResource::Api {
+                name: "nodes".into(),
+                version: "v1".into(),
+                scope: Scope::Cluster,
+            },
+            ApiResource::Apis {
+                name: "replicasets".into(),
+                group: "apps".into(),
+                version: "v1".into(),
+                preferred_version: true,
+                scope: Scope::Namespaced,
+            },
+            ApiResource::Apis {
+                name: "foos".into(),
+                group: "example.com".into(),
+                version: "v1".into(),
+                preferred_version: true,
+                scope: Scope::Namespaced,
+            },
+        ])
+    }
+
+    #[tokio::test]
+    async fn namespace内のリソースから所有しているリソースを辿る() {
+        let mut client = MockTestKubeClient::new();
+
+        mock_expect!(
+            client,
+            table_request,
+            [
+                (
+                    Table,
+                    eq("apis/apps/v1/namespaces/default/deployments/app"),
+                    Ok(table(&[("app", "deploy-uid", &[])]))
+                ),
+                (
+                    Table,
+                    eq("api/v1/namespaces/default/pods"),
+                    Ok(table(&[("app-1", "pod-uid", &["rs-uid"])]))
+                ),
+                (
+                    Table,
+                    eq("apis/apps/v1/namespaces/default/replicasets"),
+                    Ok(table(&[("app-rs", "rs-uid", &["deploy-uid"])]))
+                ),
+                (
+                    Table,
+                    eq("apis/example.com/v1/namespaces/default/foos"),
+                    bail!("forbidden")
+                )
+            ]
+        );
+
+        let root = ResourceTarget {
+            kind: ApiResource::Apis {
+                name: "deployments".into(),
+                group: "apps".into(),
+                version: "v1".into(),
+                preferred_version: true,
+                scope: Scope::Namespaced,
+            },
+            namespace: "default".into(),
+            name: "app".into(),
+        };
+
+        let kinds: Vec<ApiResource> = api_resources().namespaced_kinds().cloned().collect();
+
+        let actual = fetch_owner_tree(&client, &kinds, &root).await.unwrap();
+
+        assert_eq!(
+            actual
+                .iter()
+                .map(|line| line.content.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                "deployments.apps/app",
+                "└─ replicasets.apps/app-rs",
+                "   └─ pods/app-1",
+            ]
+        );
+    }
+}
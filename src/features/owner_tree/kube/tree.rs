@@ -0,0 +1,301 @@
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+
+use crate::{
+    features::api_resources::kube::{ApiResource, ResourceTarget},
+    kube::apis::v1_table::{Table, TableRow},
+};
+
+/// 状態の要約に使うテーブルのカラム
+const STATUS_COLUMNS: [&str; 5] = ["ready", "status", "phase", "available", "reason"];
+
+/// ネストの上限（循環参照の対策）
+const MAX_DEPTH: usize = 16;
+
+/// ownerReferencesで辿るリソース
+#[derive(Debug, Clone, PartialEq)]
+pub struct OwnedObject {
+    pub target: ResourceTarget,
+    pub uid: String,
+    /// 所有者のuid
+    pub owners: Vec<String>,
+    pub status: String,
+}
+
+impl OwnedObject {
+    /// サーバーサイドのテーブルの行から作成する
+    ///
+    /// 状態はReadyやStatusなどのカラムを`Ready=1/1 Status=Running`の形式でまとめる
+    pub fn from_table(kind: &ApiResource, table: &Table) -> Vec<Self> {
+        let status_indexes: Vec<(usize, &str)> = table
+            .column_definitions
+            .iter()
+            .enumerate()
+            .filter(|(_, def)| STATUS_COLUMNS.contains(&def.name.to_lowercase().as_str()))
+            .map(|(i, def)| (i, def.name.as_str()))
+            .collect();
+
+        table
+            .rows
+            .iter()
+            .filter_map(|row| Self::from_row(kind, row, &status_indexes))
+            .collect()
+    }
+
+    fn from_row(
+        kind: &ApiResource,
+        row: &TableRow,
+        status_indexes: &[(usize, &str)],
+    ) -> Option<Self> {
+        let target = ResourceTarget::from_row(kind, row)?;
+
+        let metadata = row.object.as_ref()?.0.get("metadata")?;
+
+        let uid = metadata.get("uid")?.as_str()?.to_string();
+
+        let owners = metadata
+            .get("ownerReferences")
+            .and_then(JsonValue::as_array)
+            .map(|owners| {
+                owners
+                    .iter()
+                    .filter_map(|owner| owner.get("uid")?.as_str().map(ToString::to_string))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let status = status_indexes
+            .iter()
+            .filter_map(|(i, name)| {
+                let cell = row.cells.get(*i)?.to_string();
+
+                (!cell.is_empty() && cell != "<none>").then(|| format!("{}={}", name, cell))
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        Some(Self {
+            target,
+            uid,
+            owners,
+            status,
+        })
+    }
+}
+
+/// 木の1行
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OwnerTreeLine {
+    pub content: String,
+    pub target: ResourceTarget,
+}
+
+/// rootを頂点として所有しているリソースを罫線で描画する
+pub fn build_tree(root: &OwnedObject, objects: &[OwnedObject]) -> Vec<OwnerTreeLine> {
+    let mut lines = vec![tree_line(String::new(), root, root)];
+
+    let mut visited = BTreeSet::from([root.uid.as_str()]);
+
+    push_children(&mut lines, root, root, objects, &mut visited, "", 0);
+
+    lines
+}
+
+fn push_children<'a>(
+    lines: &mut Vec<OwnerTreeLine>,
+    root: &OwnedObject,
+    parent: &OwnedObject,
+    objects: &'a [OwnedObject],
+    visited: &mut BTreeSet<&'a str>,
+    indent: &str,
+    depth: usize,
+) {
+    if MAX_DEPTH <= depth {
+        return;
+    }
+
+    let mut children: Vec<&OwnedObject> = objects
+        .iter()
+        .filter(|object| object.owners.contains(&parent.uid))
+        .filter(|object| !visited.contains(object.uid.as_str()))
+        .collect();
+
+    children.sort_by(|a, b| {
+        (a.target.kind.kind_name(), &a.target.name)
+            .cmp(&(b.target.kind.kind_name(), &b.target.name))
+    });
+
+    visited.extend(children.iter().map(|child| child.uid.as_str()));
+
+    for (i, child) in children.iter().enumerate() {
+        let is_last = i + 1 == children.len();
+
+        let branch = if is_last { "└─ " } else { "├─ " };
+
+        lines.push(tree_line(format!("{}{}", indent, branch), child, root));
+
+        let indent = format!("{}{}", indent, if is_last { "   " } else { "│  " });
+
+        push_children(lines, root, child, objects, visited, &indent, depth + 1);
+    }
+}
+
+fn tree_line(prefix: String, object: &OwnedObject, root: &OwnedObject) -> OwnerTreeLine {
+    let target = &object.target;
+
+    let namespace = if target.namespace.is_empty() || target.namespace == root.target.namespace {
+        String::new()
+    } else {
+        format!(" ({})", target.namespace)
+    };
+
+    let status = if object.status.is_empty() {
+        String::new()
+    } else {
+        format!("  {}", object.status)
+    };
+
+    OwnerTreeLine {
+        content: format!(
+            "{}{}/{}{}{}",
+            prefix,
+            target.kind.kind_name(),
+            target.name,
+            namespace,
+            status
+        ),
+        target: target.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use kube::discovery::Scope;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    fn kind(name: &str, group: &str) -> ApiResource {
+        if group.is_empty() {
+            ApiResource::Api {
+                name: name.to_string(),
+                version: "v1".to_string(),
+                scope: Scope::Namespaced,
+            }
+        } else {
+            ApiResource::Apis {
+                name: name.to_string(),
+                group: group.to_string(),
+                version: "v1".to_string(),
+                preferred_version: true,
+                scope: Scope::Namespaced,
+            }
+        }
+    }
+
+    fn object(kind: ApiResource, name: &str, uid: &str, owners: &[&str]) -> OwnedObject {
+        OwnedObject {
+            target: ResourceTarget {
+                kind,
+                namespace: "default".to_string(),
+                name: name.to_string(),
+            },
+            uid: uid.to_string(),
+            owners: owners.iter().map(ToString::to_string).collect(),
+            status: String::new(),
+        }
+    }
+
+    fn contents(lines: &[OwnerTreeLine]) -> Vec<&str> {
+        lines.iter().map(|line| line.content.as_str()).collect()
+    }
+
+    #[test]
+    fn テーブルの行から所有者と状態を取り出す() {
+        let table: Table = serde_json::from_value(json!({
+            "columnDefinitions": [
+                {"name": "Name", "type": "string", "format": "name", "description": "", "priority": 0},
+                {"name": "Ready", "type": "string", "format": "", "description": "", "priority": 0},
+                {"name": "Status", "type": "string", "format": "", "description": "", "priority": 0},
+                {"name": "Age", "type": "string", "format": "", "description": "", "priority": 0}
+            ],
+            "rows": [
+                {
+                    "cells": ["app-1", "1/1", "Running", "1d"],
+                    "object": {
+                        "metadata": {
+                            "name": "app-1",
+                            "namespace": "default",
+                            "uid": "pod-uid",
+                            "ownerReferences": [{"uid": "rs-uid"}]
+                        }
+                    }
+                }
+            ]
+        }))
+        .unwrap();
+
+        let actual = OwnedObject::from_table(&kind("pods", ""), &table);
+
+        assert_eq!(
+            actual,
+            vec![OwnedObject {
+                status: "Ready=1/1 Status=Running".to_string(),
+                ..object(kind("pods", ""), "app-1", "pod-uid", &["rs-uid"])
+            }]
+        );
+    }
+
+    #[test]
+    fn 所有しているリソースを再帰的に辿る() {
+        let root = object(kind("deployments", "apps"), "app", "deploy-uid", &[]);
+
+        let objects = vec![
+            object(kind("pods", ""), "app-2", "pod-2", &["rs-new"]),
+            object(kind("pods", ""), "app-1", "pod-1", &["rs-new"]),
+            object(
+                kind("replicasets", "apps"),
+                "app-new",
+                "rs-new",
+                &["deploy-uid"],
+            ),
+            object(
+                kind("replicasets", "apps"),
+                "app-old",
+                "rs-old",
+                &["deploy-uid"],
+            ),
+            object(kind("pods", ""), "other", "pod-other", &["rs-other"]),
+        ];
+
+        let actual = build_tree(&root, &objects);
+
+        assert_eq!(
+            contents(&actual),
+            vec![
+                "deployments.apps/app",
+                "├─ replicasets.apps/app-new",
+                "│  ├─ pods/app-1",
+                "│  └─ pods/app-2",
+                "└─ replicasets.apps/app-old",
+            ]
+        );
+
+        assert_eq!(actual[2].target.name, "app-1");
+    }
+
+    #[test]
+    fn 循環している所有関係は一度だけ表示する() {
+        let root = object(kind("foos", "example.com"), "a", "a", &["b"]);
+
+        let objects = vec![object(kind("foos", "example.com"), "b", "b", &["a"])];
+
+        assert_eq!(
+            contents(&build_tree(&root, &objects)),
+            vec!["foos.example.com/a", "└─ foos.example.com/b"]
+        );
+    }
+}
@@ -0,0 +1,47 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    features::api_resources::kube::ResourceTarget,
+    message::Message,
+    workers::{kube::message::Kube, replay::serde_result},
+};
+
+use super::kube::OwnerTreeLine;
+
+#[derive(Debug)]
+pub struct OwnerTreeRequest(pub ResourceTarget);
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OwnerTreeResponse(#[serde(with = "serde_result")] pub Result<Vec<OwnerTreeLine>>);
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum OwnerTreeMessage {
+    #[serde(skip)]
+    Request(OwnerTreeRequest),
+    Response(OwnerTreeResponse),
+}
+
+impl From<OwnerTreeRequest> for Message {
+    fn from(req: OwnerTreeRequest) -> Self {
+        Message::Kube(Kube::OwnerTree(OwnerTreeMessage::Request(req)))
+    }
+}
+
+impl From<OwnerTreeResponse> for Message {
+    fn from(res: OwnerTreeResponse) -> Self {
+        Message::Kube(Kube::OwnerTree(OwnerTreeMessage::Response(res)))
+    }
+}
+
+impl From<OwnerTreeMessage> for Kube {
+    fn from(m: OwnerTreeMessage) -> Self {
+        Self::OwnerTree(m)
+    }
+}
+
+impl From<OwnerTreeMessage> for Message {
+    fn from(m: OwnerTreeMessage) -> Self {
+        Self::Kube(m.into())
+    }
+}
@@ -0,0 +1,82 @@
+use crossbeam::channel::Sender;
+
+use crate::{
+    features::{
+        api_resources::kube::ResourceTarget,
+        component_id::OWNER_TREE_POPUP_ID,
+        owner_tree::message::OwnerTreeRequest,
+        yaml::{
+            message::YamlTarget,
+            view::{show_yaml, SharedYamlTarget},
+        },
+    },
+    message::Message,
+    ui::{
+        event::EventResult,
+        widget::{config::WidgetConfig, List, LiteralItem, Widget, WidgetTrait as _},
+        Window,
+    },
+};
+
+pub struct OwnerTreePopup {
+    pub popup: Widget<'static>,
+}
+
+impl OwnerTreePopup {
+    pub fn new(tx: &Sender<Message>, yaml_target: &SharedYamlTarget) -> Self {
+        let popup = List::builder()
+            .id(OWNER_TREE_POPUP_ID)
+            .widget_config(&WidgetConfig::builder().title("Owner Tree").build())
+            .on_select(on_select(tx.clone(), yaml_target.clone()))
+            .build()
+            .into();
+
+        Self { popup }
+    }
+}
+
+/// rootが所有しているリソースの木を取得してポップアップを開く
+pub fn open_owner_tree_popup(w: &mut Window, tx: &Sender<Message>, root: ResourceTarget) {
+    w.widget_clear(OWNER_TREE_POPUP_ID);
+
+    let widget = w.find_widget_mut(OWNER_TREE_POPUP_ID);
+
+    *widget.widget_config_mut().append_title_mut() =
+        Some(format!(" [{} {}]", root.kind, root.name).into());
+
+    w.open_popup(OWNER_TREE_POPUP_ID);
+
+    tx.send(OwnerTreeRequest(root).into())
+        .expect("Failed to send OwnerTreeRequest");
+}
+
+fn on_select(
+    tx: Sender<Message>,
+    yaml_target: SharedYamlTarget,
+) -> impl Fn(&mut Window, &LiteralItem) -> EventResult {
+    move |w: &mut Window, v: &LiteralItem| {
+        let Some(ResourceTarget {
+            kind,
+            namespace,
+            name,
+        }) = v.metadata.as_ref().and_then(ResourceTarget::from_metadata)
+        else {
+            return EventResult::Ignore;
+        };
+
+        w.close_popup();
+
+        show_yaml(
+            w,
+            &tx,
+            &yaml_target,
+            YamlTarget {
+                kind,
+                name,
+                namespace,
+            },
+        );
+
+        EventResult::Nop
+    }
+}
@@ -5,6 +5,8 @@ use std::borrow::Cow;
 use anyhow::{bail, Result};
 use regex::Regex;
 
+use super::log::LogLevel;
+
 use self::parser::parse_attributes;
 
 #[derive(Debug, thiserror::Error)]
@@ -25,6 +27,7 @@ pub struct Filter {
     pub label_selector: Option<LabelSelector>,
     pub include_log: Option<Vec<Regex>>,
     pub exclude_log: Option<Vec<Regex>>,
+    pub log_level: Option<LogLevelFilter>,
 }
 
 impl Filter {
@@ -137,6 +140,10 @@ impl Filter {
                         filter.exclude_log = Some(vec![regex]);
                     }
                 }
+
+                FilterAttribute::LogLevel(log_level) => {
+                    filter.log_level = Some(log_level);
+                }
             }
         }
 
@@ -205,10 +212,56 @@ impl std::fmt::Display for Filter {
             }
         }
 
+        if let Some(log_level) = &self.log_level {
+            buf.push(format!("level:{}{}", log_level.operator, log_level.level));
+        }
+
         write!(f, "{}", buf.join(" "))
     }
 }
 
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum LogLevelOperator {
+    Eq,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+}
+
+impl std::fmt::Display for LogLevelOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            LogLevelOperator::Eq => "=",
+            LogLevelOperator::Gt => ">",
+            LogLevelOperator::Ge => ">=",
+            LogLevelOperator::Lt => "<",
+            LogLevelOperator::Le => "<=",
+        };
+
+        write!(f, "{}", s)
+    }
+}
+
+/// ログレベルによる絞り込み条件（level:>=warn など）
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct LogLevelFilter {
+    pub operator: LogLevelOperator,
+    pub level: LogLevel,
+}
+
+impl LogLevelFilter {
+    pub fn is_match(&self, level: LogLevel) -> bool {
+        match self.operator {
+            LogLevelOperator::Eq => level == self.level,
+            LogLevelOperator::Gt => level > self.level,
+            LogLevelOperator::Ge => level >= self.level,
+            LogLevelOperator::Lt => level < self.level,
+            LogLevelOperator::Le => level <= self.level,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum LabelSelector {
     Resource(RetrievableResource),
@@ -281,6 +334,7 @@ pub enum FilterAttribute<'a> {
     FieldSelector(Cow<'a, str>),
     IncludeLog(Cow<'a, str>),
     ExcludeLog(Cow<'a, str>),
+    LogLevel(LogLevelFilter),
 }
 
 struct FilterAttributes;
@@ -305,3 +359,45 @@ impl<'a> From<SpecifiedResource<'a>> for FilterAttribute<'a> {
         Self::Resource(value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(LogLevelOperator::Eq, LogLevel::Info, false, true, false)]
+    #[case(LogLevelOperator::Gt, LogLevel::Info, false, false, true)]
+    #[case(LogLevelOperator::Ge, LogLevel::Info, false, true, true)]
+    #[case(LogLevelOperator::Lt, LogLevel::Info, true, false, false)]
+    #[case(LogLevelOperator::Le, LogLevel::Info, true, true, false)]
+    fn ログレベルの条件に一致するか判定する(
+        #[case] operator: LogLevelOperator,
+        #[case] level: LogLevel,
+        #[case] debug: bool,
+        #[case] info: bool,
+        #[case] error: bool,
+    ) {
+        let filter = LogLevelFilter { operator, level };
+
+        assert_eq!(filter.is_match(LogLevel::Debug), debug);
+        assert_eq!(filter.is_match(LogLevel::Info), info);
+        assert_eq!(filter.is_match(LogLevel::Error), error);
+    }
+
+    #[test]
+    fn ログレベルの条件をパースする() {
+        let filter = Filter::parse("pod:app level:>=warn").unwrap();
+
+        assert_eq!(
+            filter.log_level,
+            Some(LogLevelFilter {
+                operator: LogLevelOperator::Ge,
+                level: LogLevel::Warn,
+            })
+        );
+        assert_eq!(filter.to_string(), "pod=app level:>=warn");
+    }
+}
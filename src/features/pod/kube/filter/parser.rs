@@ -3,15 +3,15 @@ use std::borrow::Cow;
 use nom::{
     branch::alt,
     bytes::complete::{is_not, tag},
-    character::complete::{alphanumeric1, anychar, char, multispace0, multispace1},
-    combinator::{all_consuming, map, recognize, value, verify},
+    character::complete::{alpha1, alphanumeric1, anychar, char, multispace0, multispace1},
+    combinator::{all_consuming, map, map_opt, recognize, success, value, verify},
     error::{ContextError, ParseError},
     multi::{fold_many0, many1_count, separated_list1},
-    sequence::{delimited, preceded, separated_pair},
+    sequence::{delimited, pair, preceded, separated_pair},
     IResult,
 };
 
-use super::{FilterAttribute, SpecifiedResource};
+use super::{FilterAttribute, LogLevel, LogLevelFilter, LogLevelOperator, SpecifiedResource};
 
 /// 空白文字を含まない文字列をパースする
 fn non_space<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
@@ -179,6 +179,36 @@ fn exclude_log<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
     Ok((remaining, FilterAttribute::ExcludeLog(value)))
 }
 
+fn log_level_operator<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
+    s: &'a str,
+) -> IResult<&'a str, LogLevelOperator, E> {
+    alt((
+        value(LogLevelOperator::Ge, tag(">=")),
+        value(LogLevelOperator::Gt, tag(">")),
+        value(LogLevelOperator::Le, tag("<=")),
+        value(LogLevelOperator::Lt, tag("<")),
+        value(LogLevelOperator::Eq, tag("=")),
+        success(LogLevelOperator::Eq),
+    ))(s)
+}
+
+fn log_level<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
+    s: &'a str,
+) -> IResult<&'a str, FilterAttribute<'a>, E> {
+    let (remaining, (_, (operator, level))) = separated_pair(
+        alt((tag("level"), tag("lv"))),
+        char(':'),
+        pair(
+            log_level_operator,
+            map_opt(alpha1, |s: &str| s.parse::<LogLevel>().ok()),
+        ),
+    )(s)?;
+    Ok((
+        remaining,
+        FilterAttribute::LogLevel(LogLevelFilter { operator, level }),
+    ))
+}
+
 fn label_selector<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
     s: &'a str,
 ) -> IResult<&'a str, FilterAttribute, E> {
@@ -309,6 +339,7 @@ fn attribute<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
         exclude_container,
         include_log,
         exclude_log,
+        log_level,
     ))(s)?;
 
     Ok((remaining, value))
@@ -417,6 +448,34 @@ mod tests {
         assert_eq!(remaining, "");
     }
 
+    /// Log level
+    #[rstest]
+    #[case("level:warn", LogLevelOperator::Eq, LogLevel::Warn)]
+    #[case("level:=warn", LogLevelOperator::Eq, LogLevel::Warn)]
+    #[case("level:>=warn", LogLevelOperator::Ge, LogLevel::Warn)]
+    #[case("level:>info", LogLevelOperator::Gt, LogLevel::Info)]
+    #[case("level:<=debug", LogLevelOperator::Le, LogLevel::Debug)]
+    #[case("level:<error", LogLevelOperator::Lt, LogLevel::Error)]
+    #[case("lv:>=ERROR", LogLevelOperator::Ge, LogLevel::Error)]
+    #[case("level:>=warning", LogLevelOperator::Ge, LogLevel::Warn)]
+    fn log_level(#[case] query: &str, #[case] operator: LogLevelOperator, #[case] level: LogLevel) {
+        let (remaining, actual) = super::log_level::<Error<_>>(query).unwrap();
+
+        assert_eq!(
+            actual,
+            FilterAttribute::LogLevel(LogLevelFilter { operator, level })
+        );
+        assert_eq!(remaining, "");
+    }
+
+    #[rstest]
+    #[case("level:")]
+    #[case("level:>=")]
+    #[case("level:>=verbose")]
+    fn log_level_error(#[case] query: &str) {
+        assert!(super::log_level::<Error<_>>(query).is_err());
+    }
+
     /// Label selector
     #[rstest]
     #[case("labels:foo=bar,baz=qux", "foo=bar,baz=qux")]
@@ -649,6 +708,7 @@ mod tests {
     #[case("!container:hoge", FilterAttribute::ExcludeContainer("hoge".into()))]
     #[case("log:hoge", FilterAttribute::IncludeLog("hoge".into()))]
     #[case("!log:hoge", FilterAttribute::ExcludeLog("hoge".into()))]
+    #[case("level:>=warn", FilterAttribute::LogLevel(LogLevelFilter { operator: LogLevelOperator::Ge, level: LogLevel::Warn }))]
     #[case("labels:foo=bar", FilterAttribute::LabelSelector("foo=bar".into()))]
     #[case("fields:foo=bar", FilterAttribute::FieldSelector("foo=bar".into()))]
     #[case("daemonset/app", FilterAttribute::Resource(SpecifiedResource::DaemonSet("app")))]
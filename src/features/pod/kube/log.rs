@@ -1,8 +1,9 @@
 mod log_collector;
+mod log_level;
 mod log_streamer;
 mod pod_watcher;
 
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, time::Duration};
 
 use anyhow::{anyhow, bail, Result};
 use async_trait::async_trait;
@@ -23,8 +24,12 @@ use crate::{
     workers::kube::{AbortWorker, Worker},
 };
 
-pub use self::log_streamer::LogPrefixType;
-pub use super::filter::{Filter, LabelSelector, RetrievableResource};
+pub use self::{
+    log_collector::{LogLine, LogSource},
+    log_level::LogLevel,
+    log_streamer::{LogPrefixType, LogTimestampType},
+};
+pub use super::filter::{Filter, LabelSelector, LogLevelFilter, RetrievableResource};
 
 use self::{
     log_collector::{LogBuffer, LogCollector},
@@ -47,6 +52,7 @@ pub struct LogConfig {
     namespaces: Namespace,
     query: String,
     prefix_type: LogPrefixType,
+    since: Option<Duration>,
 }
 
 impl LogConfig {
@@ -55,8 +61,15 @@ impl LogConfig {
             namespaces,
             query,
             prefix_type,
+            since: None,
         }
     }
+
+    /// 指定した期間より前のログを取得しない
+    pub fn since(mut self, since: Option<Duration>) -> Self {
+        self.since = since;
+        self
+    }
 }
 
 #[derive(Clone)]
@@ -112,6 +125,8 @@ impl LogWorker {
                 prefix_type: self.config.prefix_type,
                 include_log: filter.include_log.clone(),
                 exclude_log: filter.exclude_log.clone(),
+                log_level: filter.log_level,
+                since_seconds: self.config.since.map(|since| since.as_secs() as i64),
             });
 
             pod_watchers.push(pod_watcher);
@@ -1,12 +1,48 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use crossbeam::channel::Sender;
+use serde::{Deserialize, Serialize};
 use tokio::{sync::Mutex, time};
 
 use crate::{message::Message, send_response, workers::kube::Worker};
 
-pub type LogBuffer = Arc<Mutex<Vec<String>>>;
+pub type LogBuffer = Arc<Mutex<Vec<LogLine>>>;
+
+/// 複数のストリームから届いたログをタイムスタンプ順に並べ替えるために待つ時間
+const MERGE_WINDOW: time::Duration = time::Duration::from_millis(500);
+
+/// ログの出力元コンテナ
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogSource {
+    pub namespace: String,
+    pub pod: String,
+    pub container: String,
+}
+
+/// 1行分のログ
+///
+/// contentにはプレフィックスを含めた表示用の文字列が入る
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogLine {
+    pub source: Option<LogSource>,
+    pub timestamp: Option<DateTime<Utc>>,
+    pub content: String,
+    /// contentのうちプレフィックスを除いたログ本文の開始位置
+    ///
+    /// コンテナの開始・終了を示す行など、ログ本文を持たない場合は`None`
+    #[serde(default)]
+    pub message_offset: Option<usize>,
+}
+
+impl LogLine {
+    /// プレフィックスを除いたログ本文
+    pub fn message(&self) -> Option<&str> {
+        self.message_offset
+            .and_then(|offset| self.content.get(offset..))
+    }
+}
 
 #[derive(Clone)]
 pub struct LogCollector {
@@ -20,6 +56,74 @@ impl LogCollector {
     }
 }
 
+/// 並べ替え待ちのログ
+#[derive(Debug)]
+struct PendingLogLine {
+    received_at: DateTime<Utc>,
+    line: LogLine,
+}
+
+/// 並べ替えに使う時刻
+///
+/// コンテナの開始を示す行などタイムスタンプを持たないログは、同じストリームで後に受信した
+/// 最初のログのタイムスタンプで並べ、そのストリームのログより先に取り出す。
+/// 後続のログをまだ受信していない場合は受信時刻で並べる。
+fn sort_key(pending: &[PendingLogLine], index: usize) -> DateTime<Utc> {
+    let target = &pending[index];
+
+    if let Some(timestamp) = target.line.timestamp {
+        return timestamp;
+    }
+
+    pending[index + 1..]
+        .iter()
+        .filter(|p| target.line.source.is_some() && p.line.source == target.line.source)
+        .find_map(|p| p.line.timestamp)
+        .unwrap_or(target.received_at)
+}
+
+/// MERGE_WINDOW以上待ったログをタイムスタンプ順に取り出す
+///
+/// pendingは受信順に並んでいるものとし、同じ時刻のログは受信順を維持する。
+fn take_ripe_lines(
+    pending: &mut Vec<PendingLogLine>,
+    now: DateTime<Utc>,
+    window: time::Duration,
+) -> Vec<LogLine> {
+    let window = chrono::Duration::from_std(window).unwrap_or_default();
+
+    let keys: Vec<DateTime<Utc>> = (0..pending.len())
+        .map(|index| sort_key(pending, index))
+        .collect();
+
+    let Some(threshold) = pending
+        .iter()
+        .zip(&keys)
+        .filter(|(p, _)| p.received_at + window <= now)
+        .map(|(_, key)| *key)
+        .max()
+    else {
+        return Vec::new();
+    };
+
+    let mut ripe = Vec::new();
+    let mut rest = Vec::new();
+
+    for (p, key) in pending.drain(..).zip(keys) {
+        if key <= threshold {
+            ripe.push((key, p.line));
+        } else {
+            rest.push(p);
+        }
+    }
+
+    *pending = rest;
+
+    ripe.sort_by_key(|(key, _)| *key);
+
+    ripe.into_iter().map(|(_, line)| line).collect()
+}
+
 /// 将来的にはチャネルにしたい
 #[async_trait]
 impl Worker for LogCollector {
@@ -27,14 +131,166 @@ impl Worker for LogCollector {
     async fn run(&self) -> Self::Output {
         let mut interval = tokio::time::interval(time::Duration::from_millis(200));
 
+        let mut pending: Vec<PendingLogLine> = Vec::new();
+
         loop {
             interval.tick().await;
 
-            let mut buf = self.buffer.lock().await;
+            let now = Utc::now();
+
+            {
+                let mut buf = self.buffer.lock().await;
+
+                pending.extend(buf.drain(..).map(|line| PendingLogLine {
+                    received_at: now,
+                    line,
+                }));
+            }
+
+            let lines = take_ripe_lines(&mut pending, now, MERGE_WINDOW);
 
-            if !buf.is_empty() {
-                send_response!(self.tx, Ok(std::mem::take(&mut buf)));
+            if !lines.is_empty() {
+                send_response!(self.tx, Ok(lines));
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn at(sec: u32, milli: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, sec).unwrap()
+            + chrono::Duration::milliseconds(milli as i64)
+    }
+
+    fn pending(
+        received_at: DateTime<Utc>,
+        timestamp: Option<DateTime<Utc>>,
+        content: &str,
+    ) -> PendingLogLine {
+        PendingLogLine {
+            received_at,
+            line: LogLine {
+                source: None,
+                timestamp,
+                content: content.to_string(),
+                ..Default::default()
+            },
+        }
+    }
+
+    fn contents(lines: Vec<LogLine>) -> Vec<String> {
+        lines.into_iter().map(|l| l.content).collect()
+    }
+
+    #[test]
+    fn 待機時間を過ぎていないログは取り出さない() {
+        let mut p = vec![pending(at(0, 0), Some(at(0, 0)), "a")];
+
+        let actual = take_ripe_lines(&mut p, at(0, 200), MERGE_WINDOW);
+
+        assert!(actual.is_empty());
+        assert_eq!(p.len(), 1);
+    }
+
+    #[test]
+    fn 複数ストリームのログをタイムスタンプ順に並べる() {
+        let mut p = vec![
+            pending(at(0, 0), Some(at(0, 300)), "pod-a 2"),
+            pending(at(0, 0), Some(at(0, 100)), "pod-a 1"),
+            pending(at(0, 200), Some(at(0, 200)), "pod-b 1"),
+        ];
+
+        let actual = take_ripe_lines(&mut p, at(0, 700), MERGE_WINDOW);
+
+        assert_eq!(contents(actual), vec!["pod-a 1", "pod-b 1", "pod-a 2"]);
+        assert!(p.is_empty());
+    }
+
+    #[test]
+    fn 待機中のログより古いログは先に取り出す() {
+        let mut p = vec![
+            pending(at(0, 0), Some(at(0, 100)), "old"),
+            pending(at(0, 400), Some(at(0, 50)), "older but late"),
+            pending(at(0, 400), Some(at(0, 900)), "new"),
+        ];
+
+        let actual = take_ripe_lines(&mut p, at(0, 600), MERGE_WINDOW);
+
+        assert_eq!(contents(actual), vec!["older but late", "old"]);
+        assert_eq!(contents(p.drain(..).map(|p| p.line).collect()), vec!["new"]);
+    }
+
+    #[test]
+    fn タイムスタンプがないログは受信時刻で並べる() {
+        let mut p = vec![
+            pending(at(0, 0), Some(at(0, 100)), "with timestamp"),
+            pending(at(0, 0), None, "without timestamp"),
+        ];
+
+        let actual = take_ripe_lines(&mut p, at(1, 0), MERGE_WINDOW);
+
+        assert_eq!(
+            contents(actual),
+            vec!["without timestamp", "with timestamp"]
+        );
+    }
+
+    #[test]
+    fn コンテナの開始を示す行はそのストリームの過去のログより先に取り出す() {
+        let source = |container: &str| {
+            Some(LogSource {
+                namespace: "default".to_string(),
+                pod: "pod".to_string(),
+                container: container.to_string(),
+            })
+        };
+
+        let line = |received_at, timestamp, container: &str, content: &str| PendingLogLine {
+            received_at,
+            line: LogLine {
+                source: source(container),
+                timestamp,
+                content: content.to_string(),
+                ..Default::default()
+            },
+        };
+
+        let mut p = vec![
+            line(at(10, 0), Some(at(0, 500)), "b", "b history"),
+            line(at(10, 0), None, "a", "+ pod a"),
+            line(at(10, 0), Some(at(0, 100)), "a", "a history 1"),
+            line(at(10, 0), Some(at(0, 900)), "a", "a history 2"),
+        ];
+
+        let actual = take_ripe_lines(&mut p, at(11, 0), MERGE_WINDOW);
+
+        assert_eq!(
+            contents(actual),
+            vec!["+ pod a", "a history 1", "b history", "a history 2"]
+        );
+    }
+
+    #[test]
+    fn ログ本文はプレフィックスを除いた部分を返す() {
+        let line = LogLine {
+            content: "[pod container] hello".to_string(),
+            message_offset: Some(16),
+            ..Default::default()
+        };
+
+        assert_eq!(line.message(), Some("hello"));
+
+        let line = LogLine {
+            content: "+ pod container".to_string(),
+            ..Default::default()
+        };
+
+        assert_eq!(line.message(), None);
+    }
+}
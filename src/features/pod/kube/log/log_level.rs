@@ -0,0 +1,170 @@
+use std::str::FromStr;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::workers::kube::color::fg::Color;
+
+/// ログレベル
+///
+/// 重要度の低い順に並ぶ
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+    Fatal,
+}
+
+impl FromStr for LogLevel {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "trace" | "trc" => Ok(Self::Trace),
+            "debug" | "dbg" => Ok(Self::Debug),
+            "info" | "inf" | "information" | "notice" => Ok(Self::Info),
+            "warn" | "wrn" | "warning" => Ok(Self::Warn),
+            "error" | "err" => Ok(Self::Error),
+            "fatal" | "ftl" | "critical" | "crit" | "panic" | "emerg" | "alert" => Ok(Self::Fatal),
+            _ => Err(()),
+        }
+    }
+}
+
+impl std::fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Trace => "trace",
+            Self::Debug => "debug",
+            Self::Info => "info",
+            Self::Warn => "warn",
+            Self::Error => "error",
+            Self::Fatal => "fatal",
+        };
+
+        write!(f, "{}", s)
+    }
+}
+
+/// logfmt形式（level=error）
+static LOGFMT: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(?:^|\s)(?:level|lvl|severity)="?([A-Za-z]+)"?(?:\s|$)"#).unwrap());
+
+/// klog形式（E1017 12:34:56.789012）
+static KLOG: Lazy<Regex> = Lazy::new(|| Regex::new(r"^([IWEF])\d{4} \d{2}:\d{2}:\d{2}").unwrap());
+
+/// 先頭付近にある大文字のレベル（ERROR, [WARN], INFO: など）
+///
+/// 日時などが前に付くことを考慮して2語目までを対象とする
+static PREFIX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"^(?:\S+\s+){0,2}?[\[(<]?(TRACE|DEBUG|INFO|NOTICE|WARN|WARNING|ERROR|FATAL|CRITICAL|PANIC)[\])>:]?(?:\s|$)",
+    )
+    .unwrap()
+});
+
+const JSON_LEVEL_KEYS: [&str; 4] = ["level", "severity", "lvl", "loglevel"];
+
+impl LogLevel {
+    /// ログの内容からレベルを推定する
+    pub fn detect(line: &str) -> Option<Self> {
+        let line = line.trim_start();
+
+        if line.starts_with('{') {
+            if let Some(level) = Self::detect_json(line) {
+                return Some(level);
+            }
+        }
+
+        if let Some(caps) = KLOG.captures(line) {
+            return match &caps[1] {
+                "I" => Some(Self::Info),
+                "W" => Some(Self::Warn),
+                "E" => Some(Self::Error),
+                "F" => Some(Self::Fatal),
+                _ => None,
+            };
+        }
+
+        if let Some(level) = LOGFMT.captures(line).and_then(|caps| caps[1].parse().ok()) {
+            return Some(level);
+        }
+
+        PREFIX.captures(line).and_then(|caps| caps[1].parse().ok())
+    }
+
+    fn detect_json(line: &str) -> Option<Self> {
+        let serde_json::Value::Object(map) = serde_json::from_str(line).ok()? else {
+            return None;
+        };
+
+        JSON_LEVEL_KEYS.iter().find_map(|key| {
+            map.iter()
+                .find(|(k, _)| k.eq_ignore_ascii_case(key))
+                .and_then(|(_, v)| v.as_str())
+                .and_then(|v| v.parse().ok())
+        })
+    }
+
+    /// レベルに応じた文字色
+    pub fn color(&self) -> Option<Color> {
+        match self {
+            Self::Trace | Self::Debug => Some(Color::DarkGray),
+            Self::Info => None,
+            Self::Warn => Some(Color::Yellow),
+            Self::Error => Some(Color::Red),
+            Self::Fatal => Some(Color::LightRed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::logfmt(
+        r#"time=2024-01-01T00:00:00Z level=error msg="failed""#,
+        Some(LogLevel::Error)
+    )]
+    #[case::logfmt_quoted(r#"level="warn" msg=retry"#, Some(LogLevel::Warn))]
+    #[case::logfmt_short(r#"lvl=dbg msg=hello"#, Some(LogLevel::Debug))]
+    #[case::json(r#"{"level":"info","msg":"started"}"#, Some(LogLevel::Info))]
+    #[case::json_severity(r#"{"severity":"ERROR","message":"boom"}"#, Some(LogLevel::Error))]
+    #[case::json_uppercase_key(r#"{"Level":"warning"}"#, Some(LogLevel::Warn))]
+    #[case::klog_error(
+        "E1017 12:34:56.789012       1 controller.go:42] sync failed",
+        Some(LogLevel::Error)
+    )]
+    #[case::klog_info(
+        "I1017 12:34:56.789012       1 main.go:10] started",
+        Some(LogLevel::Info)
+    )]
+    #[case::prefix("ERROR something went wrong", Some(LogLevel::Error))]
+    #[case::prefix_bracket("[WARN] disk is almost full", Some(LogLevel::Warn))]
+    #[case::prefix_colon("INFO: listening on :8080", Some(LogLevel::Info))]
+    #[case::prefix_after_timestamp(
+        "2024-01-01 00:00:00 FATAL out of memory",
+        Some(LogLevel::Fatal)
+    )]
+    #[case::lowercase_word("an error occurred", None)]
+    #[case::word_in_sentence("request handled without ERROR count", None)]
+    #[case::plain("hello world", None)]
+    #[case::invalid_json("{level: error", None)]
+    fn ログレベルを推定する(#[case] line: &str, #[case] expected: Option<LogLevel>) {
+        assert_eq!(LogLevel::detect(line), expected);
+    }
+
+    #[test]
+    fn ログレベルは重要度の順に比較できる() {
+        assert!(LogLevel::Debug < LogLevel::Info);
+        assert!(LogLevel::Warn < LogLevel::Error);
+        assert!(LogLevel::Error < LogLevel::Fatal);
+    }
+}
@@ -22,7 +22,11 @@ use crate::{
     workers::kube::{color::fg::Color, AbortWorker},
 };
 
-use super::log_collector::LogBuffer;
+use super::{
+    log_collector::{LogBuffer, LogLine, LogSource},
+    log_level::LogLevel,
+    LogLevelFilter,
+};
 
 #[derive(Debug, Clone, Copy)]
 pub enum LogPrefixType {
@@ -37,6 +41,66 @@ impl Default for LogPrefixType {
     }
 }
 
+/// ログのタイムスタンプの表示形式
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LogTimestampType {
+    #[default]
+    Hidden,
+    Local,
+    Utc,
+    Relative,
+}
+
+impl LogTimestampType {
+    /// 表示形式を順番に切り替える
+    pub fn next(self) -> Self {
+        match self {
+            Self::Hidden => Self::Local,
+            Self::Local => Self::Utc,
+            Self::Utc => Self::Relative,
+            Self::Relative => Self::Hidden,
+        }
+    }
+
+    /// 表示に必要な幅
+    pub fn width(self) -> u16 {
+        match self {
+            Self::Hidden => 0,
+            // 2024-01-01 00:00:00.000
+            Self::Local => 23,
+            // 2024-01-01T00:00:00.000Z
+            Self::Utc => 24,
+            // 59m ago
+            Self::Relative => 8,
+        }
+    }
+
+    pub fn format(self, timestamp: DateTime<Utc>, now: DateTime<Utc>) -> Option<String> {
+        match self {
+            Self::Hidden => None,
+            Self::Local => Some(
+                timestamp
+                    .with_timezone(&chrono::Local)
+                    .format("%Y-%m-%d %H:%M:%S%.3f")
+                    .to_string(),
+            ),
+            Self::Utc => Some(timestamp.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string()),
+            Self::Relative => Some(format!("{} ago", relative_time(now - timestamp))),
+        }
+    }
+}
+
+fn relative_time(duration: chrono::Duration) -> String {
+    let sec = duration.num_seconds().max(0);
+
+    match sec {
+        0..=59 => format!("{}s", sec),
+        60..=3599 => format!("{}m", sec / 60),
+        3600..=86399 => format!("{}h", sec / 3600),
+        _ => format!("{}d", sec / 86400),
+    }
+}
+
 #[derive(Clone, Copy)]
 struct PrefixColor {
     pub pod: Color,
@@ -75,6 +139,9 @@ pub struct LogStreamerOptions {
     pub prefix_type: LogPrefixType,
     pub include_log: Option<Vec<Regex>>,
     pub exclude_log: Option<Vec<Regex>>,
+    pub log_level: Option<LogLevelFilter>,
+    /// 初回に取得するログの期間（秒）
+    pub since_seconds: Option<i64>,
 }
 
 #[derive(Clone)]
@@ -100,6 +167,9 @@ impl AbortWorker for LogStreamer {
 
         let mut last_timestamp: Option<DateTime<Utc>> = None;
 
+        // レベルを含まない行（スタックトレースなど）は直前の行のレベルを引き継ぐ
+        let mut last_level: Option<LogLevel> = None;
+
         let prefix = self.log_prefix();
 
         self.send_started_message().await;
@@ -107,7 +177,9 @@ impl AbortWorker for LogStreamer {
         loop {
             interval.tick().await;
 
-            let result = self.fetch(&prefix, &mut last_timestamp).await;
+            let result = self
+                .fetch(&prefix, &mut last_timestamp, &mut last_level)
+                .await;
 
             if let Err(err) = result {
                 logger!(error, "{}", err)
@@ -145,7 +217,12 @@ impl LogStreamer {
         self
     }
 
-    async fn fetch(&self, prefix: &str, last_timestamp: &mut Option<DateTime<Utc>>) -> Result<()> {
+    async fn fetch(
+        &self,
+        prefix: &str,
+        last_timestamp: &mut Option<DateTime<Utc>>,
+        last_level: &mut Option<LogLevel>,
+    ) -> Result<()> {
         let log_params = self.log_params(last_timestamp);
 
         let api: Api<Pod> = Api::namespaced(self.client.to_client(), self.namespace());
@@ -162,25 +239,54 @@ impl LogStreamer {
                     continue;
                 }
 
-                if self.is_exclude(content) || !self.is_include(content) {
+                let Some(content) = self.process(content, last_level) else {
                     continue;
-                }
+                };
 
-                buf.push(format!("{}{}", prefix, content));
+                buf.push(self.log_message_line(Some(dt), prefix, &content));
 
                 *last_timestamp = Some(dt);
             } else {
-                if self.is_exclude(&line) || !self.is_include(&line) {
+                let Some(content) = self.process(&line, last_level) else {
                     continue;
-                }
+                };
 
-                buf.push(format!("{}{}", prefix, line));
+                buf.push(self.log_message_line(None, prefix, &content));
             }
         }
 
         Ok(())
     }
 
+    /// 絞り込み条件に一致する場合、レベルに応じて色付けした内容を返す
+    fn process(&self, content: &str, last_level: &mut Option<LogLevel>) -> Option<String> {
+        let level = LogLevel::detect(content);
+
+        if level.is_some() {
+            *last_level = level;
+        }
+
+        if self.is_exclude(content)
+            || !self.is_include(content)
+            || !self.is_level_match(*last_level)
+        {
+            return None;
+        }
+
+        match level.and_then(|level| level.color()) {
+            Some(color) => Some(color.wrap(content)),
+            None => Some(content.to_string()),
+        }
+    }
+
+    fn is_level_match(&self, level: Option<LogLevel>) -> bool {
+        let Some(filter) = &self.options.log_level else {
+            return true;
+        };
+
+        level.is_some_and(|level| filter.is_match(level))
+    }
+
     fn is_exclude(&self, s: &str) -> bool {
         self.options
             .exclude_log
@@ -201,7 +307,7 @@ impl LogStreamer {
 
         let mut buf = self.log_buffer.lock().await;
 
-        buf.push(format!("{} {}", sign, self.log_prefix_content()));
+        buf.push(self.log_line(None, format!("{} {}", sign, self.log_prefix_content())));
     }
 
     async fn send_finished_message(&self) {
@@ -209,7 +315,32 @@ impl LogStreamer {
 
         let mut buf = self.log_buffer.lock().await;
 
-        buf.push(format!("{} {}", sign, self.log_prefix_content()));
+        buf.push(self.log_line(None, format!("{} {}", sign, self.log_prefix_content())));
+    }
+
+    fn log_line(&self, timestamp: Option<DateTime<Utc>>, content: String) -> LogLine {
+        LogLine {
+            source: Some(LogSource {
+                namespace: self.namespace().to_string(),
+                pod: self.pod_name().to_string(),
+                container: self.container_name().to_string(),
+            }),
+            timestamp,
+            content,
+            message_offset: None,
+        }
+    }
+
+    fn log_message_line(
+        &self,
+        timestamp: Option<DateTime<Utc>>,
+        prefix: &str,
+        message: &str,
+    ) -> LogLine {
+        LogLine {
+            message_offset: Some(prefix.len()),
+            ..self.log_line(timestamp, format!("{}{}", prefix, message))
+        }
     }
 
     fn log_prefix_content(&self) -> String {
@@ -299,6 +430,11 @@ impl LogStreamer {
             container: Some(self.container_name().to_string()),
             timestamps: true,
             since_time: *last_timestamp,
+            // since_timeと同時には指定できないため、再接続時は前回の続きから取得する
+            since_seconds: last_timestamp
+                .is_none()
+                .then_some(self.options.since_seconds)
+                .flatten(),
             ..Default::default()
         }
     }
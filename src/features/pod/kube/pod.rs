@@ -6,6 +6,7 @@ use futures::future::try_join_all;
 use k8s_openapi::{api::core::v1::Pod, Resource as _};
 
 use crate::{
+    features::label_selector::kube::LabelSelectorTarget,
     kube::{
         apis::v1_table::TableRow,
         table::{get_resource_per_namespace, insert_ns, KubeTable, KubeTableRow},
@@ -36,15 +37,21 @@ impl Worker for PodPoller {
         let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(1));
 
         let Self {
-            base: PollerBase {
-                is_terminated, tx, ..
-            },
+            base:
+                PollerBase {
+                    is_terminated,
+                    tx,
+                    connection,
+                    ..
+                },
         } = self;
 
         while !is_terminated.load(std::sync::atomic::Ordering::Relaxed) {
-            interval.tick().await;
+            connection.tick(&mut interval).await;
 
-            let pod_info = self.get_pod_info().await;
+            let pod_info = self.poll().await;
+
+            connection.report(&pod_info);
 
             tx.send(Message::Kube(Kube::Pod(pod_info)))
                 .expect("Failed to Kube::Pod");
@@ -55,10 +62,21 @@ impl Worker for PodPoller {
 }
 
 impl PodPoller {
-    async fn get_pod_info(&self) -> Result<KubeTable> {
+    /// 対象のnamespaceのPod一覧を取得する
+    pub async fn poll(&self) -> Result<KubeTable> {
         let namespaces = self.base.shared_target_namespaces.read().await;
 
-        let jobs = self.get_pods_per_namespace(&namespaces).await;
+        let label_selector = self
+            .base
+            .shared_label_selectors
+            .read()
+            .await
+            .get(&LabelSelectorTarget::Pod)
+            .cloned();
+
+        let jobs = self
+            .get_pods_per_namespace(&namespaces, label_selector.as_deref())
+            .await;
 
         let ok_only: Vec<KubeTableRow> = jobs?.into_iter().flatten().collect();
 
@@ -85,12 +103,14 @@ impl PodPoller {
     async fn get_pods_per_namespace(
         &self,
         namespaces: &[String],
+        label_selector: Option<&str>,
     ) -> Result<Vec<Vec<KubeTableRow>>> {
         let insert_ns = insert_ns(namespaces);
         try_join_all(namespaces.iter().map(|ns| {
             get_resource_per_namespace(
                 &self.base.kube_client,
                 format!("api/v1/namespaces/{}/{}", ns, "pods"),
+                label_selector,
                 &["Name", "Ready", "Status", "Age"],
                 move |row: &TableRow, indexes: &[usize]| {
                     let mut row: Vec<String> =
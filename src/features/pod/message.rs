@@ -1,13 +1,18 @@
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
 
-use crate::{message::Message, workers::kube::message::Kube};
+use crate::{
+    message::Message,
+    workers::{kube::message::Kube, replay::serde_result},
+};
 
-use super::kube::LogConfig;
+use super::kube::{LogConfig, LogLine};
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum LogMessage {
+    #[serde(skip)]
     Request(LogConfig),
-    Response(Result<Vec<String>>),
+    Response(#[serde(with = "serde_result")] Result<Vec<LogLine>>),
 }
 
 impl From<LogMessage> for Message {
@@ -1,11 +1,15 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, path::PathBuf, rc::Rc};
 
 use crossbeam::channel::Sender;
 use ratatui::layout::{Constraint, Direction};
 
 use crate::{
     clipboard::Clipboard,
-    features::component_id::{POD_TAB_ID, POD_WIDGET_ID},
+    features::{
+        component_id::{POD_TAB_ID, POD_WIDGET_ID},
+        pod::kube::LogTimestampType,
+    },
+    history::HistoryHandle,
     kube::context::Namespace,
     message::Message,
     ui::{
@@ -15,11 +19,30 @@ use crate::{
     },
 };
 
-use super::widgets::{log_query_help_widget, log_query_widget, log_widget, pod_widget};
+use super::widgets::{
+    log_bookmark_widget, log_query_help_widget, log_query_widget, log_save_query_widget,
+    log_saved_queries_widget, log_widget, pod_widget,
+};
+
+/// ログビューの設定
+#[derive(Debug, Default, Clone)]
+pub struct LogViewConfig {
+    /// 保持する最大行数（Noneの場合は無制限）
+    pub max_lines: Option<usize>,
+    /// 保持する最大バイト数（Noneの場合は無制限）
+    pub max_bytes: Option<usize>,
+    /// 上限を超えて削除した行の書き出し先
+    pub spill_file: Option<PathBuf>,
+    /// タイムスタンプの表示形式の初期値
+    pub timestamp_type: LogTimestampType,
+}
 
 pub struct PodTab {
     pub tab: Tab<'static>,
     pub log_query_help_popup: Widget<'static>,
+    pub log_bookmark_popup: Widget<'static>,
+    pub log_save_query_popup: Widget<'static>,
+    pub log_saved_queries_popup: Widget<'static>,
 }
 
 impl PodTab {
@@ -29,11 +52,16 @@ impl PodTab {
         clipboard: &Option<Rc<RefCell<Clipboard>>>,
         split_direction: Direction,
         namespaces: Rc<RefCell<Namespace>>,
+        log_view_config: &LogViewConfig,
+        history: &HistoryHandle,
     ) -> Self {
-        let pod_widget = pod_widget(tx);
-        let log_query_widget = log_query_widget(tx, namespaces);
-        let log_widget = log_widget(clipboard);
+        let pod_widget = pod_widget(tx, history);
+        let log_query_widget = log_query_widget(tx, namespaces.clone(), history);
+        let log_widget = log_widget(clipboard, log_view_config);
         let log_query_help_widget = log_query_help_widget();
+        let log_bookmark_widget = log_bookmark_widget();
+        let log_save_query_widget = log_save_query_widget(history);
+        let log_saved_queries_widget = log_saved_queries_widget(tx, namespaces, history);
 
         let layout = layout(split_direction);
 
@@ -49,6 +77,9 @@ impl PodTab {
         Self {
             tab,
             log_query_help_popup: log_query_help_widget,
+            log_bookmark_popup: log_bookmark_widget,
+            log_save_query_popup: log_save_query_widget,
+            log_saved_queries_popup: log_saved_queries_widget,
         }
     }
 }
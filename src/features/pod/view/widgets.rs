@@ -1,9 +1,15 @@
 mod log;
+mod log_bookmark;
 mod log_query;
 mod log_query_help;
+mod log_saved_query;
 mod pod;
 
 pub(super) use log::*;
+pub(super) use log_bookmark::*;
 pub(super) use log_query::*;
 pub(super) use log_query_help::*;
+pub(super) use log_saved_query::*;
 pub(super) use pod::*;
+
+pub use pod::request_pod_log;
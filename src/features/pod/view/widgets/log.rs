@@ -1,26 +1,67 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{
+    cell::{Cell, RefCell},
+    fs::{File, OpenOptions},
+    io::{BufWriter, Write as _},
+    path::PathBuf,
+    rc::Rc,
+};
 
+use chrono::{DateTime, Utc};
 use ratatui::{crossterm::event::KeyCode, widgets::Block};
 
 use crate::{
+    ansi::strip_ansi,
     clipboard::Clipboard,
-    features::component_id::POD_LOG_WIDGET_ID,
+    features::{
+        component_id::POD_LOG_WIDGET_ID,
+        pod::{kube::LogTimestampType, view::LogViewConfig},
+    },
+    logger,
     message::UserEvent,
     ui::{
         event::EventResult,
-        widget::{config::WidgetConfig, Item, Text, Widget, WidgetTrait as _},
+        widget::{config::WidgetConfig, Item, LiteralItem, Text, Widget, WidgetTrait as _},
         Window,
     },
 };
 
-pub fn log_widget(clipboard: &Option<Rc<RefCell<Clipboard>>>) -> Widget<'static> {
-    let builder = Text::builder()
+use super::open_log_bookmark_popup;
+
+pub fn log_widget(
+    clipboard: &Option<Rc<RefCell<Clipboard>>>,
+    config: &LogViewConfig,
+) -> Widget<'static> {
+    let mut builder = Text::builder()
         .id(POD_LOG_WIDGET_ID)
         .widget_config(&WidgetConfig::builder().title("Log").build())
         .wrap()
         .follow()
+        .bookmark()
         .block_injection(block_injection())
-        .action(UserEvent::from(KeyCode::Enter), add_blankline());
+        .action(UserEvent::from(KeyCode::Enter), add_blankline())
+        .action('M', open_log_bookmark_popup())
+        .action('T', toggle_timestamp(config.timestamp_type))
+        // 相対時刻は新しいログが届かなくても経過時間に合わせて更新する
+        .periodic_redraw(config.timestamp_type == LogTimestampType::Relative);
+
+    if config.timestamp_type != LogTimestampType::Hidden {
+        builder = builder.gutter(
+            config.timestamp_type.width(),
+            timestamp_gutter(config.timestamp_type),
+        );
+    }
+
+    if let Some(max_lines) = config.max_lines {
+        builder = builder.max_lines(max_lines);
+    }
+
+    if let Some(max_bytes) = config.max_bytes {
+        builder = builder.max_bytes(max_bytes);
+    }
+
+    if let Some(path) = &config.spill_file {
+        builder = builder.on_evict(spill(path.clone()));
+    }
 
     if let Some(cb) = clipboard {
         builder.clipboard(cb.clone())
@@ -37,7 +78,21 @@ fn block_injection() -> impl Fn(&Text, bool, bool) -> Block<'static> {
 
         let mut config = text.widget_config().clone();
 
-        *config.title_mut() = format!("Log [{}/{}]", index, size).into();
+        let mut title = format!("Log [{}/{}]", index, size);
+
+        if let Some(max_bytes) = text.max_bytes() {
+            title += &format!(
+                " {}/{}",
+                format_bytes(text.bytes()),
+                format_bytes(max_bytes)
+            );
+        }
+
+        if 0 < text.dropped_lines() {
+            title += &format!(" ({} lines dropped)", text.dropped_lines());
+        }
+
+        *config.title_mut() = title.into();
 
         config.render_block(text.can_activate() && is_active, is_mouse_over)
     }
@@ -53,3 +108,88 @@ fn add_blankline() -> impl Fn(&mut Window) -> EventResult {
         EventResult::Nop
     }
 }
+
+/// タイムスタンプの表示形式を切り替える
+fn toggle_timestamp(initial: LogTimestampType) -> impl Fn(&mut Window) -> EventResult {
+    let timestamp_type = Cell::new(initial);
+
+    move |w: &mut Window| {
+        let next = timestamp_type.get().next();
+
+        timestamp_type.set(next);
+
+        let w = w.find_widget_mut(POD_LOG_WIDGET_ID).as_mut_text();
+
+        if next == LogTimestampType::Hidden {
+            w.clear_gutter();
+        } else {
+            w.set_gutter(next.width(), timestamp_gutter(next));
+        }
+
+        w.set_periodic_redraw(next == LogTimestampType::Relative);
+
+        EventResult::Nop
+    }
+}
+
+fn timestamp_gutter(timestamp_type: LogTimestampType) -> impl Fn(&LiteralItem) -> Option<String> {
+    move |item: &LiteralItem| {
+        let timestamp = item.metadata.as_ref()?.get("timestamp")?;
+
+        let timestamp: DateTime<Utc> = DateTime::parse_from_rfc3339(timestamp).ok()?.into();
+
+        timestamp_type.format(timestamp, Utc::now())
+    }
+}
+
+/// ログビューから削除された行をファイルに追記する
+///
+/// エスケープシーケンスを取り除いて書き出すため、grepなどでそのまま検索できる
+fn spill(path: PathBuf) -> impl Fn(&[LiteralItem]) {
+    let writer: RefCell<Option<BufWriter<File>>> = RefCell::new(None);
+
+    move |items: &[LiteralItem]| {
+        let mut writer = writer.borrow_mut();
+
+        if writer.is_none() {
+            match OpenOptions::new().create(true).append(true).open(&path) {
+                Ok(file) => *writer = Some(BufWriter::new(file)),
+                Err(err) => {
+                    logger!(error, "Failed to open {}: {}", path.display(), err);
+                    return;
+                }
+            }
+        }
+
+        let Some(writer) = writer.as_mut() else {
+            return;
+        };
+
+        let result = items
+            .iter()
+            .try_for_each(|item| writeln!(writer, "{}", strip_ansi(&item.item)))
+            .and_then(|_| writer.flush());
+
+        if let Err(err) = result {
+            logger!(error, "Failed to write {}: {}", path.display(), err);
+        }
+    }
+}
+
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+
+    while 1024.0 <= value && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{}{}", bytes, UNITS[unit])
+    } else {
+        format!("{:.1}{}", value, UNITS[unit])
+    }
+}
@@ -0,0 +1,107 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Local};
+
+use crate::{
+    features::component_id::{POD_LOG_BOOKMARK_POPUP_ID, POD_LOG_WIDGET_ID},
+    ui::{
+        event::EventResult,
+        widget::{config::WidgetConfig, Item, List, LiteralItem, Widget, WidgetTrait as _},
+        Window,
+    },
+};
+
+pub fn log_bookmark_widget() -> Widget<'static> {
+    List::builder()
+        .id(POD_LOG_BOOKMARK_POPUP_ID)
+        .widget_config(&WidgetConfig::builder().title("Bookmarks").build())
+        .on_select(on_select())
+        .build()
+        .into()
+}
+
+/// ログウィジェットのブックマークをポップアップに反映して開く
+pub fn open_log_bookmark_popup() -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let text = w.find_widget(POD_LOG_WIDGET_ID).as_text();
+
+        let dropped_lines = text.dropped_lines();
+
+        let items: Vec<LiteralItem> = text
+            .bookmarks()
+            .into_iter()
+            .map(|(index, item)| bookmark_item(index, dropped_lines, item))
+            .collect();
+
+        w.find_widget_mut(POD_LOG_BOOKMARK_POPUP_ID)
+            .update_widget_item(Item::Array(items));
+
+        w.open_popup(POD_LOG_BOOKMARK_POPUP_ID);
+
+        EventResult::Nop
+    }
+}
+
+fn on_select() -> impl Fn(&mut Window, &LiteralItem) -> EventResult {
+    move |w: &mut Window, v: &LiteralItem| {
+        let Some(index) = v
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get("line_index"))
+            .and_then(|index| index.parse::<usize>().ok())
+        else {
+            return EventResult::Ignore;
+        };
+
+        w.close_popup();
+
+        w.find_widget_mut(POD_LOG_WIDGET_ID)
+            .as_mut_text()
+            .jump_to_line(index);
+
+        w.activate_widget_by_id(POD_LOG_WIDGET_ID);
+
+        EventResult::Nop
+    }
+}
+
+/// `<行番号>  <時刻>  <pod>/<container>  <ログ>` 形式のアイテムを生成する
+///
+/// 行番号は削除された行も含めて数える
+fn bookmark_item(index: usize, dropped_lines: usize, item: &LiteralItem) -> LiteralItem {
+    let metadata = item.metadata.as_ref();
+
+    let timestamp = metadata
+        .and_then(|metadata| metadata.get("timestamp"))
+        .and_then(|timestamp| DateTime::parse_from_rfc3339(timestamp).ok())
+        .map(|timestamp| {
+            timestamp
+                .with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string()
+        })
+        .unwrap_or_else(|| "-".to_string());
+
+    let source = metadata
+        .and_then(|metadata| {
+            let pod = metadata.get("pod")?;
+            let container = metadata.get("container")?;
+
+            Some(format!("{}/{}", pod, container))
+        })
+        .unwrap_or_else(|| "-".to_string());
+
+    LiteralItem::new(
+        format!(
+            "{:>6}  {}  {}  {}",
+            dropped_lines + index + 1,
+            timestamp,
+            source,
+            item.item
+        ),
+        Some(BTreeMap::from([(
+            "line_index".to_string(),
+            index.to_string(),
+        )])),
+    )
+}
@@ -1,7 +1,7 @@
 use std::{cell::RefCell, rc::Rc};
 
 use crossbeam::channel::Sender;
-use ratatui::crossterm::event::KeyCode;
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 use crate::{
     features::{
@@ -11,6 +11,7 @@ use crate::{
             message::LogMessage,
         },
     },
+    history::HistoryHandle,
     kube::context::Namespace,
     message::{Message, UserEvent},
     ui::{
@@ -22,21 +23,33 @@ use crate::{
     },
 };
 
+use super::{open_log_save_query_popup, open_log_saved_queries_popup};
+
 pub fn log_query_widget(
     tx: &Sender<Message>,
     namespaces: Rc<RefCell<Namespace>>,
+    history: &HistoryHandle,
 ) -> Widget<'static> {
     let tx = tx.clone();
 
     InputFormBuilder::default()
         .id(POD_LOG_QUERY_WIDGET_ID)
         .widget_config(WidgetConfig::builder().title("Log Query").build())
+        .history(history.loader("log_query"), history.saver("log_query"))
         .actions(UserEvent::from(KeyCode::Enter), exec_query(tx, namespaces))
+        .actions(
+            UserEvent::Key(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL)),
+            open_log_save_query_popup(),
+        )
+        .actions(
+            UserEvent::Key(KeyEvent::new(KeyCode::Char('o'), KeyModifiers::CONTROL)),
+            open_log_saved_queries_popup(history),
+        )
         .build()
         .into()
 }
 
-fn exec_query(
+pub(super) fn exec_query(
     tx: Sender<Message>,
     namespaces: Rc<RefCell<Namespace>>,
 ) -> impl Fn(&mut Window) -> EventResult {
@@ -53,6 +66,8 @@ fn exec_query(
             return EventResult::Nop;
         }
 
+        widget.as_mut_input().commit_history();
+
         w.widget_clear(POD_LOG_WIDGET_ID);
 
         let namespaces = namespaces.borrow();
@@ -32,6 +32,7 @@ fn content() -> Vec<String> {
            !container:<regex>    (alias: !containers, !co, !c)
            log:<regex>           (alias: logs, lo, l)
            !log:<regex>          (alias: !logs, !lo, !l)
+           level:[op]<level>     (alias: lv, op: = > >= < <=)
            label:<selector>      (alias: labels)
            field:<selector>      (alias: fields)
            <resource>/<name>
@@ -44,6 +45,9 @@ fn content() -> Vec<String> {
            daemonset      (alias: daemonsets, ds)
            service        (alias: services, svc)
            job            (alias: jobs)
+
+        Levels:
+           trace < debug < info < warn < error < fatal
     "# }
     .lines()
     .map(ToString::to_string)
@@ -0,0 +1,189 @@
+use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
+
+use crossbeam::channel::Sender;
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::{
+    features::component_id::{
+        POD_LOG_QUERY_WIDGET_ID, POD_LOG_SAVED_QUERIES_POPUP_ID, POD_LOG_SAVE_QUERY_POPUP_ID,
+    },
+    history::HistoryHandle,
+    kube::context::Namespace,
+    message::{Message, UserEvent},
+    ui::{
+        event::EventResult,
+        widget::{
+            config::WidgetConfig, input::InputFormBuilder, Item, LiteralItem, SelectedItem,
+            SingleSelect, Widget, WidgetTrait as _,
+        },
+        Window,
+    },
+};
+
+use super::exec_query;
+
+/// ログクエリに名前を付けて保存するポップアップ
+pub fn log_save_query_widget(history: &HistoryHandle) -> Widget<'static> {
+    InputFormBuilder::default()
+        .id(POD_LOG_SAVE_QUERY_POPUP_ID)
+        .widget_config(WidgetConfig::builder().title("Save Query As").build())
+        .actions(UserEvent::from(KeyCode::Enter), save_query(history.clone()))
+        .build()
+        .into()
+}
+
+/// 保存済みのログクエリを選択するポップアップ
+pub fn log_saved_queries_widget(
+    tx: &Sender<Message>,
+    namespaces: Rc<RefCell<Namespace>>,
+    history: &HistoryHandle,
+) -> Widget<'static> {
+    SingleSelect::builder()
+        .id(POD_LOG_SAVED_QUERIES_POPUP_ID)
+        .widget_config(&WidgetConfig::builder().title("Saved Queries").build())
+        .on_select(on_select(tx.clone(), namespaces))
+        .action(
+            UserEvent::Key(KeyEvent::new(KeyCode::Char('x'), KeyModifiers::CONTROL)),
+            remove_query(history.clone()),
+        )
+        .build()
+        .into()
+}
+
+/// ログクエリが空でない場合に保存用のポップアップを開く
+pub fn open_log_save_query_popup() -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let Some(SelectedItem::Literal { item, .. }) =
+            w.find_widget(POD_LOG_QUERY_WIDGET_ID).widget_item()
+        else {
+            return EventResult::Ignore;
+        };
+
+        if item.trim().is_empty() {
+            return EventResult::Nop;
+        }
+
+        w.widget_clear(POD_LOG_SAVE_QUERY_POPUP_ID);
+        w.open_popup(POD_LOG_SAVE_QUERY_POPUP_ID);
+
+        EventResult::Nop
+    }
+}
+
+/// 保存済みのログクエリをポップアップに反映して開く
+pub fn open_log_saved_queries_popup(
+    history: &HistoryHandle,
+) -> impl Fn(&mut Window) -> EventResult {
+    let history = history.clone();
+
+    move |w: &mut Window| {
+        update_saved_queries(w, &history);
+
+        w.open_popup(POD_LOG_SAVED_QUERIES_POPUP_ID);
+
+        EventResult::Nop
+    }
+}
+
+fn save_query(history: HistoryHandle) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let Some(SelectedItem::Literal { item: name, .. }) =
+            w.find_widget(POD_LOG_SAVE_QUERY_POPUP_ID).widget_item()
+        else {
+            return EventResult::Ignore;
+        };
+
+        let name = name.trim();
+
+        if name.is_empty() {
+            return EventResult::Nop;
+        }
+
+        let Some(SelectedItem::Literal { item: query, .. }) =
+            w.find_widget(POD_LOG_QUERY_WIDGET_ID).widget_item()
+        else {
+            return EventResult::Ignore;
+        };
+
+        history.save_query(name, &query);
+
+        w.widget_clear(POD_LOG_SAVE_QUERY_POPUP_ID);
+        w.close_popup();
+
+        EventResult::Nop
+    }
+}
+
+fn on_select(
+    tx: Sender<Message>,
+    namespaces: Rc<RefCell<Namespace>>,
+) -> impl Fn(&mut Window, &LiteralItem) -> EventResult {
+    let exec_query = exec_query(tx, namespaces);
+
+    move |w: &mut Window, v: &LiteralItem| {
+        let Some(query) = v
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get("query"))
+        else {
+            return EventResult::Ignore;
+        };
+
+        w.close_popup();
+
+        w.find_widget_mut(POD_LOG_QUERY_WIDGET_ID)
+            .update_widget_item(Item::Single(LiteralItem::new(query.to_string(), None)));
+
+        w.activate_widget_by_id(POD_LOG_QUERY_WIDGET_ID);
+
+        exec_query(w)
+    }
+}
+
+fn remove_query(history: HistoryHandle) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let Some(SelectedItem::Literal {
+            metadata: Some(metadata),
+            ..
+        }) = w.find_widget(POD_LOG_SAVED_QUERIES_POPUP_ID).widget_item()
+        else {
+            return EventResult::Ignore;
+        };
+
+        let Some(name) = metadata.get("name") else {
+            return EventResult::Ignore;
+        };
+
+        history.remove_query(name);
+
+        update_saved_queries(w, &history);
+
+        EventResult::Nop
+    }
+}
+
+fn update_saved_queries(w: &mut Window, history: &HistoryHandle) {
+    let saved_queries = history.saved_queries();
+
+    let name_width = saved_queries
+        .iter()
+        .map(|(name, _)| name.chars().count())
+        .max()
+        .unwrap_or_default();
+
+    let items: Vec<LiteralItem> = saved_queries
+        .into_iter()
+        .map(|(name, query)| {
+            LiteralItem::new(
+                format!("{:<width$}  {}", name, query, width = name_width),
+                Some(BTreeMap::from([
+                    ("name".to_string(), name),
+                    ("query".to_string(), query),
+                ])),
+            )
+        })
+        .collect();
+
+    w.find_widget_mut(POD_LOG_SAVED_QUERIES_POPUP_ID)
+        .update_widget_item(Item::Array(items));
+}
@@ -8,6 +8,7 @@ use crate::{
             message::LogMessage,
         },
     },
+    history::HistoryHandle,
     kube::context::Namespace,
     message::Message,
     ui::{
@@ -17,13 +18,14 @@ use crate::{
     },
 };
 
-pub fn pod_widget(tx: &Sender<Message>) -> Widget<'static> {
+pub fn pod_widget(tx: &Sender<Message>, history: &HistoryHandle) -> Widget<'static> {
     let tx = tx.clone();
 
     Table::builder()
         .id(POD_WIDGET_ID)
         .widget_config(&WidgetConfig::builder().title("Pod").build())
         .filtered_key("NAME")
+        .filter_history(history.loader("pod_filter"), history.saver("pod_filter"))
         .block_injection(block_injection())
         .on_select(on_select(tx))
         .build()
@@ -55,29 +57,36 @@ fn on_select(tx: Sender<Message>) -> impl Fn(&mut Window, &TableItem) -> EventRe
             return EventResult::Ignore;
         };
 
-        let Some(ref namespace) = metadata.get("namespace") else {
+        let Some(namespace) = metadata.get("namespace") else {
             return EventResult::Ignore;
         };
 
-        let Some(ref name) = metadata.get("name") else {
+        let Some(name) = metadata.get("name") else {
             return EventResult::Ignore;
         };
 
-        let query_form = w.find_widget_mut(POD_LOG_QUERY_WIDGET_ID);
+        request_pod_log(w, &tx, namespace, name);
 
-        query_form.update_widget_item(Item::Single(format!("pod/{}", name).into()));
+        EventResult::WindowAction(WindowAction::Continue)
+    }
+}
 
-        let namespaces = Namespace(vec![namespace.to_string()]);
+/// Podのログの取得を要求し、ログクエリの入力欄を`pod/<name>`に切り替える
+pub fn request_pod_log(w: &mut Window, tx: &Sender<Message>, namespace: &str, name: &str) {
+    w.widget_clear(POD_LOG_WIDGET_ID);
 
-        let config = LogConfig::new(
-            format!("pod/{}", name),
-            namespaces.to_owned(),
-            LogPrefixType::OnlyContainer,
-        );
+    let query_form = w.find_widget_mut(POD_LOG_QUERY_WIDGET_ID);
 
-        tx.send(LogMessage::Request(config).into())
-            .expect("Failed to send LogMessage::Request");
+    query_form.update_widget_item(Item::Single(format!("pod/{}", name).into()));
 
-        EventResult::WindowAction(WindowAction::Continue)
-    }
+    let namespaces = Namespace(vec![namespace.to_string()]);
+
+    let config = LogConfig::new(
+        format!("pod/{}", name),
+        namespaces.to_owned(),
+        LogPrefixType::OnlyContainer,
+    );
+
+    tx.send(LogMessage::Request(config).into())
+        .expect("Failed to send LogMessage::Request");
 }
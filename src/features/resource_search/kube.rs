@@ -0,0 +1,392 @@
+mod query;
+
+use anyhow::{Context as _, Result};
+use crossbeam::channel::Sender;
+use futures::{stream, StreamExt as _};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    features::{
+        api_resources::kube::{ApiResource, ResourceTarget, SharedApiResources},
+        resource_search::message::ResourceSearchResponse,
+    },
+    kube::{apis::v1_table::Table, table::with_label_selector, KubeClientRequest},
+    logger,
+    message::Message,
+    workers::kube::{AbortWorker, SharedTargetNamespaces},
+};
+
+use query::NameMatcher;
+
+pub use query::SearchQuery;
+
+/// 表示する検索結果の上限
+const MAX_RESULTS: usize = 500;
+
+/// 同時に一覧を取得するリクエストの上限
+const MAX_CONCURRENT_REQUESTS: usize = 20;
+
+/// 検索結果の1行
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SearchResultLine {
+    pub content: String,
+    pub target: ResourceTarget,
+}
+
+/// 対象のnamespaceにあるすべてのリソースから検索する
+///
+/// API resourcesと対象のnamespaceのロックは、それぞれを複製した時点で解放する
+#[derive(Clone)]
+pub struct ResourceSearchWorker<C>
+where
+    C: KubeClientRequest,
+{
+    tx: Sender<Message>,
+    client: C,
+    shared_api_resources: SharedApiResources,
+    shared_target_namespaces: SharedTargetNamespaces,
+    query: SearchQuery,
+}
+
+impl<C: KubeClientRequest> ResourceSearchWorker<C> {
+    pub fn new(
+        tx: Sender<Message>,
+        client: C,
+        shared_api_resources: SharedApiResources,
+        shared_target_namespaces: SharedTargetNamespaces,
+        query: SearchQuery,
+    ) -> Self {
+        Self {
+            tx,
+            client,
+            shared_api_resources,
+            shared_target_namespaces,
+            query,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<C: KubeClientRequest> AbortWorker for ResourceSearchWorker<C> {
+    async fn run(&self) {
+        let kinds: Vec<ApiResource> = self
+            .shared_api_resources
+            .read()
+            .await
+            .namespaced_kinds()
+            .cloned()
+            .collect();
+
+        let namespaces = self.shared_target_namespaces.read().await.clone();
+
+        let ret = search_resources(&self.client, &kinds, &namespaces, &self.query).await;
+
+        self.tx
+            .send(ResourceSearchResponse(ret).into())
+            .expect("Failed to send ResourceSearchResponse");
+    }
+}
+
+/// 対象のnamespaceにある`kinds`のリソースから名前（あいまい検索）またはラベルセレクターに一致するものを探す
+///
+/// 一覧を取得できないリソースは無視し（すべて失敗した場合はエラー）、名前で検索した場合は一致度の高い順に並べる
+async fn search_resources<C: KubeClientRequest>(
+    client: &C,
+    kinds: &[ApiResource],
+    namespaces: &[String],
+    query: &SearchQuery,
+) -> Result<Vec<SearchResultLine>> {
+    let requests: Vec<_> = kinds
+        .iter()
+        .flat_map(|kind| {
+            namespaces
+                .iter()
+                .map(move |ns| fetch_targets(client, kind, ns, query))
+        })
+        .collect();
+
+    let results: Vec<Result<Vec<ResourceTarget>>> = stream::iter(requests)
+        .buffer_unordered(MAX_CONCURRENT_REQUESTS)
+        .collect()
+        .await;
+
+    if results.iter().all(Result::is_err) {
+        if let Some(Err(err)) = results.into_iter().next() {
+            return Err(err);
+        }
+
+        return Ok(Vec::new());
+    }
+
+    let targets: Vec<ResourceTarget> = results
+        .into_iter()
+        .filter_map(|result| {
+            result
+                .inspect_err(|err| logger!(error, "Failed to list for search: {:?}", err))
+                .ok()
+        })
+        .flatten()
+        .collect();
+
+    let mut targets = match query {
+        SearchQuery::Name(pattern) => {
+            let matcher = NameMatcher::new(pattern);
+
+            let mut scored: Vec<(i64, ResourceTarget)> = targets
+                .into_iter()
+                .filter_map(|target| matcher.score(&target.name).map(|score| (score, target)))
+                .collect();
+
+            scored.sort_by(|(a_score, a), (b_score, b)| {
+                b_score
+                    .cmp(a_score)
+                    .then_with(|| a.name.len().cmp(&b.name.len()))
+                    .then_with(|| sort_key(a).cmp(&sort_key(b)))
+            });
+
+            scored.into_iter().map(|(_, target)| target).collect()
+        }
+        SearchQuery::LabelSelector(_) => {
+            let mut targets = targets;
+            targets.sort_by(|a, b| sort_key(a).cmp(&sort_key(b)));
+            targets
+        }
+    };
+
+    targets.truncate(MAX_RESULTS);
+
+    Ok(to_lines(targets))
+}
+
+async fn fetch_targets<C: KubeClientRequest>(
+    client: &C,
+    kind: &ApiResource,
+    namespace: &str,
+    query: &SearchQuery,
+) -> Result<Vec<ResourceTarget>> {
+    let url = list_url(kind, namespace, query);
+
+    let table: Table = client
+        .table_request(&url)
+        .await
+        .with_context(|| format!("Failed to list {} in {}", kind, namespace))?;
+
+    Ok(table
+        .rows
+        .iter()
+        .filter_map(|row| ResourceTarget::from_row(kind, row))
+        .collect())
+}
+
+/// ラベルセレクターはクエリパラメータとして指定する
+fn list_url(kind: &ApiResource, namespace: &str, query: &SearchQuery) -> String {
+    let selector = match query {
+        SearchQuery::Name(_) => None,
+        SearchQuery::LabelSelector(selector) => Some(selector.as_str()),
+    };
+
+    with_label_selector(&kind.api_url_with_namespace(namespace), selector)
+}
+
+fn sort_key(target: &ResourceTarget) -> (String, &str, &str) {
+    (target.kind.kind_name(), &target.namespace, &target.name)
+}
+
+fn to_lines(targets: Vec<ResourceTarget>) -> Vec<SearchResultLine> {
+    let width = targets
+        .iter()
+        .map(|target| target.namespace.len())
+        .max()
+        .unwrap_or_default();
+
+    targets
+        .into_iter()
+        .map(|target| SearchResultLine {
+            content: format!(
+                "{:<width$}  {}/{}",
+                target.namespace,
+                target.kind.kind_name(),
+                target.name,
+                width = width
+            ),
+            target,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::bail;
+    use kube::discovery::Scope;
+    use mockall::predicate::eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use crate::{
+        features::api_resources::kube::ApiResources, kube::mock::MockTestKubeClient, mock_expect,
+    };
+
+    use super::*;
+
+    fn table(namespace: &str, names: &[&str]) -> Table {
+        serde_json::from_value(json!({
+            "columnDefinitions": [
+                {"name": "Name", "type": "string", "format": "name", "description": "", "priority": 0}
+            ],
+            "rows": names.iter().map(|name| json!({
+                "cells": [name],
+                "object": {"metadata": {"name": name, "namespace": namespace}}
+            })).collect::<Vec<_>>()
+        }))
+        .unwrap()
+    }
+
+    fn api_resources() -> ApiResources {
+        ApiResources::from(vec![
+            ApiResource::Api {
+                name: "pods".into(),
+                version: "v1".into(),
+                scope: Scope::Namespaced,
+            },
+            ApiResource::Api {
+                name: "nodes".into(),
+                version: "v1".into(),
+                scope: Scope::Cluster,
+            },
+            ApiResource::Apis {
+                name: "deployments".into(),
+                group: "apps".into(),
+                version: "v1".into(),
+                preferred_version: true,
+                scope: Scope::Namespaced,
+            },
+        ])
+    }
+
+    fn kinds() -> Vec<ApiResource> {
+        api_resources().namespaced_kinds().cloned().collect()
+    }
+
+    fn contents(lines: &[SearchResultLine]) -> Vec<&str> {
+        lines.iter().map(|line| line.content.as_str()).collect()
+    }
+
+    #[tokio::test]
+    async fn 名前の一致度が高い順に並べる() {
+        let mut client = MockTestKubeClient::new();
+
+        mock_expect!(
+            client,
+            table_request,
+            [
+                (
+                    Table,
+                    eq("api/v1/namespaces/app/pods"),
+                    Ok(table("app", &["nginx-7d9f", "redis-0"]))
+                ),
+                (Table, eq("api/v1/namespaces/web/pods"), bail!("forbidden")),
+                (
+                    Table,
+                    eq("apis/apps/v1/namespaces/app/deployments"),
+                    Ok(table("app", &["nginx", "api"]))
+                ),
+                (
+                    Table,
+                    eq("apis/apps/v1/namespaces/web/deployments"),
+                    Ok(table("web", &["ingress-nginx-controller"]))
+                )
+            ]
+        );
+
+        let actual = search_resources(
+            &client,
+            &kinds(),
+            &["app".into(), "web".into()],
+            &SearchQuery::Name("nginx".into()),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            contents(&actual),
+            vec![
+                "app  deployments.apps/nginx",
+                "app  pods/nginx-7d9f",
+                "web  deployments.apps/ingress-nginx-controller",
+            ]
+        );
+
+        assert_eq!(
+            actual[1].target,
+            ResourceTarget {
+                kind: api_resources()[0].clone(),
+                namespace: "app".into(),
+                name: "nginx-7d9f".into(),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn ラベルセレクターはクエリパラメータで指定する() {
+        let mut client = MockTestKubeClient::new();
+
+        mock_expect!(
+            client,
+            table_request,
+            [
+                (
+                    Table,
+                    eq("api/v1/namespaces/app/pods?labelSelector=app%3Dnginx%2Cenv%20in%20%28prod%29"),
+                    Ok(table("app", &["web-1", "web-0"]))
+                ),
+                (
+                    Table,
+                    eq("apis/apps/v1/namespaces/app/deployments?labelSelector=app%3Dnginx%2Cenv%20in%20%28prod%29"),
+                    Ok(table("app", &[]))
+                )
+            ]
+        );
+
+        let actual = search_resources(
+            &client,
+            &kinds(),
+            &["app".into()],
+            &SearchQuery::LabelSelector("app=nginx,env in (prod)".into()),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            contents(&actual),
+            vec!["app  pods/web-0", "app  pods/web-1"]
+        );
+    }
+
+    #[tokio::test]
+    async fn すべてのリソースの取得に失敗した場合はエラーを返す() {
+        let mut client = MockTestKubeClient::new();
+
+        mock_expect!(
+            client,
+            table_request,
+            [
+                (Table, eq("api/v1/namespaces/app/pods"), bail!("forbidden")),
+                (
+                    Table,
+                    eq("apis/apps/v1/namespaces/app/deployments"),
+                    bail!("forbidden")
+                )
+            ]
+        );
+
+        let actual = search_resources(
+            &client,
+            &kinds(),
+            &["app".into()],
+            &SearchQuery::Name("nginx".into()),
+        )
+        .await;
+
+        assert!(actual.is_err());
+    }
+}
@@ -0,0 +1,95 @@
+use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher as _};
+
+/// ラベルセレクターにのみ含まれる文字（リソース名には使えない）
+const SELECTOR_CHARS: [char; 5] = ['=', '!', '(', ')', ','];
+
+/// 検索ワード
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SearchQuery {
+    /// 名前のあいまい検索
+    Name(String),
+    /// ラベルセレクター
+    LabelSelector(String),
+}
+
+impl SearchQuery {
+    /// `-l <selector>`、またはセレクターにのみ含まれる文字を含む場合はラベルセレクターとして扱う
+    ///
+    /// 空の場合はNoneを返す
+    pub fn parse(query: &str) -> Option<Self> {
+        let query = query.trim();
+
+        if query.is_empty() {
+            return None;
+        }
+
+        if let Some(selector) = query
+            .strip_prefix("-l")
+            .filter(|rest| rest.is_empty() || rest.starts_with(char::is_whitespace))
+        {
+            let selector = selector.trim();
+
+            return (!selector.is_empty()).then(|| Self::LabelSelector(selector.to_string()));
+        }
+
+        if query.contains(SELECTOR_CHARS) {
+            Some(Self::LabelSelector(query.to_string()))
+        } else {
+            Some(Self::Name(query.to_lowercase()))
+        }
+    }
+}
+
+/// 名前のあいまい検索
+pub struct NameMatcher<'a> {
+    pattern: &'a str,
+    matcher: SkimMatcherV2,
+}
+
+impl<'a> NameMatcher<'a> {
+    pub fn new(pattern: &'a str) -> Self {
+        Self {
+            pattern,
+            matcher: SkimMatcherV2::default(),
+        }
+    }
+
+    /// 一致した場合はスコアを返す（大きいほど一致度が高い）
+    pub fn score(&self, name: &str) -> Option<i64> {
+        self.matcher.fuzzy_match(name, self.pattern)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("nginx", Some(SearchQuery::Name("nginx".into())))]
+    #[case("  NGINX ", Some(SearchQuery::Name("nginx".into())))]
+    #[case("app=nginx", Some(SearchQuery::LabelSelector("app=nginx".into())))]
+    #[case("app in (a,b)", Some(SearchQuery::LabelSelector("app in (a,b)".into())))]
+    #[case("!canary", Some(SearchQuery::LabelSelector("!canary".into())))]
+    #[case("-l app", Some(SearchQuery::LabelSelector("app".into())))]
+    #[case("-l ", None)]
+    #[case("-lb", Some(SearchQuery::Name("-lb".into())))]
+    #[case("", None)]
+    fn 検索ワードを解析する(#[case] query: &str, #[case] expected: Option<SearchQuery>) {
+        assert_eq!(SearchQuery::parse(query), expected);
+    }
+
+    #[test]
+    fn 連続して一致する名前のスコアが高い() {
+        let matcher = NameMatcher::new("ngx");
+
+        let contiguous = matcher.score("ngx-proxy").unwrap();
+        let scattered = matcher.score("nginx").unwrap();
+
+        assert!(scattered < contiguous);
+
+        assert_eq!(matcher.score("redis"), None);
+    }
+}
@@ -0,0 +1,48 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    message::Message,
+    workers::{kube::message::Kube, replay::serde_result},
+};
+
+use super::kube::{SearchQuery, SearchResultLine};
+
+#[derive(Debug)]
+pub struct ResourceSearchRequest(pub SearchQuery);
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ResourceSearchResponse(
+    #[serde(with = "serde_result")] pub Result<Vec<SearchResultLine>>,
+);
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum ResourceSearchMessage {
+    #[serde(skip)]
+    Request(ResourceSearchRequest),
+    Response(ResourceSearchResponse),
+}
+
+impl From<ResourceSearchRequest> for Message {
+    fn from(req: ResourceSearchRequest) -> Self {
+        Message::Kube(Kube::ResourceSearch(ResourceSearchMessage::Request(req)))
+    }
+}
+
+impl From<ResourceSearchResponse> for Message {
+    fn from(res: ResourceSearchResponse) -> Self {
+        Message::Kube(Kube::ResourceSearch(ResourceSearchMessage::Response(res)))
+    }
+}
+
+impl From<ResourceSearchMessage> for Kube {
+    fn from(m: ResourceSearchMessage) -> Self {
+        Self::ResourceSearch(m)
+    }
+}
+
+impl From<ResourceSearchMessage> for Message {
+    fn from(m: ResourceSearchMessage) -> Self {
+        Self::Kube(m.into())
+    }
+}
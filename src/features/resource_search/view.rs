@@ -0,0 +1,3 @@
+mod popups;
+
+pub use popups::*;
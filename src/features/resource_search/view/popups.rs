@@ -0,0 +1,195 @@
+use crossbeam::channel::Sender;
+use ratatui::crossterm::event::KeyCode;
+
+use crate::{
+    features::{
+        api_resources::kube::ResourceTarget,
+        component_id::{
+            NETWORK_DESCRIPTION_WIDGET_ID, NETWORK_TAB_ID, POD_LOG_WIDGET_ID, POD_TAB_ID,
+            RESOURCE_SEARCH_POPUP_ID, RESOURCE_SEARCH_RESULT_POPUP_ID,
+        },
+        network::view::{request_description, DescriptionTarget, SharedDescriptionTarget},
+        pod::view::request_pod_log,
+        resource_search::{kube::SearchQuery, message::ResourceSearchRequest},
+        yaml::{
+            message::YamlTarget,
+            view::{show_yaml, SharedYamlTarget},
+        },
+    },
+    message::{Message, UserEvent},
+    ui::{
+        event::EventResult,
+        widget::{
+            config::WidgetConfig, input::InputFormBuilder, List, LiteralItem, SelectedItem, Widget,
+            WidgetTrait as _,
+        },
+        Window,
+    },
+};
+
+pub struct ResourceSearchPopup {
+    pub popup: Widget<'static>,
+    pub result_popup: Widget<'static>,
+}
+
+impl ResourceSearchPopup {
+    pub fn new(
+        tx: &Sender<Message>,
+        yaml_target: &SharedYamlTarget,
+        description_target: &SharedDescriptionTarget,
+    ) -> Self {
+        let popup = InputFormBuilder::default()
+            .id(RESOURCE_SEARCH_POPUP_ID)
+            .widget_config(
+                WidgetConfig::builder()
+                    .title("Search: <name> | <label selector>")
+                    .build(),
+            )
+            .actions(UserEvent::from(KeyCode::Enter), search(tx.clone()))
+            .build()
+            .into();
+
+        let result_popup = List::builder()
+            .id(RESOURCE_SEARCH_RESULT_POPUP_ID)
+            .widget_config(&WidgetConfig::builder().title("Search Result").build())
+            .on_select(open_yaml(tx.clone(), yaml_target.clone()))
+            .action(
+                'd',
+                open_description(tx.clone(), description_target.clone()),
+            )
+            .action('l', open_log(tx.clone()))
+            .build()
+            .into();
+
+        Self {
+            popup,
+            result_popup,
+        }
+    }
+}
+
+pub fn open_resource_search_popup(w: &mut Window) -> EventResult {
+    w.open_popup(RESOURCE_SEARCH_POPUP_ID);
+    EventResult::Nop
+}
+
+fn search(tx: Sender<Message>) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let Some(SelectedItem::Literal { item: query, .. }) =
+            w.find_widget(RESOURCE_SEARCH_POPUP_ID).widget_item()
+        else {
+            return EventResult::Ignore;
+        };
+
+        let Some(query) = SearchQuery::parse(&query) else {
+            return EventResult::Nop;
+        };
+
+        w.close_popup();
+
+        let title = match &query {
+            SearchQuery::Name(name) => format!(" : {}", name),
+            SearchQuery::LabelSelector(selector) => format!(" : -l {}", selector),
+        };
+
+        *(w.find_widget_mut(RESOURCE_SEARCH_RESULT_POPUP_ID)
+            .widget_config_mut()
+            .append_title_mut()) = Some(title.into());
+
+        w.widget_clear(RESOURCE_SEARCH_RESULT_POPUP_ID);
+        w.open_popup(RESOURCE_SEARCH_RESULT_POPUP_ID);
+
+        tx.send(ResourceSearchRequest(query).into())
+            .expect("Failed to send ResourceSearchRequest");
+
+        EventResult::Nop
+    }
+}
+
+fn selected_target(w: &Window) -> Option<ResourceTarget> {
+    let Some(SelectedItem::Literal {
+        metadata: Some(metadata),
+        ..
+    }) = w.find_widget(RESOURCE_SEARCH_RESULT_POPUP_ID).widget_item()
+    else {
+        return None;
+    };
+
+    ResourceTarget::from_metadata(&metadata)
+}
+
+fn open_yaml(
+    tx: Sender<Message>,
+    yaml_target: SharedYamlTarget,
+) -> impl Fn(&mut Window, &LiteralItem) -> EventResult {
+    move |w: &mut Window, v: &LiteralItem| {
+        let Some(ResourceTarget {
+            kind,
+            namespace,
+            name,
+        }) = v.metadata.as_ref().and_then(ResourceTarget::from_metadata)
+        else {
+            return EventResult::Ignore;
+        };
+
+        w.close_popup();
+
+        show_yaml(
+            w,
+            &tx,
+            &yaml_target,
+            YamlTarget {
+                kind,
+                name,
+                namespace,
+            },
+        );
+
+        EventResult::Nop
+    }
+}
+
+/// Networkタブで説明を表示できるリソースの場合のみ
+fn open_description(
+    tx: Sender<Message>,
+    description_target: SharedDescriptionTarget,
+) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let Some(target) = selected_target(w).and_then(|target| {
+            DescriptionTarget::from_api_resource(&target.kind, &target.namespace, &target.name)
+        }) else {
+            return EventResult::Ignore;
+        };
+
+        w.close_popup();
+
+        w.activate_tab_by_id(NETWORK_TAB_ID);
+
+        request_description(w, &tx, &description_target, target);
+
+        w.activate_widget_by_id(NETWORK_DESCRIPTION_WIDGET_ID);
+
+        EventResult::Nop
+    }
+}
+
+/// Podの場合のみ
+fn open_log(tx: Sender<Message>) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let Some(target) = selected_target(w)
+            .filter(|target| target.kind.is_api() && target.kind.name() == "pods")
+        else {
+            return EventResult::Ignore;
+        };
+
+        w.close_popup();
+
+        w.activate_tab_by_id(POD_TAB_ID);
+
+        request_pod_log(w, &tx, &target.namespace, &target.name);
+
+        w.activate_widget_by_id(POD_LOG_WIDGET_ID);
+
+        EventResult::Nop
+    }
+}
@@ -1,12 +1,15 @@
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    features::api_resources::kube::ApiResource, message::Message, workers::kube::message::Kube,
+    features::api_resources::kube::ApiResource,
+    message::Message,
+    workers::{kube::message::Kube, replay::serde_result},
 };
 
 pub use super::kube::YamlTarget;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct YamlResourceListItem {
     pub kind: ApiResource,
     pub name: String,
@@ -14,7 +17,7 @@ pub struct YamlResourceListItem {
     pub value: String,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct YamlResourceList {
     pub items: Vec<YamlResourceListItem>,
 }
@@ -38,11 +41,11 @@ impl From<YamlRequest> for Message {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum YamlResponse {
-    APIs(Result<Vec<ApiResource>>),
-    Resource(Result<YamlResourceList>),
-    Yaml(Result<Vec<String>>),
+    APIs(#[serde(with = "serde_result")] Result<Vec<ApiResource>>),
+    Resource(#[serde(with = "serde_result")] Result<YamlResourceList>),
+    Yaml(#[serde(with = "serde_result")] Result<Vec<String>>),
 }
 
 impl From<YamlResponse> for Message {
@@ -51,8 +54,9 @@ impl From<YamlResponse> for Message {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum YamlMessage {
+    #[serde(skip)]
     Request(YamlRequest),
     Response(YamlResponse),
 }
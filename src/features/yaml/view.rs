@@ -3,3 +3,4 @@ mod tab;
 mod widget;
 
 pub use tab::*;
+pub use widget::{show_yaml, SharedYamlTarget};
@@ -4,7 +4,10 @@ use ratatui::crossterm::event::KeyCode;
 use crate::{
     features::{
         component_id::{YAML_KIND_POPUP_ID, YAML_NAME_POPUP_ID},
-        yaml::message::{YamlRequest, YamlTarget},
+        yaml::{
+            message::{YamlRequest, YamlTarget},
+            view::SharedYamlTarget,
+        },
     },
     logger,
     message::Message,
@@ -15,19 +18,22 @@ use crate::{
     },
 };
 
-pub fn name_popup(tx: &Sender<Message>) -> Widget<'static> {
+pub fn name_popup(tx: &Sender<Message>, target: &SharedYamlTarget) -> Widget<'static> {
     let tx = tx.clone();
 
     SingleSelect::builder()
         .id(YAML_NAME_POPUP_ID)
         .widget_config(&WidgetConfig::builder().title("Name").build())
-        .on_select(on_select(tx))
+        .on_select(on_select(tx, target.clone()))
         .action(KeyCode::Esc, open_kind_popup())
         .build()
         .into()
 }
 
-fn on_select(tx: Sender<Message>) -> impl Fn(&mut Window, &LiteralItem) -> EventResult {
+fn on_select(
+    tx: Sender<Message>,
+    target: SharedYamlTarget,
+) -> impl Fn(&mut Window, &LiteralItem) -> EventResult {
     move |w, v| {
         logger!(info, "Select Item: {:?}", v);
 
@@ -53,15 +59,16 @@ fn on_select(tx: Sender<Message>) -> impl Fn(&mut Window, &LiteralItem) -> Event
             unreachable!()
         };
 
-        tx.send(
-            YamlRequest::Yaml(YamlTarget {
-                kind,
-                name: name.to_string(),
-                namespace: namespace.to_string(),
-            })
-            .into(),
-        )
-        .expect("Failed to send YamlRequest::Yaml");
+        let yaml_target = YamlTarget {
+            kind,
+            name: name.to_string(),
+            namespace: namespace.to_string(),
+        };
+
+        *target.borrow_mut() = Some(yaml_target.clone());
+
+        tx.send(YamlRequest::Yaml(yaml_target).into())
+            .expect("Failed to send YamlRequest::Yaml");
 
         EventResult::Nop
     }
@@ -16,7 +16,7 @@ use crate::{
 
 use super::{
     popups::{kind::kind_popup, name::name_popup, not_found::not_found_popup},
-    widget::yaml_widget,
+    widget::{yaml_widget, SharedYamlTarget},
 };
 
 pub struct YamlTab {
@@ -31,8 +31,9 @@ impl YamlTab {
         title: &'static str,
         tx: &Sender<Message>,
         clipboard: &Option<Rc<RefCell<Clipboard>>>,
+        target: &SharedYamlTarget,
     ) -> Self {
-        let yaml_widget = yaml_widget(tx, clipboard);
+        let yaml_widget = yaml_widget(tx, clipboard, target);
 
         let layout = NestedWidgetLayout::default().nested_widget_layout([NestedLayoutElement(
             Constraint::Percentage(100),
@@ -42,7 +43,7 @@ impl YamlTab {
         YamlTab {
             tab: Tab::new(YAML_TAB_ID, title, [yaml_widget], layout),
             kind_popup: kind_popup(tx),
-            name_popup: name_popup(tx),
+            name_popup: name_popup(tx, target),
             not_found_popup: not_found_popup(),
         }
     }
@@ -6,8 +6,10 @@ use ratatui::widgets::Block;
 use crate::{
     clipboard::Clipboard,
     features::{
-        component_id::{YAML_KIND_POPUP_ID, YAML_WIDGET_ID},
-        yaml::message::YamlRequest,
+        api_resources::kube::ResourceTarget,
+        component_id::{YAML_KIND_POPUP_ID, YAML_TAB_ID, YAML_WIDGET_ID},
+        owner_tree::view::open_owner_tree_popup,
+        yaml::message::{YamlRequest, YamlTarget},
     },
     message::Message,
     ui::{
@@ -17,17 +19,20 @@ use crate::{
     },
 };
 
+/// Yamlタブに表示しているリソース
+pub type SharedYamlTarget = Rc<RefCell<Option<YamlTarget>>>;
+
 pub fn yaml_widget(
     tx: &Sender<Message>,
     clipboard: &Option<Rc<RefCell<Clipboard>>>,
+    target: &SharedYamlTarget,
 ) -> Widget<'static> {
-    let tx = tx.clone();
-
     let builder = Text::builder()
         .id(YAML_WIDGET_ID)
         .widget_config(&WidgetConfig::builder().title("Yaml").build())
         .block_injection(block_injection())
-        .action('f', open_kind_popup(tx))
+        .action('f', open_kind_popup(tx.clone()))
+        .action('t', open_owner_tree(tx.clone(), target.clone()))
         .wrap();
 
     if let Some(cb) = clipboard {
@@ -39,6 +44,21 @@ pub fn yaml_widget(
     .into()
 }
 
+/// Yamlタブに切り替えてリソースのYAMLを表示する
+pub fn show_yaml(
+    w: &mut Window,
+    tx: &Sender<Message>,
+    shared_target: &SharedYamlTarget,
+    target: YamlTarget,
+) {
+    *shared_target.borrow_mut() = Some(target.clone());
+
+    tx.send(YamlRequest::Yaml(target).into())
+        .expect("Failed to send YamlRequest::Yaml");
+
+    w.activate_tab_by_id(YAML_TAB_ID);
+}
+
 fn open_kind_popup(tx: Sender<Message>) -> impl Fn(&mut Window) -> EventResult {
     move |w: &mut Window| {
         tx.send(YamlRequest::APIs.into())
@@ -48,6 +68,34 @@ fn open_kind_popup(tx: Sender<Message>) -> impl Fn(&mut Window) -> EventResult {
     }
 }
 
+fn open_owner_tree(
+    tx: Sender<Message>,
+    target: SharedYamlTarget,
+) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let Some(YamlTarget {
+            kind,
+            name,
+            namespace,
+        }) = target.borrow().clone()
+        else {
+            return EventResult::Ignore;
+        };
+
+        open_owner_tree_popup(
+            w,
+            &tx,
+            ResourceTarget {
+                kind,
+                namespace,
+                name,
+            },
+        );
+
+        EventResult::Nop
+    }
+}
+
 fn block_injection() -> impl Fn(&Text, bool, bool) -> Block<'static> {
     |text: &Text, is_active: bool, is_mouse_over: bool| {
         let (index, size) = text.state();
@@ -0,0 +1,323 @@
+use std::{
+    cell::RefCell,
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{kube::context::Context, logger};
+
+/// 種類ごとに保持する履歴の最大数
+const MAX_ENTRIES: usize = 100;
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+struct ContextHistory {
+    #[serde(default)]
+    entries: BTreeMap<String, Vec<String>>,
+    #[serde(default)]
+    saved_queries: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+struct HistoryData {
+    #[serde(default)]
+    contexts: BTreeMap<String, ContextHistory>,
+}
+
+/// コンテキストごとの入力履歴と名前付きで保存したクエリ
+///
+/// pathが指定されている場合、更新のたびにファイルへ書き出す
+#[derive(Debug, Default)]
+pub struct History {
+    path: Option<PathBuf>,
+    data: HistoryData,
+}
+
+impl History {
+    /// ファイルから読み込む
+    ///
+    /// ファイルが存在しない、または読み込めない場合は空の履歴を返す
+    pub fn load(path: Option<PathBuf>) -> Self {
+        let data = path
+            .as_deref()
+            .filter(|path| path.exists())
+            .and_then(|path| match Self::read(path) {
+                Ok(data) => Some(data),
+                Err(err) => {
+                    logger!(error, "Failed to read {}: {}", path.display(), err);
+                    None
+                }
+            })
+            .unwrap_or_default();
+
+        Self { path, data }
+    }
+
+    /// `$XDG_STATE_HOME/kubetui/history.json`
+    ///
+    /// XDG_STATE_HOMEが未設定の場合は`~/.local/state`を使う
+    pub fn default_path() -> Option<PathBuf> {
+        let state_home = std::env::var_os("XDG_STATE_HOME")
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| {
+                std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/state"))
+            })?;
+
+        Some(state_home.join("kubetui").join("history.json"))
+    }
+
+    fn read(path: &Path) -> Result<HistoryData> {
+        let contents = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    fn write(&self) {
+        let Some(path) = &self.path else {
+            return;
+        };
+
+        let result = (|| -> Result<()> {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+
+            fs::write(path, serde_json::to_string_pretty(&self.data)?)?;
+
+            Ok(())
+        })();
+
+        if let Err(err) = result {
+            logger!(error, "Failed to write {}: {}", path.display(), err);
+        }
+    }
+
+    /// 古い順に並んだ履歴
+    pub fn entries(&self, context: &str, kind: &str) -> Vec<String> {
+        self.data
+            .contexts
+            .get(context)
+            .and_then(|history| history.entries.get(kind))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// 履歴の末尾に追加する
+    ///
+    /// 同じ内容がすでにある場合は末尾に移動する
+    pub fn push(&mut self, context: &str, kind: &str, entry: &str) {
+        if entry.trim().is_empty() {
+            return;
+        }
+
+        let entries = self
+            .data
+            .contexts
+            .entry(context.to_string())
+            .or_default()
+            .entries
+            .entry(kind.to_string())
+            .or_default();
+
+        entries.retain(|e| e != entry);
+        entries.push(entry.to_string());
+
+        if MAX_ENTRIES < entries.len() {
+            entries.drain(..entries.len() - MAX_ENTRIES);
+        }
+
+        self.write();
+    }
+
+    /// 名前順に並んだ保存済みのクエリ
+    pub fn saved_queries(&self, context: &str) -> Vec<(String, String)> {
+        self.data
+            .contexts
+            .get(context)
+            .map(|history| {
+                history
+                    .saved_queries
+                    .iter()
+                    .map(|(name, query)| (name.clone(), query.clone()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// 同じ名前のクエリがある場合は上書きする
+    pub fn save_query(&mut self, context: &str, name: &str, query: &str) {
+        self.data
+            .contexts
+            .entry(context.to_string())
+            .or_default()
+            .saved_queries
+            .insert(name.to_string(), query.to_string());
+
+        self.write();
+    }
+
+    pub fn remove_query(&mut self, context: &str, name: &str) {
+        let Some(history) = self.data.contexts.get_mut(context) else {
+            return;
+        };
+
+        if history.saved_queries.remove(name).is_some() {
+            self.write();
+        }
+    }
+}
+
+/// 現在のコンテキストの履歴を読み書きするためのハンドル
+#[derive(Debug, Clone)]
+pub struct HistoryHandle {
+    history: Rc<RefCell<History>>,
+    context: Rc<RefCell<Context>>,
+}
+
+impl HistoryHandle {
+    pub fn new(history: Rc<RefCell<History>>, context: Rc<RefCell<Context>>) -> Self {
+        Self { history, context }
+    }
+
+    pub fn entries(&self, kind: &str) -> Vec<String> {
+        self.history
+            .borrow()
+            .entries(&self.context.borrow().to_string(), kind)
+    }
+
+    pub fn push(&self, kind: &str, entry: &str) {
+        self.history
+            .borrow_mut()
+            .push(&self.context.borrow().to_string(), kind, entry)
+    }
+
+    pub fn saved_queries(&self) -> Vec<(String, String)> {
+        self.history
+            .borrow()
+            .saved_queries(&self.context.borrow().to_string())
+    }
+
+    pub fn save_query(&self, name: &str, query: &str) {
+        self.history
+            .borrow_mut()
+            .save_query(&self.context.borrow().to_string(), name, query)
+    }
+
+    pub fn remove_query(&self, name: &str) {
+        self.history
+            .borrow_mut()
+            .remove_query(&self.context.borrow().to_string(), name)
+    }
+
+    /// 入力フォームに渡す履歴の読み込み処理
+    pub fn loader(&self, kind: &'static str) -> impl Fn() -> Vec<String> {
+        let handle = self.clone();
+        move || handle.entries(kind)
+    }
+
+    /// 入力フォームに渡す履歴の追加処理
+    pub fn saver(&self, kind: &'static str) -> impl Fn(&str) {
+        let handle = self.clone();
+        move |entry: &str| handle.push(kind, entry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn 同じ内容を追加すると末尾に移動する() {
+        let mut history = History::default();
+
+        history.push("ctx", "log_query", "pod:a");
+        history.push("ctx", "log_query", "pod:b");
+        history.push("ctx", "log_query", "pod:a");
+
+        assert_eq!(history.entries("ctx", "log_query"), vec!["pod:b", "pod:a"]);
+    }
+
+    #[test]
+    fn 空白のみの入力は追加しない() {
+        let mut history = History::default();
+
+        history.push("ctx", "log_query", "  ");
+
+        assert!(history.entries("ctx", "log_query").is_empty());
+    }
+
+    #[test]
+    fn 上限を超えると古いものから削除する() {
+        let mut history = History::default();
+
+        for i in 0..MAX_ENTRIES + 2 {
+            history.push("ctx", "log_query", &i.to_string());
+        }
+
+        let entries = history.entries("ctx", "log_query");
+
+        assert_eq!(entries.len(), MAX_ENTRIES);
+        assert_eq!(entries.first().map(String::as_str), Some("2"));
+    }
+
+    #[test]
+    fn 履歴はコンテキストと種類ごとに分かれる() {
+        let mut history = History::default();
+
+        history.push("a", "log_query", "pod:a");
+        history.push("b", "log_query", "pod:b");
+        history.push("a", "pod_filter", "nginx");
+
+        assert_eq!(history.entries("a", "log_query"), vec!["pod:a"]);
+        assert_eq!(history.entries("b", "log_query"), vec!["pod:b"]);
+        assert_eq!(history.entries("a", "pod_filter"), vec!["nginx"]);
+    }
+
+    #[test]
+    fn クエリを名前付きで保存して削除できる() {
+        let mut history = History::default();
+
+        history.save_query("ctx", "errors", "level:>=error");
+        history.save_query("ctx", "api", "deploy/api");
+        history.save_query("ctx", "errors", "level:>=warn");
+
+        assert_eq!(
+            history.saved_queries("ctx"),
+            vec![
+                ("api".to_string(), "deploy/api".to_string()),
+                ("errors".to_string(), "level:>=warn".to_string()),
+            ]
+        );
+
+        history.remove_query("ctx", "api");
+
+        assert_eq!(
+            history.saved_queries("ctx"),
+            vec![("errors".to_string(), "level:>=warn".to_string())]
+        );
+    }
+
+    #[test]
+    fn ファイルに書き出した内容を読み込める() {
+        let path = std::env::temp_dir().join(format!(
+            "kubetui-history-test-{}/history.json",
+            std::process::id()
+        ));
+
+        let mut history = History::load(Some(path.clone()));
+        history.push("ctx", "log_query", "pod:a");
+        history.save_query("ctx", "a", "pod:a");
+
+        let loaded = History::load(Some(path.clone()));
+
+        assert_eq!(loaded.data, history.data);
+
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+}
@@ -1,6 +1,8 @@
 pub mod apis;
 mod client;
 pub mod context;
+#[cfg(test)]
+pub mod fake_api_server;
 pub mod table;
 
 pub use client::*;
@@ -8,3 +8,6 @@ pub use gateways::*;
 mod httproutes;
 pub use httproutes::*;
 
+mod grpcroutes;
+pub use grpcroutes::*;
+
@@ -10,7 +10,7 @@ use serde::de::DeserializeOwned;
 
 use crate::logger;
 
-use super::apis::v1_table::Table;
+use super::{apis::v1_table::Table, table::with_label_selector};
 
 const TABLE_REQUEST_HEADER: &str = "application/json;as=Table;v=v1;g=meta.k8s.io,application/json;as=Table;v=v1beta1;g=meta.k8s.io,application/json";
 
@@ -77,7 +77,11 @@ impl KubeClient {
 pub trait KubeClientRequest: Send + Sync {
     async fn table_request<T: DeserializeOwned + 'static>(&self, path: &str) -> Result<T>;
 
-    async fn table_namespaced<K>(&self, namespace: &str) -> Result<Table>
+    async fn table_namespaced<K>(
+        &self,
+        namespace: &str,
+        label_selector: Option<String>,
+    ) -> Result<Table>
     where
         K: Resource<DynamicType = (), Scope = NamespaceResourceScope> + 'static;
 
@@ -94,14 +98,21 @@ impl KubeClientRequest for KubeClient {
         self.inner_request(path, TABLE_REQUEST_HEADER).await
     }
 
-    async fn table_namespaced<K>(&self, namespace: &str) -> Result<Table>
+    async fn table_namespaced<K>(
+        &self,
+        namespace: &str,
+        label_selector: Option<String>,
+    ) -> Result<Table>
     where
         K: Resource<DynamicType = (), Scope = NamespaceResourceScope> + 'static,
     {
         let api: Api<K> = Api::namespaced(self.client.clone(), namespace);
 
-        self.inner_request(api.resource_url(), TABLE_REQUEST_HEADER)
-            .await
+        self.inner_request(
+            &with_label_selector(api.resource_url(), label_selector.as_deref()),
+            TABLE_REQUEST_HEADER,
+        )
+        .await
     }
 
     async fn request<T: DeserializeOwned + 'static>(&self, path: &str) -> Result<T> {
@@ -145,7 +156,7 @@ pub mod mock {
         #[async_trait::async_trait]
         impl KubeClientRequest for TestKubeClient {
             async fn table_request<T: DeserializeOwned + 'static>(&self, path: &str) -> Result<T>;
-            async fn table_namespaced<K: Resource<DynamicType=(), Scope = NamespaceResourceScope> + 'static>(&self, ns: &str) -> Result<Table>;
+            async fn table_namespaced<K: Resource<DynamicType=(), Scope = NamespaceResourceScope> + 'static>(&self, ns: &str, label_selector: Option<String>) -> Result<Table>;
             async fn request<T: DeserializeOwned + 'static>(&self, path: &str) -> Result<T>;
             async fn request_text(&self, path: &str) -> Result<String>;
             fn client(&self) -> &kube::Client;
@@ -0,0 +1,1009 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    convert::Infallible,
+    io,
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+};
+
+use anyhow::{Context as _, Result};
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use futures::{
+    future::{self, Ready},
+    stream::{self, BoxStream},
+    StreamExt as _,
+};
+use http::{header::ACCEPT, Request, Response, StatusCode};
+use http_body::Frame;
+use http_body_util::StreamBody;
+use kube::Client;
+use serde::Deserialize;
+use serde_json::{json, Value as JsonValue};
+use tower_service::Service;
+
+use super::KubeClient;
+
+/// `KubeClient`に設定するサーバーのURL（リクエストはプロセス内で処理するため接続はしない）
+const FAKE_SERVER_URL: &str = "http://fake-api-server";
+
+type FakeBody = StreamBody<BoxStream<'static, Result<Frame<Bytes>, Infallible>>>;
+
+/// フィクスチャの形式
+///
+/// ```yaml
+/// resources:
+///   - apiVersion: v1
+///     kind: Pod
+///     metadata:
+///       name: app
+///       namespace: default
+/// logs:
+///   # <namespace>/<pod>/<container>: タイムスタンプ付きのログ
+///   default/app/nginx:
+///     - 2024-01-01T00:00:00Z hello
+/// # 403を返すリソース（複数形）
+/// forbidden:
+///   - secrets
+/// ```
+#[derive(Debug, Default, Deserialize)]
+struct Fixture {
+    #[serde(default)]
+    resources: Vec<JsonValue>,
+    #[serde(default)]
+    logs: BTreeMap<String, Vec<String>>,
+    #[serde(default)]
+    forbidden: BTreeSet<String>,
+}
+
+#[derive(Debug, Clone)]
+struct FakeResource {
+    group: String,
+    version: String,
+    kind: String,
+    plural: String,
+    namespace: Option<String>,
+    name: String,
+    object: JsonValue,
+}
+
+impl FakeResource {
+    fn try_from_value(object: JsonValue) -> Result<Self> {
+        let api_version = object["apiVersion"]
+            .as_str()
+            .context("apiVersion is required")?;
+
+        let (group, version) = api_version.rsplit_once('/').unwrap_or(("", api_version));
+
+        let kind = object["kind"].as_str().context("kind is required")?;
+
+        let name = object["metadata"]["name"]
+            .as_str()
+            .context("metadata.name is required")?;
+
+        Ok(Self {
+            group: group.to_string(),
+            version: version.to_string(),
+            kind: kind.to_string(),
+            plural: plural(kind),
+            namespace: object["metadata"]["namespace"].as_str().map(String::from),
+            name: name.to_string(),
+            object,
+        })
+    }
+
+    fn label(&self, key: &str) -> Option<&str> {
+        self.object["metadata"]["labels"][key].as_str()
+    }
+}
+
+fn plural(kind: &str) -> String {
+    let kind = kind.to_lowercase();
+
+    if kind == "endpoints" {
+        kind
+    } else if kind.ends_with("ay") || kind.ends_with("ey") {
+        format!("{}s", kind)
+    } else if let Some(stem) = kind.strip_suffix('y') {
+        format!("{}ies", stem)
+    } else if kind.ends_with('s') {
+        format!("{}es", kind)
+    } else {
+        format!("{}s", kind)
+    }
+}
+
+/// テスト用のKubernetes APIサーバー
+///
+/// フィクスチャに定義したリソースとログを保持し、`kube::Client`のリクエストをプロセス内で処理する。
+/// Table、list、get、watch、logとディスカバリーのエンドポイントに対応する。
+#[derive(Debug, Clone)]
+pub struct FakeApiServer {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    resources: Vec<FakeResource>,
+    logs: BTreeMap<String, Vec<String>>,
+    /// 403を返すリソース（複数形）
+    forbidden: BTreeSet<String>,
+    /// `false`の場合は接続を拒否する
+    is_available: AtomicBool,
+}
+
+impl FakeApiServer {
+    /// `test/fixtures`配下のフィクスチャを読み込む
+    pub fn from_fixture(name: &str) -> Self {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("test/fixtures")
+            .join(name);
+
+        let yaml = std::fs::read_to_string(&path)
+            .unwrap_or_else(|err| panic!("Failed to read {}: {}", path.display(), err));
+
+        Self::from_yaml(&yaml)
+            .unwrap_or_else(|err| panic!("Invalid fixture {}: {:#}", path.display(), err))
+    }
+
+    pub fn from_yaml(yaml: &str) -> Result<Self> {
+        let Fixture {
+            resources,
+            logs,
+            forbidden,
+        } = serde_yaml::from_str(yaml)?;
+
+        let mut resources = resources
+            .into_iter()
+            .map(FakeResource::try_from_value)
+            .collect::<Result<Vec<_>>>()?;
+
+        // フィクスチャに定義していないnamespaceも存在するものとして扱う
+        let namespaces: BTreeSet<String> = resources
+            .iter()
+            .filter_map(|r| r.namespace.clone())
+            .chain(["default".to_string()])
+            .filter(|ns| {
+                !resources
+                    .iter()
+                    .any(|r| r.kind == "Namespace" && &r.name == ns)
+            })
+            .collect();
+
+        for ns in namespaces {
+            resources.push(FakeResource::try_from_value(json!({
+                "apiVersion": "v1",
+                "kind": "Namespace",
+                "metadata": { "name": ns },
+                "status": { "phase": "Active" },
+            }))?);
+        }
+
+        Ok(Self {
+            inner: Arc::new(Inner {
+                resources,
+                logs,
+                forbidden,
+                is_available: AtomicBool::new(true),
+            }),
+        })
+    }
+
+    /// APIサーバーの停止と復旧を再現する
+    pub fn set_available(&self, is_available: bool) {
+        self.inner
+            .is_available
+            .store(is_available, Ordering::Relaxed);
+    }
+
+    pub fn client(&self) -> KubeClient {
+        KubeClient::new(Client::new(self.clone(), "default"), FAKE_SERVER_URL)
+    }
+
+    fn handle(&self, req: &Request<kube::client::Body>) -> Response<FakeBody> {
+        let path = req.uri().path();
+
+        let query = Query::parse(req.uri().query().unwrap_or_default());
+
+        let as_table = req
+            .headers()
+            .get(ACCEPT)
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|v| v.contains("as=Table"));
+
+        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+
+        let (group, version, rest) = match segments.as_slice() {
+            ["api"] => return json_response(StatusCode::OK, &self.api_versions()),
+            ["apis"] => return json_response(StatusCode::OK, &self.api_group_list()),
+            ["api", version, rest @ ..] => ("", *version, rest),
+            ["apis", group, version, rest @ ..] => (*group, *version, rest),
+            _ => return not_found(path),
+        };
+
+        let route = match rest {
+            [] => return json_response(StatusCode::OK, &self.api_resource_list(group, version)),
+            [plural] => Route::List {
+                namespace: None,
+                plural,
+            },
+            ["namespaces", ns, plural] => Route::List {
+                namespace: Some(ns),
+                plural,
+            },
+            [plural, name] => Route::Get {
+                namespace: None,
+                plural,
+                name,
+            },
+            ["namespaces", ns, plural, name] => Route::Get {
+                namespace: Some(ns),
+                plural,
+                name,
+            },
+            ["namespaces", ns, "pods", name, "log"] => {
+                return self.log(ns, name, &query);
+            }
+            _ => return not_found(path),
+        };
+
+        let plural = match route {
+            Route::List { plural, .. } | Route::Get { plural, .. } => plural,
+        };
+
+        if self.inner.forbidden.contains(plural) {
+            return forbidden(path);
+        }
+
+        match route {
+            Route::List { namespace, plural } => {
+                let items: Vec<&FakeResource> = self
+                    .inner
+                    .resources
+                    .iter()
+                    .filter(|r| r.group == group && r.version == version && r.plural == plural)
+                    .filter(|r| namespace.is_none() || r.namespace.as_deref() == namespace)
+                    .filter(|r| query.matches(r))
+                    .collect();
+
+                if query.is_watch() {
+                    watch_response(&items)
+                } else if as_table {
+                    json_response(StatusCode::OK, &table(plural, &items, Utc::now()))
+                } else {
+                    json_response(StatusCode::OK, &list(&items))
+                }
+            }
+            Route::Get {
+                namespace,
+                plural,
+                name,
+            } => self
+                .inner
+                .resources
+                .iter()
+                .find(|r| {
+                    r.group == group
+                        && r.version == version
+                        && r.plural == plural
+                        && r.namespace.as_deref() == namespace
+                        && r.name == name
+                })
+                .map_or_else(
+                    || not_found(path),
+                    |r| json_response(StatusCode::OK, &r.object),
+                ),
+        }
+    }
+
+    fn log(&self, namespace: &str, pod: &str, query: &Query) -> Response<FakeBody> {
+        let prefix = format!("{}/{}/", namespace, pod);
+
+        let lines = match query.get("container") {
+            Some(container) => self.inner.logs.get(&format!("{}{}", prefix, container)),
+            None => self
+                .inner
+                .logs
+                .iter()
+                .find_map(|(key, lines)| key.starts_with(&prefix).then_some(lines)),
+        };
+
+        let Some(lines) = lines else {
+            return not_found(&format!("{}log", prefix));
+        };
+
+        let since_time = query
+            .get("sinceTime")
+            .and_then(|t| DateTime::parse_from_rfc3339(t).ok());
+
+        let timestamps = query.get("timestamps") == Some("true");
+
+        let chunks: Vec<Bytes> = lines
+            .iter()
+            .filter_map(|line| {
+                let (timestamp, content) = line.split_once(' ').unwrap_or((line, ""));
+
+                let timestamp = DateTime::parse_from_rfc3339(timestamp).ok();
+
+                if since_time.is_some_and(|since| timestamp.is_some_and(|ts| ts <= since)) {
+                    return None;
+                }
+
+                let line = if timestamps { line.as_str() } else { content };
+
+                Some(Bytes::from(format!("{}\n", line)))
+            })
+            .collect();
+
+        if query.get("follow") == Some("true") {
+            streaming_response(chunks)
+        } else {
+            let body: Vec<u8> = chunks.concat();
+
+            response(StatusCode::OK, Bytes::from(body))
+        }
+    }
+
+    fn api_versions(&self) -> JsonValue {
+        json!({
+            "kind": "APIVersions",
+            "versions": ["v1"],
+            "serverAddressByClientCIDRs": [],
+        })
+    }
+
+    fn group_versions(&self) -> BTreeMap<&str, BTreeSet<&str>> {
+        let mut ret: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+
+        for r in self.inner.resources.iter().filter(|r| !r.group.is_empty()) {
+            ret.entry(&r.group).or_default().insert(&r.version);
+        }
+
+        ret
+    }
+
+    fn api_group_list(&self) -> JsonValue {
+        let groups: Vec<JsonValue> = self
+            .group_versions()
+            .into_iter()
+            .map(|(group, versions)| {
+                let versions: Vec<JsonValue> = versions
+                    .into_iter()
+                    .map(|version| {
+                        json!({
+                            "groupVersion": format!("{}/{}", group, version),
+                            "version": version,
+                        })
+                    })
+                    .collect();
+
+                json!({
+                    "name": group,
+                    "preferredVersion": versions.last(),
+                    "versions": versions,
+                })
+            })
+            .collect();
+
+        json!({
+            "kind": "APIGroupList",
+            "apiVersion": "v1",
+            "groups": groups,
+        })
+    }
+
+    fn api_resource_list(&self, group: &str, version: &str) -> JsonValue {
+        let mut kinds: BTreeMap<&str, (&str, bool)> = BTreeMap::new();
+
+        for r in self
+            .inner
+            .resources
+            .iter()
+            .filter(|r| r.group == group && r.version == version)
+        {
+            let namespaced = &mut kinds.entry(&r.plural).or_insert((&r.kind, false)).1;
+
+            *namespaced |= r.namespace.is_some();
+        }
+
+        let resources: Vec<JsonValue> = kinds
+            .into_iter()
+            .map(|(plural, (kind, namespaced))| {
+                json!({
+                    "name": plural,
+                    "singularName": kind.to_lowercase(),
+                    "namespaced": namespaced,
+                    "kind": kind,
+                    "verbs": ["get", "list", "watch"],
+                })
+            })
+            .collect();
+
+        let group_version = if group.is_empty() {
+            version.to_string()
+        } else {
+            format!("{}/{}", group, version)
+        };
+
+        json!({
+            "kind": "APIResourceList",
+            "apiVersion": "v1",
+            "groupVersion": group_version,
+            "resources": resources,
+        })
+    }
+}
+
+enum Route<'a> {
+    List {
+        namespace: Option<&'a str>,
+        plural: &'a str,
+    },
+    Get {
+        namespace: Option<&'a str>,
+        plural: &'a str,
+        name: &'a str,
+    },
+}
+
+impl Service<Request<kube::client::Body>> for FakeApiServer {
+    type Response = Response<FakeBody>;
+    type Error = io::Error;
+    type Future = Ready<Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: Request<kube::client::Body>) -> Self::Future {
+        if !self.inner.is_available.load(Ordering::Relaxed) {
+            return future::ready(Err(io::Error::new(
+                io::ErrorKind::ConnectionRefused,
+                "connection refused",
+            )));
+        }
+
+        future::ready(Ok(self.handle(&req)))
+    }
+}
+
+/// クエリパラメータ
+#[derive(Debug, Default)]
+struct Query(BTreeMap<String, String>);
+
+impl Query {
+    fn parse(query: &str) -> Self {
+        Self(
+            query
+                .split('&')
+                .filter(|kv| !kv.is_empty())
+                .map(|kv| {
+                    let (k, v) = kv.split_once('=').unwrap_or((kv, ""));
+                    (percent_decode(k), percent_decode(v))
+                })
+                .collect(),
+        )
+    }
+
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).map(String::as_str)
+    }
+
+    fn is_watch(&self) -> bool {
+        matches!(self.get("watch"), Some("true" | "1"))
+    }
+
+    /// labelSelectorとfieldSelectorに一致するか
+    ///
+    /// 等価（`=`, `==`, `!=`）と存在（`key`, `!key`）の条件に対応する
+    fn matches(&self, resource: &FakeResource) -> bool {
+        let label_selector = self.get("labelSelector").unwrap_or_default();
+
+        let is_label_match = split_selector(label_selector).all(|req| match req {
+            Requirement::Equal(k, v) => resource.label(k) == Some(v),
+            Requirement::NotEqual(k, v) => resource.label(k) != Some(v),
+            Requirement::Exists(k) => resource.label(k).is_some(),
+            Requirement::NotExists(k) => resource.label(k).is_none(),
+        });
+
+        let field_selector = self.get("fieldSelector").unwrap_or_default();
+
+        let field = |key: &str| match key {
+            "metadata.name" => Some(resource.name.as_str()),
+            "metadata.namespace" => resource.namespace.as_deref(),
+            _ => None,
+        };
+
+        let is_field_match = split_selector(field_selector).all(|req| match req {
+            Requirement::Equal(k, v) => field(k) == Some(v),
+            Requirement::NotEqual(k, v) => field(k) != Some(v),
+            Requirement::Exists(_) | Requirement::NotExists(_) => true,
+        });
+
+        is_label_match && is_field_match
+    }
+}
+
+enum Requirement<'a> {
+    Equal(&'a str, &'a str),
+    NotEqual(&'a str, &'a str),
+    Exists(&'a str),
+    NotExists(&'a str),
+}
+
+fn split_selector(selector: &str) -> impl Iterator<Item = Requirement<'_>> {
+    selector
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            if let Some((k, v)) = s.split_once("!=") {
+                Requirement::NotEqual(k.trim(), v.trim())
+            } else if let Some((k, v)) = s.split_once("==").or_else(|| s.split_once('=')) {
+                Requirement::Equal(k.trim(), v.trim())
+            } else if let Some(k) = s.strip_prefix('!') {
+                Requirement::NotExists(k.trim())
+            } else {
+                Requirement::Exists(s)
+            }
+        })
+}
+
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+
+    let mut ret = Vec::with_capacity(bytes.len());
+
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' if i + 2 < bytes.len() => {
+                match std::str::from_utf8(&bytes[i + 1..i + 3])
+                    .ok()
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                {
+                    Some(b) => {
+                        ret.push(b);
+                        i += 3;
+                        continue;
+                    }
+                    None => ret.push(b'%'),
+                }
+            }
+            b'+' => ret.push(b' '),
+            b => ret.push(b),
+        }
+
+        i += 1;
+    }
+
+    String::from_utf8_lossy(&ret).into_owned()
+}
+
+fn list(items: &[&FakeResource]) -> JsonValue {
+    let kind = items
+        .first()
+        .map_or("List".to_string(), |r| format!("{}List", r.kind));
+
+    json!({
+        "apiVersion": "v1",
+        "kind": kind,
+        "metadata": { "resourceVersion": "1" },
+        "items": items.iter().map(|r| &r.object).collect::<Vec<_>>(),
+    })
+}
+
+/// 種類ごとのTableの列
+fn columns(plural: &str) -> &'static [&'static str] {
+    match plural {
+        "pods" => &["Name", "Ready", "Status", "Restarts", "Age"],
+        "configmaps" => &["Name", "Data", "Age"],
+        "secrets" => &["Name", "Type", "Data", "Age"],
+        "events" => &["Last Seen", "Type", "Reason", "Object", "Message"],
+        "namespaces" => &["Name", "Status", "Age"],
+        _ => &["Name", "Age"],
+    }
+}
+
+fn table(plural: &str, items: &[&FakeResource], now: DateTime<Utc>) -> JsonValue {
+    let columns = columns(plural);
+
+    let column_definitions: Vec<JsonValue> = columns
+        .iter()
+        .map(|name| {
+            json!({
+                "name": name,
+                "type": "string",
+                "format": "",
+                "description": "",
+                "priority": 0,
+            })
+        })
+        .collect();
+
+    let rows: Vec<JsonValue> = items
+        .iter()
+        .map(|r| {
+            let cells: Vec<String> = columns.iter().map(|c| cell(r, c, now)).collect();
+
+            json!({
+                "cells": cells,
+                "object": {
+                    "kind": "PartialObjectMetadata",
+                    "apiVersion": "meta.k8s.io/v1",
+                    "metadata": r.object["metadata"],
+                },
+            })
+        })
+        .collect();
+
+    json!({
+        "kind": "Table",
+        "apiVersion": "meta.k8s.io/v1",
+        "metadata": { "resourceVersion": "1" },
+        "columnDefinitions": column_definitions,
+        "rows": rows,
+    })
+}
+
+fn cell(r: &FakeResource, column: &str, now: DateTime<Utc>) -> String {
+    let o = &r.object;
+
+    let str_or = |v: &JsonValue, default: &str| v.as_str().unwrap_or(default).to_string();
+
+    match (r.kind.as_str(), column) {
+        (_, "Name") => r.name.clone(),
+        (_, "Age") => age(&o["metadata"]["creationTimestamp"], now),
+        ("Pod", "Ready") => {
+            let statuses = o["status"]["containerStatuses"].as_array();
+
+            let ready = statuses.map_or(0, |s| s.iter().filter(|s| s["ready"] == true).count());
+
+            let total = o["spec"]["containers"].as_array().map_or(0, Vec::len);
+
+            format!("{}/{}", ready, total)
+        }
+        ("Pod", "Status") => o["status"]["containerStatuses"]
+            .as_array()
+            .and_then(|s| {
+                s.iter()
+                    .find_map(|s| s["state"]["waiting"]["reason"].as_str())
+            })
+            .map_or_else(|| str_or(&o["status"]["phase"], "Unknown"), String::from),
+        ("Pod", "Restarts") => o["status"]["containerStatuses"]
+            .as_array()
+            .map_or(0, |s| {
+                s.iter()
+                    .filter_map(|s| s["restartCount"].as_u64())
+                    .sum::<u64>()
+            })
+            .to_string(),
+        (_, "Data") => {
+            let len = |v: &JsonValue| v.as_object().map_or(0, |m| m.len());
+
+            (len(&o["data"]) + len(&o["binaryData"])).to_string()
+        }
+        ("Secret", "Type") => str_or(&o["type"], "Opaque"),
+        ("Event", "Type") => str_or(&o["type"], "Normal"),
+        ("Event", "Last Seen") => age(&o["lastTimestamp"], now),
+        ("Event", "Reason") => str_or(&o["reason"], ""),
+        ("Event", "Object") => format!(
+            "{}/{}",
+            o["involvedObject"]["kind"]
+                .as_str()
+                .unwrap_or_default()
+                .to_lowercase(),
+            o["involvedObject"]["name"].as_str().unwrap_or_default()
+        ),
+        ("Event", "Message") => str_or(&o["message"], ""),
+        ("Namespace", "Status") => str_or(&o["status"]["phase"], "Active"),
+        _ => String::new(),
+    }
+}
+
+fn age(timestamp: &JsonValue, now: DateTime<Utc>) -> String {
+    let Some(timestamp) = timestamp
+        .as_str()
+        .and_then(|t| DateTime::parse_from_rfc3339(t).ok())
+    else {
+        return "<unknown>".to_string();
+    };
+
+    let sec = (now - timestamp.with_timezone(&Utc)).num_seconds().max(0);
+
+    match sec {
+        0..=59 => format!("{}s", sec),
+        60..=3599 => format!("{}m", sec / 60),
+        3600..=86399 => format!("{}h", sec / 3600),
+        _ => format!("{}d", sec / 86400),
+    }
+}
+
+fn response(status: StatusCode, body: Bytes) -> Response<FakeBody> {
+    let body = StreamBody::new(stream::once(future::ready(Ok(Frame::data(body)))).boxed());
+
+    Response::builder()
+        .status(status)
+        .body(body)
+        .expect("Failed to build response")
+}
+
+fn json_response(status: StatusCode, value: &JsonValue) -> Response<FakeBody> {
+    response(status, Bytes::from(value.to_string()))
+}
+
+/// 送信した後も接続を維持するレスポンス（watchとfollowのログ）
+fn streaming_response(chunks: Vec<Bytes>) -> Response<FakeBody> {
+    let body = StreamBody::new(
+        stream::iter(chunks.into_iter().map(|chunk| Ok(Frame::data(chunk))))
+            .chain(stream::pending())
+            .boxed(),
+    );
+
+    Response::builder()
+        .status(StatusCode::OK)
+        .body(body)
+        .expect("Failed to build response")
+}
+
+fn watch_response(items: &[&FakeResource]) -> Response<FakeBody> {
+    let chunks = items
+        .iter()
+        .map(|r| {
+            let event = json!({ "type": "ADDED", "object": r.object });
+
+            Bytes::from(format!("{}\n", event))
+        })
+        .collect();
+
+    streaming_response(chunks)
+}
+
+fn forbidden(path: &str) -> Response<FakeBody> {
+    json_response(
+        StatusCode::FORBIDDEN,
+        &json!({
+            "kind": "Status",
+            "apiVersion": "v1",
+            "metadata": {},
+            "status": "Failure",
+            "message": format!("access to {} is forbidden", path),
+            "reason": "Forbidden",
+            "code": 403,
+        }),
+    )
+}
+
+fn not_found(path: &str) -> Response<FakeBody> {
+    json_response(
+        StatusCode::NOT_FOUND,
+        &json!({
+            "kind": "Status",
+            "apiVersion": "v1",
+            "metadata": {},
+            "status": "Failure",
+            "message": format!("the server could not find the requested resource ({})", path),
+            "reason": "NotFound",
+            "code": 404,
+        }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{AsyncBufReadExt as _, TryStreamExt as _};
+    use indoc::indoc;
+    use k8s_openapi::api::core::v1::Pod;
+    use kube::{
+        api::{ListParams, LogParams, WatchEvent, WatchParams},
+        Api, Discovery,
+    };
+    use pretty_assertions::assert_eq;
+
+    use crate::kube::{apis::v1_table::Table, KubeClientRequest as _};
+
+    use super::*;
+
+    fn server() -> FakeApiServer {
+        FakeApiServer::from_fixture("default.yaml")
+    }
+
+    #[tokio::test]
+    async fn podの一覧を取得できる() {
+        let api: Api<Pod> = Api::namespaced(server().client().to_client(), "default");
+
+        let pods = api.list(&ListParams::default()).await.unwrap();
+
+        let names: Vec<_> = pods
+            .iter()
+            .filter_map(|p| p.metadata.name.clone())
+            .collect();
+
+        assert_eq!(names, vec!["app", "backend"]);
+    }
+
+    #[tokio::test]
+    async fn label_selectorで絞り込める() {
+        let api: Api<Pod> = Api::namespaced(server().client().to_client(), "default");
+
+        let pods = api
+            .list(&ListParams::default().labels("app!=app"))
+            .await
+            .unwrap();
+
+        let names: Vec<_> = pods
+            .iter()
+            .filter_map(|p| p.metadata.name.clone())
+            .collect();
+
+        assert_eq!(names, vec!["backend"]);
+    }
+
+    #[tokio::test]
+    async fn 存在しないリソースは404を返す() {
+        let api: Api<Pod> = Api::namespaced(server().client().to_client(), "default");
+
+        let Err(kube::Error::Api(err)) = api.get("unknown").await else {
+            panic!("unexpected response");
+        };
+
+        assert_eq!(err.code, 404);
+    }
+
+    #[tokio::test]
+    async fn table形式で取得できる() {
+        let client = server().client();
+
+        let table: Table = client
+            .table_request("api/v1/namespaces/default/pods")
+            .await
+            .unwrap();
+
+        let columns: Vec<_> = table
+            .column_definitions
+            .iter()
+            .map(|c| c.name.as_str())
+            .collect();
+
+        assert_eq!(columns, vec!["Name", "Ready", "Status", "Restarts", "Age"]);
+
+        let cells: Vec<_> = table.rows[1].cells[..4]
+            .iter()
+            .map(|c| c.0.as_str().unwrap())
+            .collect();
+
+        assert_eq!(cells, vec!["backend", "0/1", "CrashLoopBackOff", "3"]);
+    }
+
+    #[tokio::test]
+    async fn watchは既存のリソースをaddedとして送信する() {
+        let api: Api<Pod> = Api::namespaced(server().client().to_client(), "default");
+
+        let stream = api
+            .watch(&WatchParams::default().fields("metadata.name=app"), "0")
+            .await
+            .unwrap();
+
+        let events: Vec<_> = stream.take(1).try_collect().await.unwrap();
+
+        let [WatchEvent::Added(pod)] = events.as_slice() else {
+            panic!("unexpected events: {:?}", events);
+        };
+
+        assert_eq!(pod.metadata.name.as_deref(), Some("app"));
+    }
+
+    #[tokio::test]
+    async fn ログをsince_time以降に絞り込みタイムスタンプを付与して取得できる() {
+        let api: Api<Pod> = Api::namespaced(server().client().to_client(), "default");
+
+        let lp = LogParams {
+            container: Some("nginx".to_string()),
+            follow: true,
+            timestamps: true,
+            since_time: Some(
+                DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z")
+                    .unwrap()
+                    .with_timezone(&Utc),
+            ),
+            ..Default::default()
+        };
+
+        let stream = api.log_stream("app", &lp).await.unwrap();
+
+        let lines: Vec<_> = stream.lines().take(2).try_collect().await.unwrap();
+
+        assert_eq!(
+            lines,
+            vec![
+                "2024-01-01T00:00:01Z GET /favicon.ico 404",
+                "2024-01-01T00:00:02Z POST /login 200",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn フィクスチャのリソースをディスカバリーで返す() {
+        let discovery = Discovery::new(server().client().to_client())
+            .run()
+            .await
+            .unwrap();
+
+        let mut kinds: Vec<_> = discovery
+            .groups()
+            .flat_map(|g| g.recommended_resources())
+            .map(|(ar, _)| ar.kind)
+            .collect();
+
+        kinds.sort();
+
+        assert_eq!(
+            kinds,
+            vec![
+                "ConfigMap",
+                "Event",
+                "Ingress",
+                "Namespace",
+                "Pod",
+                "Service"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn 停止中は接続を拒否する() {
+        let server = server();
+
+        let api: Api<Pod> = Api::namespaced(server.client().to_client(), "default");
+
+        server.set_available(false);
+
+        let err = api.list(&ListParams::default()).await.unwrap_err();
+
+        assert!(matches!(err, kube::Error::Service(_)), "{:?}", err);
+
+        server.set_available(true);
+
+        assert!(api.list(&ListParams::default()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn 禁止したリソースは403を返す() {
+        let server = FakeApiServer::from_yaml(indoc! {"
+            forbidden:
+              - pods
+        "})
+        .unwrap();
+
+        let api: Api<Pod> = Api::namespaced(server.client().to_client(), "default");
+
+        let Err(kube::Error::Api(err)) = api.list(&ListParams::default()).await else {
+            panic!("unexpected response");
+        };
+
+        assert_eq!(err.code, 403);
+    }
+
+    #[test]
+    fn 複数形に変換する() {
+        assert_eq!(plural("Pod"), "pods");
+        assert_eq!(plural("Ingress"), "ingresses");
+        assert_eq!(plural("NetworkPolicy"), "networkpolicies");
+        assert_eq!(plural("Endpoints"), "endpoints");
+        assert_eq!(plural("Gateway"), "gateways");
+    }
+
+    #[test]
+    fn パーセントエンコーディングをデコードする() {
+        assert_eq!(
+            percent_decode("app%3Dfoo%2Cbar%21%3Dbaz"),
+            "app=foo,bar!=baz"
+        );
+        assert_eq!(percent_decode("100%"), "100%");
+    }
+}
@@ -1,13 +1,14 @@
 use std::collections::BTreeMap;
 
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
 
 use crate::kube::{
     apis::v1_table::{Table, TableRow},
-    KubeClient, KubeClientRequest as _,
+    KubeClientRequest,
 };
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct KubeTableRow {
     pub namespace: String,
     pub name: String,
@@ -15,7 +16,7 @@ pub struct KubeTableRow {
     pub row: Vec<String>,
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct KubeTable {
     pub header: Vec<String>,
     pub rows: Vec<KubeTableRow>,
@@ -73,22 +74,165 @@ pub fn insert_ns(namespaces: &[String]) -> bool {
     namespaces.len() != 1
 }
 
-pub async fn get_resource_per_namespace<F>(
-    client: &KubeClient,
+/// ラベルセレクターを指定した場合は一致するリソースのみを取得する
+///
+/// 各行のメタデータには、ラベル選択のためにリソースのラベルとアノテーションのキーを追加する
+pub async fn get_resource_per_namespace<C, F>(
+    client: &C,
     path: String,
+    label_selector: Option<&str>,
     target_values: &[&str],
     create_cells: F,
 ) -> Result<Vec<KubeTableRow>>
 where
+    C: KubeClientRequest,
     F: Fn(&TableRow, &[usize]) -> KubeTableRow,
 {
-    let table: Table = client.table_request(&path).await?;
+    let table: Table = client
+        .table_request(&with_label_selector(&path, label_selector))
+        .await?;
 
     let indexes = table.find_indexes(target_values);
 
     Ok(table
         .rows
         .iter()
-        .map(|row| (create_cells)(row, &indexes))
+        .map(|row| {
+            let mut kube_row = (create_cells)(row, &indexes);
+
+            let labels = label_metadata(row);
+
+            if !labels.is_empty() {
+                kube_row
+                    .metadata
+                    .get_or_insert_with(Default::default)
+                    .extend(labels);
+            }
+
+            kube_row
+        })
         .collect())
 }
+
+/// ラベルセレクターをクエリパラメータとして付与する
+///
+/// パスがすでにクエリパラメータを持つ場合は`&`で繋げる
+pub fn with_label_selector(path: &str, label_selector: Option<&str>) -> String {
+    match label_selector {
+        Some(selector) => {
+            let separator = if path.contains('?') { '&' } else { '?' };
+
+            format!(
+                "{}{}labelSelector={}",
+                path,
+                separator,
+                encode_query_value(selector)
+            )
+        }
+        None => path.to_string(),
+    }
+}
+
+/// 英数字と`-_.~`以外をパーセントエンコードする
+fn encode_query_value(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// テーブルの行に含まれるメタデータから、ラベル（JSONのオブジェクト）とアノテーションのキー（JSONの配列）を取り出す
+///
+/// アノテーションの値は大きくなりやすいためキーのみを保持する
+pub fn label_metadata(row: &TableRow) -> BTreeMap<String, String> {
+    let Some(metadata) = row
+        .object
+        .as_ref()
+        .and_then(|object| object.0.get("metadata"))
+    else {
+        return BTreeMap::new();
+    };
+
+    let mut ret = BTreeMap::new();
+
+    if let Some(labels) = metadata.get("labels").filter(|labels| labels.is_object()) {
+        ret.insert(LABELS_METADATA_KEY.to_string(), labels.to_string());
+    }
+
+    if let Some(annotations) = metadata.get("annotations").and_then(|a| a.as_object()) {
+        let keys: Vec<&String> = annotations.keys().collect();
+
+        if let Ok(json) = serde_json::to_string(&keys) {
+            ret.insert(ANNOTATIONS_METADATA_KEY.to_string(), json);
+        }
+    }
+
+    ret
+}
+
+pub const LABELS_METADATA_KEY: &str = "labels";
+
+pub const ANNOTATIONS_METADATA_KEY: &str = "annotations";
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn ラベルセレクターをエンコードして付与する() {
+        assert_eq!(
+            with_label_selector(
+                "api/v1/namespaces/default/pods",
+                Some("app=nginx,env in (prod)")
+            ),
+            "api/v1/namespaces/default/pods?labelSelector=app%3Dnginx%2Cenv%20in%20%28prod%29"
+        );
+
+        assert_eq!(
+            with_label_selector("api/v1/namespaces/default/pods", None),
+            "api/v1/namespaces/default/pods"
+        );
+    }
+
+    #[test]
+    fn クエリパラメータを持つパスには続けてラベルセレクターを付与する() {
+        assert_eq!(
+            with_label_selector(
+                "apis/example.com/v1/namespaces/default/foos?includeObject=Object",
+                Some("app=nginx")
+            ),
+            "apis/example.com/v1/namespaces/default/foos?includeObject=Object&labelSelector=app%3Dnginx"
+        );
+    }
+
+    #[test]
+    fn 行のメタデータからラベルとアノテーションのキーを取り出す() {
+        let row: TableRow = serde_json::from_value(json!({
+            "cells": ["nginx"],
+            "object": {
+                "metadata": {
+                    "name": "nginx",
+                    "labels": {"app": "nginx"},
+                    "annotations": {"b": "large value", "a": "value"}
+                }
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            label_metadata(&row),
+            BTreeMap::from([
+                ("labels".to_string(), r#"{"app":"nginx"}"#.to_string()),
+                ("annotations".to_string(), r#"["a","b"]"#.to_string()),
+            ])
+        );
+    }
+}
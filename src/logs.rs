@@ -0,0 +1,249 @@
+use std::{
+    io::{ErrorKind, Write},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use crossbeam::channel::RecvTimeoutError;
+use serde::Serialize;
+use tokio::runtime::Runtime;
+
+use crate::{
+    ansi::strip_ansi,
+    cmd::LogsArgs,
+    features::pod::{
+        kube::{Filter, LogConfig, LogLine, LogPrefixType, LogWorker},
+        message::LogMessage,
+    },
+    kube::context::Namespace,
+    message::Message,
+    workers::kube::{
+        current_kube_state, message::Kube, AbortWorker as _, KubeState, KubeWorkerConfig,
+    },
+};
+
+/// 終了の確認とログの受信を待つ間隔
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// ログクエリに一致するログを標準出力に書き出し続ける
+///
+/// TUIと同じLogWorkerを使用し、Ctrl-Cを受け付けると終了する
+pub fn run(config: KubeWorkerConfig, args: LogsArgs) -> Result<()> {
+    // TUIではヘルプの案内を含めたエラーを返すため、起動前に検証する
+    Filter::parse(&args.query).map_err(|err| anyhow!("{}\nInvalid query.", err))?;
+
+    let is_terminated = Arc::new(AtomicBool::new(false));
+
+    {
+        let is_terminated = is_terminated.clone();
+
+        ctrlc::set_handler(move || is_terminated.store(true, Ordering::Relaxed))?;
+    }
+
+    let runtime = Runtime::new()?;
+
+    let KubeState {
+        client,
+        target_namespaces,
+        ..
+    } = runtime.block_on(current_kube_state(config))?;
+
+    let prefix_type = if 1 < target_namespaces.len() {
+        LogPrefixType::All
+    } else {
+        LogPrefixType::PodAndContainer
+    };
+
+    let log_config = LogConfig::new(
+        args.query.clone(),
+        Namespace(target_namespaces),
+        prefix_type,
+    )
+    .since(args.since());
+
+    let (tx, rx) = crossbeam::channel::unbounded();
+
+    let worker = LogWorker::new(tx, client, log_config);
+
+    let handle = runtime.block_on(async { worker.spawn() });
+
+    let output = Output::new(&args);
+
+    let mut stdout = std::io::stdout().lock();
+
+    let mut has_error = false;
+
+    let result = loop {
+        if is_terminated.load(Ordering::Relaxed) {
+            break Ok(());
+        }
+
+        match rx.recv_timeout(POLL_INTERVAL) {
+            Ok(Message::Kube(Kube::Log(LogMessage::Response(Ok(lines))))) => {
+                match write_lines(&mut stdout, output, &lines) {
+                    Ok(()) => {}
+                    // パイプの読み込み側（headなど）が終了した場合は正常に終了する
+                    Err(err) if err.kind() == ErrorKind::BrokenPipe => break Ok(()),
+                    Err(err) => break Err(err.into()),
+                }
+            }
+            Ok(Message::Kube(Kube::Log(LogMessage::Response(Err(err))))) => {
+                eprintln!("{:#}", err);
+
+                has_error = true;
+            }
+            Ok(_) => {}
+            Err(RecvTimeoutError::Timeout) => {}
+            // ログの取得を開始できずにLogWorkerが終了した（エラーは受信時に出力済み）
+            Err(RecvTimeoutError::Disconnected) => {
+                break if has_error {
+                    Err(anyhow!("Stopped streaming logs"))
+                } else {
+                    Ok(())
+                };
+            }
+        }
+    };
+
+    handle.abort();
+
+    runtime.shutdown_background();
+
+    result
+}
+
+fn write_lines(writer: &mut impl Write, output: Output, lines: &[LogLine]) -> std::io::Result<()> {
+    for line in lines {
+        if let Some(line) = output.format(line)? {
+            writeln!(writer, "{}", line)?;
+        }
+    }
+
+    writer.flush()
+}
+
+/// 出力形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Output {
+    Color,
+    Plain,
+    Json,
+}
+
+impl Output {
+    fn new(args: &LogsArgs) -> Self {
+        if args.json {
+            Self::Json
+        } else if args.no_color {
+            Self::Plain
+        } else {
+            Self::Color
+        }
+    }
+
+    /// 1行分のログを出力する文字列に変換する
+    ///
+    /// JSONではコンテナの開始・終了を示す行など、ログ本文を持たない行は出力しない
+    fn format(self, line: &LogLine) -> serde_json::Result<Option<String>> {
+        match self {
+            Self::Color => Ok(Some(line.content.clone())),
+            Self::Plain => Ok(Some(strip_ansi(&line.content))),
+            Self::Json => {
+                let Some(message) = line.message() else {
+                    return Ok(None);
+                };
+
+                let source = line.source.as_ref();
+
+                let json = JsonLogLine {
+                    namespace: source.map(|s| s.namespace.as_str()),
+                    pod: source.map(|s| s.pod.as_str()),
+                    container: source.map(|s| s.container.as_str()),
+                    timestamp: line.timestamp,
+                    message: strip_ansi(message),
+                };
+
+                Ok(Some(serde_json::to_string(&json)?))
+            }
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct JsonLogLine<'a> {
+    namespace: Option<&'a str>,
+    pod: Option<&'a str>,
+    container: Option<&'a str>,
+    timestamp: Option<DateTime<Utc>>,
+    message: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone as _;
+    use pretty_assertions::assert_eq;
+
+    use crate::features::pod::kube::LogSource;
+
+    use super::*;
+
+    fn line() -> LogLine {
+        let prefix = "\x1b[92m[app nginx]\x1b[39m ";
+
+        LogLine {
+            source: Some(LogSource {
+                namespace: "default".to_string(),
+                pod: "app".to_string(),
+                container: "nginx".to_string(),
+            }),
+            timestamp: Some(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()),
+            content: format!("{}\x1b[31mERROR failed\x1b[39m", prefix),
+            message_offset: Some(prefix.len()),
+        }
+    }
+
+    #[test]
+    fn no_colorではエスケープシーケンスを取り除く() {
+        assert_eq!(
+            Output::Plain.format(&line()).unwrap(),
+            Some("[app nginx] ERROR failed".to_string())
+        );
+    }
+
+    #[test]
+    fn 色付きではそのまま出力する() {
+        assert_eq!(Output::Color.format(&line()).unwrap(), Some(line().content));
+    }
+
+    #[test]
+    fn jsonではプレフィックスを除いた本文と出力元を出力する() {
+        let actual = Output::Json.format(&line()).unwrap().unwrap();
+
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&actual).unwrap(),
+            serde_json::json!({
+                "namespace": "default",
+                "pod": "app",
+                "container": "nginx",
+                "timestamp": "2024-01-01T00:00:00Z",
+                "message": "ERROR failed",
+            })
+        );
+    }
+
+    #[test]
+    fn jsonではログ本文を持たない行を出力しない() {
+        let line = LogLine {
+            content: "+ app nginx".to_string(),
+            message_offset: None,
+            ..line()
+        };
+
+        assert_eq!(Output::Json.format(&line).unwrap(), None);
+    }
+}
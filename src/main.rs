@@ -3,9 +3,12 @@ mod app;
 mod clipboard;
 mod cmd;
 mod features;
+mod history;
 mod kube;
 mod logging;
+mod logs;
 mod message;
+mod snapshot;
 mod ui;
 mod workers;
 
@@ -19,7 +22,11 @@ use ratatui::crossterm::{
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 
-use crate::{app::App, cmd::Command, logging::Logger};
+use crate::{
+    app::App,
+    cmd::{Command, SubCommand},
+    logging::Logger,
+};
 
 macro_rules! enable_raw_mode {
     () => {
@@ -58,6 +65,19 @@ fn set_signal_handler() {
 }
 
 fn main() -> Result<()> {
+    let command = Command::init();
+
+    // ターミナルを操作しないため、シグナルハンドラーなどは設定しない
+    match command.subcommand.clone() {
+        Some(SubCommand::Snapshot(args)) => {
+            return snapshot::run(command.kube_worker_config(), args);
+        }
+        Some(SubCommand::Logs(args)) => {
+            return logs::run(command.kube_worker_config(), args);
+        }
+        None => {}
+    }
+
     set_signal_handler();
 
     let default_hook = panic::take_hook();
@@ -70,8 +90,6 @@ fn main() -> Result<()> {
         default_hook(info);
     }));
 
-    let command = Command::init();
-
     if command.logging {
         Logger::init()?;
     }
@@ -0,0 +1,297 @@
+use std::{
+    io::Write as _,
+    sync::{atomic::AtomicBool, Arc},
+};
+
+use anyhow::{bail, Result};
+use serde::{ser::SerializeMap as _, Serialize, Serializer};
+use tokio::{runtime::Runtime, sync::RwLock};
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    ansi::strip_ansi,
+    cmd::{SnapshotArgs, SnapshotFormat, SnapshotTab},
+    features::{
+        api_resources::kube::{fetch_api_resources, ApiResources},
+        config::kube::ConfigPoller,
+        event::kube::EventPoller,
+        label_selector::kube::LabelSelectors,
+        network::kube::NetworkPoller,
+        pod::kube::PodPoller,
+    },
+    kube::table::KubeTable,
+    workers::kube::{current_kube_state, Connection, KubeState, KubeWorkerConfig, PollerBase},
+};
+
+/// 列の区切り
+const COLUMN_SEPARATOR: &str = "   ";
+
+/// 指定したタブのテーブルを1度だけ取得して標準出力に書き出す
+///
+/// TUIと同じポーラーを使用し、rawモードには移行しない。
+/// Networkタブの詳細などテーブル以外の内容は出力しない
+pub fn run(config: KubeWorkerConfig, args: SnapshotArgs) -> Result<()> {
+    let SnapshotArgs {
+        tab,
+        format,
+        selector,
+    } = args;
+
+    if selector.is_some() && tab.label_selector_target().is_none() {
+        bail!("Label selector is not supported for the {:?} tab", tab);
+    }
+
+    let table = Runtime::new()?.block_on(fetch(config, tab, selector))?;
+
+    let output = render(&table, format)?;
+
+    let mut stdout = std::io::stdout().lock();
+
+    stdout.write_all(output.as_bytes())?;
+    stdout.flush()?;
+
+    Ok(())
+}
+
+async fn fetch(
+    config: KubeWorkerConfig,
+    tab: SnapshotTab,
+    selector: Option<String>,
+) -> Result<KubeTable> {
+    let KubeState {
+        client,
+        target_namespaces,
+        ..
+    } = current_kube_state(config).await?;
+
+    let mut label_selectors = LabelSelectors::default();
+
+    if let (Some(target), Some(selector)) = (tab.label_selector_target(), selector) {
+        label_selectors.insert(target, selector);
+    }
+
+    // ポーラーの送信先として必要なだけで、1度しか取得しないため受信はしない
+    let (tx, _rx) = crossbeam::channel::unbounded();
+
+    let base = PollerBase {
+        is_terminated: Arc::new(AtomicBool::new(false)),
+        tx: tx.clone(),
+        shared_target_namespaces: Arc::new(RwLock::new(target_namespaces)),
+        shared_label_selectors: Arc::new(RwLock::new(label_selectors)),
+        kube_client: client.clone(),
+        connection: Connection::shared(String::new(), tx.clone()),
+    };
+
+    match tab {
+        SnapshotTab::Pod => PodPoller::new(base).poll().await,
+        SnapshotTab::Config => ConfigPoller::new(base).poll().await,
+        SnapshotTab::Event => EventPoller::new(base).poll().await,
+        SnapshotTab::Network => {
+            let api_resources = ApiResources::shared();
+
+            *api_resources.write().await = fetch_api_resources(&client).await?;
+
+            NetworkPoller::new(base, api_resources).poll().await
+        }
+    }
+}
+
+fn render(table: &KubeTable, format: SnapshotFormat) -> Result<String> {
+    let rows: Vec<SnapshotRow> = table
+        .rows
+        .iter()
+        .map(|row| SnapshotRow {
+            header: &table.header,
+            cells: row.row.iter().map(|cell| strip_ansi(cell)).collect(),
+        })
+        .collect();
+
+    let output = match format {
+        SnapshotFormat::Text => render_text(&table.header, &rows),
+        SnapshotFormat::Json => serde_json::to_string_pretty(&rows)? + "\n",
+        SnapshotFormat::Yaml => serde_yaml::to_string(&rows)?,
+    };
+
+    Ok(output)
+}
+
+/// 列幅を揃えたテーブルに変換する
+///
+/// 複数行のセルは1行にまとめる
+fn render_text(header: &[String], rows: &[SnapshotRow]) -> String {
+    let lines: Vec<Vec<String>> = std::iter::once(header.to_vec())
+        .chain(rows.iter().map(|row| {
+            row.cells
+                .iter()
+                .map(|cell| cell.lines().collect::<Vec<_>>().join(" "))
+                .collect()
+        }))
+        .collect();
+
+    let widths: Vec<usize> = (0..header.len())
+        .map(|i| {
+            lines
+                .iter()
+                .filter_map(|line| line.get(i))
+                .map(|cell| cell.width())
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+
+    lines
+        .iter()
+        .map(|line| {
+            let mut s = String::new();
+
+            for (i, cell) in line.iter().enumerate() {
+                s += cell;
+
+                if i < line.len() - 1 {
+                    s += &" ".repeat(widths[i].saturating_sub(cell.width()));
+                    s += COLUMN_SEPARATOR;
+                }
+            }
+
+            s.trim_end().to_string() + "\n"
+        })
+        .collect()
+}
+
+/// JSONとYAMLで出力する1行
+///
+/// ヘッダーの順序を保つため、ヘッダーをキーとしたマップとしてシリアライズする
+struct SnapshotRow<'a> {
+    header: &'a [String],
+    cells: Vec<String>,
+}
+
+impl Serialize for SnapshotRow<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.header.len()))?;
+
+        for (key, value) in self.header.iter().zip(&self.cells) {
+            map.serialize_entry(key, value)?;
+        }
+
+        map.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use crate::kube::table::KubeTableRow;
+
+    use super::*;
+
+    fn table() -> KubeTable {
+        let row = |cells: &[&str]| KubeTableRow {
+            namespace: "default".to_string(),
+            name: cells[0].to_string(),
+            metadata: None,
+            row: cells.iter().map(ToString::to_string).collect(),
+        };
+
+        KubeTable {
+            header: ["NAME", "STATUS", "AGE"]
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            rows: vec![
+                row(&["app", "Running", "1d"]),
+                row(&[
+                    "\x1b[31mbackend\x1b[0m",
+                    "\x1b[31mCrashLoopBackOff\x1b[0m",
+                    "\x1b[31m10m\x1b[0m",
+                ]),
+                row(&["日本語", "Pending", "5s"]),
+            ],
+        }
+    }
+
+    #[test]
+    fn textは列幅を揃えエスケープシーケンスを取り除く() {
+        let actual = render(&table(), SnapshotFormat::Text).unwrap();
+
+        assert_eq!(
+            actual,
+            indoc! {"
+                NAME      STATUS             AGE
+                app       Running            1d
+                backend   CrashLoopBackOff   10m
+                日本語    Pending            5s
+            "}
+        );
+    }
+
+    #[test]
+    fn textは複数行のセルを1行にまとめる() {
+        let table = KubeTable {
+            header: vec!["OBJECT".to_string(), "MESSAGE".to_string()],
+            rows: vec![KubeTableRow {
+                row: vec!["pod/app".to_string(), "foo\nbar".to_string()],
+                ..Default::default()
+            }],
+        };
+
+        let actual = render(&table, SnapshotFormat::Text).unwrap();
+
+        assert_eq!(
+            actual,
+            indoc! {"
+                OBJECT    MESSAGE
+                pod/app   foo bar
+            "}
+        );
+    }
+
+    #[test]
+    fn jsonはヘッダーをキーにした配列を出力する() {
+        let actual = render(&table(), SnapshotFormat::Json).unwrap();
+
+        let actual: serde_json::Value = serde_json::from_str(&actual).unwrap();
+
+        assert_eq!(
+            actual,
+            serde_json::json!([
+                {"NAME": "app", "STATUS": "Running", "AGE": "1d"},
+                {"NAME": "backend", "STATUS": "CrashLoopBackOff", "AGE": "10m"},
+                {"NAME": "日本語", "STATUS": "Pending", "AGE": "5s"},
+            ])
+        );
+    }
+
+    #[test]
+    fn yamlはヘッダーの順序でキーを出力する() {
+        let actual = render(&table(), SnapshotFormat::Yaml).unwrap();
+
+        assert_eq!(
+            actual,
+            indoc! {"
+                - NAME: app
+                  STATUS: Running
+                  AGE: 1d
+                - NAME: backend
+                  STATUS: CrashLoopBackOff
+                  AGE: 10m
+                - NAME: 日本語
+                  STATUS: Pending
+                  AGE: 5s
+            "}
+        );
+    }
+
+    #[test]
+    fn 行がない場合textはヘッダーのみjsonは空配列を出力する() {
+        let table = KubeTable {
+            header: vec!["NAME".to_string()],
+            rows: vec![],
+        };
+
+        assert_eq!(render(&table, SnapshotFormat::Text).unwrap(), "NAME\n");
+        assert_eq!(render(&table, SnapshotFormat::Json).unwrap(), "[]\n");
+    }
+}
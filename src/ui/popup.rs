@@ -33,6 +33,8 @@ struct PopupChunkSize {
     width: f32,
     /// content height percentage (0.0 ~ 100.0)
     height: f32,
+    /// content height in rows (takes precedence over height)
+    fixed_height: Option<u16>,
 }
 
 impl Default for PopupChunkSize {
@@ -40,6 +42,7 @@ impl Default for PopupChunkSize {
         Self {
             width: 85.0,
             height: 85.0,
+            fixed_height: None,
         }
     }
 }
@@ -48,8 +51,11 @@ impl PopupChunkSize {
     fn chunk(&self, parent_chunk: Rect) -> Rect {
         let horizontal_margin =
             (parent_chunk.width as f32 * ((100.0 - self.width) / 2.0 / 100.0)).round() as u16;
-        let vertical_margin =
-            (parent_chunk.height as f32 * ((100.0 - self.height) / 2.0 / 100.0)).round() as u16;
+        let vertical_margin = if let Some(height) = self.fixed_height {
+            parent_chunk.height.saturating_sub(height) / 2
+        } else {
+            (parent_chunk.height as f32 * ((100.0 - self.height) / 2.0 / 100.0)).round() as u16
+        };
 
         parent_chunk.inner(Margin {
             vertical: vertical_margin,
@@ -73,6 +79,12 @@ impl<'a> Popup<'a> {
         }
     }
 
+    /// 中身の高さを行数で指定する（枠と余白を含む）
+    pub fn fixed_height(mut self, height: u16) -> Self {
+        self.chunk_size.fixed_height = Some(height);
+        self
+    }
+
     pub fn chunk(&self) -> Rect {
         self.chunk
     }
@@ -186,6 +186,14 @@ pub trait WidgetTrait {
     // Modify Widget Item
     fn append_widget_item(&mut self, _: Item);
     fn update_widget_item(&mut self, _: Item);
+    /// 直前の`update_widget_item`で表示内容が変わったか
+    fn is_content_changed(&self) -> bool {
+        true
+    }
+    /// 更新がなくても、時間の経過で表示が変わるため定期的に再描画が必要か
+    fn needs_periodic_redraw(&self, _is_active: bool) -> bool {
+        false
+    }
     // Widget append title
     // Render widget title -> format!("{}: {}", title, append_title)
     // When clear, append_title clear.
@@ -257,6 +265,14 @@ impl<'a> Widget<'a> {
         }
     }
 
+    pub fn as_input(&self) -> &InputForm {
+        if let Self::Input(w) = self {
+            w
+        } else {
+            panic!("called as_input() on {:?}", self)
+        }
+    }
+
     // as_mut_*
     pub fn as_mut_list(&mut self) -> &mut List<'a> {
         if let Self::List(w) = self {
@@ -297,4 +313,12 @@ impl<'a> Widget<'a> {
             panic!("called as_mut_multiple_select() on {:?}", self)
         }
     }
+
+    pub fn as_mut_input(&mut self) -> &mut InputForm {
+        if let Self::Input(w) = self {
+            w
+        } else {
+            panic!("called as_mut_input() on {:?}", self)
+        }
+    }
 }
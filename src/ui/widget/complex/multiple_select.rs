@@ -790,6 +790,11 @@ impl WidgetTrait for MultipleSelect<'_> {
         self.selected_widget.update_widget_item(items);
     }
 
+    /// アクティブな間はフィルター入力のカーソルを点滅させる
+    fn needs_periodic_redraw(&self, is_active: bool) -> bool {
+        is_active
+    }
+
     fn on_mouse_event(&mut self, ev: MouseEvent) -> EventResult {
         let pos = (ev.column, ev.row);
 
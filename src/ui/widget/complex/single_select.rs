@@ -277,6 +277,11 @@ impl WidgetTrait for SingleSelect<'_> {
         self.select_widget.update_widget_item(items);
     }
 
+    /// アクティブな間はフィルター入力のカーソルを点滅させる
+    fn needs_periodic_redraw(&self, is_active: bool) -> bool {
+        is_active
+    }
+
     fn on_mouse_event(&mut self, ev: MouseEvent) -> EventResult {
         let pos = (ev.column, ev.row);
 
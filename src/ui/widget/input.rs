@@ -11,6 +11,7 @@ use ratatui::{
 };
 
 use crate::{
+    define_callback,
     message::UserEvent,
     ui::{
         event::{Callback, CallbackFn, EventResult},
@@ -19,6 +20,9 @@ use crate::{
     },
 };
 
+define_callback!(pub HistoryLoader, Fn() -> Vec<String>);
+define_callback!(pub HistorySaver, Fn(&str));
+
 #[derive(Debug)]
 enum Mode {
     Show,
@@ -198,6 +202,103 @@ impl Content {
     }
 }
 
+/// Up/Downで履歴を辿っている状態
+#[derive(Debug)]
+struct HistoryCursor {
+    /// 古い順に並んだ履歴
+    entries: Vec<String>,
+    /// 表示中の履歴のインデックス（entries.len()のときは入力中の内容）
+    index: usize,
+    /// 履歴を辿り始める前の入力内容
+    draft: String,
+}
+
+/// Ctrl-Rによる履歴の逆方向インクリメンタル検索
+#[derive(Debug, Default)]
+struct ReverseSearch {
+    entries: Vec<String>,
+    query: String,
+    matched: Option<usize>,
+    /// 検索を始める前の入力内容
+    original: String,
+}
+
+impl ReverseSearch {
+    fn new(entries: Vec<String>, original: String) -> Self {
+        let mut search = Self {
+            entries,
+            original,
+            ..Default::default()
+        };
+
+        search.search_before(search.entries.len());
+
+        search
+    }
+
+    /// endより古い履歴からqueryを含むものを探す
+    ///
+    /// 見つからない場合は現在の一致を維持する
+    fn search_before(&mut self, end: usize) -> bool {
+        let end = end.min(self.entries.len());
+
+        let found = self.entries[..end]
+            .iter()
+            .rposition(|entry| entry.contains(&self.query));
+
+        if found.is_some() {
+            self.matched = found;
+        }
+
+        found.is_some()
+    }
+
+    fn push_char(&mut self, c: char) {
+        self.query.push(c);
+
+        let end = self.matched.map_or(self.entries.len(), |i| i + 1);
+
+        if !self.search_before(end) {
+            self.matched = None;
+        }
+    }
+
+    fn pop_char(&mut self) {
+        self.query.pop();
+
+        self.matched = None;
+        self.search_before(self.entries.len());
+    }
+
+    fn search_older(&mut self) {
+        if let Some(matched) = self.matched {
+            self.search_before(matched);
+        } else {
+            self.search_before(self.entries.len());
+        }
+    }
+
+    fn matched_entry(&self) -> Option<&str> {
+        self.matched.map(|i| self.entries[i].as_str())
+    }
+
+    fn rendered_content(&self) -> Line<'static> {
+        let label = if self.matched.is_some() || self.query.is_empty() {
+            "reverse-i-search"
+        } else {
+            "failed reverse-i-search"
+        };
+
+        Line::from(vec![
+            Span::styled(
+                format!("({})`{}': ", label, self.query),
+                Style::default().add_modifier(Modifier::DIM),
+            ),
+            Span::raw(self.matched_entry().unwrap_or_default().to_string()),
+        ])
+    }
+}
+
 #[derive(Derivative)]
 #[derivative(Debug, Default)]
 pub struct InputFormBuilder {
@@ -205,6 +306,8 @@ pub struct InputFormBuilder {
     widget_config: WidgetConfig,
     #[derivative(Debug = "ignore")]
     actions: Vec<(UserEvent, Callback)>,
+    #[derivative(Debug = "ignore")]
+    history: Option<(HistoryLoader, HistorySaver)>,
 }
 
 impl InputFormBuilder {
@@ -227,11 +330,22 @@ impl InputFormBuilder {
         self
     }
 
+    /// Up/DownとCtrl-Rで辿る履歴の読み書き
+    pub fn history<L, S>(mut self, loader: L, saver: S) -> Self
+    where
+        L: Into<HistoryLoader>,
+        S: Into<HistorySaver>,
+    {
+        self.history = Some((loader.into(), saver.into()));
+        self
+    }
+
     pub fn build(self) -> InputForm {
         InputForm {
             id: self.id,
             widget_config: self.widget_config,
             actions: self.actions,
+            history: self.history,
             ..Default::default()
         }
     }
@@ -247,6 +361,10 @@ pub struct InputForm {
     scroll: usize,
     #[derivative(Debug = "ignore")]
     actions: Vec<(UserEvent, Callback)>,
+    #[derivative(Debug = "ignore")]
+    history: Option<(HistoryLoader, HistorySaver)>,
+    history_cursor: Option<HistoryCursor>,
+    reverse_search: Option<ReverseSearch>,
 }
 
 impl InputForm {
@@ -308,6 +426,8 @@ impl InputForm {
     pub fn clear(&mut self) {
         self.content.clear();
         self.scroll = 0;
+        self.history_cursor = None;
+        self.reverse_search = None;
     }
 
     pub fn move_cursor_top(&mut self) {
@@ -368,12 +488,149 @@ impl InputForm {
         self.block(true, true).inner(self.chunk).width as usize
     }
 
+    pub fn is_reverse_searching(&self) -> bool {
+        self.reverse_search.is_some()
+    }
+
+    /// 入力内容を履歴に追加する
+    pub fn commit_history(&mut self) {
+        self.history_cursor = None;
+
+        if let Some((_, saver)) = &self.history {
+            saver(&self.content());
+        }
+    }
+
+    fn history_prev(&mut self) {
+        let Some((loader, _)) = &self.history else {
+            return;
+        };
+
+        let mut cursor = self.history_cursor.take().unwrap_or_else(|| {
+            let entries = loader();
+            HistoryCursor {
+                index: entries.len(),
+                entries,
+                draft: self.content(),
+            }
+        });
+
+        if 0 < cursor.index {
+            cursor.index -= 1;
+            self.update_content(cursor.entries[cursor.index].clone());
+        }
+
+        self.history_cursor = Some(cursor);
+    }
+
+    fn history_next(&mut self) {
+        let Some(mut cursor) = self.history_cursor.take() else {
+            return;
+        };
+
+        cursor.index += 1;
+
+        if cursor.index < cursor.entries.len() {
+            self.update_content(cursor.entries[cursor.index].clone());
+            self.history_cursor = Some(cursor);
+        } else {
+            self.update_content(cursor.draft);
+        }
+    }
+
+    fn start_reverse_search(&mut self) {
+        let Some((loader, _)) = &self.history else {
+            return;
+        };
+
+        self.history_cursor = None;
+        self.reverse_search = Some(ReverseSearch::new(loader(), self.content()));
+    }
+
+    fn on_reverse_search_key_event(&mut self, key: KeyEvent) -> EventResult {
+        let Some(search) = self.reverse_search.as_mut() else {
+            return EventResult::Ignore;
+        };
+
+        match key_event_to_code(key) {
+            KeyCode::Char('r') if key.modifiers == KeyModifiers::CONTROL => {
+                search.search_older();
+            }
+
+            KeyCode::Char('g') if key.modifiers == KeyModifiers::CONTROL => {
+                let original = std::mem::take(&mut search.original);
+                self.update_content(original);
+            }
+
+            KeyCode::Esc => {
+                let original = std::mem::take(&mut search.original);
+                self.update_content(original);
+            }
+
+            KeyCode::Delete => {
+                search.pop_char();
+            }
+
+            KeyCode::Char(c) if key.modifiers != KeyModifiers::CONTROL => {
+                search.push_char(c);
+            }
+
+            KeyCode::Enter => {
+                self.accept_reverse_search();
+            }
+
+            _ => {
+                self.accept_reverse_search();
+                return self.on_key_event(key);
+            }
+        }
+
+        EventResult::Nop
+    }
+
+    fn accept_reverse_search(&mut self) {
+        let Some(search) = self.reverse_search.take() else {
+            return;
+        };
+
+        let content = search
+            .matched_entry()
+            .map(ToString::to_string)
+            .unwrap_or(search.original);
+
+        self.update_content(content);
+    }
+
     pub fn on_mouse_event(&mut self, _: MouseEvent) -> EventResult {
         EventResult::Ignore
     }
 
     pub fn on_key_event(&mut self, key: KeyEvent) -> EventResult {
-        match key_event_to_code(key) {
+        if self.reverse_search.is_some() {
+            return self.on_reverse_search_key_event(key);
+        }
+
+        let code = key_event_to_code(key);
+
+        if !matches!(code, KeyCode::Up | KeyCode::Down) {
+            self.history_cursor = None;
+        }
+
+        match code {
+            KeyCode::Up if self.history.is_some() => {
+                self.history_prev();
+            }
+
+            KeyCode::Down if self.history.is_some() => {
+                self.history_next();
+            }
+
+            KeyCode::Char('r')
+                if key.modifiers == KeyModifiers::CONTROL && self.history.is_some() =>
+            {
+                self.start_reverse_search();
+            }
+
             KeyCode::Delete => {
                 self.remove_char();
             }
@@ -402,7 +659,7 @@ impl InputForm {
                 self.back_cursor();
             }
 
-            KeyCode::Char(c) => {
+            KeyCode::Char(c) if key.modifiers != KeyModifiers::CONTROL => {
                 self.insert_char(c);
             }
             _ => {
@@ -485,6 +742,11 @@ impl WidgetTrait for InputForm {
         self.update_content(item);
     }
 
+    /// アクティブな間はカーソルを点滅させる
+    fn needs_periodic_redraw(&self, is_active: bool) -> bool {
+        is_active
+    }
+
     fn on_mouse_event(&mut self, ev: MouseEvent) -> EventResult {
         self.on_mouse_event(ev)
     }
@@ -504,12 +766,17 @@ impl WidgetTrait for InputForm {
 
 impl RenderTrait for InputForm {
     fn render(&mut self, f: &mut Frame, is_active: bool, is_mouse_over: bool) {
-        let content = self.content.rendered_content(is_active);
         let block = self.block(is_active, is_mouse_over);
 
-        let widget = Paragraph::new(content)
-            .block(block)
-            .scroll((0, self.scroll as u16));
+        let widget = if let Some(search) = &self.reverse_search {
+            Paragraph::new(search.rendered_content()).block(block)
+        } else {
+            let content = self.content.rendered_content(is_active);
+
+            Paragraph::new(content)
+                .block(block)
+                .scroll((0, self.scroll as u16))
+        };
 
         f.render_widget(widget, self.chunk);
     }
@@ -621,4 +888,148 @@ mod tests {
             );
         }
     }
+
+    mod history {
+        use std::{cell::RefCell, rc::Rc};
+
+        use pretty_assertions::assert_eq;
+
+        use super::*;
+
+        fn key(code: KeyCode) -> KeyEvent {
+            KeyEvent::from(code)
+        }
+
+        fn ctrl(c: char) -> KeyEvent {
+            KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL)
+        }
+
+        fn input_with_history(entries: &[&str]) -> (InputForm, Rc<RefCell<Vec<String>>>) {
+            let history = Rc::new(RefCell::new(
+                entries.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            ));
+
+            let loader = history.clone();
+            let saver = history.clone();
+
+            let input = InputForm::builder()
+                .history(
+                    move || loader.borrow().clone(),
+                    move |entry: &str| saver.borrow_mut().push(entry.to_string()),
+                )
+                .build();
+
+            (input, history)
+        }
+
+        fn type_str(input: &mut InputForm, s: &str) {
+            s.chars().for_each(|c| {
+                input.on_key_event(key(KeyCode::Char(c)));
+            });
+        }
+
+        #[test]
+        fn 上下キーで履歴を辿り入力中の内容に戻る() {
+            let (mut input, _) = input_with_history(&["first", "second"]);
+
+            type_str(&mut input, "draft");
+
+            input.on_key_event(key(KeyCode::Up));
+            assert_eq!(input.content(), "second");
+
+            input.on_key_event(key(KeyCode::Up));
+            assert_eq!(input.content(), "first");
+
+            input.on_key_event(key(KeyCode::Up));
+            assert_eq!(input.content(), "first");
+
+            input.on_key_event(key(KeyCode::Down));
+            assert_eq!(input.content(), "second");
+
+            input.on_key_event(key(KeyCode::Down));
+            assert_eq!(input.content(), "draft");
+        }
+
+        #[test]
+        fn 履歴がない場合は上下キーを処理しない() {
+            let mut input = InputForm::builder().build();
+
+            assert!(matches!(
+                input.on_key_event(key(KeyCode::Up)),
+                EventResult::Ignore
+            ));
+        }
+
+        #[test]
+        fn 確定した内容を履歴に追加する() {
+            let (mut input, history) = input_with_history(&[]);
+
+            type_str(&mut input, "pod:app");
+            input.commit_history();
+
+            assert_eq!(*history.borrow(), vec!["pod:app"]);
+        }
+
+        #[test]
+        fn ctrl_rで履歴を逆方向に検索する() {
+            let (mut input, _) =
+                input_with_history(&["pod:api", "pod:web", "pod:api-v2", "level:>=warn"]);
+
+            input.on_key_event(ctrl('r'));
+            type_str(&mut input, "api");
+
+            assert_eq!(
+                input
+                    .reverse_search
+                    .as_ref()
+                    .and_then(|s| s.matched_entry()),
+                Some("pod:api-v2")
+            );
+
+            input.on_key_event(ctrl('r'));
+
+            assert_eq!(
+                input
+                    .reverse_search
+                    .as_ref()
+                    .and_then(|s| s.matched_entry()),
+                Some("pod:api")
+            );
+
+            input.on_key_event(key(KeyCode::Enter));
+
+            assert!(input.reverse_search.is_none());
+            assert_eq!(input.content(), "pod:api");
+        }
+
+        #[test]
+        fn 逆方向検索をキャンセルすると元の内容に戻る() {
+            let (mut input, _) = input_with_history(&["pod:api"]);
+
+            type_str(&mut input, "draft");
+
+            input.on_key_event(ctrl('r'));
+            type_str(&mut input, "api");
+            input.on_key_event(key(KeyCode::Esc));
+
+            assert!(input.reverse_search.is_none());
+            assert_eq!(input.content(), "draft");
+        }
+
+        #[test]
+        fn 一致しない文字を入力すると検索失敗になる() {
+            let (mut input, _) = input_with_history(&["pod:api"]);
+
+            input.on_key_event(ctrl('r'));
+            type_str(&mut input, "xyz");
+
+            assert_eq!(
+                input
+                    .reverse_search
+                    .as_ref()
+                    .and_then(|s| s.matched_entry()),
+                None
+            );
+        }
+    }
 }
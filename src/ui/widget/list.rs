@@ -14,6 +14,7 @@ use super::{config::WidgetConfig, Item, LiteralItem, RenderTrait, SelectedItem,
 
 use crate::{
     define_callback,
+    message::UserEvent,
     ui::{
         event::{Callback, EventResult},
         key_event_to_code,
@@ -86,6 +87,8 @@ pub struct List<'a> {
     #[derivative(Debug = "ignore")]
     on_select: Option<OnSelectCallback>,
     #[derivative(Debug = "ignore")]
+    actions: Vec<(UserEvent, Callback)>,
+    #[derivative(Debug = "ignore")]
     block_injection: Option<RenderBlockInjection>,
 }
 
@@ -99,6 +102,8 @@ pub struct ListBuilder {
     #[derivative(Debug = "ignore")]
     on_select: Option<OnSelectCallback>,
     #[derivative(Debug = "ignore")]
+    actions: Vec<(UserEvent, Callback)>,
+    #[derivative(Debug = "ignore")]
     block_injection: Option<RenderBlockInjection>,
 }
 
@@ -130,6 +135,15 @@ impl ListBuilder {
         self
     }
 
+    pub fn action<F, E>(mut self, ev: E, cb: F) -> Self
+    where
+        E: Into<UserEvent>,
+        F: Into<Callback>,
+    {
+        self.actions.push((ev.into(), cb.into()));
+        self
+    }
+
     pub fn block_injection<F>(mut self, block_injection: F) -> Self
     where
         F: Into<RenderBlockInjection>,
@@ -143,6 +157,7 @@ impl ListBuilder {
             id: self.id,
             widget_config: self.widget_config,
             on_select: self.on_select,
+            actions: self.actions,
             state: self.state,
             block_injection: self.block_injection,
             ..Default::default()
@@ -372,10 +387,11 @@ impl<'a> WidgetTrait for List<'a> {
 
                 return EventResult::Ignore;
             }
-            KeyCode::Char(_) => {
-                return EventResult::Ignore;
-            }
             _ => {
+                if let Some(cb) = self.match_action(UserEvent::Key(ev)) {
+                    return EventResult::Callback(cb.clone());
+                }
+
                 return EventResult::Ignore;
             }
         }
@@ -416,6 +432,12 @@ impl<'a> List<'a> {
             .selected()
             .and_then(|index| self.items().get(index).map(|item| Rc::new(item.clone())))
     }
+
+    fn match_action(&self, ev: UserEvent) -> Option<&Callback> {
+        self.actions
+            .iter()
+            .find_map(|(cb_ev, cb)| if *cb_ev == ev { Some(cb) } else { None })
+    }
 }
 
 impl RenderTrait for List<'_> {
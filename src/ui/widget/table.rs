@@ -26,7 +26,9 @@ use crate::{
 };
 
 use super::{
-    config::WidgetConfig, styled_graphemes, Item, RenderTrait, SelectedItem, TableItem, WidgetTrait,
+    config::WidgetConfig,
+    input::{HistoryLoader, HistorySaver},
+    styled_graphemes, Item, RenderTrait, SelectedItem, TableItem, WidgetTrait,
 };
 
 use filter_form::{FilterForm, FILTER_HEIGHT};
@@ -58,6 +60,8 @@ pub struct TableBuilder {
     block_injection: Option<RenderBlockInjection>,
     #[derivative(Debug = "ignore")]
     highlight_injection: Option<RenderHighlightInjection>,
+    #[derivative(Debug = "ignore")]
+    filter_history: Option<(HistoryLoader, HistorySaver)>,
 }
 
 #[allow(dead_code)]
@@ -128,6 +132,16 @@ impl TableBuilder {
         self
     }
 
+    /// フィルターフォームで辿る履歴の読み書き
+    pub fn filter_history<L, S>(mut self, loader: L, saver: S) -> Self
+    where
+        L: Into<HistoryLoader>,
+        S: Into<HistorySaver>,
+    {
+        self.filter_history = Some((loader.into(), saver.into()));
+        self
+    }
+
     pub fn build(self) -> Table<'static> {
         let mut table = Table {
             id: self.id,
@@ -142,6 +156,10 @@ impl TableBuilder {
             ..Default::default()
         };
 
+        if let Some(history) = self.filter_history {
+            table.filter_widget = FilterForm::with_history(history);
+        }
+
         table.items = InnerItem::builder()
             .header(self.header)
             .items(self.items)
@@ -212,6 +230,8 @@ pub struct Table<'a> {
     filter_widget: FilterForm,
     filtered_key: String,
     mode: Mode,
+    /// 直前の`update_widget_item`で内容が変わったか
+    content_changed: bool,
     #[derivative(Debug = "ignore")]
     on_select: Option<OnSelectCallback>,
     #[derivative(Debug = "ignore")]
@@ -255,6 +275,8 @@ impl<'a> Table<'a> {
 
         self.items.update_filter(self.filter_widget.word());
 
+        self.content_changed = true;
+
         self.adjust_selected(old_len, self.items.len());
 
         self.update_row_bounds();
@@ -441,13 +463,22 @@ impl WidgetTrait for Table<'_> {
     fn update_widget_item(&mut self, items: Item) {
         let old_len = self.items.len();
 
-        self.items.update_items(items.table());
+        self.content_changed = self.items.update_items(items.table());
 
         self.adjust_selected(old_len, self.items.len());
 
         self.update_row_bounds();
     }
 
+    fn is_content_changed(&self) -> bool {
+        self.content_changed
+    }
+
+    /// フィルター入力中はカーソルを点滅させる
+    fn needs_periodic_redraw(&self, is_active: bool) -> bool {
+        is_active && self.mode.is_filter_input()
+    }
+
     fn on_mouse_event(&mut self, ev: MouseEvent) -> EventResult {
         if self.items.is_empty() {
             return EventResult::Nop;
@@ -558,11 +589,12 @@ impl WidgetTrait for Table<'_> {
             },
 
             Mode::FilterInput => match key_event_to_code(ev) {
-                KeyCode::Enter => {
+                KeyCode::Enter if !self.filter_widget.is_reverse_searching() => {
+                    self.filter_widget.commit_history();
                     self.mode.filter_confirm();
                 }
 
-                KeyCode::Esc => {
+                KeyCode::Esc if !self.filter_widget.is_reverse_searching() => {
                     self.filter_cancel();
                 }
 
@@ -1102,4 +1134,31 @@ mod tests {
             }
         }
     }
+
+    mod 内容の変更 {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        fn items() -> Vec<TableItem> {
+            vec![
+                TableItem::new(vec!["Item-0".to_string(), "Item-0".to_string()], None),
+                TableItem::new(vec!["Item-1".to_string(), "Item-1".to_string()], None),
+            ]
+        }
+
+        #[test]
+        fn 同じアイテムで更新したときは変更なしとする() {
+            let mut table = Table::builder()
+                .header(["A".to_string(), "B".to_string()])
+                .build();
+
+            table.update_widget_item(Item::Table(items()));
+
+            assert_eq!(table.is_content_changed(), true);
+
+            table.update_widget_item(Item::Table(items()));
+
+            assert_eq!(table.is_content_changed(), false);
+        }
+    }
 }
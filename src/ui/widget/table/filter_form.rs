@@ -7,7 +7,11 @@ use ratatui::{
 
 use crate::ui::{
     event::EventResult,
-    widget::{config::WidgetConfig, input::InputForm, RenderTrait},
+    widget::{
+        config::WidgetConfig,
+        input::{HistoryLoader, HistorySaver, InputForm, InputFormBuilder},
+        RenderTrait,
+    },
 };
 
 #[derive(Debug, Default)]
@@ -28,9 +32,7 @@ impl Default for FilterForm {
     fn default() -> Self {
         Self {
             widget_config: WidgetConfig::default(),
-            input_widget: InputForm::builder()
-                .widget_config(WidgetConfig::builder().block(Block::default()).build())
-                .build(),
+            input_widget: Self::input_builder().build(),
             chunk: Chunk::default(),
             layout: Layout::default()
                 .direction(Direction::Horizontal)
@@ -43,6 +45,19 @@ impl Default for FilterForm {
 pub const FILTER_HEIGHT: u16 = 3;
 
 impl FilterForm {
+    fn input_builder() -> InputFormBuilder {
+        InputForm::builder().widget_config(WidgetConfig::builder().block(Block::default()).build())
+    }
+
+    pub fn with_history(history: (HistoryLoader, HistorySaver)) -> Self {
+        let (loader, saver) = history;
+
+        Self {
+            input_widget: Self::input_builder().history(loader, saver).build(),
+            ..Default::default()
+        }
+    }
+
     pub fn update_chunk(&mut self, chunk: Rect) {
         let block_chunk = Rect::new(chunk.x, chunk.y, chunk.width, FILTER_HEIGHT);
 
@@ -74,6 +89,14 @@ impl FilterForm {
         self.input_widget.clear();
     }
 
+    pub fn commit_history(&mut self) {
+        self.input_widget.commit_history();
+    }
+
+    pub fn is_reverse_searching(&self) -> bool {
+        self.input_widget.is_reverse_searching()
+    }
+
     pub fn render(&mut self, f: &mut Frame<'_>, is_active: bool) {
         f.render_widget(
             self.widget_config.render_block(is_active, false),
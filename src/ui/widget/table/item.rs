@@ -118,10 +118,18 @@ impl<'a> InnerItem<'a> {
         self.item_margin
     }
 
-    pub fn update_items(&mut self, item: Vec<TableItem>) {
+    /// 内容が変わった場合にtrueを返す
+    pub fn update_items(&mut self, item: Vec<TableItem>) -> bool {
+        // 内容が変わらない場合はフィルターと描画用の行の再生成を省略する
+        if self.original_items == item {
+            return false;
+        }
+
         self.original_items = item;
         self.inner_filter_items();
         self.inner_update_rendered_items();
+
+        true
     }
 
     pub fn update_max_width(&mut self, max_width: usize) {
@@ -3,7 +3,12 @@ mod render;
 mod search_form;
 mod wrap;
 
-use std::{cell::RefCell, rc::Rc};
+use std::{
+    cell::RefCell,
+    collections::BTreeSet,
+    hash::{DefaultHasher, Hash, Hasher},
+    rc::Rc,
+};
 
 use derivative::Derivative;
 
@@ -38,6 +43,8 @@ use self::{
 };
 
 define_callback!(pub RenderBlockInjection, Fn(&Text, bool, bool) -> Block<'static> );
+define_callback!(pub OnEvictCallback, Fn(&[LiteralItem]));
+define_callback!(pub GutterInjection, Fn(&LiteralItem) -> Option<String>);
 
 mod highlight_content {
 
@@ -164,12 +171,20 @@ pub struct TextBuilder {
     item: Vec<LiteralItem>,
     wrap: bool,
     follow: bool,
+    bookmark: bool,
+    max_lines: Option<usize>,
+    max_bytes: Option<usize>,
     #[derivative(Debug = "ignore")]
     block_injection: Option<RenderBlockInjection>,
     #[derivative(Debug = "ignore")]
     actions: Vec<(UserEvent, Callback)>,
     #[derivative(Debug = "ignore")]
     clipboard: Option<Rc<RefCell<Clipboard>>>,
+    #[derivative(Debug = "ignore")]
+    on_evict: Option<OnEvictCallback>,
+    #[derivative(Debug = "ignore")]
+    gutter: Option<(u16, GutterInjection)>,
+    periodic_redraw: bool,
 }
 
 impl TextBuilder {
@@ -202,6 +217,41 @@ impl TextBuilder {
         self
     }
 
+    pub fn bookmark(mut self) -> Self {
+        self.bookmark = true;
+        self
+    }
+
+    /// 保持する最大行数（超えた場合は9割まで削除する）
+    pub fn max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = Some(max_lines);
+        self
+    }
+
+    /// 保持する最大バイト数（超えた場合は9割まで削除する）
+    pub fn max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = Some(max_bytes);
+        self
+    }
+
+    /// 上限を超えて先頭から削除された行を受け取るコールバック
+    pub fn on_evict<F>(mut self, on_evict: F) -> Self
+    where
+        F: Into<OnEvictCallback>,
+    {
+        self.on_evict = Some(on_evict.into());
+        self
+    }
+
+    /// 各行の左側に幅widthの領域を設けて、行ごとにgutterの返す文字列を表示する
+    pub fn gutter<F>(mut self, width: u16, gutter: F) -> Self
+    where
+        F: Into<GutterInjection>,
+    {
+        self.gutter = Some((width, gutter.into()));
+        self
+    }
+
     pub fn action<F, E>(mut self, ev: E, cb: F) -> Self
     where
         E: Into<UserEvent>,
@@ -224,6 +274,12 @@ impl TextBuilder {
         self
     }
 
+    /// 時間の経過で表示が変わる内容（相対時刻など）を表示する場合に、定期的に再描画する
+    pub fn periodic_redraw(mut self, periodic_redraw: bool) -> Self {
+        self.periodic_redraw = periodic_redraw;
+        self
+    }
+
     pub fn build(self) -> Text {
         Text {
             id: self.id,
@@ -231,9 +287,15 @@ impl TextBuilder {
             item: TextItem::new(self.item, None),
             wrap: self.wrap,
             follow: self.follow,
+            bookmark: self.bookmark,
+            max_lines: self.max_lines,
+            max_bytes: self.max_bytes,
             actions: self.actions,
             block_injection: self.block_injection,
             clipboard: self.clipboard,
+            on_evict: self.on_evict,
+            gutter: self.gutter,
+            periodic_redraw: self.periodic_redraw,
             ..Default::default()
         }
     }
@@ -253,12 +315,34 @@ pub struct Text {
     /// 検索中、検索ワード入力中、オフの3つのモード
     mode: Mode,
     highlight_content: Option<HighlightContent>,
+    /// ブックマーク機能を有効にするか
+    bookmark: bool,
+    /// ブックマークした行のインデックス
+    bookmarks: BTreeSet<usize>,
+    /// 保持する最大行数
+    max_lines: Option<usize>,
+    /// 保持する最大バイト数
+    max_bytes: Option<usize>,
+    /// 上限を超えて先頭から削除した行数
+    dropped_lines: usize,
+    /// 直前に`update_widget_item`で設定した内容のハッシュ値
+    /// 同じ内容で更新されたときに折り返しの再計算を省略する
+    item_hash: Option<u64>,
+    /// 直前の`update_widget_item`で内容が変わったか
+    content_changed: bool,
+    /// 相対時刻など、時間の経過で変わる内容を表示しているか
+    periodic_redraw: bool,
     #[derivative(Debug = "ignore")]
     block_injection: Option<RenderBlockInjection>,
     #[derivative(Debug = "ignore")]
     actions: Vec<(UserEvent, Callback)>,
     #[derivative(Debug = "ignore")]
     clipboard: Option<Rc<RefCell<Clipboard>>>,
+    #[derivative(Debug = "ignore")]
+    on_evict: Option<OnEvictCallback>,
+    /// 各行の左側に表示する領域の幅と内容
+    #[derivative(Debug = "ignore")]
+    gutter: Option<(u16, GutterInjection)>,
 }
 
 impl Text {
@@ -267,6 +351,59 @@ impl Text {
     }
 }
 
+/// ガター
+///
+/// 行の左側に行ごとの付加情報（タイムスタンプなど）を表示する。
+/// 折り返した行は先頭の行にのみ表示する。
+impl Text {
+    pub fn set_gutter<F>(&mut self, width: u16, gutter: F)
+    where
+        F: Into<GutterInjection>,
+    {
+        self.gutter = Some((width, gutter.into()));
+        self.update_chunk(self.chunk);
+    }
+
+    pub fn clear_gutter(&mut self) {
+        self.gutter = None;
+        self.update_chunk(self.chunk);
+    }
+
+    pub fn set_periodic_redraw(&mut self, periodic_redraw: bool) {
+        self.periodic_redraw = periodic_redraw;
+    }
+
+    /// 区切りの1列を含めたガターの幅
+    fn gutter_width(&self) -> u16 {
+        self.gutter
+            .as_ref()
+            .map_or(0, |(width, _)| width.saturating_add(1))
+    }
+
+    /// 表示中の行ごとのガターの内容
+    fn gutter_contents(&self, height: usize) -> Vec<Option<String>> {
+        let Some((_, gutter)) = &self.gutter else {
+            return Vec::new();
+        };
+
+        let wrapped_lines = self.item.wrapped_lines();
+
+        (self.scroll.y..)
+            .take(height)
+            .map_while(|i| wrapped_lines.get(i).map(|line| (i, line.index())))
+            .map(|(i, index)| {
+                let is_head = i == 0 || wrapped_lines[i - 1].index() != index;
+
+                if is_head {
+                    self.item.literal_item(index).and_then(&**gutter)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
 /// ワード検索機能
 ///
 /// # Features
@@ -371,6 +508,163 @@ impl Text {
     }
 }
 
+/// ブックマーク機能
+///
+/// # Features
+///
+/// - 選択中の行のブックマークを切り替え
+///   - 検索ワード確定後は選択中のマッチ箇所の行
+///   - それ以外は画面の一番上の行
+/// - 次・前のブックマークに移動
+/// - 行の追加や折り返し幅の変更後もブックマークを保持
+impl Text {
+    pub fn toggle_bookmark(&mut self) {
+        let Some(index) = self.selected_line_index() else {
+            return;
+        };
+
+        if !self.bookmarks.remove(&index) {
+            self.bookmarks.insert(index);
+        }
+    }
+
+    /// 画面の一番上の行より後ろにあるブックマークに移動する
+    /// 最後のブックマークより後ろの場合は最初のブックマークに移動する
+    pub fn bookmark_next(&mut self) {
+        let current = self.item.line_index(self.scroll.y).unwrap_or_default();
+
+        let next = self
+            .bookmarks
+            .range((current + 1)..)
+            .next()
+            .or_else(|| self.bookmarks.first())
+            .copied();
+
+        if let Some(index) = next {
+            self.jump_to_line(index);
+        }
+    }
+
+    /// 画面の一番上の行より前にあるブックマークに移動する
+    /// 最初のブックマークより前の場合は最後のブックマークに移動する
+    pub fn bookmark_prev(&mut self) {
+        let current = self.item.line_index(self.scroll.y).unwrap_or_default();
+
+        let prev = self
+            .bookmarks
+            .range(..current)
+            .next_back()
+            .or_else(|| self.bookmarks.last())
+            .copied();
+
+        if let Some(index) = prev {
+            self.jump_to_line(index);
+        }
+    }
+
+    /// ブックマークした行のインデックスと内容を行順に返す
+    pub fn bookmarks(&self) -> Vec<(usize, &LiteralItem)> {
+        self.bookmarks
+            .iter()
+            .filter_map(|&index| self.item.literal_item(index).map(|item| (index, item)))
+            .collect()
+    }
+
+    /// 選択中の行（検索ワード確定後は選択中のマッチ箇所の行、それ以外は画面の一番上の行）
+    pub fn selected_literal_item(&self) -> Option<&LiteralItem> {
+        self.selected_line_index()
+            .and_then(|index| self.item.literal_item(index))
+    }
+
+    /// 指定した行が画面の一番上になるようにスクロールする
+    pub fn jump_to_line(&mut self, line_index: usize) {
+        if let Some(line_number) = self.item.line_number(line_index) {
+            self.scroll.y = line_number.min(self.scroll_y_last_index());
+        }
+    }
+
+    fn selected_line_index(&self) -> Option<usize> {
+        let line_number = if self.mode.is_search_confirm() {
+            self.item
+                .highlight_selected_line_number()
+                .unwrap_or(self.scroll.y)
+        } else {
+            self.scroll.y
+        };
+
+        self.item.line_index(line_number)
+    }
+}
+
+/// 保持する行数・バイト数の制限
+///
+/// 上限を超えた場合は古い行から削除する
+/// 表示位置とブックマークは削除後も同じ行を指すように調整する
+impl Text {
+    pub fn dropped_lines(&self) -> usize {
+        self.dropped_lines
+    }
+
+    pub fn bytes(&self) -> usize {
+        self.item.bytes()
+    }
+
+    pub fn max_bytes(&self) -> Option<usize> {
+        self.max_bytes
+    }
+
+    /// 上限を超えた場合は上限の9割まで先頭から削除する
+    ///
+    /// 削除のたびに残りの行のインデックスを振り直すため、超えた分だけを削除すると追加のたびに全行を走査することになる
+    fn evict(&mut self) {
+        let low_water_mark = |max: usize| max - max / 10;
+
+        let over_lines = self
+            .max_lines
+            .filter(|max_lines| *max_lines < self.item.len())
+            .map(|max_lines| self.item.len() - low_water_mark(max_lines))
+            .unwrap_or_default();
+
+        let over_bytes = self
+            .max_bytes
+            .filter(|max_bytes| *max_bytes < self.item.bytes())
+            .map(|max_bytes| self.item.lines_over_bytes(low_water_mark(max_bytes)))
+            .unwrap_or_default();
+
+        let n = over_lines.max(over_bytes);
+
+        if n == 0 {
+            return;
+        }
+
+        let is_bottom = self.is_bottom();
+
+        let (removed, removed_wrapped_len) = self.item.remove_front(n);
+
+        self.dropped_lines += removed.len();
+
+        self.bookmarks = self
+            .bookmarks
+            .iter()
+            .filter_map(|index| index.checked_sub(removed.len()))
+            .collect();
+
+        if self.follow && is_bottom {
+            self.select_last();
+        } else {
+            self.scroll.y = self
+                .scroll
+                .y
+                .saturating_sub(removed_wrapped_len)
+                .min(self.scroll_y_last_index());
+        }
+
+        if let Some(on_evict) = &self.on_evict {
+            on_evict(&removed);
+        }
+    }
+}
+
 impl Text {
     pub fn scroll_right(&mut self, i: usize) {
         if self.wrap {
@@ -421,9 +715,16 @@ impl Text {
     }
 
     pub fn inner_chunk(&self) -> Rect {
-        let chunk = self.chunk();
+        let chunk = self.widget_config.block().inner(self.chunk());
+
+        let gutter_width = self.gutter_width().min(chunk.width);
 
-        self.widget_config.block().inner(chunk)
+        Rect::new(
+            chunk.x + gutter_width,
+            chunk.y,
+            chunk.width - gutter_width,
+            chunk.height,
+        )
     }
 
     fn is_bottom(&self) -> bool {
@@ -505,6 +806,8 @@ impl WidgetTrait for Text {
     fn append_widget_item(&mut self, item: Item) {
         let is_bottom = self.is_bottom();
 
+        self.item_hash = None;
+
         match item {
             Item::Single(i) => self.item.push(i),
             Item::Array(i) => self.item.extend(i),
@@ -513,6 +816,8 @@ impl WidgetTrait for Text {
             }
         }
 
+        self.evict();
+
         if self.follow && is_bottom {
             self.select_last()
         }
@@ -522,8 +827,27 @@ impl WidgetTrait for Text {
         let is_bottom = self.is_bottom();
 
         let item = item.array();
+
+        let item_hash = {
+            let mut hasher = DefaultHasher::new();
+            item.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        self.content_changed = self.item_hash != Some(item_hash);
+
+        if !self.content_changed {
+            return;
+        }
+
+        self.item_hash = Some(item_hash);
+
         self.item.update(item);
 
+        self.bookmarks.clear();
+
+        self.evict();
+
         if self.follow && is_bottom {
             self.select_last()
         }
@@ -533,6 +857,15 @@ impl WidgetTrait for Text {
         }
     }
 
+    fn is_content_changed(&self) -> bool {
+        self.content_changed
+    }
+
+    /// 検索ワード入力中のカーソルの点滅と、時間の経過で変わる内容を表示するため
+    fn needs_periodic_redraw(&self, is_active: bool) -> bool {
+        (is_active && self.mode.is_search_input()) || self.periodic_redraw
+    }
+
     fn on_mouse_event(&mut self, ev: MouseEvent) -> EventResult {
         if self.item.is_empty() {
             return EventResult::Nop;
@@ -716,6 +1049,18 @@ impl WidgetTrait for Text {
                     self.search_prev();
                 }
 
+                Char('m') if self.bookmark => {
+                    self.toggle_bookmark();
+                }
+
+                Char(']') if self.bookmark => {
+                    self.bookmark_next();
+                }
+
+                Char('[') if self.bookmark => {
+                    self.bookmark_prev();
+                }
+
                 _ => {
                     if let Some(cb) = self.match_action(UserEvent::Key(ev)) {
                         return EventResult::Callback(cb.clone());
@@ -751,8 +1096,10 @@ impl WidgetTrait for Text {
 
         self.chunk = chunk;
 
-        if self.wrap {
-            self.item.rewrap(self.inner_chunk().width as usize);
+        let wrap_width = self.inner_chunk().width as usize;
+
+        if self.wrap && self.item.wrap_width() != Some(wrap_width) {
+            self.item.rewrap(wrap_width);
         };
 
         self.search_widget.update_chunk(chunk);
@@ -776,6 +1123,9 @@ impl WidgetTrait for Text {
         };
 
         self.item = TextItem::new(vec![], wrap_width);
+        self.bookmarks.clear();
+        self.dropped_lines = 0;
+        self.item_hash = None;
         self.search_cancel();
 
         *(self.widget_config.append_title_mut()) = None;
@@ -796,7 +1146,15 @@ impl RenderTrait for Text {
         let mut builder = Render::builder()
             .block(block)
             .lines(wrapped_lines)
-            .scroll(self.scroll);
+            .scroll(self.scroll)
+            .bookmarks(&self.bookmarks);
+
+        if self.gutter.is_some() {
+            builder = builder.gutter(
+                self.gutter_width(),
+                self.gutter_contents(self.inner_chunk().height as usize),
+            );
+        }
 
         if let Some(highlight_content) = &self.highlight_content {
             builder = builder.highlight_area(Some(highlight_content.area));
@@ -855,6 +1213,315 @@ impl RenderTrait for Text {
 mod tests {
     use super::*;
 
+    mod ブックマーク {
+        use super::*;
+
+        fn text() -> Text {
+            let mut text = Text::builder()
+                .items((0..10).map(|i| i.to_string()).collect::<Vec<_>>())
+                .bookmark()
+                .build();
+
+            // 枠を除いて5行表示
+            text.update_chunk(Rect::new(0, 0, 10, 7));
+
+            text
+        }
+
+        #[test]
+        fn 画面の一番上の行のブックマークを切り替える() {
+            let mut text = text();
+
+            text.select_next(2);
+            text.toggle_bookmark();
+
+            assert_eq!(text.bookmarks(), vec![(2, &LiteralItem::new("2", None))]);
+
+            text.toggle_bookmark();
+
+            assert!(text.bookmarks().is_empty());
+        }
+
+        #[test]
+        fn 次のブックマークに移動し最後の次は最初に戻る() {
+            let mut text = text();
+
+            text.select_next(1);
+            text.toggle_bookmark();
+            text.select_next(2);
+            text.toggle_bookmark();
+
+            text.select_first();
+
+            text.bookmark_next();
+            assert_eq!(text.scroll.y, 1);
+
+            text.bookmark_next();
+            assert_eq!(text.scroll.y, 3);
+
+            text.bookmark_next();
+            assert_eq!(text.scroll.y, 1);
+        }
+
+        #[test]
+        fn 前のブックマークに移動し最初の前は最後に戻る() {
+            let mut text = text();
+
+            text.select_next(1);
+            text.toggle_bookmark();
+            text.select_next(2);
+            text.toggle_bookmark();
+
+            text.bookmark_prev();
+            assert_eq!(text.scroll.y, 1);
+
+            text.bookmark_prev();
+            assert_eq!(text.scroll.y, 3);
+        }
+
+        #[test]
+        fn 行を追加してもブックマークを保持する() {
+            let mut text = text();
+
+            text.select_next(4);
+            text.toggle_bookmark();
+
+            text.append_widget_item(Item::Array(vec![
+                LiteralItem::new("10", None),
+                LiteralItem::new("11", None),
+            ]));
+
+            assert_eq!(text.bookmarks(), vec![(4, &LiteralItem::new("4", None))]);
+        }
+
+        #[test]
+        fn 同じ内容で更新した場合はブックマークを保持し異なる内容で更新した場合は削除する() {
+            let mut text = text();
+
+            let items = || {
+                Item::Array(
+                    (0..10)
+                        .map(|i| LiteralItem::new(i.to_string(), None))
+                        .collect(),
+                )
+            };
+
+            text.update_widget_item(items());
+
+            text.select_next(2);
+            text.toggle_bookmark();
+
+            text.update_widget_item(items());
+
+            assert_eq!(text.bookmarks(), vec![(2, &LiteralItem::new("2", None))]);
+
+            text.update_widget_item(Item::Array(vec![LiteralItem::new("new", None)]));
+
+            assert!(text.bookmarks().is_empty());
+        }
+
+        #[test]
+        fn クリアするとブックマークを削除する() {
+            let mut text = text();
+
+            text.toggle_bookmark();
+            text.clear();
+
+            assert!(text.bookmarks().is_empty());
+        }
+    }
+
+    mod ガター {
+        use super::*;
+
+        fn text() -> Text {
+            let mut text = Text::builder()
+                .items(vec!["0123456789".to_string(), "abc".to_string()])
+                .wrap()
+                .gutter(3, |item: &LiteralItem| {
+                    Some(format!("#{}", item.item.len()))
+                })
+                .build();
+
+            // 枠を除いて幅10
+            text.update_chunk(Rect::new(0, 0, 12, 7));
+
+            text
+        }
+
+        #[test]
+        fn ガターと区切りの幅を除いて折り返す() {
+            let text = text();
+
+            assert_eq!(text.inner_chunk().width, 6);
+            assert_eq!(text.item.wrapped_lines().len(), 3);
+        }
+
+        #[test]
+        fn 折り返した行は先頭の行にのみガターを表示する() {
+            let text = text();
+
+            assert_eq!(
+                text.gutter_contents(5),
+                vec![Some("#10".to_string()), None, Some("#3".to_string())]
+            );
+        }
+
+        #[test]
+        fn ガターを外すと全幅で折り返す() {
+            let mut text = text();
+
+            text.clear_gutter();
+
+            assert_eq!(text.inner_chunk().width, 10);
+            assert_eq!(text.item.wrapped_lines().len(), 2);
+            assert!(text.gutter_contents(5).is_empty());
+        }
+    }
+
+    mod 再描画 {
+        use super::*;
+
+        #[test]
+        fn 同じ内容で更新したときは変更なしとする() {
+            let mut text = Text::default();
+
+            text.update_widget_item(Item::Array(vec!["a".into()]));
+
+            assert!(text.is_content_changed());
+
+            text.update_widget_item(Item::Array(vec!["a".into()]));
+
+            assert!(!text.is_content_changed());
+        }
+
+        #[test]
+        fn 時間で変わる内容を表示している間は非アクティブでも定期的に再描画する() {
+            let mut text = Text::builder().periodic_redraw(true).build();
+
+            assert!(text.needs_periodic_redraw(false));
+
+            text.set_periodic_redraw(false);
+
+            assert!(!text.needs_periodic_redraw(true));
+        }
+    }
+
+    mod 保持する行の制限 {
+        use super::*;
+
+        fn items(range: std::ops::Range<usize>) -> Item {
+            Item::Array(range.map(LiteralItem::from).collect())
+        }
+
+        #[test]
+        fn 最大行数を超えた古い行を削除する() {
+            let mut text = Text::builder().max_lines(5).build();
+
+            text.update_chunk(Rect::new(0, 0, 10, 5));
+
+            text.append_widget_item(items(0..8));
+
+            assert_eq!(text.dropped_lines(), 3);
+            assert_eq!(
+                text.item.literal_item(0),
+                Some(&LiteralItem::new("3", None))
+            );
+        }
+
+        #[test]
+        fn 最大バイト数を超えた古い行を削除する() {
+            let mut text = Text::builder().max_bytes(10).build();
+
+            text.update_chunk(Rect::new(0, 0, 10, 5));
+
+            text.append_widget_item(items(0..20));
+
+            // 上限の9割（9バイト）まで削除するため"16".."19"の4行で8バイト
+            assert_eq!(text.dropped_lines(), 16);
+            assert_eq!(text.bytes(), 8);
+        }
+
+        #[test]
+        fn 最大行数を超えた場合は上限の9割まで削除する() {
+            let mut text = Text::builder().max_lines(100).build();
+
+            text.update_chunk(Rect::new(0, 0, 10, 5));
+
+            text.append_widget_item(items(0..100));
+
+            assert_eq!(text.dropped_lines(), 0);
+
+            text.append_widget_item(items(100..101));
+
+            assert_eq!(text.dropped_lines(), 11);
+            assert_eq!(
+                text.item.literal_item(0),
+                Some(&LiteralItem::new("11", None))
+            );
+
+            text.append_widget_item(items(101..111));
+
+            assert_eq!(text.dropped_lines(), 11);
+        }
+
+        #[test]
+        fn 削除した行をコールバックに渡す() {
+            let evicted = Rc::new(RefCell::new(Vec::new()));
+
+            let cloned = evicted.clone();
+            let mut text = Text::builder()
+                .max_lines(3)
+                .on_evict(move |items: &[LiteralItem]| {
+                    cloned.borrow_mut().extend(items.iter().cloned())
+                })
+                .build();
+
+            text.append_widget_item(items(0..5));
+
+            assert_eq!(
+                *evicted.borrow(),
+                vec![LiteralItem::new("0", None), LiteralItem::new("1", None)]
+            );
+        }
+
+        #[test]
+        fn 削除後もブックマークと表示位置は同じ行を指す() {
+            let mut text = Text::builder().max_lines(10).bookmark().build();
+
+            // 枠を除いて3行表示
+            text.update_chunk(Rect::new(0, 0, 10, 5));
+
+            text.append_widget_item(items(0..10));
+
+            text.select_first();
+            text.select_next(5);
+            text.toggle_bookmark();
+
+            // 上限の9割（9行）まで削除するため先頭の4行を削除する
+            text.append_widget_item(items(10..13));
+
+            assert_eq!(text.scroll.y, 1);
+            assert_eq!(text.bookmarks(), vec![(1, &LiteralItem::new("5", None))]);
+        }
+
+        #[test]
+        fn 削除された行のブックマークは取り除く() {
+            let mut text = Text::builder().max_lines(5).bookmark().build();
+
+            text.update_chunk(Rect::new(0, 0, 10, 5));
+
+            text.append_widget_item(items(0..5));
+
+            text.select_first();
+            text.toggle_bookmark();
+
+            text.append_widget_item(items(5..6));
+
+            assert!(text.bookmarks().is_empty());
+        }
+    }
+
     mod スクロール {
         use super::*;
 
@@ -68,6 +68,9 @@ pub struct TextItem {
 
     /// 1行あたりの最大文字数
     max_chars: usize,
+
+    /// 保持している文字列のバイト数
+    bytes: usize,
 }
 
 type Graphemes = Vec<StyledGrapheme>;
@@ -85,12 +88,15 @@ impl TextItem {
             .max()
             .unwrap_or_default();
 
+        let bytes = lines.iter().map(Line::bytes).sum();
+
         Self {
             lines,
             wrapped_lines,
             highlights: None,
             wrap_width,
             max_chars,
+            bytes,
         }
     }
 
@@ -147,6 +153,8 @@ impl TextItem {
             wrapped_lines: line_number..(line_number + wrapped_lines.len()),
         };
 
+        self.bytes += line.bytes();
+
         self.lines.push(line);
         self.wrapped_lines.extend(wrapped_lines);
 
@@ -181,6 +189,8 @@ impl TextItem {
             .unwrap_or_default()
             .max(self.max_chars);
 
+        self.bytes += lines.iter().map(Line::bytes).sum::<usize>();
+
         self.lines.extend(lines);
         self.wrapped_lines
             .extend(wrapped_lines.into_iter().flatten());
@@ -278,6 +288,118 @@ impl TextItem {
     pub fn max_chars(&self) -> usize {
         self.max_chars
     }
+
+    /// 折り返し後の行番号から元の行のインデックスを返す
+    pub fn line_index(&self, line_number: usize) -> Option<usize> {
+        self.wrapped_lines.get(line_number).map(WrappedLine::index)
+    }
+
+    /// 元の行のインデックスから折り返し後の先頭の行番号を返す
+    pub fn line_number(&self, line_index: usize) -> Option<usize> {
+        self.lines.get(line_index).map(|line| line.line_number)
+    }
+
+    pub fn literal_item(&self, line_index: usize) -> Option<&LiteralItem> {
+        self.lines.get(line_index).map(|line| &line.literal_item)
+    }
+
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /// 保持するバイト数をmax_bytes以下にするために先頭から削除する必要のある行数を返す
+    pub fn lines_over_bytes(&self, max_bytes: usize) -> usize {
+        let mut bytes = self.bytes;
+
+        self.lines
+            .iter()
+            .take_while(|line| {
+                if bytes <= max_bytes {
+                    return false;
+                }
+
+                bytes -= line.bytes();
+                true
+            })
+            .count()
+    }
+
+    /// 先頭からn行削除する
+    ///
+    /// 削除した行と、削除した折り返し後の行数を返す
+    pub fn remove_front(&mut self, n: usize) -> (Vec<LiteralItem>, usize) {
+        let n = n.min(self.lines.len());
+
+        if n == 0 {
+            return (Vec::new(), 0);
+        }
+
+        let removed_wrapped_len = self.lines[n - 1].wrapped_lines.end;
+
+        let removed_widest = self.wrapped_lines[..removed_wrapped_len]
+            .iter()
+            .any(|l| l.line().len() == self.max_chars);
+
+        // Lineのgraphemesはヒープ上にあるため、Vecから取り除いても
+        // 残っているWrappedLineのポインタは有効なまま
+        let removed: Vec<Line> = self.lines.drain(..n).collect();
+        self.wrapped_lines.drain(..removed_wrapped_len);
+
+        self.lines.iter_mut().for_each(|line| {
+            line.line_index -= n;
+            line.line_number -= removed_wrapped_len;
+            line.wrapped_lines = (line.wrapped_lines.start - removed_wrapped_len)
+                ..(line.wrapped_lines.end - removed_wrapped_len);
+        });
+
+        self.wrapped_lines
+            .iter_mut()
+            .for_each(|wrapped_line| wrapped_line.line_index -= n);
+
+        if let Some(highlights) = &mut self.highlights {
+            let removed_highlights_len = highlights
+                .item
+                .iter()
+                .take_while(|hl| hl.line_index < n)
+                .count();
+
+            highlights.item.drain(..removed_highlights_len);
+
+            highlights.item.iter_mut().for_each(|hl| {
+                hl.line_index -= n;
+                hl.line_number -= removed_wrapped_len;
+            });
+
+            highlights.selected_index = highlights
+                .selected_index
+                .saturating_sub(removed_highlights_len);
+
+            if highlights.item.is_empty() {
+                self.highlights = None;
+            }
+        }
+
+        self.bytes -= removed.iter().map(Line::bytes).sum::<usize>();
+
+        // 最も長い行を削除したときだけ残りの行から求め直す
+        if removed_widest {
+            self.max_chars = self
+                .wrapped_lines
+                .iter()
+                .map(|l| l.line().len())
+                .max()
+                .unwrap_or_default();
+        }
+
+        (
+            removed.into_iter().map(|line| line.literal_item).collect(),
+            removed_wrapped_len,
+        )
+    }
 }
 
 impl TextItem {
@@ -423,6 +545,10 @@ impl TextItem {
         &self.wrapped_lines
     }
 
+    pub fn wrap_width(&self) -> Option<usize> {
+        self.wrap_width
+    }
+
     pub fn rewrap(&mut self, wrap_width: usize) {
         self.wrap_width = Some(wrap_width);
 
@@ -515,6 +641,10 @@ impl Default for Line {
 }
 
 impl Line {
+    fn bytes(&self) -> usize {
+        self.literal_item.item.len()
+    }
+
     pub fn highlight_word(
         &mut self,
         word: &str,
@@ -898,6 +1028,116 @@ mod tests {
                 assert_eq!(item.max_chars(), 0);
             }
         }
+
+        mod remove_front {
+            use super::*;
+            use pretty_assertions::assert_eq;
+            use rstest::rstest;
+
+            #[test]
+            fn 先頭の行を削除してインデックスと行番号を詰める() {
+                let mut item = TextItem::new(
+                    vec![
+                        LiteralItem::new("0123456789", None),
+                        LiteralItem::new("01234", None),
+                        LiteralItem::new("0123456789", None),
+                    ],
+                    Some(5),
+                );
+
+                let (removed, removed_wrapped_len) = item.remove_front(1);
+
+                assert_eq!(removed, vec![LiteralItem::new("0123456789", None)]);
+                assert_eq!(removed_wrapped_len, 2);
+
+                assert_eq!(item.lines.len(), 2);
+                assert_eq!(item.lines[0].line_index, 0);
+                assert_eq!(item.lines[0].line_number, 0);
+                assert_eq!(item.lines[0].wrapped_lines, 0..1);
+                assert_eq!(item.lines[1].line_index, 1);
+                assert_eq!(item.lines[1].line_number, 1);
+                assert_eq!(item.lines[1].wrapped_lines, 1..3);
+
+                assert_eq!(item.wrapped_lines.len(), 3);
+                assert_eq!(item.wrapped_lines[0].line_index, 0);
+                assert_eq!(
+                    item.wrapped_lines[0].slice_ptr,
+                    &item.lines[0].graphemes[..]
+                );
+                assert_eq!(item.wrapped_lines[2].line_index, 1);
+                assert_eq!(
+                    item.wrapped_lines[2].slice_ptr,
+                    &item.lines[1].graphemes[5..]
+                );
+
+                assert_eq!(item.bytes(), 15);
+            }
+
+            #[test]
+            fn 削除した行のハイライトを取り除く() {
+                let mut item = TextItem::new(
+                    vec![
+                        LiteralItem::new("hello", None),
+                        LiteralItem::new("world", None),
+                        LiteralItem::new("hello world", None),
+                    ],
+                    None,
+                );
+
+                item.highlight("hello");
+                item.select_next_highlight();
+
+                item.remove_front(1);
+
+                assert_eq!(item.highlight_status(), (1, 1));
+                assert_eq!(item.highlight_selected_line_number(), Some(1));
+            }
+
+            #[rstest]
+            #[case::最も長い行を削除(vec!["0123456789", "01234", "012"], 5)]
+            #[case::最も長い行を残す(vec!["01234", "0123456789", "012"], 10)]
+            fn 最大文字数を更新する(#[case] lines: Vec<&str>, #[case] expected: usize) {
+                let mut item = TextItem::new(
+                    lines
+                        .into_iter()
+                        .map(|l| LiteralItem::new(l, None))
+                        .collect(),
+                    None,
+                );
+
+                item.remove_front(1);
+
+                assert_eq!(item.max_chars(), expected);
+            }
+
+            #[test]
+            fn 行数より多く指定するとすべて削除する() {
+                let mut item = TextItem::new(vec![LiteralItem::new("0", None)], None);
+
+                let (removed, _) = item.remove_front(10);
+
+                assert_eq!(removed.len(), 1);
+                assert!(item.is_empty());
+                assert_eq!(item.bytes(), 0);
+            }
+
+            #[test]
+            fn バイト数の上限に収めるための削除行数を返す() {
+                let item = TextItem::new(
+                    vec![
+                        LiteralItem::new("0123", None),
+                        LiteralItem::new("0123", None),
+                        LiteralItem::new("0123", None),
+                    ],
+                    None,
+                );
+
+                assert_eq!(item.lines_over_bytes(12), 0);
+                assert_eq!(item.lines_over_bytes(8), 1);
+                assert_eq!(item.lines_over_bytes(7), 2);
+                assert_eq!(item.lines_over_bytes(0), 3);
+            }
+        }
     }
 
     mod graphemes {
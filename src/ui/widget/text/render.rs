@@ -8,10 +8,12 @@
 ///
 /// このモジュールではステートを持たないこととし、
 /// 上位のレイヤーでスクロールの位置や折り返しを管理すること
+use std::collections::BTreeSet;
+
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
-    style::{Modifier, Style},
+    style::{Color, Modifier, Style},
     widgets::{Block, Widget},
 };
 use unicode_width::UnicodeWidthStr;
@@ -35,6 +37,9 @@ pub struct Render<'a> {
     lines: &'a [WrappedLine],
     scroll: Scroll,
     highlight_area: Option<HighlightArea>,
+    bookmarks: Option<&'a BTreeSet<usize>>,
+    /// 各行の左側に表示する領域の幅と表示行ごとの内容
+    gutter: Option<(u16, Vec<Option<String>>)>,
 }
 
 pub struct RenderBuilder<'a>(Render<'a>);
@@ -60,6 +65,17 @@ impl<'a> RenderBuilder<'a> {
         self
     }
 
+    pub fn bookmarks(mut self, bookmarks: &'a BTreeSet<usize>) -> Self {
+        self.0.bookmarks = Some(bookmarks);
+        self
+    }
+
+    /// contentsは表示する行ごとの内容
+    pub fn gutter(mut self, width: u16, contents: Vec<Option<String>>) -> Self {
+        self.0.gutter = Some((width, contents));
+        self
+    }
+
     pub fn build(self) -> Render<'a> {
         self.0
     }
@@ -73,10 +89,23 @@ impl<'a> Render<'a> {
 
 impl Widget for Render<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let text_area = self.block.inner(area);
+        let mut text_area = self.block.inner(area);
 
         self.block.render(area, buf);
 
+        if let Some((width, contents)) = &self.gutter {
+            let width = (*width).min(text_area.width);
+
+            render_gutter(
+                Rect::new(text_area.x, text_area.y, width, text_area.height),
+                contents,
+                buf,
+            );
+
+            text_area.x += width;
+            text_area.width -= width;
+        }
+
         let start = self.scroll.y;
         let end = text_area.height as usize;
         let area_width = text_area.width as usize;
@@ -84,6 +113,10 @@ impl Widget for Render<'_> {
         for (y, line) in self.lines.iter().skip(start).take(end).enumerate() {
             let mut x = 0;
 
+            let is_bookmarked = self
+                .bookmarks
+                .is_some_and(|bookmarks| bookmarks.contains(&line.index()));
+
             let iter = LineIterator::new(line.line(), self.scroll.x, text_area.width as usize)
                 .collect::<Vec<_>>();
 
@@ -100,6 +133,10 @@ impl Widget for Render<'_> {
                     }
                 }
 
+                if is_bookmarked {
+                    style = style.patch(bookmark_style());
+                }
+
                 buf.get_mut(text_area.left() + x as u16, text_area.top() + y as u16)
                     .set_symbol(symbol)
                     .set_style(style);
@@ -118,8 +155,13 @@ impl Widget for Render<'_> {
             }
 
             while x < area_width {
-                buf.get_mut(text_area.left() + x as u16, text_area.top() + y as u16)
-                    .set_symbol(" ");
+                let cell = buf.get_mut(text_area.left() + x as u16, text_area.top() + y as u16);
+
+                cell.set_symbol(" ");
+
+                if is_bookmarked {
+                    cell.set_style(bookmark_style());
+                }
 
                 x += " ".width()
             }
@@ -127,6 +169,35 @@ impl Widget for Render<'_> {
     }
 }
 
+/// 1列分の区切りを右端に残してガターを描画する
+fn render_gutter(area: Rect, contents: &[Option<String>], buf: &mut Buffer) {
+    let content_width = area.width.saturating_sub(1);
+
+    for (y, content) in contents.iter().take(area.height as usize).enumerate() {
+        let Some(content) = content else {
+            continue;
+        };
+
+        buf.set_stringn(
+            area.x,
+            area.y + y as u16,
+            content,
+            content_width as usize,
+            gutter_style(),
+        );
+    }
+}
+
+#[inline]
+fn gutter_style() -> Style {
+    Style::default().fg(Color::DarkGray)
+}
+
+#[inline]
+fn bookmark_style() -> Style {
+    Style::default().bg(Color::DarkGray)
+}
+
 #[derive(Debug, Default)]
 struct LineIterator<'a> {
     /// 一行分のStyledGraphemeの配列の参照
@@ -459,6 +530,47 @@ mod tests {
         }
     }
 
+    mod ガター {
+        use super::*;
+
+        #[test]
+        fn 行の左側にガターを描画する() {
+            let (mut terminal, area) = setup_terminal(TERMINAL_WIDTH, 5);
+
+            let styled_graphemes = vec!["abcdefghijklmnopqrstuvwxyz", "hello"].styled_graphemes();
+
+            let lines: Vec<_> = styled_graphemes
+                .iter()
+                .enumerate()
+                .map(|(i, sg)| WrappedLine::new(i, sg))
+                .collect();
+
+            let render = Render::builder()
+                .block(Block::default().borders(Borders::ALL))
+                .lines(&lines)
+                .gutter(6, vec![Some("12:00:00".to_string()), None])
+                .build();
+
+            terminal
+                .draw(|f| {
+                    f.render_widget(render, area);
+                })
+                .unwrap();
+
+            let mut expected = Buffer::with_lines(vec![
+                "┌──────────────────┐",
+                "│12:00 abcdefghijkl│",
+                "│      hello       │",
+                "│                  │",
+                "└──────────────────┘",
+            ]);
+
+            expected.set_style(Rect::new(1, 1, 5, 1), gutter_style());
+
+            terminal.backend().assert_buffer(&expected);
+        }
+    }
+
     mod スクロール {
         use super::*;
 
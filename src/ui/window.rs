@@ -255,6 +255,28 @@ impl<'a> Window<'a> {
     }
 }
 
+impl<'a> Window<'a> {
+    /// 表示中のウィジェットに、更新がなくても定期的に再描画が必要なものがあるか
+    pub fn needs_periodic_redraw(&self) -> bool {
+        let tab = self.active_tab();
+
+        let active_widget_id = tab.active_widget_id();
+
+        let tab_needs_redraw = tab
+            .as_ref_widgets()
+            .iter()
+            .any(|w| w.needs_periodic_redraw(w.id() == active_widget_id));
+
+        let popup_needs_redraw = self
+            .open_popup_id
+            .as_ref()
+            .and_then(|id| self.popups.iter().find(|p| p.id() == id))
+            .is_some_and(|p| p.widget().needs_periodic_redraw(true));
+
+        tab_needs_redraw || popup_needs_redraw
+    }
+}
+
 // Tab
 #[allow(dead_code)]
 impl<'a> Window<'a> {
@@ -1,9 +1,11 @@
 pub mod kube;
 mod render;
+pub mod replay;
 mod tick;
 mod user_input;
 
 pub use kube::KubeWorker;
 pub use render::*;
+pub use replay::Replay;
 pub use tick::*;
 pub use user_input::*;
@@ -1,12 +1,19 @@
 pub mod color;
 mod config;
+mod connection;
 mod controller;
+mod credential;
 pub mod message;
 mod store;
+mod watcher;
 mod worker;
 
-pub use config::KubeWorkerConfig;
+pub use config::{KubeWorkerConfig, KUBECONFIG_SEPARATOR};
+pub use connection::{
+    is_connection_error, Connection, ConnectionStatus, ConnectionStatuses, SharedConnectionStatuses,
+};
 pub use controller::*;
+pub use store::KubeState;
 pub use worker::*;
 
 use std::sync::{
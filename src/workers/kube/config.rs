@@ -1,16 +1,25 @@
-use std::{ops::Deref, path::PathBuf};
+use std::{collections::BTreeMap, ops::Deref, path::PathBuf};
 
-use anyhow::{anyhow, Result};
-use kube::config::{Kubeconfig, KubeconfigError};
+use anyhow::{anyhow, Context as _, Result};
+use kube::config::Kubeconfig;
+
+use crate::features::{context::message::ContextInfo, impersonation::kube::Impersonation};
 
 use super::TargetNamespaces;
 
+/// 複数のkubeconfigを指定するときの区切り文字（`KUBECONFIG`と同じ）
+pub const KUBECONFIG_SEPARATOR: char = if cfg!(windows) { ';' } else { ':' };
+
+/// コンテキストを定義したkubeconfigのファイル
+pub type ContextSources = BTreeMap<String, PathBuf>;
+
 #[derive(Debug, Default, Clone)]
 pub struct KubeWorkerConfig {
-    pub kubeconfig: Option<PathBuf>,
+    pub kubeconfig: Vec<PathBuf>,
     pub target_namespaces: Option<TargetNamespaces>,
     pub context: Option<String>,
     pub all_namespaces: bool,
+    pub impersonation: Option<Impersonation>,
 }
 
 pub struct Context(String);
@@ -52,10 +61,189 @@ impl Context {
     }
 }
 
-pub fn read_kubeconfig(kubeconfig: Option<PathBuf>) -> Result<Kubeconfig, KubeconfigError> {
-    if let Some(path) = kubeconfig {
-        Kubeconfig::read_from(path)
-    } else {
-        Kubeconfig::read()
+/// `--kubeconfig`で指定したパス、`KUBECONFIG`、`~/.kube/config`の順に読み込むファイルを決める
+pub fn kubeconfig_paths(kubeconfig: &[PathBuf]) -> Vec<PathBuf> {
+    if !kubeconfig.is_empty() {
+        return kubeconfig.to_vec();
+    }
+
+    if let Some(value) = std::env::var_os("KUBECONFIG") {
+        let paths: Vec<PathBuf> = std::env::split_paths(&value)
+            .filter(|path| !path.as_os_str().is_empty())
+            .collect();
+
+        if !paths.is_empty() {
+            return paths;
+        }
+    }
+
+    std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .map(|home| vec![PathBuf::from(home).join(".kube").join("config")])
+        .unwrap_or_default()
+}
+
+/// kubeconfigを読み込み、コンテキストを定義したファイルを返す
+///
+/// 複数のファイルはkubectlと同じく先に指定したファイルの定義を優先してマージし、存在しないファイルは読み飛ばす
+pub fn read_kubeconfig(paths: &[PathBuf]) -> Result<(Kubeconfig, ContextSources)> {
+    let mut merged: Option<Kubeconfig> = None;
+    let mut sources = ContextSources::new();
+
+    for path in paths {
+        // 1つだけ指定した場合は、存在しないことをエラーとして返す
+        if 1 < paths.len() && !path.exists() {
+            continue;
+        }
+
+        let kubeconfig = Kubeconfig::read_from(path)
+            .with_context(|| format!("Failed to read kubeconfig {}", path.display()))?;
+
+        for context in &kubeconfig.contexts {
+            sources
+                .entry(context.name.to_string())
+                .or_insert_with(|| path.clone());
+        }
+
+        merged = Some(match merged {
+            Some(merged) => merged
+                .merge(kubeconfig)
+                .with_context(|| format!("Failed to merge kubeconfig {}", path.display()))?,
+            None => kubeconfig,
+        });
+    }
+
+    let kubeconfig = merged.ok_or_else(|| anyhow!("Cannot find kubeconfig"))?;
+
+    Ok((kubeconfig, sources))
+}
+
+/// コンテキストの一覧に表示する情報
+pub fn context_infos(kubeconfig: &Kubeconfig, sources: &ContextSources) -> Vec<ContextInfo> {
+    kubeconfig
+        .contexts
+        .iter()
+        .map(|named_context| {
+            let context = named_context.context.as_ref();
+
+            ContextInfo {
+                name: named_context.name.to_string(),
+                cluster: context
+                    .map(|ctx| ctx.cluster.to_string())
+                    .unwrap_or_default(),
+                user: context.map(|ctx| ctx.user.to_string()).unwrap_or_default(),
+                namespace: context.and_then(|ctx| ctx.namespace.clone()),
+                source: sources.get(&named_context.name).cloned(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn write(name: &str, yaml: &str) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("kubetui-{}-{}.yaml", name, std::process::id()));
+
+        fs::write(&path, yaml).unwrap();
+
+        path
+    }
+
+    #[test]
+    fn 複数のkubeconfigをマージし先に指定したファイルを優先する() {
+        let first = write(
+            "config-first",
+            indoc! {r#"
+                apiVersion: v1
+                kind: Config
+                clusters:
+                  - name: kind
+                    cluster:
+                      server: https://127.0.0.1:6443
+                contexts:
+                  - name: kind
+                    context:
+                      cluster: kind
+                      user: kind
+                      namespace: app
+                current-context: kind
+                users:
+                  - name: kind
+                    user:
+                      token: kind
+            "#},
+        );
+
+        let second = write(
+            "config-second",
+            indoc! {r#"
+                apiVersion: v1
+                kind: Config
+                clusters:
+                  - name: prod
+                    cluster:
+                      server: https://192.168.0.1
+                contexts:
+                  - name: kind
+                    context:
+                      cluster: prod
+                      user: prod
+                  - name: prod
+                    context:
+                      cluster: prod
+                      user: prod
+                current-context: prod
+                users:
+                  - name: prod
+                    user:
+                      token: prod
+            "#},
+        );
+
+        let missing = std::env::temp_dir().join("kubetui-config-missing.yaml");
+
+        let (kubeconfig, sources) =
+            read_kubeconfig(&[first.clone(), missing, second.clone()]).unwrap();
+
+        assert_eq!(kubeconfig.current_context, Some("kind".to_string()));
+
+        assert_eq!(
+            context_infos(&kubeconfig, &sources),
+            vec![
+                ContextInfo {
+                    name: "kind".to_string(),
+                    cluster: "kind".to_string(),
+                    user: "kind".to_string(),
+                    namespace: Some("app".to_string()),
+                    source: Some(first.clone()),
+                },
+                ContextInfo {
+                    name: "prod".to_string(),
+                    cluster: "prod".to_string(),
+                    user: "prod".to_string(),
+                    namespace: None,
+                    source: Some(second.clone()),
+                },
+            ]
+        );
+
+        fs::remove_file(first).unwrap();
+        fs::remove_file(second).unwrap();
+    }
+
+    #[test]
+    fn 指定したkubeconfigが存在しないときerrを返す() {
+        let missing = std::env::temp_dir().join("kubetui-config-missing.yaml");
+
+        assert!(read_kubeconfig(&[missing]).is_err());
+        assert!(read_kubeconfig(&[]).is_err());
     }
 }
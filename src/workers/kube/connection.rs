@@ -0,0 +1,341 @@
+use std::{
+    cell::RefCell,
+    collections::BTreeMap,
+    rc::Rc,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use anyhow::Result;
+use crossbeam::channel::Sender;
+use serde::{Deserialize, Serialize};
+use tokio::time::{Instant, Interval};
+
+use crate::{features::impersonation::kube::Impersonation, logger, message::Message};
+
+use super::message::Kube;
+
+/// 再接続を試みるまでの最初の待機時間
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+
+/// 再接続を試みるまでの待機時間の上限
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// コンテキストごとの接続状態
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConnectionStatus {
+    /// 最初のレスポンスを待っている
+    Connecting,
+    Connected,
+    /// 接続できず、再接続を試みている
+    Disconnected {
+        attempts: u32,
+        error: String,
+    },
+}
+
+/// コンテキストの接続状態と再接続の間隔を管理する
+///
+/// ポーラー間で共有し、接続できない間はすべてのポーラーが指数バックオフで再試行する
+#[derive(Debug)]
+pub struct Connection {
+    context: String,
+    tx: Sender<Message>,
+    state: Mutex<BackoffState>,
+}
+
+pub type SharedConnection = Arc<Connection>;
+
+#[derive(Debug, Default)]
+struct BackoffState {
+    status: Option<ConnectionStatus>,
+    attempts: u32,
+    /// 次に再試行する時刻、接続できている場合は`None`
+    next_retry: Option<Instant>,
+}
+
+impl Connection {
+    pub fn new(context: impl Into<String>, tx: Sender<Message>) -> Self {
+        Self {
+            context: context.into(),
+            tx,
+            state: Default::default(),
+        }
+    }
+
+    pub fn shared(context: impl Into<String>, tx: Sender<Message>) -> SharedConnection {
+        Arc::new(Self::new(context, tx))
+    }
+
+    /// 接続状態を送信する（変化がない場合は送信しない）
+    fn set_status(&self, state: &mut BackoffState, status: ConnectionStatus) {
+        if state.status.as_ref() == Some(&status) {
+            return;
+        }
+
+        state.status = Some(status.clone());
+
+        let _ = self.tx.send(
+            Kube::Connection {
+                context: self.context.to_string(),
+                status,
+            }
+            .into(),
+        );
+    }
+
+    /// 最初のレスポンスを待っていることを通知する
+    pub fn connecting(&self) {
+        let mut state = self.state.lock().expect("Failed to lock connection state");
+
+        self.set_status(&mut state, ConnectionStatus::Connecting);
+    }
+
+    /// リクエストの結果を反映する
+    ///
+    /// 接続に関するエラー以外（権限がないなど）はAPIサーバーに到達できているため接続済みとして扱う
+    pub fn report<T>(&self, result: &Result<T>) {
+        match result {
+            Err(err) if is_connection_error(err) => self.failed(&format!("{:#}", err)),
+            _ => self.succeeded(),
+        }
+    }
+
+    pub fn succeeded(&self) {
+        let mut state = self.state.lock().expect("Failed to lock connection state");
+
+        if state.attempts != 0 {
+            logger!(info, "Reconnected to {}", self.context);
+        }
+
+        state.attempts = 0;
+        state.next_retry = None;
+
+        self.set_status(&mut state, ConnectionStatus::Connected);
+    }
+
+    pub fn failed(&self, error: &str) {
+        self.failed_at(error, Instant::now());
+    }
+
+    fn failed_at(&self, error: &str, now: Instant) {
+        let mut state = self.state.lock().expect("Failed to lock connection state");
+
+        // 待機中に届いた失敗は、待機前に送ったリクエストの結果のため間隔を延ばさない
+        if state.next_retry.is_some_and(|next_retry| now < next_retry) {
+            return;
+        }
+
+        state.attempts += 1;
+        state.next_retry = Some(now + backoff(state.attempts));
+
+        logger!(
+            error,
+            "Failed to connect to {} (attempts={}): {}",
+            self.context,
+            state.attempts,
+            error
+        );
+
+        let status = ConnectionStatus::Disconnected {
+            attempts: state.attempts,
+            error: error.to_string(),
+        };
+
+        self.set_status(&mut state, status);
+    }
+
+    /// 次に再試行する時刻
+    pub fn next_retry(&self) -> Option<Instant> {
+        self.state
+            .lock()
+            .expect("Failed to lock connection state")
+            .next_retry
+    }
+
+    /// 再接続の待機中は次に再試行する時刻まで待つ
+    ///
+    /// 待機した場合は`true`を返す
+    pub async fn wait_for_retry(&self) -> bool {
+        let mut waited = false;
+
+        // 待機中に他のポーラーが失敗して時刻が延びる場合があるため、再度確認する
+        while let Some(next_retry) = self.next_retry().filter(|t| Instant::now() < *t) {
+            tokio::time::sleep_until(next_retry).await;
+
+            waited = true;
+        }
+
+        waited
+    }
+
+    /// ポーラーの次の取得まで待つ
+    pub async fn tick(&self, interval: &mut Interval) {
+        interval.tick().await;
+
+        // 待機している間に溜まったtickをまとめて消化しないようにする
+        if self.wait_for_retry().await {
+            interval.reset();
+        }
+    }
+}
+
+/// 失敗した回数に応じた再試行までの待機時間
+fn backoff(attempts: u32) -> Duration {
+    let exp = attempts.saturating_sub(1).min(16);
+
+    INITIAL_BACKOFF.saturating_mul(1 << exp).min(MAX_BACKOFF)
+}
+
+/// APIサーバーに到達できない、または認証情報が無効なエラーか
+pub fn is_connection_error(err: &anyhow::Error) -> bool {
+    err.chain().any(|cause| {
+        let Some(err) = cause.downcast_ref::<kube::Error>() else {
+            return false;
+        };
+
+        match err {
+            kube::Error::HyperError(_) | kube::Error::Service(_) | kube::Error::Auth(_) => true,
+            kube::Error::Api(res) => res.code == 401,
+            _ => false,
+        }
+    })
+}
+
+/// レンダースレッドで保持するコンテキストごとの接続状態と、接続に使用している偽装ユーザー
+#[derive(Debug, Default)]
+pub struct ConnectionStatuses {
+    statuses: BTreeMap<String, ConnectionStatus>,
+    impersonation: Option<Impersonation>,
+}
+
+pub type SharedConnectionStatuses = Rc<RefCell<ConnectionStatuses>>;
+
+impl ConnectionStatuses {
+    pub fn get(&self, context: &str) -> Option<&ConnectionStatus> {
+        self.statuses.get(context)
+    }
+
+    pub fn update(&mut self, context: String, status: ConnectionStatus) {
+        self.statuses.insert(context, status);
+    }
+
+    pub fn impersonation(&self) -> Option<&Impersonation> {
+        self.impersonation.as_ref()
+    }
+
+    pub fn update_impersonation(&mut self, impersonation: Option<Impersonation>) {
+        self.impersonation = impersonation;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+    use crossbeam::channel::Receiver;
+    use kube::core::ErrorResponse;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn connection() -> (Connection, Receiver<Message>) {
+        let (tx, rx) = crossbeam::channel::unbounded();
+
+        (Connection::new("kind", tx), rx)
+    }
+
+    fn statuses(rx: &Receiver<Message>) -> Vec<ConnectionStatus> {
+        rx.try_iter()
+            .map(|message| match message {
+                Message::Kube(Kube::Connection { context, status }) => {
+                    assert_eq!(context, "kind");
+                    status
+                }
+                _ => panic!("unexpected message"),
+            })
+            .collect()
+    }
+
+    fn api_error(code: u16) -> anyhow::Error {
+        kube::Error::Api(ErrorResponse {
+            status: "Failure".to_string(),
+            message: String::new(),
+            reason: String::new(),
+            code,
+        })
+        .into()
+    }
+
+    #[test]
+    fn 待機時間は倍々に延び上限を超えない() {
+        let actual: Vec<u64> = (1..=7).map(|n| backoff(n).as_secs()).collect();
+
+        assert_eq!(actual, vec![1, 2, 4, 8, 16, 30, 30]);
+        assert_eq!(backoff(u32::MAX), MAX_BACKOFF);
+    }
+
+    #[test]
+    fn 未認証と通信エラーは接続エラーとして扱う() {
+        assert!(is_connection_error(&api_error(401)));
+        assert!(is_connection_error(
+            &anyhow::Error::from(kube::Error::Service("connection refused".into()))
+                .context("Failed to fetch pods")
+        ));
+        assert!(!is_connection_error(&api_error(403)));
+        assert!(!is_connection_error(&anyhow!("unknown")));
+    }
+
+    #[test]
+    fn 待機中の失敗では間隔を延ばさない() {
+        let (connection, rx) = connection();
+
+        let now = Instant::now();
+
+        connection.failed_at("refused", now);
+        connection.failed_at("refused", now + Duration::from_millis(500));
+
+        assert_eq!(connection.next_retry(), Some(now + Duration::from_secs(1)));
+
+        connection.failed_at("refused", now + Duration::from_secs(1));
+
+        assert_eq!(connection.next_retry(), Some(now + Duration::from_secs(3)));
+
+        assert_eq!(
+            statuses(&rx),
+            vec![
+                ConnectionStatus::Disconnected {
+                    attempts: 1,
+                    error: "refused".to_string()
+                },
+                ConnectionStatus::Disconnected {
+                    attempts: 2,
+                    error: "refused".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn 成功すると接続済みに戻り待機しない() {
+        let (connection, rx) = connection();
+
+        connection.connecting();
+        connection.report::<()>(&Err(api_error(401)));
+        connection.report(&Ok(()));
+        connection.report::<()>(&Err(api_error(403)));
+
+        assert_eq!(connection.next_retry(), None);
+
+        assert_eq!(
+            statuses(&rx),
+            vec![
+                ConnectionStatus::Connecting,
+                ConnectionStatus::Disconnected {
+                    attempts: 1,
+                    error: format!("{:#}", api_error(401))
+                },
+                ConnectionStatus::Connected,
+            ]
+        );
+    }
+}
@@ -8,17 +8,19 @@ use std::{
 
 use anyhow::{anyhow, Result};
 use async_trait::async_trait;
-use crossbeam::channel::{Receiver, Sender};
-use futures::future::select_all;
+use crossbeam::channel::{Receiver, RecvTimeoutError, Sender};
+use futures::future::{select, select_all, Either};
 use k8s_openapi::api::core::v1::Namespace;
 use kube::{api::ListParams, config::Kubeconfig, Api, ResourceExt as _};
 use tokio::{
     sync::RwLock,
     task::{self, AbortHandle, JoinHandle},
+    time::Instant,
 };
 
 use crate::{
     features::{
+        access_review::{kube::AccessReviewWorker, message::AccessReviewMessage},
         api_resources::{
             kube::{ApiPoller, ApiResource, ApiResources, SharedApiResources},
             message::{ApiMessage, ApiRequest, ApiResponse},
@@ -27,18 +29,40 @@ use crate::{
             kube::{ConfigPoller, ConfigsDataWorker},
             message::ConfigMessage,
         },
-        context::message::{ContextMessage, ContextRequest, ContextResponse},
+        context::message::{ContextInfo, ContextMessage, ContextRequest, ContextResponse},
+        custom_resource::{
+            kube::{
+                CustomResourceDefinitionsWorker, CustomResourceExplainWorker, CustomResourceWorker,
+            },
+            message::{CustomResourceMessage, CustomResourceRequest},
+        },
         event::kube::EventPoller,
         get::{kube::yaml::GetYamlWorker, message::GetMessage},
-        namespace::message::{NamespaceMessage, NamespaceRequest, NamespaceResponse},
+        impersonation::{
+            kube::Impersonation,
+            message::{ImpersonationMessage, ImpersonationRequest, ImpersonationResponse},
+        },
+        label_selector::{kube::SharedLabelSelectors, message::LabelSelectorRequest},
+        namespace::{
+            kube::{NamespaceDescriptionWorker, NamespaceOverviewPoller},
+            message::{NamespaceMessage, NamespaceRequest, NamespaceResponse},
+        },
         network::{
-            kube::{NetworkDescriptionWorker, NetworkPoller},
-            message::NetworkMessage,
+            kube::{check_reachability, NetworkDescriptionWorker, NetworkPoller},
+            message::{NetworkMessage, NetworkResponse},
+        },
+        owner_tree::{
+            kube::OwnerTreeWorker,
+            message::{OwnerTreeMessage, OwnerTreeRequest},
         },
         pod::{
             kube::{LogWorker, PodPoller},
             message::LogMessage,
         },
+        resource_search::{
+            kube::ResourceSearchWorker,
+            message::{ResourceSearchMessage, ResourceSearchRequest},
+        },
         yaml::{
             kube::{FetchResourceList, YamlWorker},
             message::{YamlMessage, YamlRequest, YamlResponse},
@@ -51,8 +75,10 @@ use crate::{
 };
 
 use super::{
-    config::{read_kubeconfig, Context, KubeWorkerConfig},
+    config::{context_infos, kubeconfig_paths, Context, KubeWorkerConfig},
+    connection::{Connection, SharedConnection},
     store::{KubeState, KubeStore},
+    watcher::KubeconfigWatcher,
     worker::Worker,
     AbortWorker as _,
 };
@@ -63,6 +89,9 @@ pub type SharedTargetNamespaces = Arc<RwLock<TargetNamespaces>>;
 pub type TargetApiResources = Vec<ApiResource>;
 pub type SharedTargetApiResources = Arc<RwLock<TargetApiResources>>;
 
+/// kubeconfigの再読み込みで更新するコンテキストの一覧
+type SharedContexts = Arc<RwLock<Vec<ContextInfo>>>;
+
 async fn fetch_all_namespaces(client: KubeClient) -> Result<Vec<String>> {
     let namespaces: Api<Namespace> = Api::all(client.as_client().clone());
     let lp = ListParams::default();
@@ -71,17 +100,261 @@ async fn fetch_all_namespaces(client: KubeClient) -> Result<Vec<String>> {
     Ok(ns_list.iter().map(|ns| ns.name_any()).collect())
 }
 
+/// 起動オプションで指定したnamespace
+///
+/// 起動時にクラスターへ接続できない場合でも起動できるように、最初に接続できたときに反映する
+struct InitialNamespaces {
+    context: String,
+    namespaces: Option<TargetNamespaces>,
+    all_namespaces: bool,
+}
+
+impl InitialNamespaces {
+    async fn apply(&self, state: &mut KubeState) -> Result<()> {
+        if let Some(namespaces) = &self.namespaces {
+            state.target_namespaces = namespaces.clone();
+        }
+
+        if self.all_namespaces {
+            state.target_namespaces = fetch_all_namespaces(state.client.clone()).await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// 起動時に読み込んだkubeconfig
+struct Initialized {
+    contexts: Vec<ContextInfo>,
+    /// kubeconfigの`current-context`
+    current_context: Option<String>,
+    context: String,
+    store: KubeStore,
+    initial_namespaces: InitialNamespaces,
+    watcher: KubeconfigWatcher,
+}
+
+/// kubeconfigを読み込み、起動オプションで指定したコンテキストを反映する
+///
+/// クラスターへの接続は行わない
+fn initialize(config: KubeWorkerConfig) -> Result<Initialized> {
+    let KubeWorkerConfig {
+        kubeconfig,
+        target_namespaces,
+        context,
+        all_namespaces,
+        impersonation,
+    } = config;
+
+    // 読み込んでから監視を始めるまでの変更を見逃さないように、先に監視を始める
+    let watcher = KubeconfigWatcher::new(kubeconfig_paths(&kubeconfig));
+
+    let (kubeconfig, sources) = watcher.read()?;
+
+    let context = Context::try_from(&kubeconfig, context)?;
+
+    let mut store = KubeStore::new(kubeconfig.clone());
+
+    store.set_impersonation(impersonation);
+
+    let initial_namespaces = InitialNamespaces {
+        context: context.to_string(),
+        namespaces: target_namespaces,
+        all_namespaces,
+    };
+
+    Ok(Initialized {
+        contexts: context_infos(&kubeconfig, &sources),
+        current_context: kubeconfig.current_context,
+        context: context.to_string(),
+        store,
+        initial_namespaces,
+        watcher,
+    })
+}
+
+/// コンテキストの接続先を返す
+///
+/// 起動オプションで指定したコンテキストに初めて接続できた場合は、指定したnamespaceを反映する
+async fn connect(
+    store: &mut KubeStore,
+    context: &str,
+    initial_namespaces: &mut Option<InitialNamespaces>,
+) -> Result<KubeState> {
+    let state = store.get_or_connect(context).await?;
+
+    if let Some(initial) = initial_namespaces
+        .as_ref()
+        .filter(|initial| initial.context == context)
+    {
+        // 失敗した場合は次の接続で再度反映する
+        initial.apply(state).await?;
+
+        *initial_namespaces = None;
+    }
+
+    Ok(state.clone())
+}
+
+/// 起動オプションで指定したコンテキストの接続先と対象のnamespaceを返す
+pub async fn current_kube_state(config: KubeWorkerConfig) -> Result<KubeState> {
+    let Initialized {
+        context,
+        mut store,
+        initial_namespaces,
+        ..
+    } = initialize(config)?;
+
+    connect(&mut store, &context, &mut Some(initial_namespaces)).await
+}
+
+/// kubeconfigを再読み込みしたあとに使用するコンテキスト
+///
+/// kubeconfigの`current-context`が変わった場合はそのコンテキストに切り替え、
+/// 使用中のコンテキストが削除された場合は`current-context`に切り替える。
+/// 使用中のコンテキストの定義が変わった場合は同じコンテキストで接続し直す。
+/// ポーラーを再起動する必要がない場合は`None`を返す
+fn context_after_reload(
+    context: &str,
+    previous_current_context: Option<&str>,
+    kubeconfig: &Kubeconfig,
+    changed_contexts: &[String],
+) -> Option<String> {
+    let exists = |name: &str| kubeconfig.contexts.iter().any(|ctx| ctx.name == name);
+
+    let current_context = kubeconfig.current_context.as_deref();
+
+    if current_context != previous_current_context {
+        if let Some(current_context) = current_context.filter(|ctx| exists(ctx)) {
+            if current_context != context {
+                return Some(current_context.to_string());
+            }
+        }
+    }
+
+    if !exists(context) {
+        return Context::try_from(kubeconfig, None)
+            .ok()
+            .map(|ctx| ctx.to_string());
+    }
+
+    changed_contexts
+        .iter()
+        .any(|ctx| ctx == context)
+        .then(|| context.to_string())
+}
+
+/// kubeconfigを再読み込みし、コンテキストの一覧と接続先を更新する
+///
+/// ポーラーを再起動して使用するコンテキストを返す
+async fn reload_kubeconfig(
+    watcher: &KubeconfigWatcher,
+    store: &mut KubeStore,
+    shared_contexts: &SharedContexts,
+    current_context: &mut Option<String>,
+    context: &str,
+) -> Option<String> {
+    // 書き込み途中のファイルを読み込んだ場合は、書き込みが終わったときの変更で再度読み込む
+    let (kubeconfig, sources) = match watcher.read() {
+        Ok(ret) => ret,
+        Err(err) => {
+            logger!(error, "Failed to reload kubeconfig: {:#}", err);
+            return None;
+        }
+    };
+
+    logger!(info, "Reloaded kubeconfig");
+
+    let changed_contexts = store.changed_contexts(&kubeconfig);
+
+    let next = context_after_reload(
+        context,
+        current_context.as_deref(),
+        &kubeconfig,
+        &changed_contexts,
+    );
+
+    *shared_contexts.write().await = context_infos(&kubeconfig, &sources);
+    *current_context = kubeconfig.current_context.clone();
+
+    store.reload(kubeconfig);
+
+    next
+}
+
+/// 再試行を待っている間に起きたこと
+enum RetryEvent {
+    /// 再試行する時刻になった
+    Elapsed,
+    ChangedContext(String),
+    ChangedKubeconfig,
+    ChangedImpersonation(Option<Impersonation>),
+}
+
+/// 接続先を生成できない間、再試行する時刻まで待つ
+///
+/// 待機中もコンテキストの一覧の取得と切り替え、kubeconfigと偽装ユーザーの変更を受け付ける
+async fn wait_for_retry(
+    rx: &Receiver<Message>,
+    tx: &Sender<Message>,
+    contexts: &[ContextInfo],
+    connection: &Connection,
+    watcher: &mut KubeconfigWatcher,
+    is_terminated: &AtomicBool,
+) -> Result<RetryEvent> {
+    while !is_terminated.load(Ordering::Relaxed) {
+        if watcher.has_changed() {
+            return Ok(RetryEvent::ChangedKubeconfig);
+        }
+
+        let Some(next_retry) = connection.next_retry() else {
+            break;
+        };
+
+        let remaining = next_retry.saturating_duration_since(Instant::now());
+
+        if remaining.is_zero() {
+            break;
+        }
+
+        let rx = rx.clone();
+        let timeout = remaining.min(Duration::from_secs(1));
+
+        let message = match task::spawn_blocking(move || rx.recv_timeout(timeout)).await? {
+            Ok(message) => message,
+            Err(RecvTimeoutError::Timeout) => continue,
+            Err(RecvTimeoutError::Disconnected) => break,
+        };
+
+        match message {
+            Message::Kube(Kube::Context(ContextMessage::Request(req))) => match req {
+                ContextRequest::Get => tx.send(ContextResponse::Get(contexts.to_vec()).into())?,
+                ContextRequest::Set(ctx) => return Ok(RetryEvent::ChangedContext(ctx)),
+            },
+            Message::Kube(Kube::Impersonation(ImpersonationMessage::Request(
+                ImpersonationRequest(impersonation),
+            ))) => return Ok(RetryEvent::ChangedImpersonation(impersonation)),
+            _ => {}
+        }
+    }
+
+    Ok(RetryEvent::Elapsed)
+}
+
 #[derive(Clone)]
 pub struct PollerBase {
     pub is_terminated: Arc<AtomicBool>,
     pub tx: Sender<Message>,
     pub shared_target_namespaces: SharedTargetNamespaces,
+    pub shared_label_selectors: SharedLabelSelectors,
     pub kube_client: KubeClient,
+    pub connection: SharedConnection,
 }
 
 #[derive(Clone)]
 pub enum WorkerResult {
     ChangedContext(String),
+    ChangedImpersonation(Option<Impersonation>),
     Terminated,
 }
 
@@ -89,9 +362,12 @@ pub struct KubeController {
     tx: Sender<Message>,
     rx: Receiver<Message>,
     is_terminated: Arc<AtomicBool>,
-    kubeconfig: Kubeconfig,
+    contexts: Vec<ContextInfo>,
+    current_context: Option<String>,
     context: String,
     store: KubeStore,
+    initial_namespaces: Option<InitialNamespaces>,
+    watcher: KubeconfigWatcher,
 }
 
 impl KubeController {
@@ -101,43 +377,56 @@ impl KubeController {
         is_terminated: Arc<AtomicBool>,
         config: KubeWorkerConfig,
     ) -> Result<Self> {
-        let KubeWorkerConfig {
-            kubeconfig,
-            target_namespaces,
+        let Initialized {
+            contexts,
+            current_context,
             context,
-            all_namespaces,
-        } = config;
-
-        let kubeconfig = read_kubeconfig(kubeconfig)?;
-
-        let context = Context::try_from(&kubeconfig, context)?;
-
-        let mut store = KubeStore::try_from_kubeconfig(kubeconfig.clone()).await?;
-
-        let KubeState {
-            client: state_client,
-            target_namespaces: state_of_target_namespaces,
-            ..
-        } = store.get_mut(&context)?;
-
-        if let Some(namespaces) = target_namespaces {
-            *state_of_target_namespaces = namespaces;
-        }
+            store,
+            initial_namespaces,
+            watcher,
+        } = initialize(config)?;
 
-        if all_namespaces {
-            let target_namespaces = fetch_all_namespaces(state_client.clone()).await?;
+        Ok(Self {
+            tx,
+            rx,
+            is_terminated,
+            contexts,
+            current_context,
+            context,
+            store,
+            initial_namespaces: Some(initial_namespaces),
+            watcher,
+        })
+    }
 
-            *state_of_target_namespaces = target_namespaces;
-        }
+    /// kubeconfigを読み込まずに、1つのコンテキストと接続先で生成する
+    #[cfg(test)]
+    pub fn with_client(
+        tx: Sender<Message>,
+        rx: Receiver<Message>,
+        is_terminated: Arc<AtomicBool>,
+        context: &str,
+        state: KubeState,
+    ) -> Self {
+        let store = KubeStore::from(std::collections::BTreeMap::from([(
+            context.to_string(),
+            state,
+        )]));
 
-        Ok(Self {
+        Self {
             tx,
             rx,
             is_terminated,
-            kubeconfig,
+            contexts: vec![ContextInfo {
+                name: context.to_string(),
+                ..Default::default()
+            }],
+            current_context: None,
             context: context.to_string(),
             store,
-        })
+            initial_namespaces: None,
+            watcher: KubeconfigWatcher::disabled(),
+        }
     }
 
     pub async fn run(self) -> Result<()> {
@@ -145,17 +434,88 @@ impl KubeController {
             tx,
             rx,
             is_terminated,
-            kubeconfig,
+            contexts,
+            mut current_context,
             mut context,
             mut store,
+            mut initial_namespaces,
+            mut watcher,
         } = self;
 
+        let shared_label_selectors = SharedLabelSelectors::default();
+
+        let shared_contexts: SharedContexts = Arc::new(RwLock::new(contexts));
+
+        let mut current_connection: Option<SharedConnection> = None;
+
         while !is_terminated.load(Ordering::Relaxed) {
+            let connection = current_connection
+                .get_or_insert_with(|| {
+                    let connection = Connection::shared(&context, tx.clone());
+                    connection.connecting();
+                    connection
+                })
+                .clone();
+
             let KubeState {
                 client,
                 target_namespaces,
                 target_api_resources,
-            } = store.get(&context)?.clone();
+            } = match connect(&mut store, &context, &mut initial_namespaces).await {
+                Ok(state) => state,
+                Err(err) => {
+                    connection.failed(&format!("{:#}", err));
+
+                    tx.send(Message::Kube(Kube::RestoreContext {
+                        context: context.to_string(),
+                        namespaces: Vec::new(),
+                    }))?;
+
+                    tx.send(ImpersonationResponse(store.impersonation().cloned()).into())?;
+
+                    let contexts = shared_contexts.read().await.to_vec();
+
+                    let event = wait_for_retry(
+                        &rx,
+                        &tx,
+                        &contexts,
+                        &connection,
+                        &mut watcher,
+                        &is_terminated,
+                    )
+                    .await?;
+
+                    let changed = match event {
+                        RetryEvent::Elapsed => None,
+                        RetryEvent::ChangedContext(ctx) => Some(ctx),
+                        RetryEvent::ChangedKubeconfig => {
+                            reload_kubeconfig(
+                                &watcher,
+                                &mut store,
+                                &shared_contexts,
+                                &mut current_context,
+                                &context,
+                            )
+                            .await
+                        }
+                        RetryEvent::ChangedImpersonation(impersonation) => {
+                            store.set_impersonation(impersonation);
+
+                            // 別の接続先として扱い、再試行を待たずに接続し直す
+                            current_connection = None;
+
+                            None
+                        }
+                    };
+
+                    if let Some(ctx) = changed.filter(|ctx| ctx != &context) {
+                        context = ctx;
+                        current_connection = None;
+                    }
+
+                    continue;
+                }
+            };
 
             tx.send(Message::Kube(Kube::RestoreContext {
                 context: context.to_string(),
@@ -166,25 +526,25 @@ impl KubeController {
                 target_api_resources.to_vec(),
             )))?;
 
+            tx.send(ImpersonationResponse(store.impersonation().cloned()).into())?;
+
             let shared_target_namespaces = Arc::new(RwLock::new(target_namespaces.to_vec()));
             let shared_target_api_resources = Arc::new(RwLock::new(target_api_resources.to_vec()));
             let shared_api_resources = ApiResources::shared();
 
             let poller_base = PollerBase {
                 shared_target_namespaces: shared_target_namespaces.clone(),
+                shared_label_selectors: shared_label_selectors.clone(),
                 tx: tx.clone(),
                 is_terminated: is_terminated.clone(),
                 kube_client: client.clone(),
+                connection,
             };
 
             let event_controller_handle = EventController::new(
                 poller_base.clone(),
                 rx.clone(),
-                kubeconfig
-                    .contexts
-                    .iter()
-                    .map(|ctx| ctx.name.to_string())
-                    .collect(),
+                shared_contexts.clone(),
                 shared_target_api_resources.clone(),
                 shared_api_resources.clone(),
             )
@@ -195,6 +555,7 @@ impl KubeController {
             let network_handle =
                 NetworkPoller::new(poller_base.clone(), shared_api_resources.clone()).spawn();
             let event_handle = EventPoller::new(poller_base.clone()).spawn();
+            let namespace_handle = NamespaceOverviewPoller::new(poller_base.clone()).spawn();
             let api_handle = ApiPoller::new(
                 poller_base.clone(),
                 shared_target_api_resources.clone(),
@@ -208,11 +569,52 @@ impl KubeController {
                 config_handle,
                 network_handle,
                 event_handle,
+                namespace_handle,
                 api_handle,
             ];
 
             while !handles.is_empty() {
-                let (result, _, vec) = select_all(handles).await;
+                let event = match select(select_all(handles), Box::pin(watcher.changed())).await {
+                    Either::Left((ret, _)) => Ok(ret),
+                    Either::Right(((), pending)) => Err(pending.into_inner()),
+                };
+
+                let (result, _, vec) = match event {
+                    Ok(ret) => ret,
+                    // kubeconfigが変更された
+                    Err(pending) => {
+                        handles = pending;
+
+                        store.insert(
+                            context.to_string(),
+                            KubeState::new(
+                                client.clone(),
+                                shared_target_namespaces.read().await.to_vec(),
+                                shared_target_api_resources.read().await.to_vec(),
+                            ),
+                        );
+
+                        let next = reload_kubeconfig(
+                            &watcher,
+                            &mut store,
+                            &shared_contexts,
+                            &mut current_context,
+                            &context,
+                        )
+                        .await;
+
+                        if let Some(ctx) = next {
+                            Self::abort(&handles);
+
+                            handles.clear();
+
+                            context = ctx;
+                            current_connection = None;
+                        }
+
+                        continue;
+                    }
+                };
 
                 handles = vec;
 
@@ -221,19 +623,35 @@ impl KubeController {
                         WorkerResult::ChangedContext(ctx) => {
                             Self::abort(&handles);
 
-                            let target_namespaces = shared_target_namespaces.read().await;
-                            let target_api_resources = shared_target_api_resources.read().await;
-
                             store.insert(
                                 context.to_string(),
                                 KubeState::new(
                                     client.clone(),
-                                    target_namespaces.to_vec(),
-                                    target_api_resources.to_vec(),
+                                    shared_target_namespaces.read().await.to_vec(),
+                                    shared_target_api_resources.read().await.to_vec(),
                                 ),
                             );
 
                             context = ctx;
+                            current_connection = None;
+                        }
+                        WorkerResult::ChangedImpersonation(impersonation) => {
+                            Self::abort(&handles);
+
+                            handles.clear();
+
+                            store.insert(
+                                context.to_string(),
+                                KubeState::new(
+                                    client.clone(),
+                                    shared_target_namespaces.read().await.to_vec(),
+                                    shared_target_api_resources.read().await.to_vec(),
+                                ),
+                            );
+
+                            store.set_impersonation(impersonation);
+
+                            current_connection = None;
                         }
                         WorkerResult::Terminated => {}
                     },
@@ -259,7 +677,7 @@ impl KubeController {
 struct EventController {
     base: PollerBase,
     rx: Receiver<Message>,
-    contexts: Vec<String>,
+    contexts: SharedContexts,
     shared_target_api_resources: SharedTargetApiResources,
     shared_api_resources: SharedApiResources,
 }
@@ -268,7 +686,7 @@ impl EventController {
     fn new(
         base: PollerBase,
         rx: Receiver<Message>,
-        contexts: Vec<String>,
+        contexts: SharedContexts,
         shared_target_api_resources: SharedTargetApiResources,
         shared_api_resources: SharedApiResources,
     ) -> Self {
@@ -292,6 +710,13 @@ impl Worker for EventController {
         let mut network_handler: Option<AbortHandle> = None;
         let mut yaml_handler: Option<AbortHandle> = None;
         let mut get_handler: Option<AbortHandle> = None;
+        let mut custom_resource_handler: Option<AbortHandle> = None;
+        let mut custom_resource_definitions_handler: Option<AbortHandle> = None;
+        let mut custom_resource_explain_handler: Option<AbortHandle> = None;
+        let mut owner_tree_handler: Option<AbortHandle> = None;
+        let mut resource_search_handler: Option<AbortHandle> = None;
+        let mut access_review_handler: Option<AbortHandle> = None;
+        let mut namespace_handler: Option<AbortHandle> = None;
 
         let EventController {
             base: poll_worker,
@@ -303,9 +728,11 @@ impl Worker for EventController {
 
         let PollerBase {
             shared_target_namespaces,
+            shared_label_selectors,
             tx,
             is_terminated,
             kube_client,
+            ..
         } = poll_worker;
 
         while !is_terminated.load(Ordering::Relaxed) {
@@ -358,6 +785,23 @@ impl Worker for EventController {
                             tx.send(NamespaceResponse::Set(req).into())
                                 .expect("Failed to send NamespaceResponse:Set");
                         }
+                        NamespaceRequest::Describe(req) => {
+                            if let Some(handler) = namespace_handler {
+                                handler.abort();
+                            }
+
+                            namespace_handler = Some(
+                                NamespaceDescriptionWorker::new(
+                                    is_terminated.clone(),
+                                    tx,
+                                    kube_client.clone(),
+                                    req,
+                                )
+                                .spawn(),
+                            );
+
+                            task::yield_now().await;
+                        }
                     },
 
                     Kube::Log(LogMessage::Request(req)) => {
@@ -405,9 +849,12 @@ impl Worker for EventController {
                     }
 
                     Kube::Context(ContextMessage::Request(req)) => match req {
-                        ContextRequest::Get => tx
-                            .send(ContextResponse::Get(contexts.to_vec()).into())
-                            .expect("Failed to send ContextResponse::Get"),
+                        ContextRequest::Get => {
+                            let contexts = contexts.read().await.to_vec();
+
+                            tx.send(ContextResponse::Get(contexts).into())
+                                .expect("Failed to send ContextResponse::Get")
+                        }
                         ContextRequest::Set(req) => {
                             if let Some(h) = log_handler {
                                 h.abort();
@@ -429,10 +876,84 @@ impl Worker for EventController {
                                 h.abort();
                             }
 
+                            if let Some(h) = custom_resource_handler {
+                                h.abort();
+                            }
+
+                            if let Some(h) = custom_resource_definitions_handler {
+                                h.abort();
+                            }
+
+                            if let Some(h) = custom_resource_explain_handler {
+                                h.abort();
+                            }
+
+                            if let Some(h) = owner_tree_handler {
+                                h.abort();
+                            }
+
+                            if let Some(h) = resource_search_handler {
+                                h.abort();
+                            }
+
+                            if let Some(h) = access_review_handler {
+                                h.abort();
+                            }
+
                             return WorkerResult::ChangedContext(req);
                         }
                     },
 
+                    Kube::Impersonation(ImpersonationMessage::Request(ImpersonationRequest(
+                        req,
+                    ))) => {
+                        if let Some(h) = log_handler {
+                            h.abort();
+                        }
+
+                        if let Some(h) = config_handler {
+                            h.abort();
+                        }
+
+                        if let Some(h) = network_handler {
+                            h.abort();
+                        }
+
+                        if let Some(h) = yaml_handler {
+                            h.abort();
+                        }
+
+                        if let Some(h) = get_handler {
+                            h.abort();
+                        }
+
+                        if let Some(h) = custom_resource_handler {
+                            h.abort();
+                        }
+
+                        if let Some(h) = custom_resource_definitions_handler {
+                            h.abort();
+                        }
+
+                        if let Some(h) = custom_resource_explain_handler {
+                            h.abort();
+                        }
+
+                        if let Some(h) = owner_tree_handler {
+                            h.abort();
+                        }
+
+                        if let Some(h) = resource_search_handler {
+                            h.abort();
+                        }
+
+                        if let Some(h) = access_review_handler {
+                            h.abort();
+                        }
+
+                        return WorkerResult::ChangedImpersonation(req);
+                    }
+
                     Kube::Yaml(YamlMessage::Request(ev)) => {
                         use YamlRequest::*;
                         match ev {
@@ -482,6 +1003,125 @@ impl Worker for EventController {
                         }
                     }
 
+                    Kube::CustomResource(CustomResourceMessage::Request(req)) => match req {
+                        CustomResourceRequest::Definitions => {
+                            if let Some(handler) = custom_resource_definitions_handler {
+                                handler.abort();
+                            }
+
+                            custom_resource_definitions_handler = Some(
+                                CustomResourceDefinitionsWorker::new(
+                                    tx,
+                                    kube_client.clone(),
+                                    shared_api_resources.clone(),
+                                )
+                                .spawn(),
+                            );
+
+                            task::yield_now().await;
+                        }
+                        CustomResourceRequest::Select(req) => {
+                            if let Some(handler) = custom_resource_handler {
+                                handler.abort();
+                            }
+
+                            custom_resource_handler = Some(
+                                CustomResourceWorker::new(
+                                    is_terminated.clone(),
+                                    tx,
+                                    kube_client.clone(),
+                                    shared_target_namespaces.clone(),
+                                    req,
+                                )
+                                .spawn(),
+                            );
+
+                            task::yield_now().await;
+                        }
+                        CustomResourceRequest::Explain(req) => {
+                            if let Some(handler) = custom_resource_explain_handler {
+                                handler.abort();
+                            }
+
+                            custom_resource_explain_handler = Some(
+                                CustomResourceExplainWorker::new(tx, kube_client.clone(), req)
+                                    .spawn(),
+                            );
+
+                            task::yield_now().await;
+                        }
+                    },
+
+                    Kube::OwnerTree(OwnerTreeMessage::Request(OwnerTreeRequest(root))) => {
+                        if let Some(handler) = owner_tree_handler {
+                            handler.abort();
+                        }
+
+                        owner_tree_handler = Some(
+                            OwnerTreeWorker::new(
+                                tx,
+                                kube_client.clone(),
+                                shared_api_resources.clone(),
+                                root,
+                            )
+                            .spawn(),
+                        );
+
+                        task::yield_now().await;
+                    }
+
+                    Kube::ResourceSearch(ResourceSearchMessage::Request(
+                        ResourceSearchRequest(query),
+                    )) => {
+                        if let Some(handler) = resource_search_handler {
+                            handler.abort();
+                        }
+
+                        resource_search_handler = Some(
+                            ResourceSearchWorker::new(
+                                tx,
+                                kube_client.clone(),
+                                shared_api_resources.clone(),
+                                shared_target_namespaces.clone(),
+                                query,
+                            )
+                            .spawn(),
+                        );
+
+                        task::yield_now().await;
+                    }
+
+                    Kube::AccessReview(AccessReviewMessage::Request(req)) => {
+                        if let Some(handler) = access_review_handler {
+                            handler.abort();
+                        }
+
+                        access_review_handler = Some(
+                            AccessReviewWorker::new(
+                                tx,
+                                kube_client.clone(),
+                                shared_api_resources.clone(),
+                                req,
+                            )
+                            .spawn(),
+                        );
+
+                        task::yield_now().await;
+                    }
+
+                    Kube::LabelSelector(LabelSelectorRequest { target, selector }) => {
+                        let mut label_selectors = shared_label_selectors.write().await;
+
+                        match selector {
+                            Some(selector) => {
+                                label_selectors.insert(target, selector);
+                            }
+                            None => {
+                                label_selectors.remove(&target);
+                            }
+                        }
+                    }
+
                     Kube::Get(GetMessage::Request(req)) => {
                         if let Some(handler) = get_handler {
                             handler.abort();
@@ -512,6 +1152,13 @@ impl Worker for EventController {
 
                         task::yield_now().await;
                     }
+
+                    Kube::Network(NetworkMessage::Reachability(req)) => {
+                        let ret = check_reachability(kube_client, req).await;
+
+                        tx.send(NetworkResponse::Reachability(ret).into())
+                            .expect("Failed to send NetworkResponse::Reachability");
+                    }
                     _ => unreachable!(),
                 },
                 Ok(_) => unreachable!(),
@@ -662,4 +1309,60 @@ mod tests {
             }
         }
     }
+    mod context_after_reload {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        fn kubeconfig(current_context: &str) -> Kubeconfig {
+            Kubeconfig::from_yaml(&CONFIG.replace(
+                "current-context: cluster-2",
+                &format!("current-context: {}", current_context),
+            ))
+            .unwrap()
+        }
+
+        #[test]
+        fn current_contextが変わったときは切り替える() {
+            let actual = context_after_reload(
+                "cluster-1",
+                Some("cluster-2"),
+                &kubeconfig("cluster-3"),
+                &[],
+            );
+
+            assert_eq!(actual, Some("cluster-3".to_string()));
+        }
+
+        #[test]
+        fn 変更がないときは再起動しない() {
+            let actual = context_after_reload(
+                "cluster-1",
+                Some("cluster-2"),
+                &kubeconfig("cluster-2"),
+                &[],
+            );
+
+            assert_eq!(actual, None);
+        }
+
+        #[test]
+        fn 使用中のコンテキストの定義が変わったときは接続し直す() {
+            let actual = context_after_reload(
+                "cluster-1",
+                Some("cluster-2"),
+                &kubeconfig("cluster-2"),
+                &["cluster-1".to_string()],
+            );
+
+            assert_eq!(actual, Some("cluster-1".to_string()));
+        }
+
+        #[test]
+        fn 使用中のコンテキストが削除されたときはcurrent_contextに切り替える() {
+            let actual =
+                context_after_reload("deleted", Some("cluster-2"), &kubeconfig("cluster-2"), &[]);
+
+            assert_eq!(actual, Some("cluster-2".to_string()));
+        }
+    }
 }
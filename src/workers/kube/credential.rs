@@ -0,0 +1,586 @@
+use std::{
+    collections::hash_map::RandomState,
+    fs,
+    hash::{BuildHasher as _, Hasher as _},
+    io::{self, Write as _},
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{anyhow, Context as _, Result};
+use chrono::{DateTime, Utc};
+use kube::{
+    config::{AuthInfo, ExecAuthCluster, ExecConfig, ExecInteractiveMode},
+    Config,
+};
+use serde::{Deserialize, Serialize};
+use tokio::task::{self, AbortHandle};
+
+use crate::logger;
+
+/// 有効期限のこの時間前に認証情報を更新する
+const REFRESH_MARGIN: Duration = Duration::from_secs(5 * 60);
+
+/// 認証情報の更新に失敗したときに再試行するまでの時間
+const RETRY_INTERVAL: Duration = Duration::from_secs(10);
+
+/// トークンファイルのディレクトリの作成を試みる回数
+const MAX_CREATE_DIR_ATTEMPTS: usize = 8;
+
+/// exec pluginの出力
+#[derive(Debug, Deserialize)]
+struct ExecCredential {
+    status: Option<ExecCredentialStatus>,
+}
+
+#[derive(Debug, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ExecCredentialStatus {
+    token: Option<String>,
+    expiration_timestamp: Option<DateTime<Utc>>,
+    client_certificate_data: Option<String>,
+}
+
+/// exec pluginに`KUBERNETES_EXEC_INFO`で渡す情報
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ExecInfo<'a> {
+    api_version: Option<&'a str>,
+    kind: &'static str,
+    spec: ExecInfoSpec<'a>,
+}
+
+#[derive(Serialize)]
+struct ExecInfoSpec<'a> {
+    interactive: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cluster: Option<&'a ExecAuthCluster>,
+}
+
+/// exec pluginで取得したトークンを有効期限の前に更新する
+///
+/// kube-rsはトークンの期限が切れる直前にリクエストの中でexec pluginを実行し、失敗するとリクエストも失敗する。
+/// そのため、exec pluginを別タスクで実行してトークンファイルに書き出し、kube-rsにはトークンファイルを読み込ませる。
+/// 破棄すると更新を止めてトークンファイルを削除する
+#[derive(Debug)]
+pub struct CredentialRefresher {
+    path: PathBuf,
+    handle: Option<AbortHandle>,
+}
+
+impl CredentialRefresher {
+    /// 接続先の設定がexec pluginを使う場合はトークンを取得し、トークンファイルを使うように書き換える
+    ///
+    /// exec pluginがクライアント証明書を返すなど、トークンファイルに置き換えられない場合は`None`を返し設定を変更しない。
+    /// exec pluginの実行やトークンファイルの作成に失敗した場合も、クライアントの作成は止めずにkube-rsのexec pluginの処理に任せる
+    pub async fn start(config: &mut Config) -> Option<Self> {
+        // 所有者のみがアクセスできるディレクトリを確認できないため、トークンファイルを作らない
+        if cfg!(not(unix)) {
+            return None;
+        }
+
+        let exec = refreshable_exec(&config.auth_info)?.clone();
+
+        let refresher = match Self::spawn(exec).await {
+            Ok(refresher) => refresher?,
+            Err(err) => {
+                logger!(
+                    error,
+                    "Failed to prepare the token file for the exec plugin: {:#}",
+                    err
+                );
+
+                return None;
+            }
+        };
+
+        config.auth_info.exec = None;
+        config.auth_info.token_file = Some(refresher.path.to_string_lossy().to_string());
+
+        Some(refresher)
+    }
+
+    /// exec pluginを実行してトークンファイルに書き出し、有効期限がある場合は更新するタスクを起動する
+    async fn spawn(exec: ExecConfig) -> Result<Option<Self>> {
+        let status = {
+            let exec = exec.clone();
+            task::spawn_blocking(move || run_exec(&exec)).await??
+        };
+
+        let (Some(token), None) = (&status.token, &status.client_certificate_data) else {
+            return Ok(None);
+        };
+
+        // 書き込みに失敗した場合は破棄して作成したディレクトリを削除する
+        let mut refresher = Self {
+            path: token_file_path()?,
+            handle: None,
+        };
+
+        write_token(&refresher.path, token)?;
+
+        refresher.handle = status.expiration_timestamp.map(|expires_at| {
+            task::spawn(refresh(exec, refresher.path.clone(), expires_at)).abort_handle()
+        });
+
+        Ok(Some(refresher))
+    }
+
+    #[cfg(test)]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for CredentialRefresher {
+    fn drop(&mut self) {
+        if let Some(handle) = &self.handle {
+            handle.abort();
+        }
+
+        let _ = fs::remove_file(&self.path);
+
+        if let Some(dir) = self.path.parent() {
+            let _ = fs::remove_dir(dir);
+        }
+    }
+}
+
+/// kube-rsがexec pluginで認証する場合はその設定を返す
+///
+/// kube-rsはauth-provider、ユーザー名とパスワード、トークン、トークンファイル、exec pluginの順に認証方法を選ぶ。
+/// 対話的な入力が必要なexec pluginはTUIの裏で実行できないため対象外にする
+fn refreshable_exec(auth_info: &AuthInfo) -> Option<&ExecConfig> {
+    if auth_info.auth_provider.is_some()
+        || (auth_info.username.is_some() && auth_info.password.is_some())
+        || auth_info.token.is_some()
+        || auth_info.token_file.is_some()
+    {
+        return None;
+    }
+
+    auth_info
+        .exec
+        .as_ref()
+        .filter(|exec| exec.interactive_mode != Some(ExecInteractiveMode::Always))
+}
+
+/// 有効期限が切れる前にトークンを更新し続ける
+async fn refresh(exec: ExecConfig, path: PathBuf, expires_at: DateTime<Utc>) {
+    let mut expires_at = expires_at;
+    let mut next = refresh_at(Utc::now(), expires_at);
+
+    loop {
+        let wait = (next - Utc::now()).to_std().unwrap_or_default();
+
+        tokio::time::sleep(wait).await;
+
+        let result = {
+            let exec = exec.clone();
+            task::spawn_blocking(move || run_exec(&exec)).await
+        };
+
+        let status = result
+            .map_err(Into::into)
+            .and_then(|result| result)
+            .and_then(|status| match &status.token {
+                Some(token) => write_token(&path, token).map(|_| status),
+                None => Err(anyhow!("exec plugin did not return a token")),
+            });
+
+        match status {
+            Ok(status) => {
+                logger!(info, "Refreshed exec credential {}", path.display());
+
+                // 有効期限がない場合は以降更新しない
+                let Some(new_expires_at) = status.expiration_timestamp else {
+                    break;
+                };
+
+                expires_at = new_expires_at;
+                next = refresh_at(Utc::now(), expires_at);
+            }
+            Err(err) => {
+                logger!(
+                    error,
+                    "Failed to refresh exec credential (expires at {}): {:#}",
+                    expires_at,
+                    err
+                );
+
+                next = Utc::now() + RETRY_INTERVAL;
+            }
+        }
+    }
+}
+
+/// トークンを更新する時刻
+///
+/// 有効期間が短い場合は残り時間の半分が過ぎたときに更新する
+fn refresh_at(now: DateTime<Utc>, expires_at: DateTime<Utc>) -> DateTime<Utc> {
+    let remaining = (expires_at - now).to_std().unwrap_or_default();
+
+    now + remaining.saturating_sub(REFRESH_MARGIN).max(remaining / 2)
+}
+
+/// exec pluginを実行して認証情報を取得する
+fn run_exec(exec: &ExecConfig) -> Result<ExecCredentialStatus> {
+    let program = exec
+        .command
+        .as_ref()
+        .ok_or_else(|| anyhow!("exec plugin command is not set"))?;
+
+    let mut cmd = Command::new(program);
+
+    if let Some(args) = &exec.args {
+        cmd.args(args);
+    }
+
+    if let Some(env) = &exec.env {
+        cmd.envs(
+            env.iter()
+                .filter_map(|env| Some((env.get("name")?, env.get("value")?))),
+        );
+    }
+
+    let exec_info = ExecInfo {
+        api_version: exec.api_version.as_deref(),
+        kind: "ExecCredential",
+        spec: ExecInfoSpec {
+            interactive: false,
+            cluster: exec.cluster.as_ref().filter(|_| exec.provide_cluster_info),
+        },
+    };
+
+    cmd.env("KUBERNETES_EXEC_INFO", serde_json::to_string(&exec_info)?);
+
+    if let Some(envs) = &exec.drop_env {
+        for env in envs {
+            cmd.env_remove(env);
+        }
+    }
+
+    let output = cmd
+        .stdin(Stdio::null())
+        .output()
+        .with_context(|| format!("Failed to run exec plugin {}", program))?;
+
+    if !output.status.success() {
+        return Err(anyhow!(
+            "exec plugin {} failed with {}: {}",
+            program,
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    let credential: ExecCredential = serde_json::from_slice(&output.stdout)
+        .with_context(|| format!("Failed to parse the output of exec plugin {}", program))?;
+
+    credential
+        .status
+        .ok_or_else(|| anyhow!("exec plugin {} did not return a status", program))
+}
+
+/// トークンファイルごとに新しく作ったディレクトリの中のパスを返す
+///
+/// XDG_RUNTIME_DIRが自分だけが使えるディレクトリの場合はその下に、それ以外は一時ディレクトリの下に作る
+fn token_file_path() -> Result<PathBuf> {
+    if let Some(runtime_dir) = runtime_dir() {
+        if let Ok(dir) = create_private_dir(&runtime_dir) {
+            // 作成したディレクトリと所有者が異なる場合は他のユーザーのディレクトリのため使わない
+            if is_same_owner(&runtime_dir, &dir) {
+                return Ok(dir.join("token"));
+            }
+
+            let _ = fs::remove_dir(&dir);
+        }
+    }
+
+    let dir = create_private_dir(&std::env::temp_dir())?;
+
+    Ok(dir.join("token"))
+}
+
+/// XDG_RUNTIME_DIRがシンボリックリンクではなく、所有者以外がアクセスできないディレクトリの場合に返す
+fn runtime_dir() -> Option<PathBuf> {
+    let dir = PathBuf::from(std::env::var_os("XDG_RUNTIME_DIR")?);
+
+    let metadata = fs::symlink_metadata(&dir).ok()?;
+
+    (dir.is_absolute() && metadata.is_dir() && is_private(&metadata)).then_some(dir)
+}
+
+/// 推測できない名前のディレクトリを所有者のみがアクセスできる権限で作る
+///
+/// 既存のディレクトリやシンボリックリンクを使わないように親ディレクトリは作らず、
+/// 同じ名前が存在する場合は別の名前で作り直す
+fn create_private_dir(base: &Path) -> Result<PathBuf> {
+    for _ in 0..MAX_CREATE_DIR_ATTEMPTS {
+        let dir = base.join(format!("kubetui-{}-{:016x}", std::process::id(), random()));
+
+        let mut builder = fs::DirBuilder::new();
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::DirBuilderExt as _;
+
+            builder.mode(0o700);
+        }
+
+        match builder.create(&dir) {
+            Ok(()) => {}
+            Err(err) if err.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(err) => {
+                return Err(err).with_context(|| format!("Failed to create {}", dir.display()))
+            }
+        }
+
+        let metadata = fs::symlink_metadata(&dir)
+            .with_context(|| format!("Failed to read metadata of {}", dir.display()))?;
+
+        if !metadata.is_dir() || !is_private(&metadata) {
+            return Err(anyhow!("{} is not a private directory", dir.display()));
+        }
+
+        return Ok(dir);
+    }
+
+    Err(anyhow!(
+        "Failed to create a directory for the token file in {}",
+        base.display()
+    ))
+}
+
+/// プロセスごとにランダムな鍵を持つ`RandomState`から推測できない値を作る
+fn random() -> u64 {
+    let mut hasher = RandomState::new().build_hasher();
+
+    hasher.write_u128(
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos(),
+    );
+
+    hasher.finish()
+}
+
+#[cfg(unix)]
+fn is_private(metadata: &fs::Metadata) -> bool {
+    use std::os::unix::fs::MetadataExt as _;
+
+    metadata.mode() & 0o077 == 0
+}
+
+#[cfg(not(unix))]
+fn is_private(_: &fs::Metadata) -> bool {
+    false
+}
+
+#[cfg(unix)]
+fn is_same_owner(a: &Path, b: &Path) -> bool {
+    use std::os::unix::fs::MetadataExt as _;
+
+    match (fs::symlink_metadata(a), fs::symlink_metadata(b)) {
+        (Ok(a), Ok(b)) => a.uid() == b.uid(),
+        _ => false,
+    }
+}
+
+#[cfg(not(unix))]
+fn is_same_owner(_: &Path, _: &Path) -> bool {
+    false
+}
+
+/// kube-rsが書き込み途中のファイルを読まないように、一時ファイルに書き込んでから置き換える
+///
+/// 一時ファイルは既存のファイルやシンボリックリンクを開かないように必ず新しく作る
+fn write_token(path: &Path, token: &str) -> Result<()> {
+    let tmp = path.with_extension("tmp");
+
+    // 前回の書き込みが中断した場合に残った一時ファイルを削除する
+    let _ = fs::remove_file(&tmp);
+
+    let mut options = fs::OpenOptions::new();
+
+    options.write(true).create_new(true);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt as _;
+
+        options.mode(0o600);
+    }
+
+    let mut file = options
+        .open(&tmp)
+        .with_context(|| format!("Failed to open {}", tmp.display()))?;
+
+    file.write_all(token.as_bytes())?;
+
+    fs::rename(&tmp, path).with_context(|| format!("Failed to write {}", path.display()))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone as _;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn exec(script: &str) -> ExecConfig {
+        ExecConfig {
+            api_version: Some("client.authentication.k8s.io/v1beta1".to_string()),
+            command: Some("sh".to_string()),
+            args: Some(vec!["-c".to_string(), script.to_string()]),
+            env: None,
+            drop_env: None,
+            interactive_mode: None,
+            provide_cluster_info: false,
+            cluster: None,
+        }
+    }
+
+    #[test]
+    fn 有効期限の5分前に更新する() {
+        let now = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+
+        assert_eq!(
+            refresh_at(now, now + Duration::from_secs(15 * 60)),
+            now + Duration::from_secs(10 * 60)
+        );
+    }
+
+    #[test]
+    fn 有効期間が短い場合は残り時間の半分で更新する() {
+        let now = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+
+        assert_eq!(
+            refresh_at(now, now + Duration::from_secs(4 * 60)),
+            now + Duration::from_secs(2 * 60)
+        );
+
+        assert_eq!(refresh_at(now, now - Duration::from_secs(60)), now);
+    }
+
+    #[test]
+    fn トークンやトークンファイルがある場合はexec_pluginを使わない() {
+        let auth_info = AuthInfo {
+            token_file: Some("/var/run/token".to_string()),
+            exec: Some(exec("")),
+            ..Default::default()
+        };
+
+        assert!(refreshable_exec(&auth_info).is_none());
+
+        let auth_info = AuthInfo {
+            exec: Some(exec("")),
+            ..Default::default()
+        };
+
+        assert!(refreshable_exec(&auth_info).is_some());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn exec_pluginの出力からトークンと有効期限を取得する() {
+        let exec = exec(
+            r#"echo "{\"kind\":\"ExecCredential\",\"status\":{\"token\":\"$(echo $KUBERNETES_EXEC_INFO | grep -c '\"interactive\":false')\",\"expirationTimestamp\":\"2024-01-01T00:15:00Z\"}}""#,
+        );
+
+        assert_eq!(
+            run_exec(&exec).unwrap(),
+            ExecCredentialStatus {
+                token: Some("1".to_string()),
+                expiration_timestamp: Some(Utc.with_ymd_and_hms(2024, 1, 1, 0, 15, 0).unwrap()),
+                client_certificate_data: None,
+            }
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn exec_pluginが失敗した場合は標準エラー出力を含める() {
+        let err = run_exec(&exec("echo expired >&2; exit 1")).unwrap_err();
+
+        assert!(err.to_string().contains("expired"), "{}", err);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn exec_pluginのトークンをトークンファイルに書き出す() {
+        let mut config = Config::new("https://192.168.0.1".parse().unwrap());
+
+        config.auth_info.exec = Some(exec(
+            r#"echo '{"kind":"ExecCredential","status":{"token":"token-1"}}'"#,
+        ));
+
+        let refresher = CredentialRefresher::start(&mut config).await.unwrap();
+
+        let path = refresher.path().to_path_buf();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "token-1");
+        assert_eq!(
+            config.auth_info.token_file,
+            Some(path.to_string_lossy().to_string())
+        );
+        assert!(config.auth_info.exec.is_none());
+
+        drop(refresher);
+
+        assert!(!path.exists());
+        assert!(!path.parent().unwrap().exists());
+    }
+
+    #[tokio::test]
+    async fn exec_pluginが失敗した場合は設定を変更せずにkube_rsに任せる() {
+        let mut config = Config::new("https://192.168.0.1".parse().unwrap());
+
+        config.auth_info.exec = Some(exec("exit 1"));
+
+        assert!(CredentialRefresher::start(&mut config).await.is_none());
+        assert!(config.auth_info.exec.is_some());
+        assert!(config.auth_info.token_file.is_none());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn トークンファイルごとに所有者のみがアクセスできるディレクトリを作る() {
+        use std::os::unix::fs::MetadataExt as _;
+
+        let base = create_private_dir(&std::env::temp_dir()).unwrap();
+
+        let a = create_private_dir(&base).unwrap();
+        let b = create_private_dir(&base).unwrap();
+
+        assert_ne!(a, b);
+        assert_eq!(fs::symlink_metadata(&a).unwrap().mode() & 0o777, 0o700);
+
+        fs::remove_dir(a).unwrap();
+        fs::remove_dir(b).unwrap();
+        fs::remove_dir(base).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn 一時ファイルのシンボリックリンクを辿らずに書き込む() {
+        let dir = create_private_dir(&std::env::temp_dir()).unwrap();
+
+        let target = dir.join("target");
+        let path = dir.join("token");
+
+        fs::write(&target, "original").unwrap();
+        std::os::unix::fs::symlink(&target, path.with_extension("tmp")).unwrap();
+
+        write_token(&path, "token-1").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "token-1");
+        assert_eq!(fs::read_to_string(&target).unwrap(), "original");
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -1,19 +1,29 @@
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     features::{
-        api_resources::message::ApiMessage, config::message::ConfigMessage,
-        context::message::ContextMessage, get::message::GetMessage,
-        namespace::message::NamespaceMessage, network::message::NetworkMessage,
-        pod::message::LogMessage, yaml::message::YamlMessage,
+        access_review::message::AccessReviewMessage, api_resources::message::ApiMessage,
+        config::message::ConfigMessage, context::message::ContextMessage,
+        custom_resource::message::CustomResourceMessage, get::message::GetMessage,
+        impersonation::message::ImpersonationMessage,
+        label_selector::message::LabelSelectorRequest, namespace::message::NamespaceMessage,
+        network::message::NetworkMessage, owner_tree::message::OwnerTreeMessage,
+        pod::message::LogMessage, resource_search::message::ResourceSearchMessage,
+        yaml::message::YamlMessage,
     },
     kube::table::KubeTable,
     message::Message,
+    workers::replay::{serde_result, ReplayMessage},
 };
 
-use super::controller::{TargetApiResources, TargetNamespaces};
+use super::{
+    connection::ConnectionStatus,
+    controller::{TargetApiResources, TargetNamespaces},
+};
 
-#[derive(Debug)]
+/// `--record`で記録するため、レンダースレッドが受信するメッセージはシリアライズできるようにする
+#[derive(Debug, Serialize, Deserialize)]
 pub enum Kube {
     Context(ContextMessage),
     Api(ApiMessage),
@@ -22,14 +32,27 @@ pub enum Kube {
         context: String,
         namespaces: TargetNamespaces,
     },
-    Event(Result<Vec<String>>),
+    Connection {
+        context: String,
+        status: ConnectionStatus,
+    },
+    Impersonation(ImpersonationMessage),
+    Event(#[serde(with = "serde_result")] Result<Vec<String>>),
     Namespace(NamespaceMessage),
-    Pod(Result<KubeTable>),
+    Pod(#[serde(with = "serde_result")] Result<KubeTable>),
     Log(LogMessage),
     Config(ConfigMessage),
     Network(NetworkMessage),
     Yaml(YamlMessage),
     Get(GetMessage),
+    CustomResource(CustomResourceMessage),
+    OwnerTree(OwnerTreeMessage),
+    ResourceSearch(ResourceSearchMessage),
+    AccessReview(AccessReviewMessage),
+    #[serde(skip)]
+    LabelSelector(LabelSelectorRequest),
+    #[serde(skip)]
+    Replay(ReplayMessage),
 }
 
 impl From<Kube> for Message {
@@ -1,15 +1,17 @@
 use std::{collections::BTreeMap, fmt::Debug};
 
 use anyhow::{anyhow, Result};
-use futures::future::try_join_all;
 use kube::{
     config::{KubeConfigOptions, Kubeconfig},
     Client, Config,
 };
 
-use crate::kube::KubeClient;
+use crate::{features::impersonation::kube::Impersonation, kube::KubeClient};
 
-use super::controller::{TargetApiResources, TargetNamespaces};
+use super::{
+    controller::{TargetApiResources, TargetNamespaces},
+    credential::CredentialRefresher,
+};
 
 pub type Context = String;
 
@@ -34,15 +36,35 @@ impl KubeState {
     }
 }
 
-#[derive(Debug)]
-#[cfg_attr(test, derive(PartialEq))]
+/// コンテキストごとの接続先
+///
+/// 接続先はコンテキストを初めて使用するときに生成するため、
+/// 認証情報の期限切れなどで接続先を生成できないコンテキストがあっても他のコンテキストは使用できる
+#[derive(Debug, Default)]
 pub struct KubeStore {
+    kubeconfig: Kubeconfig,
     inner: BTreeMap<Context, KubeState>,
+    /// exec pluginで取得したトークンを更新し続けるタスク
+    credentials: BTreeMap<Context, CredentialRefresher>,
+    /// kubeconfigの再読み込みで接続先を破棄したコンテキストで選択していたnamespaceとAPI
+    restored: BTreeMap<Context, (TargetNamespaces, TargetApiResources)>,
+    /// すべての接続先に適用する偽装ユーザー
+    impersonation: Option<Impersonation>,
 }
 
 impl From<BTreeMap<Context, KubeState>> for KubeStore {
     fn from(inner: BTreeMap<Context, KubeState>) -> Self {
-        KubeStore { inner }
+        KubeStore {
+            inner,
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+impl PartialEq for KubeStore {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.inner == rhs.inner
     }
 }
 
@@ -57,70 +79,156 @@ impl std::fmt::Debug for KubeState {
 }
 
 impl KubeStore {
-    pub async fn try_from_kubeconfig(config: Kubeconfig) -> Result<Self> {
+    pub fn new(kubeconfig: Kubeconfig) -> Self {
+        Self {
+            kubeconfig,
+            ..Default::default()
+        }
+    }
+
+    /// コンテキストの接続先を返す
+    ///
+    /// 接続先を生成していない場合は生成する。生成に失敗した場合は保持せず、次の呼び出しで再度生成する
+    pub async fn get_or_connect(&mut self, context: &str) -> Result<&mut KubeState> {
+        if !self.inner.contains_key(context) {
+            let (mut state, credential) =
+                Self::connect(&self.kubeconfig, context, self.impersonation.as_ref()).await?;
+
+            if let Some((namespaces, api_resources)) = self.restored.remove(context) {
+                state.target_namespaces = namespaces;
+                state.target_api_resources = api_resources;
+            }
+
+            if let Some(credential) = credential {
+                self.credentials.insert(context.to_string(), credential);
+            }
+
+            self.inner.insert(context.to_string(), state);
+        }
+
+        self.get_mut(context)
+    }
+
+    async fn connect(
+        kubeconfig: &Kubeconfig,
+        context: &str,
+        impersonation: Option<&Impersonation>,
+    ) -> Result<(KubeState, Option<CredentialRefresher>)> {
         let Kubeconfig {
             clusters,
             contexts,
             auth_infos,
             ..
-        } = &config;
-
-        let jobs: Vec<(Context, KubeState)> = try_join_all(contexts.iter().map(|context| async {
-            let cluster = clusters.iter().find_map(|cluster| {
-                if cluster.name == context.name {
-                    Some(cluster.name.to_string())
-                } else {
-                    None
-                }
-            });
-
-            let user = auth_infos.iter().find_map(|auth_info| {
-                let Some(kube::config::Context { ref user, .. }) = context.context else {
-                    return None;
-                };
-
-                if &auth_info.name == user {
-                    Some(auth_info.name.to_string())
-                } else {
-                    None
-                }
-            });
-
-            let options = KubeConfigOptions {
-                context: Some(context.name.to_string()),
-                cluster,
-                user,
+        } = kubeconfig;
+
+        let context = contexts
+            .iter()
+            .find(|ctx| ctx.name == context)
+            .ok_or_else(|| anyhow!(format!("Cannot find context {}", context)))?;
+
+        let cluster = clusters.iter().find_map(|cluster| {
+            if cluster.name == context.name {
+                Some(cluster.name.to_string())
+            } else {
+                None
+            }
+        });
+
+        let user = auth_infos.iter().find_map(|auth_info| {
+            let Some(kube::config::Context { ref user, .. }) = context.context else {
+                return None;
             };
 
-            let config = Config::from_custom_kubeconfig(config.clone(), &options).await?;
+            if &auth_info.name == user {
+                Some(auth_info.name.to_string())
+            } else {
+                None
+            }
+        });
 
-            let cluster_url: String = config.cluster_url.to_string();
-            let target_namespace = config.default_namespace.to_string();
+        let options = KubeConfigOptions {
+            context: Some(context.name.to_string()),
+            cluster,
+            user,
+        };
 
-            let client = Client::try_from(config)?;
+        let mut config = Config::from_custom_kubeconfig(kubeconfig.clone(), &options).await?;
 
-            let kube_client = KubeClient::new(client, cluster_url);
+        let credential = CredentialRefresher::start(&mut config).await;
 
-            anyhow::Ok((
-                context.name.to_string(),
-                KubeState {
-                    client: kube_client,
-                    target_namespaces: vec![target_namespace],
-                    target_api_resources: vec![],
-                },
-            ))
-        }))
-        .await?;
+        if let Some(impersonation) = impersonation {
+            impersonation.apply(&mut config);
+        }
+
+        let cluster_url: String = config.cluster_url.to_string();
+        let target_namespace = config.default_namespace.to_string();
+
+        let client = Client::try_from(config)?;
+
+        let kube_client = KubeClient::new(client, cluster_url);
 
-        let inner: BTreeMap<Context, KubeState> = jobs.into_iter().collect();
+        let state = KubeState {
+            client: kube_client,
+            target_namespaces: vec![target_namespace],
+            target_api_resources: vec![],
+        };
 
-        Ok(inner.into())
+        Ok((state, credential))
     }
 
-    pub fn get(&self, context: &str) -> Result<&KubeState> {
+    /// 再読み込みしたkubeconfigで定義が変わったコンテキスト（削除したコンテキストを含む）を返す
+    pub fn changed_contexts(&self, kubeconfig: &Kubeconfig) -> Vec<Context> {
         self.inner
-            .get(context)
-            .ok_or_else(|| anyhow!(format!("Cannot get context {}", context)))
+            .keys()
+            .filter(|context| {
+                definition(&self.kubeconfig, context) != definition(kubeconfig, context)
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// kubeconfigを置き換え、定義が変わったコンテキストの接続先を破棄する
+    ///
+    /// 選択していたnamespaceとAPIは、次に接続先を生成したときに引き継ぐ
+    pub fn reload(&mut self, kubeconfig: Kubeconfig) -> Vec<Context> {
+        let changed = self.changed_contexts(&kubeconfig);
+
+        for context in &changed {
+            self.disconnect(context);
+        }
+
+        self.kubeconfig = kubeconfig;
+
+        changed
+    }
+
+    pub fn impersonation(&self) -> Option<&Impersonation> {
+        self.impersonation.as_ref()
+    }
+
+    /// 偽装ユーザーを置き換え、すべての接続先を破棄する
+    ///
+    /// 偽装ユーザーは接続先の生成時に適用するため、次に接続先を生成したときに反映される
+    pub fn set_impersonation(&mut self, impersonation: Option<Impersonation>) {
+        let contexts: Vec<Context> = self.inner.keys().cloned().collect();
+
+        for context in &contexts {
+            self.disconnect(context);
+        }
+
+        self.impersonation = impersonation;
+    }
+
+    /// 接続先を破棄し、選択していたnamespaceとAPIを保持する
+    fn disconnect(&mut self, context: &str) {
+        self.credentials.remove(context);
+
+        if let Some(state) = self.inner.remove(context) {
+            self.restored.insert(
+                context.to_string(),
+                (state.target_namespaces, state.target_api_resources),
+            );
+        }
     }
 
     pub fn get_mut(&mut self, context: &str) -> Result<&mut KubeState> {
@@ -134,6 +242,33 @@ impl KubeStore {
     }
 }
 
+/// コンテキストと、そのコンテキストが参照するクラスターとユーザーの定義
+///
+/// kube-rsの型は比較できないため、JSONに変換して比較する
+fn definition(kubeconfig: &Kubeconfig, context: &str) -> Option<serde_json::Value> {
+    let named_context = kubeconfig.contexts.iter().find(|ctx| ctx.name == context)?;
+
+    let (cluster, user) = named_context
+        .context
+        .as_ref()
+        .map(|ctx| {
+            let cluster = kubeconfig
+                .clusters
+                .iter()
+                .find(|cluster| cluster.name == ctx.cluster);
+
+            let user = kubeconfig
+                .auth_infos
+                .iter()
+                .find(|auth_info| auth_info.name == ctx.user);
+
+            (cluster, user)
+        })
+        .unwrap_or_default();
+
+    serde_json::to_value((named_context, cluster, user)).ok()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -179,6 +314,10 @@ mod tests {
                   cluster: cluster-3
                   user: user-3
                 name: cluster-3
+              - context:
+                  cluster: cluster-3
+                  user: user-4
+                name: cluster-4
             current-context: cluster-2
             kind: Config
             preferences: {}
@@ -192,14 +331,23 @@ mod tests {
               - name: user-3
                 user:
                   token: user-3
+              - name: user-4
+                user:
+                  exec:
+                    apiVersion: client.authentication.k8s.io/v1beta1
+                    command: kubetui-nonexistent-credential-plugin
             "#
     };
 
     #[tokio::test]
-    async fn kubeconfigからstateを生成() {
+    async fn 使用したコンテキストのstateを生成() {
         let kubeconfig = Kubeconfig::from_yaml(CONFIG).unwrap();
 
-        let actual = KubeStore::try_from_kubeconfig(kubeconfig).await.unwrap();
+        let mut actual = KubeStore::new(kubeconfig);
+
+        for context in ["cluster-1", "cluster-2", "cluster-3"] {
+            actual.get_or_connect(context).await.unwrap();
+        }
 
         let config = Config::new(Default::default());
 
@@ -235,4 +383,94 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[tokio::test]
+    async fn 接続先を生成できないコンテキストがあっても他のコンテキストは使用できる() {
+        let kubeconfig = Kubeconfig::from_yaml(CONFIG).unwrap();
+
+        let mut store = KubeStore::new(kubeconfig);
+
+        assert!(store.get_or_connect("cluster-4").await.is_err());
+        assert!(store.get_mut("cluster-4").is_err());
+
+        let state = store.get_or_connect("cluster-1").await.unwrap();
+
+        assert_eq!(state.client.as_server_url(), "https://192.168.0.1");
+    }
+
+    #[tokio::test]
+    async fn kubeconfigを再読み込みすると定義が変わったコンテキストだけ接続し直す() {
+        let kubeconfig = Kubeconfig::from_yaml(CONFIG).unwrap();
+
+        let mut store = KubeStore::new(kubeconfig);
+
+        for context in ["cluster-1", "cluster-2"] {
+            store.get_or_connect(context).await.unwrap();
+        }
+
+        store.get_mut("cluster-1").unwrap().target_namespaces = vec!["selected".to_string()];
+
+        let kubeconfig = Kubeconfig::from_yaml(
+            &CONFIG
+                .replace("server: https://192.168.0.1", "server: https://192.168.1.1")
+                .replace("current-context: cluster-2", "current-context: cluster-1"),
+        )
+        .unwrap();
+
+        assert_eq!(store.reload(kubeconfig), vec!["cluster-1".to_string()]);
+
+        assert!(store.get_mut("cluster-1").is_err());
+        assert!(store.get_mut("cluster-2").is_ok());
+
+        let state = store.get_or_connect("cluster-1").await.unwrap();
+
+        assert_eq!(state.client.as_server_url(), "https://192.168.1.1");
+        assert_eq!(state.target_namespaces, vec!["selected".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn 削除したコンテキストの接続先を破棄する() {
+        let kubeconfig = Kubeconfig::from_yaml(CONFIG).unwrap();
+
+        let mut store = KubeStore::new(kubeconfig.clone());
+
+        store.get_or_connect("cluster-3").await.unwrap();
+
+        let kubeconfig = Kubeconfig {
+            contexts: kubeconfig
+                .contexts
+                .into_iter()
+                .filter(|ctx| ctx.name != "cluster-3")
+                .collect(),
+            ..kubeconfig
+        };
+
+        assert_eq!(store.reload(kubeconfig), vec!["cluster-3".to_string()]);
+        assert!(store.get_or_connect("cluster-3").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn 偽装ユーザーを変更するとすべての接続先を破棄してnamespaceを引き継ぐ() {
+        let kubeconfig = Kubeconfig::from_yaml(CONFIG).unwrap();
+
+        let mut store = KubeStore::new(kubeconfig);
+
+        for context in ["cluster-1", "cluster-2"] {
+            store.get_or_connect(context).await.unwrap();
+        }
+
+        store.get_mut("cluster-1").unwrap().target_namespaces = vec!["selected".to_string()];
+
+        let impersonation = Impersonation::new("alice", vec!["dev".to_string()]);
+
+        store.set_impersonation(Some(impersonation.clone()));
+
+        assert_eq!(store.impersonation(), Some(&impersonation));
+        assert!(store.get_mut("cluster-1").is_err());
+        assert!(store.get_mut("cluster-2").is_err());
+
+        let state = store.get_or_connect("cluster-1").await.unwrap();
+
+        assert_eq!(state.target_namespaces, vec!["selected".to_string()]);
+    }
 }
@@ -0,0 +1,131 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use anyhow::Result;
+use kube::config::Kubeconfig;
+
+use super::config::{read_kubeconfig, ContextSources};
+
+/// kubeconfigの変更を確認する間隔
+const WATCH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// 変更を検知するためのファイルの更新時刻とサイズ
+type FileStamp = (SystemTime, u64);
+
+/// kubeconfigのファイルを監視する
+///
+/// 複数のファイルを読み込んでいる場合はすべてのファイルを監視する。
+/// エディタや`aws eks update-kubeconfig`などでの置き換えにも対応するため、更新時刻とサイズをポーリングで比較する
+#[derive(Debug)]
+pub struct KubeconfigWatcher {
+    paths: Vec<PathBuf>,
+    stamps: Vec<Option<FileStamp>>,
+}
+
+impl KubeconfigWatcher {
+    pub fn new(paths: Vec<PathBuf>) -> Self {
+        let stamps = paths.iter().map(|path| stamp(path)).collect();
+
+        Self { paths, stamps }
+    }
+
+    /// 何も監視しない
+    #[cfg(test)]
+    pub fn disabled() -> Self {
+        Self::new(Vec::new())
+    }
+
+    /// 監視しているkubeconfigを読み込む
+    pub fn read(&self) -> Result<(Kubeconfig, ContextSources)> {
+        read_kubeconfig(&self.paths)
+    }
+
+    /// 前回の確認からファイルが変更されたか
+    pub fn has_changed(&mut self) -> bool {
+        let mut changed = false;
+
+        for (path, prev) in self.paths.iter().zip(self.stamps.iter_mut()) {
+            let stamp = stamp(path);
+
+            if *prev != stamp {
+                *prev = stamp;
+                changed = true;
+            }
+        }
+
+        changed
+    }
+
+    /// ファイルが変更されるまで待つ
+    pub async fn changed(&mut self) {
+        if self.paths.is_empty() {
+            return std::future::pending().await;
+        }
+
+        loop {
+            tokio::time::sleep(WATCH_INTERVAL).await;
+
+            if self.has_changed() {
+                return;
+            }
+        }
+    }
+}
+
+/// ファイルが存在しない場合は`None`
+fn stamp(path: &Path) -> Option<FileStamp> {
+    let metadata = fs::metadata(path).ok()?;
+
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("kubetui-{}-{}.yaml", name, std::process::id()))
+    }
+
+    #[test]
+    fn ファイルを変更すると変更を検知する() {
+        let path = temp_path("watcher-changed");
+
+        fs::write(&path, "contexts: []").unwrap();
+
+        let mut watcher = KubeconfigWatcher::new(vec![path.clone()]);
+
+        assert!(!watcher.has_changed());
+
+        fs::write(&path, "contexts: []\ncurrent-context: kind").unwrap();
+
+        assert!(watcher.has_changed());
+        assert!(!watcher.has_changed());
+
+        fs::remove_file(&path).unwrap();
+
+        assert!(watcher.has_changed());
+    }
+
+    #[test]
+    fn 存在しないファイルを作成すると変更を検知する() {
+        let missing = temp_path("watcher-created");
+        let existing = temp_path("watcher-existing");
+
+        fs::write(&existing, "contexts: []").unwrap();
+
+        let mut watcher = KubeconfigWatcher::new(vec![existing.clone(), missing.clone()]);
+
+        assert!(!watcher.has_changed());
+
+        fs::write(&missing, "contexts: []").unwrap();
+
+        assert!(watcher.has_changed());
+
+        fs::remove_file(&missing).unwrap();
+        fs::remove_file(&existing).unwrap();
+    }
+}
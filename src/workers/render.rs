@@ -1,38 +1,69 @@
 mod action;
+mod frame_limiter;
+#[cfg(test)]
+mod ui_driver;
 mod window;
 
 use std::{
     cell::RefCell,
     io::{self},
+    path::PathBuf,
     rc::Rc,
     sync::{
         atomic::{AtomicBool, Ordering},
         Arc,
     },
+    time::{Duration, Instant},
 };
 
 use anyhow::Result;
-use crossbeam::channel::{Receiver, Sender};
+use crossbeam::channel::{Receiver, RecvTimeoutError, Sender};
 use ratatui::{backend::CrosstermBackend, layout::Direction, Terminal, TerminalOptions, Viewport};
 
 use crate::{
+    features::pod::view::LogViewConfig,
+    history::History,
     kube::context::{Context, Namespace},
     logger,
     message::Message,
     panic_set_hook,
-    ui::WindowAction,
+    ui::{Window, WindowAction},
+    workers::{
+        kube::{ConnectionStatuses, SharedConnectionStatuses},
+        replay::{Recorder, ReplayStatus, SharedReplayStatus},
+    },
 };
 
 use self::{
     action::{update_contents, window_action},
+    frame_limiter::FrameLimiter,
     window::WindowInit,
 };
 
+/// 1回の描画までにまとめて処理するメッセージの上限
+///
+/// メッセージが絶え間なく届く場合でも描画が止まらないようにする
+const MAX_MESSAGES_PER_FRAME: usize = 256;
+
+/// レンダースレッドの設定
+#[derive(Debug, Clone)]
+pub struct RenderConfig {
+    pub direction: Direction,
+    pub log_view_config: LogViewConfig,
+    pub history_file: Option<PathBuf>,
+    /// 描画の最小間隔
+    pub frame_interval: Duration,
+    /// 受信したメッセージの記録先
+    pub record_file: Option<PathBuf>,
+    /// 記録したメッセージを再生しているか
+    pub replay: bool,
+}
+
 pub struct Render {
     tx: Sender<Message>,
     rx: Receiver<Message>,
     is_terminated: Arc<AtomicBool>,
-    direction: Direction,
+    config: RenderConfig,
 }
 
 impl Render {
@@ -40,13 +71,13 @@ impl Render {
         tx: Sender<Message>,
         rx: Receiver<Message>,
         is_terminated: Arc<AtomicBool>,
-        direction: Direction,
+        config: RenderConfig,
     ) -> Self {
         Self {
-            direction,
             tx,
             rx,
             is_terminated,
+            config,
         }
     }
 
@@ -73,15 +104,34 @@ impl Render {
     fn render(&self) -> Result<()> {
         let namespace = Rc::new(RefCell::new(Namespace::new()));
         let context = Rc::new(RefCell::new(Context::new()));
+        let history = Rc::new(RefCell::new(History::load(
+            self.config.history_file.clone(),
+        )));
+        let connection_statuses = SharedConnectionStatuses::default();
+        let replay_status = self
+            .config
+            .replay
+            .then(|| Rc::new(RefCell::new(ReplayStatus::default())));
 
         let mut window = WindowInit::new(
-            self.direction,
+            self.config.direction,
             self.tx.clone(),
             context.clone(),
             namespace.clone(),
+            self.config.log_view_config.clone(),
+            history,
+            replay_status.clone(),
         )
+        .connection_statuses(connection_statuses.clone())
         .build();
 
+        let mut recorder = self
+            .config
+            .record_file
+            .as_deref()
+            .map(Recorder::create)
+            .transpose()?;
+
         let mut terminal = Terminal::with_options(
             CrosstermBackend::new(io::stdout()),
             TerminalOptions {
@@ -91,29 +141,91 @@ impl Render {
 
         terminal.clear()?;
 
+        let mut frame_limiter = FrameLimiter::new(self.config.frame_interval);
+
         while !self.is_terminated.load(Ordering::Relaxed) {
-            terminal.draw(|f| {
-                window.render(f);
-            })?;
-
-            match window_action(&mut window, &self.rx) {
-                WindowAction::Continue => {}
-                WindowAction::CloseWindow => {
-                    self.is_terminated
-                        .store(true, std::sync::atomic::Ordering::Relaxed);
-                    // break
+            if frame_limiter.should_draw(Instant::now()) {
+                terminal.draw(|f| {
+                    window.render(f);
+                })?;
+
+                frame_limiter.drawn(Instant::now());
+            }
+
+            let message = match frame_limiter.timeout(Instant::now()) {
+                Some(timeout) => match self.rx.recv_timeout(timeout) {
+                    Ok(message) => message,
+                    Err(RecvTimeoutError::Timeout) => continue,
+                    Err(RecvTimeoutError::Disconnected) => panic!("Failed to recv"),
+                },
+                None => self.rx.recv().expect("Failed to recv"),
+            };
+
+            // 描画までに届いたメッセージはまとめて処理する
+            let messages = std::iter::once(message)
+                .chain(self.rx.try_iter())
+                .take(MAX_MESSAGES_PER_FRAME);
+
+            for message in messages {
+                if let (Some(recorder), Message::Kube(message)) = (&mut recorder, &message) {
+                    if let Err(err) = recorder.record(message) {
+                        logger!(error, "Failed to record message: {:?}", err);
+                    }
                 }
-                WindowAction::UpdateContents(ev) => {
-                    update_contents(
+
+                // 表示が変わらないメッセージでは再描画しない
+                let is_dirty = match message {
+                    Message::Tick => window.needs_periodic_redraw(),
+                    message => self.update(
                         &mut window,
-                        ev,
-                        &mut context.borrow_mut(),
-                        &mut namespace.borrow_mut(),
-                    );
+                        message,
+                        &context,
+                        &namespace,
+                        &connection_statuses,
+                        replay_status.as_ref(),
+                    ),
+                };
+
+                if is_dirty {
+                    frame_limiter.mark_dirty();
+                }
+
+                if self.is_terminated.load(Ordering::Relaxed) {
+                    break;
                 }
             }
         }
 
         Ok(())
     }
+
+    fn update(
+        &self,
+        window: &mut Window,
+        message: Message,
+        context: &RefCell<Context>,
+        namespace: &RefCell<Namespace>,
+        connection_statuses: &RefCell<ConnectionStatuses>,
+        replay_status: Option<&SharedReplayStatus>,
+    ) -> bool {
+        match window_action(window, message) {
+            WindowAction::Continue => true,
+            WindowAction::CloseWindow => {
+                self.is_terminated.store(true, Ordering::Relaxed);
+                true
+            }
+            WindowAction::UpdateContents(ev) => {
+                let mut replay_status = replay_status.map(|status| status.borrow_mut());
+
+                update_contents(
+                    window,
+                    ev,
+                    &mut context.borrow_mut(),
+                    &mut namespace.borrow_mut(),
+                    &mut connection_statuses.borrow_mut(),
+                    replay_status.as_deref_mut(),
+                )
+            }
+        }
+    }
 }
@@ -1,24 +1,50 @@
 use std::collections::BTreeMap;
 
 use anyhow::Result;
-use crossbeam::channel::Receiver;
 
 use crate::{
     features::{
-        api_resources::message::{ApiMessage, ApiResponse},
+        access_review::message::{AccessReviewMessage, AccessReviewResponse},
+        api_resources::{
+            kube::{ListLine, ResourceTarget},
+            message::{ApiMessage, ApiResponse},
+        },
         component_id::{
-            CONFIG_RAW_DATA_WIDGET_ID, CONFIG_WIDGET_ID, CONTEXT_POPUP_ID, EVENT_WIDGET_ID,
-            LIST_POPUP_ID, LIST_WIDGET_ID, MULTIPLE_NAMESPACES_POPUP_ID,
-            NETWORK_DESCRIPTION_WIDGET_ID, NETWORK_WIDGET_ID, POD_LOG_WIDGET_ID, POD_WIDGET_ID,
+            ACCESS_REVIEW_RESULT_POPUP_ID, CONFIG_RAW_DATA_WIDGET_ID, CONFIG_WIDGET_ID,
+            CUSTOM_RESOURCE_EXPLAIN_POPUP_ID, CUSTOM_RESOURCE_KIND_POPUP_ID,
+            CUSTOM_RESOURCE_WIDGET_ID, EVENT_WIDGET_ID, LIST_POPUP_ID, LIST_WIDGET_ID,
+            MULTIPLE_NAMESPACES_POPUP_ID, NAMESPACE_DESCRIPTION_WIDGET_ID, NAMESPACE_WIDGET_ID,
+            NETWORK_DESCRIPTION_WIDGET_ID, NETWORK_GRAPH_POPUP_ID,
+            NETWORK_REACHABILITY_RESULT_POPUP_ID, NETWORK_WIDGET_ID, OWNER_TREE_POPUP_ID,
+            POD_LOG_WIDGET_ID, POD_WIDGET_ID, RESOURCE_SEARCH_RESULT_POPUP_ID,
             SINGLE_NAMESPACE_POPUP_ID, YAML_KIND_POPUP_ID, YAML_NAME_POPUP_ID,
             YAML_NOT_FOUND_POPUP_ID, YAML_POPUP_ID, YAML_WIDGET_ID,
         },
         config::message::ConfigMessage,
-        context::message::{ContextMessage, ContextResponse},
+        context::{
+            message::{ContextMessage, ContextResponse},
+            view::update_context_popup,
+        },
+        custom_resource::message::{CustomResourceMessage, CustomResourceResponse},
         get::message::{GetMessage, GetResponse},
+        impersonation::message::{ImpersonationMessage, ImpersonationResponse},
         namespace::message::{NamespaceMessage, NamespaceResponse},
-        network::message::{NetworkMessage, NetworkResponse},
-        pod::message::LogMessage,
+        network::{
+            message::{NetworkMessage, NetworkResponse},
+            view::graph_items,
+        },
+        owner_tree::{
+            kube::OwnerTreeLine,
+            message::{OwnerTreeMessage, OwnerTreeResponse},
+        },
+        pod::{
+            kube::{LogLine, LogSource},
+            message::LogMessage,
+        },
+        resource_search::{
+            kube::SearchResultLine,
+            message::{ResourceSearchMessage, ResourceSearchResponse},
+        },
         yaml::message::{YamlMessage, YamlResourceListItem, YamlResponse},
     },
     kube::{
@@ -33,7 +59,10 @@ use crate::{
         widget::{Item, LiteralItem, TableItem, WidgetTrait},
         Window, WindowAction,
     },
-    workers::kube::message::Kube,
+    workers::{
+        kube::{message::Kube, ConnectionStatuses},
+        replay::{ReplayMessage, ReplayResponse, ReplayStatus},
+    },
 };
 
 macro_rules! error_format {
@@ -66,8 +95,8 @@ fn exec_callback(cb: Callback, w: &mut Window) -> WindowAction {
     WindowAction::Continue
 }
 
-pub fn window_action(window: &mut Window, rx: &Receiver<Message>) -> WindowAction {
-    match rx.recv().expect("Failed to recv") {
+pub fn window_action(window: &mut Window, message: Message) -> WindowAction {
+    match message {
         Message::User(ev) => match window.on_event(ev) {
             EventResult::Nop => {}
 
@@ -95,7 +124,8 @@ pub fn window_action(window: &mut Window, rx: &Receiver<Message>) -> WindowActio
     WindowAction::Continue
 }
 
-fn update_widget_item_for_table(window: &mut Window, id: &str, table: Result<KubeTable>) {
+/// 表示内容が変わった場合にtrueを返す
+fn update_widget_item_for_table(window: &mut Window, id: &str, table: Result<KubeTable>) -> bool {
     let widget = window.find_widget_mut(id);
     let w = widget.as_mut_table();
 
@@ -166,9 +196,12 @@ fn update_widget_item_for_table(window: &mut Window, id: &str, table: Result<Kub
             w.update_header_and_rows(&["ERROR".to_string()], &rows);
         }
     }
+
+    w.is_content_changed()
 }
 
-fn update_widget_item_for_vec(window: &mut Window, id: &str, vec: Result<Vec<String>>) {
+/// 表示内容が変わった場合にtrueを返す
+fn update_widget_item_for_vec(window: &mut Window, id: &str, vec: Result<Vec<String>>) -> bool {
     let widget = window.find_widget_mut(id);
     match vec {
         Ok(i) => {
@@ -178,17 +211,64 @@ fn update_widget_item_for_vec(window: &mut Window, id: &str, vec: Result<Vec<Str
             widget.update_widget_item(Item::Array(error_lines!(e)));
         }
     }
+
+    widget.is_content_changed()
 }
 
+fn log_line_to_literal_item(line: LogLine) -> LiteralItem {
+    let LogLine {
+        source,
+        timestamp,
+        content,
+        ..
+    } = line;
+
+    let mut metadata = BTreeMap::new();
+
+    if let Some(LogSource {
+        namespace,
+        pod,
+        container,
+    }) = source
+    {
+        metadata.insert("namespace".to_string(), namespace);
+        metadata.insert("pod".to_string(), pod);
+        metadata.insert("container".to_string(), container);
+    }
+
+    if let Some(timestamp) = timestamp {
+        metadata.insert("timestamp".to_string(), timestamp.to_rfc3339());
+    }
+
+    LiteralItem {
+        metadata: (!metadata.is_empty()).then_some(metadata),
+        item: convert_tabs_to_spaces(content),
+    }
+}
+
+fn list_line_to_literal_item(line: ListLine) -> LiteralItem {
+    let ListLine { content, target } = line;
+
+    LiteralItem {
+        metadata: target.as_ref().map(ResourceTarget::to_metadata),
+        item: content,
+    }
+}
+
+/// 受信した内容をウィジェットに反映する
+///
+/// テーブルやテキストの内容が変わらなかった場合は再描画が不要なためfalseを返す
 pub fn update_contents(
     window: &mut Window,
     ev: Kube,
     context: &mut Context,
     namespace: &mut Namespace,
-) {
+    connection_statuses: &mut ConnectionStatuses,
+    replay_status: Option<&mut ReplayStatus>,
+) -> bool {
     match ev {
         Kube::Pod(pods_table) => {
-            update_widget_item_for_table(window, POD_WIDGET_ID, pods_table);
+            return update_widget_item_for_table(window, POD_WIDGET_ID, pods_table);
         }
 
         Kube::Log(LogMessage::Response(res)) => {
@@ -196,13 +276,7 @@ pub fn update_contents(
 
             match res {
                 Ok(i) => {
-                    let array = i
-                        .into_iter()
-                        .map(|i| LiteralItem {
-                            metadata: None,
-                            item: convert_tabs_to_spaces(i),
-                        })
-                        .collect();
+                    let array = i.into_iter().map(log_line_to_literal_item).collect();
 
                     widget.append_widget_item(Item::Array(array));
                 }
@@ -217,16 +291,16 @@ pub fn update_contents(
 
             match res {
                 Table(list) => {
-                    update_widget_item_for_table(window, CONFIG_WIDGET_ID, list);
+                    return update_widget_item_for_table(window, CONFIG_WIDGET_ID, list);
                 }
                 Data(data) => {
-                    update_widget_item_for_vec(window, CONFIG_RAW_DATA_WIDGET_ID, data);
+                    return update_widget_item_for_vec(window, CONFIG_RAW_DATA_WIDGET_ID, data);
                 }
             }
         }
 
         Kube::Event(ev) => {
-            update_widget_item_for_vec(window, EVENT_WIDGET_ID, ev);
+            return update_widget_item_for_vec(window, EVENT_WIDGET_ID, ev);
         }
 
         Kube::Namespace(NamespaceMessage::Response(res)) => match res {
@@ -257,11 +331,17 @@ pub fn update_contents(
             NamespaceResponse::Set(res) => {
                 namespace.update(res);
             }
+            NamespaceResponse::Overview(res) => {
+                return update_widget_item_for_table(window, NAMESPACE_WIDGET_ID, res);
+            }
+            NamespaceResponse::Description(res) => {
+                return update_widget_item_for_vec(window, NAMESPACE_DESCRIPTION_WIDGET_ID, res);
+            }
         },
 
         Kube::Context(ContextMessage::Response(res)) => match res {
             ContextResponse::Get(res) => {
-                update_widget_item_for_vec(window, CONTEXT_POPUP_ID, Ok(res));
+                update_context_popup(window, &res);
             }
         },
 
@@ -283,6 +363,16 @@ pub fn update_contents(
                 .select_all();
         }
 
+        Kube::Connection { context, status } => {
+            connection_statuses.update(context, status);
+        }
+
+        Kube::Impersonation(ImpersonationMessage::Response(ImpersonationResponse(
+            impersonation,
+        ))) => {
+            connection_statuses.update_impersonation(impersonation);
+        }
+
         Kube::RestoreAPIs(list) => {
             let w = window
                 .find_widget_mut(LIST_POPUP_ID)
@@ -340,7 +430,19 @@ pub fn update_contents(
                     }
                 }
                 Poll(list) => {
-                    update_widget_item_for_vec(window, LIST_WIDGET_ID, list);
+                    let widget = window.find_widget_mut(LIST_WIDGET_ID);
+                    match list {
+                        Ok(lines) => {
+                            let items = lines.into_iter().map(list_line_to_literal_item).collect();
+
+                            widget.update_widget_item(Item::Array(items));
+                        }
+                        Err(e) => {
+                            widget.update_widget_item(Item::Array(error_lines!(e)));
+                        }
+                    }
+
+                    return widget.is_content_changed();
                 }
             }
         }
@@ -425,7 +527,7 @@ pub fn update_contents(
                     }
                 },
                 Yaml(res) => {
-                    update_widget_item_for_vec(window, YAML_WIDGET_ID, res);
+                    return update_widget_item_for_vec(window, YAML_WIDGET_ID, res);
                 }
             }
         }
@@ -434,20 +536,134 @@ pub fn update_contents(
             let widget = window.find_widget_mut(YAML_POPUP_ID).widget_config_mut();
             *(widget.append_title_mut()) = Some(format!(" : {}/{}", kind, name).into());
 
-            update_widget_item_for_vec(window, YAML_POPUP_ID, yaml);
+            return update_widget_item_for_vec(window, YAML_POPUP_ID, yaml);
         }
 
         Kube::Network(NetworkMessage::Response(ev)) => {
             use NetworkResponse::*;
 
             match ev {
-                List(res) => update_widget_item_for_table(window, NETWORK_WIDGET_ID, res),
+                List(res) => return update_widget_item_for_table(window, NETWORK_WIDGET_ID, res),
                 Yaml(res) => {
-                    update_widget_item_for_vec(window, NETWORK_DESCRIPTION_WIDGET_ID, res);
+                    return update_widget_item_for_vec(window, NETWORK_DESCRIPTION_WIDGET_ID, res);
+                }
+                Reachability(res) => {
+                    return update_widget_item_for_vec(
+                        window,
+                        NETWORK_REACHABILITY_RESULT_POPUP_ID,
+                        res,
+                    );
+                }
+                Graph(graph) => {
+                    window
+                        .find_widget_mut(NETWORK_GRAPH_POPUP_ID)
+                        .update_widget_item(Item::Array(graph_items(&graph)));
                 }
             }
         }
 
+        Kube::AccessReview(AccessReviewMessage::Response(AccessReviewResponse(res))) => {
+            return update_widget_item_for_vec(window, ACCESS_REVIEW_RESULT_POPUP_ID, res);
+        }
+
+        Kube::OwnerTree(OwnerTreeMessage::Response(OwnerTreeResponse(res))) => {
+            let widget = window.find_widget_mut(OWNER_TREE_POPUP_ID);
+            match res {
+                Ok(lines) => {
+                    let items = lines
+                        .into_iter()
+                        .map(|OwnerTreeLine { content, target }| {
+                            LiteralItem::new(content, Some(target.to_metadata()))
+                        })
+                        .collect();
+
+                    widget.update_widget_item(Item::Array(items));
+                }
+                Err(e) => {
+                    widget.update_widget_item(Item::Array(error_lines!(e)));
+                }
+            }
+        }
+
+        Kube::ResourceSearch(ResourceSearchMessage::Response(ResourceSearchResponse(res))) => {
+            let widget = window.find_widget_mut(RESOURCE_SEARCH_RESULT_POPUP_ID);
+            match res {
+                Ok(lines) => {
+                    let items = lines
+                        .into_iter()
+                        .map(|SearchResultLine { content, target }| {
+                            LiteralItem::new(content, Some(target.to_metadata()))
+                        })
+                        .collect();
+
+                    widget.update_widget_item(Item::Array(items));
+                }
+                Err(e) => {
+                    widget.update_widget_item(Item::Array(error_lines!(e)));
+                }
+            }
+        }
+
+        Kube::CustomResource(CustomResourceMessage::Response(ev)) => {
+            use CustomResourceResponse::*;
+
+            match ev {
+                Definitions(res) => {
+                    let widget = window.find_widget_mut(CUSTOM_RESOURCE_KIND_POPUP_ID);
+                    match res {
+                        Ok(vec) => {
+                            let items = vec
+                                .into_iter()
+                                .map(|key| {
+                                    let Ok(json) = serde_json::to_string(&key) else {
+                                        unreachable!()
+                                    };
+
+                                    let metadata = BTreeMap::from([("key".into(), json)]);
+
+                                    let item = if key.is_preferred_version() {
+                                        key.to_string()
+                                    } else {
+                                        format!("\x1b[90m{}\x1b[39m", key)
+                                    };
+
+                                    LiteralItem::new(item, Some(metadata))
+                                })
+                                .collect();
+
+                            widget.update_widget_item(Item::Array(items));
+                        }
+                        Err(e) => {
+                            widget.update_widget_item(Item::Array(error_lines!(e)));
+                        }
+                    }
+                }
+                Table(res) => {
+                    return update_widget_item_for_table(window, CUSTOM_RESOURCE_WIDGET_ID, res);
+                }
+                Explain(res) => {
+                    return update_widget_item_for_vec(
+                        window,
+                        CUSTOM_RESOURCE_EXPLAIN_POPUP_ID,
+                        res,
+                    );
+                }
+            }
+        }
+
+        Kube::Replay(ReplayMessage::Response(res)) => match res {
+            ReplayResponse::Status(status) => {
+                if let Some(replay_status) = replay_status {
+                    *replay_status = status;
+                }
+            }
+            ReplayResponse::Rewind => {
+                window.widget_clear(POD_LOG_WIDGET_ID);
+            }
+        },
+
         _ => unreachable!(),
     }
+
+    true
 }
@@ -0,0 +1,107 @@
+use std::time::{Duration, Instant};
+
+/// 描画の頻度を制限する
+///
+/// 前回の描画以降に内容が更新された場合のみ描画し、描画の間隔は`interval`以上空ける
+#[derive(Debug)]
+pub struct FrameLimiter {
+    interval: Duration,
+    last_drawn: Option<Instant>,
+    dirty: bool,
+}
+
+impl FrameLimiter {
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            last_drawn: None,
+            dirty: true,
+        }
+    }
+
+    pub fn mark_dirty(&mut self) {
+        self.dirty = true;
+    }
+
+    pub fn should_draw(&self, now: Instant) -> bool {
+        self.timeout(now).is_some_and(|timeout| timeout.is_zero())
+    }
+
+    pub fn drawn(&mut self, now: Instant) {
+        self.last_drawn = Some(now);
+        self.dirty = false;
+    }
+
+    /// 次の描画までの待ち時間
+    ///
+    /// 描画する内容がない場合は`None`を返す
+    pub fn timeout(&self, now: Instant) -> Option<Duration> {
+        if !self.dirty {
+            return None;
+        }
+
+        let timeout = self
+            .last_drawn
+            .map(|last_drawn| (last_drawn + self.interval).saturating_duration_since(now))
+            .unwrap_or_default();
+
+        Some(timeout)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    const INTERVAL: Duration = Duration::from_millis(100);
+
+    #[test]
+    fn 初回は即座に描画する() {
+        let limiter = FrameLimiter::new(INTERVAL);
+
+        assert!(limiter.should_draw(Instant::now()));
+    }
+
+    #[test]
+    fn 更新がなければ描画しない() {
+        let now = Instant::now();
+
+        let mut limiter = FrameLimiter::new(INTERVAL);
+        limiter.drawn(now);
+
+        let later = now + INTERVAL * 2;
+
+        assert!(!limiter.should_draw(later));
+        assert_eq!(limiter.timeout(later), None);
+    }
+
+    #[test]
+    fn 間隔が経過するまでは更新があっても描画しない() {
+        let now = Instant::now();
+
+        let mut limiter = FrameLimiter::new(INTERVAL);
+        limiter.drawn(now);
+        limiter.mark_dirty();
+
+        let later = now + Duration::from_millis(30);
+
+        assert!(!limiter.should_draw(later));
+        assert_eq!(limiter.timeout(later), Some(Duration::from_millis(70)));
+    }
+
+    #[test]
+    fn 間隔が経過した後に更新があれば描画する() {
+        let now = Instant::now();
+
+        let mut limiter = FrameLimiter::new(INTERVAL);
+        limiter.drawn(now);
+        limiter.mark_dirty();
+
+        let later = now + INTERVAL;
+
+        assert!(limiter.should_draw(later));
+        assert_eq!(limiter.timeout(later), Some(Duration::ZERO));
+    }
+}
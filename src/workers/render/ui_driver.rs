@@ -0,0 +1,334 @@
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crossbeam::channel::{Receiver, Sender};
+use ratatui::{backend::TestBackend, layout::Direction, Terminal};
+use tokio::runtime::Runtime;
+
+use crate::{
+    features::pod::view::LogViewConfig,
+    history::History,
+    kube::{
+        context::{Context, Namespace},
+        fake_api_server::FakeApiServer,
+    },
+    message::{Message, UserEvent},
+    ui::{Window, WindowAction},
+    workers::kube::{KubeController, KubeState, SharedConnectionStatuses},
+};
+
+use super::{
+    action::{update_contents, window_action},
+    window::WindowInit,
+};
+
+/// 画面の大きさ
+const WIDTH: u16 = 160;
+const HEIGHT: u16 = 40;
+
+/// `wait_until`で画面を確認する間隔
+const WAIT_INTERVAL: Duration = Duration::from_millis(20);
+
+/// `wait_until`のタイムアウト
+const WAIT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 偽のAPIサーバーに接続したWindowを操作するテスト用のドライバー
+///
+/// レンダースレッドと同じ手順でメッセージを処理し、`TestBackend`に描画した内容を文字列として返す
+pub struct UiDriver {
+    window: Window<'static>,
+    terminal: Terminal<TestBackend>,
+    context: Rc<RefCell<Context>>,
+    namespace: Rc<RefCell<Namespace>>,
+    connection_statuses: SharedConnectionStatuses,
+    rx: Receiver<Message>,
+    is_terminated: Arc<AtomicBool>,
+    is_closed: bool,
+    runtime: Option<Runtime>,
+}
+
+impl UiDriver {
+    /// フィクスチャを読み込んだ偽のAPIサーバーに`default` namespaceで接続する
+    pub fn from_fixture(name: &str) -> Self {
+        Self::new(FakeApiServer::from_fixture(name), &["default"])
+    }
+
+    pub fn new(server: FakeApiServer, namespaces: &[&str]) -> Self {
+        let (tx_render, rx_render): (Sender<Message>, Receiver<Message>) =
+            crossbeam::channel::unbounded();
+        let (tx_kube, rx_kube): (Sender<Message>, Receiver<Message>) =
+            crossbeam::channel::unbounded();
+
+        let is_terminated = Arc::new(AtomicBool::new(false));
+
+        let runtime = Runtime::new().expect("Failed to create runtime");
+
+        // kube::Clientの生成にはランタイムが必要
+        let _guard = runtime.enter();
+
+        let controller = KubeController::with_client(
+            tx_render,
+            rx_kube,
+            is_terminated.clone(),
+            "fake",
+            KubeState::new(
+                server.client(),
+                namespaces.iter().map(ToString::to_string).collect(),
+                Vec::new(),
+            ),
+        );
+
+        runtime.spawn(controller.run());
+
+        let context = Rc::new(RefCell::new(Context::new()));
+        let namespace = Rc::new(RefCell::new(Namespace::new()));
+        let connection_statuses = SharedConnectionStatuses::default();
+
+        let window = WindowInit::new(
+            Direction::Vertical,
+            tx_kube,
+            context.clone(),
+            namespace.clone(),
+            LogViewConfig::default(),
+            Rc::new(RefCell::new(History::default())),
+            None,
+        )
+        .connection_statuses(connection_statuses.clone())
+        .build();
+
+        let terminal =
+            Terminal::new(TestBackend::new(WIDTH, HEIGHT)).expect("Failed to create terminal");
+
+        Self {
+            window,
+            terminal,
+            context,
+            namespace,
+            connection_statuses,
+            rx: rx_render,
+            is_terminated,
+            is_closed: false,
+            runtime: Some(runtime),
+        }
+    }
+
+    /// キー入力などのイベントを送る
+    pub fn send(&mut self, ev: impl Into<UserEvent>) -> &mut Self {
+        self.update(Message::User(ev.into()));
+        self
+    }
+
+    /// 文字列を1文字ずつ入力する
+    pub fn input(&mut self, s: &str) -> &mut Self {
+        for c in s.chars() {
+            self.send(c);
+        }
+        self
+    }
+
+    /// 受信済みのメッセージをすべて処理する
+    pub fn pump(&mut self) -> &mut Self {
+        while let Ok(message) = self.rx.try_recv() {
+            self.update(message);
+        }
+        self
+    }
+
+    /// 描画した画面を行ごとに改行で区切った文字列で返す
+    pub fn screen(&mut self) -> String {
+        self.terminal
+            .draw(|f| self.window.render(f))
+            .expect("Failed to draw");
+
+        let buffer = self.terminal.backend().buffer();
+
+        (0..buffer.area.height)
+            .map(|y| {
+                let line: String = (0..buffer.area.width)
+                    .map(|x| buffer.get(x, y).symbol())
+                    .collect();
+
+                line.trim_end().to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// 画面が条件を満たすまでメッセージの処理と描画を繰り返す
+    ///
+    /// タイムアウトした場合は最後に描画した画面を含めてpanicする
+    pub fn wait_until(&mut self, predicate: impl Fn(&str) -> bool) -> String {
+        let deadline = Instant::now() + WAIT_TIMEOUT;
+
+        loop {
+            let screen = self.pump().screen();
+
+            if predicate(&screen) {
+                return screen;
+            }
+
+            if deadline < Instant::now() {
+                panic!("Timed out waiting for the screen:\n{}", screen);
+            }
+
+            thread::sleep(WAIT_INTERVAL);
+        }
+    }
+
+    /// 画面に文字列が表示されるまで待つ
+    pub fn wait_for(&mut self, text: &str) -> String {
+        self.wait_until(|screen| screen.contains(text))
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.is_closed
+    }
+
+    pub fn context(&self) -> String {
+        self.context.borrow().to_string()
+    }
+
+    pub fn namespaces(&self) -> Vec<String> {
+        self.namespace.borrow().to_vec()
+    }
+
+    fn update(&mut self, message: Message) {
+        match window_action(&mut self.window, message) {
+            WindowAction::Continue => {}
+            WindowAction::CloseWindow => {
+                self.is_closed = true;
+            }
+            WindowAction::UpdateContents(ev) => {
+                update_contents(
+                    &mut self.window,
+                    ev,
+                    &mut self.context.borrow_mut(),
+                    &mut self.namespace.borrow_mut(),
+                    &mut self.connection_statuses.borrow_mut(),
+                    None,
+                );
+            }
+        }
+    }
+}
+
+impl Drop for UiDriver {
+    fn drop(&mut self) {
+        self.is_terminated.store(true, Ordering::Relaxed);
+
+        if let Some(runtime) = self.runtime.take() {
+            runtime.shutdown_background();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::crossterm::event::KeyCode;
+
+    use super::*;
+
+    #[test]
+    fn podの一覧を表示する() {
+        let mut driver = UiDriver::from_fixture("default.yaml");
+
+        let screen = driver.wait_for("CrashLoopBackOff");
+
+        assert!(screen.contains("app"), "{}", screen);
+        assert_eq!(driver.context(), "fake");
+        assert_eq!(driver.namespaces(), vec!["default"]);
+    }
+
+    #[test]
+    fn podを選択するとログを表示しクエリで絞り込める() {
+        let mut driver = UiDriver::from_fixture("default.yaml");
+
+        driver.wait_for("CrashLoopBackOff");
+
+        driver.send(KeyCode::Enter);
+
+        let screen = driver.wait_for("POST /login 200");
+
+        assert!(screen.contains("GET /favicon.ico 404"), "{}", screen);
+        assert!(screen.contains("pod/app"), "{}", screen);
+
+        // ログクエリの入力欄に移動し、`pod/app`にログの絞り込みを追加する
+        driver
+            .send(KeyCode::Tab)
+            .send(KeyCode::End)
+            .input(" log:404")
+            .send(KeyCode::Enter);
+
+        let screen = driver.wait_until(|screen| {
+            screen.contains("GET /favicon.ico 404") && !screen.contains("POST /login 200")
+        });
+
+        assert!(!screen.contains("GET /index.html 200"), "{}", screen);
+    }
+
+    #[test]
+    fn タブを切り替えると対応するリソースを表示する() {
+        let mut driver = UiDriver::from_fixture("default.yaml");
+
+        driver.send('2');
+
+        driver.wait_for("app-config");
+
+        driver.send('4');
+
+        driver.wait_for("Back-off restarting failed container");
+    }
+
+    #[test]
+    fn クラスターに接続できなくても起動し復旧すると表示する() {
+        let server = FakeApiServer::from_fixture("default.yaml");
+
+        server.set_available(false);
+
+        let mut driver = UiDriver::new(server.clone(), &["default"]);
+
+        let screen = driver.wait_for("[disconnected (retry #1)");
+
+        assert!(screen.contains("ctx: fake"), "{}", screen);
+
+        server.set_available(true);
+
+        driver.wait_until(|screen| {
+            screen.contains("CrashLoopBackOff") && screen.contains("[connected]")
+        });
+    }
+
+    #[test]
+    fn 接続が切れると再接続して表示を更新する() {
+        let server = FakeApiServer::from_fixture("default.yaml");
+
+        let mut driver = UiDriver::new(server.clone(), &["default"]);
+
+        driver.wait_for("[connected]");
+
+        server.set_available(false);
+
+        driver.wait_for("[disconnected");
+
+        server.set_available(true);
+
+        driver.wait_for("[connected]");
+    }
+
+    #[test]
+    fn qで終了する() {
+        let mut driver = UiDriver::from_fixture("default.yaml");
+
+        driver.send('q');
+
+        assert!(driver.is_closed());
+    }
+}
@@ -11,13 +11,15 @@ use k8s_openapi::{
 use ratatui::{
     crossterm::event::{KeyCode, KeyEvent, KeyModifiers},
     layout::Direction,
-    text::Line,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
     widgets::Paragraph,
 };
 
 use crate::{
     clipboard::Clipboard,
     features::{
+        access_review::view::{open_access_review_popup, AccessReviewPopup},
         api_resources::view::ListTab,
         component_id::{
             CONFIG_WIDGET_ID, CONTEXT_POPUP_ID, HELP_POPUP_ID, MULTIPLE_NAMESPACES_POPUP_ID,
@@ -25,25 +27,39 @@ use crate::{
         },
         config::view::ConfigTab,
         context::{message::ContextRequest, view::ContextPopup},
+        custom_resource::view::CustomResourceTab,
         event::view::EventTab,
         get::{
             message::{GetRequest, GetYamlKind},
             view::YamlPopup,
         },
         help::HelpPopup,
+        impersonation::{
+            kube::Impersonation,
+            view::{open_impersonation_popup, ImpersonationPopup},
+        },
+        label_selector::view::{
+            open_label_selector_popup, LabelSelectorPopup, SharedLabelSelectorTarget,
+        },
         namespace::{
             message::NamespaceRequest,
-            view::{MultipleNamespacesPopup, SingleNamespacePopup},
+            view::{MultipleNamespacesPopup, NamespaceTab, SingleNamespacePopup},
         },
         network::{
-            message::{GatewayVersion, HTTPRouteVersion},
-            view::NetworkTab,
+            message::{GRPCRouteVersion, GatewayVersion, HTTPRouteVersion},
+            view::{NetworkTab, SharedDescriptionTarget},
         },
-        pod::view::PodTab,
-        yaml::view::YamlTab,
+        owner_tree::view::OwnerTreePopup,
+        pod::view::{LogViewConfig, PodTab},
+        resource_search::view::{open_resource_search_popup, ResourceSearchPopup},
+        yaml::view::{SharedYamlTarget, YamlTab},
     },
+    history::{History, HistoryHandle},
     kube::{
-        apis::networking::gateway::v1::{Gateway, HTTPRoute},
+        apis::networking::gateway::{
+            v1::{GRPCRoute, Gateway, HTTPRoute},
+            v1alpha2::{TCPRoute, TLSRoute},
+        },
         context::{Context, Namespace},
     },
     message::{Message, UserEvent},
@@ -53,13 +69,24 @@ use crate::{
         widget::{SelectedItem, WidgetTrait},
         Header, Tab, Window, WindowAction,
     },
+    workers::{
+        kube::{ConnectionStatus, SharedConnectionStatuses},
+        replay::{ReplayRequest, SharedReplayStatus},
+    },
 };
 
+/// 再生時に`<`、`>`で移動する秒数
+const REPLAY_SEEK_SECS: i64 = 10;
+
 pub struct WindowInit {
     split_mode: Direction,
     tx: Sender<Message>,
     context: Rc<RefCell<Context>>,
     namespaces: Rc<RefCell<Namespace>>,
+    log_view_config: LogViewConfig,
+    history: HistoryHandle,
+    replay_status: Option<SharedReplayStatus>,
+    connection_statuses: SharedConnectionStatuses,
 }
 
 impl WindowInit {
@@ -68,17 +95,34 @@ impl WindowInit {
         tx: Sender<Message>,
         context: Rc<RefCell<Context>>,
         namespaces: Rc<RefCell<Namespace>>,
+        log_view_config: LogViewConfig,
+        history: Rc<RefCell<History>>,
+        replay_status: Option<SharedReplayStatus>,
     ) -> Self {
+        let history = HistoryHandle::new(history, context.clone());
+
         Self {
             split_mode,
             tx,
             context,
             namespaces,
+            log_view_config,
+            history,
+            replay_status,
+            connection_statuses: Default::default(),
         }
     }
 
+    /// ヘッダーに表示するコンテキストごとの接続状態
+    pub fn connection_statuses(mut self, connection_statuses: SharedConnectionStatuses) -> Self {
+        self.connection_statuses = connection_statuses;
+        self
+    }
+
     pub fn build(self) -> Window<'static> {
-        let (tabs, popups) = self.tabs_popups();
+        let label_selector_target = SharedLabelSelectorTarget::default();
+
+        let (tabs, popups) = self.tabs_popups(&label_selector_target);
 
         let builder = Window::builder().tabs(tabs).popup(popups);
 
@@ -126,19 +170,85 @@ impl WindowInit {
 
         let open_yaml = open_yaml(self.tx.clone());
 
+        let builder = builder.action(
+            UserEvent::Key(KeyEvent::new(KeyCode::Char('S'), KeyModifiers::SHIFT)),
+            open_resource_search_popup,
+        );
+
+        let builder = builder.action(
+            UserEvent::Key(KeyEvent::new(KeyCode::Char('I'), KeyModifiers::SHIFT)),
+            open_impersonation_popup,
+        );
+
+        let builder = builder.action(
+            UserEvent::Key(KeyEvent::new(KeyCode::Char('A'), KeyModifiers::SHIFT)),
+            open_access_review_popup,
+        );
+
         let builder = builder.action('h', open_help).action('?', open_help);
         let builder = builder.action('y', open_yaml);
+        let builder = builder.action('l', open_label_selector_popup(label_selector_target));
 
         let builder = builder.action('q', fn_close).action(KeyCode::Esc, fn_close);
 
+        let builder = if self.replay_status.is_some() {
+            let tx = self.tx.clone();
+            let builder = builder.action(
+                UserEvent::Key(KeyEvent::new(KeyCode::Char('P'), KeyModifiers::SHIFT)),
+                move |_: &mut Window| {
+                    tx.send(ReplayRequest::TogglePause.into())
+                        .expect("Failed to send ReplayRequest::TogglePause");
+                    EventResult::Nop
+                },
+            );
+
+            let tx = self.tx.clone();
+            let builder = builder.action('<', move |_: &mut Window| {
+                tx.send(ReplayRequest::Seek(-REPLAY_SEEK_SECS).into())
+                    .expect("Failed to send ReplayRequest::Seek");
+                EventResult::Nop
+            });
+
+            let tx = self.tx.clone();
+            builder.action('>', move |_: &mut Window| {
+                tx.send(ReplayRequest::Seek(REPLAY_SEEK_SECS).into())
+                    .expect("Failed to send ReplayRequest::Seek");
+                EventResult::Nop
+            })
+        } else {
+            builder
+        };
+
         let context = self.context.clone();
         let namespaces = self.namespaces.clone();
+        let replay_status = self.replay_status.clone();
+        let connection_statuses = self.connection_statuses.clone();
 
         let header = Header::new_callback(2, move || {
             let context = context.borrow();
             let namespaces = namespaces.borrow();
+
+            let mut context_line = match &replay_status {
+                Some(status) => {
+                    vec![format!(" ctx: {}  [replay {}]", context, status.borrow()).into()]
+                }
+                None => vec![format!(" ctx: {}", context).into()],
+            };
+
+            let connection_statuses = connection_statuses.borrow();
+
+            if let Some(status) = connection_statuses.get(&context.to_string()) {
+                context_line.push("  ".into());
+                context_line.push(connection_status_span(status));
+            }
+
+            if let Some(impersonation) = connection_statuses.impersonation() {
+                context_line.push("  ".into());
+                context_line.push(impersonation_span(impersonation));
+            }
+
             Paragraph::new(vec![
-                Line::from(format!(" ctx: {}", context)),
+                Line::from(context_line),
                 Line::from(format!(" ns: {}", namespaces)),
             ])
         });
@@ -148,25 +258,51 @@ impl WindowInit {
         builder.build()
     }
 
-    fn tabs_popups(&self) -> (Vec<Tab<'static>>, Vec<Popup<'static>>) {
+    fn tabs_popups(
+        &self,
+        label_selector_target: &SharedLabelSelectorTarget,
+    ) -> (Vec<Tab<'static>>, Vec<Popup<'static>>) {
         let clipboard = Some(Rc::new(RefCell::new(Clipboard::new())));
 
         let PodTab {
             tab: pod_tab,
             log_query_help_popup,
+            log_bookmark_popup,
+            log_save_query_popup,
+            log_saved_queries_popup,
         } = PodTab::new(
             "Pod",
             &self.tx,
             &clipboard,
             self.split_mode,
             self.namespaces.clone(),
+            &self.log_view_config,
+            &self.history,
         );
 
-        let ConfigTab { tab: config_tab } =
-            ConfigTab::new("Config", &self.tx, &clipboard, self.split_mode);
+        let ConfigTab { tab: config_tab } = ConfigTab::new(
+            "Config",
+            &self.tx,
+            &clipboard,
+            self.split_mode,
+            &self.history,
+        );
 
-        let NetworkTab { tab: network_tab } =
-            NetworkTab::new("Network", &self.tx, &clipboard, self.split_mode);
+        let description_target = SharedDescriptionTarget::default();
+
+        let NetworkTab {
+            tab: network_tab,
+            graph_popup: network_graph_popup,
+            reachability_popup: network_reachability_popup,
+            reachability_result_popup: network_reachability_result_popup,
+        } = NetworkTab::new(
+            "Network",
+            &self.tx,
+            &clipboard,
+            self.split_mode,
+            &self.history,
+            &description_target,
+        );
 
         let EventTab { tab: event_tab } = EventTab::new("Event", &clipboard);
 
@@ -175,12 +311,28 @@ impl WindowInit {
             popup: list_popup,
         } = ListTab::new("List", &self.tx, &clipboard);
 
+        let yaml_target = SharedYamlTarget::default();
+
         let YamlTab {
             tab: yaml_tab,
             kind_popup: yaml_kind_popup,
             name_popup: yaml_name_popup,
             not_found_popup: yaml_not_found_popup,
-        } = YamlTab::new("Yaml", &self.tx, &clipboard);
+        } = YamlTab::new("Yaml", &self.tx, &clipboard, &yaml_target);
+
+        let CustomResourceTab {
+            tab: custom_resource_tab,
+            kind_popup: custom_resource_kind_popup,
+            explain_popup: custom_resource_explain_popup,
+        } = CustomResourceTab::new("CRD", &self.tx, &clipboard, &self.history);
+
+        let NamespaceTab { tab: namespace_tab } = NamespaceTab::new(
+            "Namespace",
+            &self.tx,
+            &clipboard,
+            self.split_mode,
+            &self.history,
+        );
 
         let ContextPopup {
             popup: context_popup,
@@ -194,6 +346,28 @@ impl WindowInit {
             popup: multiple_namespaces_popup,
         } = MultipleNamespacesPopup::new(&self.tx);
 
+        let OwnerTreePopup {
+            popup: owner_tree_popup,
+        } = OwnerTreePopup::new(&self.tx, &yaml_target);
+
+        let ResourceSearchPopup {
+            popup: resource_search_popup,
+            result_popup: resource_search_result_popup,
+        } = ResourceSearchPopup::new(&self.tx, &yaml_target, &description_target);
+
+        let LabelSelectorPopup {
+            popup: label_selector_popup,
+        } = LabelSelectorPopup::new(&self.tx, label_selector_target);
+
+        let ImpersonationPopup {
+            popup: impersonation_popup,
+        } = ImpersonationPopup::new(&self.tx);
+
+        let AccessReviewPopup {
+            popup: access_review_popup,
+            result_popup: access_review_result_popup,
+        } = AccessReviewPopup::new(&self.tx, &clipboard, &self.namespaces);
+
         let HelpPopup { popup: help_popup } = HelpPopup::new();
 
         let YamlPopup { popup: yaml_popup } = YamlPopup::new(&clipboard);
@@ -206,6 +380,8 @@ impl WindowInit {
             event_tab,
             list_tab,
             yaml_tab,
+            custom_resource_tab,
+            namespace_tab,
         ];
 
         let popups = vec![
@@ -216,8 +392,23 @@ impl WindowInit {
             Popup::new(yaml_kind_popup),
             Popup::new(yaml_name_popup),
             Popup::new(yaml_not_found_popup),
+            Popup::new(custom_resource_kind_popup),
+            Popup::new(custom_resource_explain_popup),
+            Popup::new(owner_tree_popup),
+            Popup::new(resource_search_popup).fixed_height(5),
+            Popup::new(resource_search_result_popup),
+            Popup::new(label_selector_popup),
+            Popup::new(impersonation_popup).fixed_height(5),
+            Popup::new(access_review_popup).fixed_height(5),
+            Popup::new(access_review_result_popup),
             Popup::new(help_popup),
             Popup::new(log_query_help_popup),
+            Popup::new(log_bookmark_popup),
+            Popup::new(log_save_query_popup).fixed_height(5),
+            Popup::new(log_saved_queries_popup),
+            Popup::new(network_graph_popup),
+            Popup::new(network_reachability_popup).fixed_height(5),
+            Popup::new(network_reachability_result_popup),
             Popup::new(yaml_popup),
         ];
 
@@ -271,6 +462,13 @@ fn open_yaml(tx: Sender<Message>) -> impl CallbackFn {
                 Some("v1beta1") => GetYamlKind::HTTPRoute(HTTPRouteVersion::V1Beta1),
                 _ => unreachable!(),
             },
+            Some(GRPCRoute::KIND) => match version.as_ref().map(|v| v.as_str()) {
+                Some("v1") => GetYamlKind::GRPCRoute(GRPCRouteVersion::V1),
+                Some("v1alpha2") => GetYamlKind::GRPCRoute(GRPCRouteVersion::V1Alpha2),
+                _ => unreachable!(),
+            },
+            Some(TLSRoute::KIND) => GetYamlKind::TLSRoute,
+            Some(TCPRoute::KIND) => GetYamlKind::TCPRoute,
             _ => {
                 unreachable!();
             }
@@ -292,3 +490,33 @@ fn open_yaml(tx: Sender<Message>) -> impl CallbackFn {
         EventResult::Nop
     }
 }
+
+/// ヘッダーに表示する接続状態
+fn connection_status_span(status: &ConnectionStatus) -> Span<'static> {
+    match status {
+        ConnectionStatus::Connecting => Span::styled("[connecting]", Color::Yellow),
+        ConnectionStatus::Connected => Span::styled("[connected]", Color::Green),
+        ConnectionStatus::Disconnected { attempts, error } => {
+            // ヘッダーは1行のため、エラーの1行目のみ表示する
+            let error = error.lines().next().unwrap_or_default();
+
+            Span::styled(
+                format!("[disconnected (retry #{}): {}]", attempts, error),
+                Color::Red,
+            )
+        }
+    }
+}
+
+/// ヘッダーに表示する偽装ユーザー
+///
+/// 偽装したまま操作し続けないように、接続状態とは別に目立つ色で表示する
+fn impersonation_span(impersonation: &Impersonation) -> Span<'static> {
+    Span::styled(
+        format!("[as: {}]", impersonation),
+        Style::default()
+            .fg(Color::Black)
+            .bg(Color::Magenta)
+            .add_modifier(Modifier::BOLD),
+    )
+}
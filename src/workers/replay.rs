@@ -0,0 +1,8 @@
+mod message;
+mod record;
+pub mod serde_result;
+mod worker;
+
+pub use message::*;
+pub use record::*;
+pub use worker::*;
@@ -0,0 +1,97 @@
+use std::{cell::RefCell, fmt::Display, rc::Rc};
+
+use crate::{message::Message, workers::kube::message::Kube};
+
+/// 再生位置の操作
+#[derive(Debug)]
+pub enum ReplayRequest {
+    /// 一時停止と再開を切り替える
+    TogglePause,
+    /// 指定した秒数だけ再生位置を移動する（負の値で巻き戻す）
+    Seek(i64),
+}
+
+#[derive(Debug)]
+pub enum ReplayResponse {
+    Status(ReplayStatus),
+    /// 巻き戻したため、追記して表示している内容（ログ）を消去する
+    Rewind,
+}
+
+#[derive(Debug)]
+pub enum ReplayMessage {
+    Request(ReplayRequest),
+    Response(ReplayResponse),
+}
+
+/// 再生状態（秒単位）
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReplayStatus {
+    pub position: u64,
+    pub duration: u64,
+    pub paused: bool,
+}
+
+pub type SharedReplayStatus = Rc<RefCell<ReplayStatus>>;
+
+impl Display for ReplayStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} / {}",
+            format_secs(self.position),
+            format_secs(self.duration)
+        )?;
+
+        if self.paused {
+            write!(f, " (paused)")?;
+        }
+
+        Ok(())
+    }
+}
+
+fn format_secs(secs: u64) -> String {
+    format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+}
+
+impl From<ReplayRequest> for Message {
+    fn from(req: ReplayRequest) -> Self {
+        Message::Kube(Kube::Replay(ReplayMessage::Request(req)))
+    }
+}
+
+impl From<ReplayResponse> for Message {
+    fn from(res: ReplayResponse) -> Self {
+        Message::Kube(Kube::Replay(ReplayMessage::Response(res)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn 再生位置と長さを時分秒で表示する() {
+        let status = ReplayStatus {
+            position: 65,
+            duration: 3725,
+            paused: false,
+        };
+
+        assert_eq!(status.to_string(), "00:01:05 / 01:02:05");
+    }
+
+    #[test]
+    fn 一時停止中はpausedを付けて表示する() {
+        let status = ReplayStatus {
+            position: 0,
+            duration: 10,
+            paused: true,
+        };
+
+        assert_eq!(status.to_string(), "00:00:00 / 00:00:10 (paused)");
+    }
+}
@@ -0,0 +1,214 @@
+use std::{
+    fs::{self, File},
+    io::{BufRead as _, BufReader, BufWriter, Write as _},
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context as _, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::workers::kube::message::Kube;
+
+/// 記録ファイルの1行
+///
+/// 記録を開始してからの経過時間（ミリ秒）とメッセージをJSON Linesで保存する
+#[derive(Serialize)]
+struct RecordLine<'a> {
+    elapsed_ms: u64,
+    message: &'a Kube,
+}
+
+#[derive(Deserialize)]
+struct RecordLineOwned {
+    elapsed_ms: u64,
+    message: serde_json::Value,
+}
+
+/// 読み込んだ記録
+///
+/// 巻き戻した場合に同じメッセージを再度送信するため、メッセージはJSONのまま保持し送信時に変換する
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordEntry {
+    pub elapsed: Duration,
+    pub(super) message: serde_json::Value,
+}
+
+impl RecordEntry {
+    pub fn message(&self) -> Result<Kube> {
+        Ok(serde_json::from_value(self.message.clone())?)
+    }
+}
+
+/// レンダースレッドが受信したメッセージをファイルに記録する
+pub struct Recorder {
+    writer: BufWriter<File>,
+    started_at: Instant,
+}
+
+impl Recorder {
+    /// 記録にはSecretのデータなども含まれるため、unixでは所有者のみ読み書きできるファイルとして作成する
+    pub fn create(path: &Path) -> Result<Self> {
+        let mut options = fs::OpenOptions::new();
+
+        options.write(true).create(true).truncate(true);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt as _;
+
+            options.mode(0o600);
+        }
+
+        let file = options
+            .open(path)
+            .with_context(|| format!("Failed to create record file: {}", path.display()))?;
+
+        // 既存のファイルに上書きする場合はモードが変わらないため設定し直す
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt as _;
+
+            file.set_permissions(fs::Permissions::from_mode(0o600))
+                .with_context(|| format!("Failed to set permissions: {}", path.display()))?;
+        }
+
+        Ok(Self {
+            writer: BufWriter::new(file),
+            started_at: Instant::now(),
+        })
+    }
+
+    pub fn record(&mut self, message: &Kube) -> Result<()> {
+        let line = RecordLine {
+            elapsed_ms: self.started_at.elapsed().as_millis() as u64,
+            message,
+        };
+
+        serde_json::to_writer(&mut self.writer, &line)?;
+
+        // 異常終了した場合でもそれまでの記録を残す
+        writeln!(self.writer)?;
+        self.writer.flush()?;
+
+        Ok(())
+    }
+}
+
+/// `--record`で記録したファイルを読み込む
+pub fn load_records(path: &Path) -> Result<Vec<RecordEntry>> {
+    let file = File::open(path)
+        .with_context(|| format!("Failed to open record file: {}", path.display()))?;
+
+    let mut entries = Vec::new();
+
+    for (i, line) in BufReader::new(file).lines().enumerate() {
+        let line = line?;
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let RecordLineOwned {
+            elapsed_ms,
+            message,
+        } = serde_json::from_str(&line)
+            .with_context(|| format!("Invalid record at line {}", i + 1))?;
+
+        entries.push(RecordEntry {
+            elapsed: Duration::from_millis(elapsed_ms),
+            message,
+        });
+    }
+
+    entries.sort_by_key(|entry| entry.elapsed);
+
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+    use pretty_assertions::assert_eq;
+
+    use crate::kube::table::{KubeTable, KubeTableRow};
+
+    use super::*;
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("kubetui-{}-{}.jsonl", name, std::process::id()))
+    }
+
+    #[test]
+    fn 記録したメッセージを読み込める() {
+        let path = temp_path("record");
+
+        let mut recorder = Recorder::create(&path).unwrap();
+
+        recorder
+            .record(&Kube::Pod(Ok(KubeTable {
+                header: vec!["NAME".to_string()],
+                rows: vec![KubeTableRow {
+                    namespace: "default".to_string(),
+                    name: "app".to_string(),
+                    metadata: None,
+                    row: vec!["app".to_string()],
+                }],
+            })))
+            .unwrap();
+
+        recorder
+            .record(&Kube::Event(Err(anyhow!("forbidden"))))
+            .unwrap();
+
+        let entries = load_records(&path).unwrap();
+
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(entries.len(), 2);
+
+        let Kube::Pod(Ok(table)) = entries[0].message().unwrap() else {
+            panic!("unexpected message");
+        };
+
+        assert_eq!(table.rows[0].row, vec!["app".to_string()]);
+
+        let Kube::Event(Err(err)) = entries[1].message().unwrap() else {
+            panic!("unexpected message");
+        };
+
+        assert_eq!(err.to_string(), "forbidden");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn 記録ファイルは所有者のみ読み書きできる() {
+        use std::os::unix::fs::PermissionsExt as _;
+
+        let path = temp_path("permissions");
+
+        std::fs::write(&path, "").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+
+        Recorder::create(&path).unwrap();
+
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    #[test]
+    fn 不正な行がある場合は行番号を含むエラーを返す() {
+        let path = temp_path("invalid");
+
+        std::fs::write(&path, "{\"elapsed_ms\":0,\"message\":{}}\nnot json\n").unwrap();
+
+        let actual = load_records(&path);
+
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(actual.unwrap_err().to_string(), "Invalid record at line 2");
+    }
+}
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// `anyhow::Result`を記録する
+///
+/// エラーは原因を含めて`{:#}`で整形した文字列として保存し、読み込み時にその文字列をメッセージとするエラーに戻す
+/// （`{:?}`はバックトレースを含む場合があるため使わない）
+pub fn serialize<T, S>(value: &anyhow::Result<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize,
+    S: Serializer,
+{
+    match value {
+        Ok(value) => Ok::<&T, String>(value).serialize(serializer),
+        Err(err) => Err::<&T, String>(format!("{:#}", err)).serialize(serializer),
+    }
+}
+
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<anyhow::Result<T>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    let value = Result::<T, String>::deserialize(deserializer)?;
+
+    Ok(value.map_err(anyhow::Error::msg))
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+    use pretty_assertions::assert_eq;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct Wrapper(#[serde(with = "super")] anyhow::Result<Vec<String>>);
+
+    #[test]
+    fn 成功した値はそのまま復元する() {
+        let json = serde_json::to_string(&Wrapper(Ok(vec!["a".to_string()]))).unwrap();
+
+        let Wrapper(actual) = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(actual.unwrap(), vec!["a".to_string()]);
+    }
+
+    #[test]
+    fn エラーはメッセージの文字列として復元する() {
+        let err = anyhow!("not found").context("failed to get pods");
+
+        let json = serde_json::to_string(&Wrapper(Err(err))).unwrap();
+
+        let Wrapper(actual) = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(
+            actual.unwrap_err().to_string(),
+            "failed to get pods: not found"
+        );
+    }
+}
@@ -0,0 +1,329 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use crossbeam::channel::{Receiver, RecvTimeoutError, Sender};
+
+use crate::{logger, message::Message, panic_set_hook, workers::kube::message::Kube};
+
+use super::{
+    message::{ReplayMessage, ReplayRequest, ReplayResponse, ReplayStatus},
+    record::RecordEntry,
+};
+
+/// 再生状態を確認する間隔
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// 記録したメッセージを記録時と同じ間隔でレンダースレッドに送信する
+///
+/// クラスターには接続せず、レンダースレッドからのリクエストは再生位置の操作以外を無視する
+pub struct Replay {
+    tx: Sender<Message>,
+    rx: Receiver<Message>,
+    is_terminated: Arc<AtomicBool>,
+    entries: Vec<RecordEntry>,
+}
+
+impl Replay {
+    pub fn new(
+        tx: Sender<Message>,
+        rx: Receiver<Message>,
+        is_terminated: Arc<AtomicBool>,
+        entries: Vec<RecordEntry>,
+    ) -> Self {
+        Self {
+            tx,
+            rx,
+            is_terminated,
+            entries,
+        }
+    }
+
+    pub fn start(self) -> Result<()> {
+        logger!(info, "replay start");
+
+        let is_terminated = self.is_terminated.clone();
+
+        let ret = self.replay();
+
+        is_terminated.store(true, Ordering::Relaxed);
+
+        logger!(info, "replay end");
+
+        ret
+    }
+
+    pub fn set_panic_hook(&self) {
+        let is_terminated = self.is_terminated.clone();
+
+        panic_set_hook!({
+            is_terminated.store(true, Ordering::Relaxed);
+        });
+    }
+
+    fn replay(self) -> Result<()> {
+        let Self {
+            tx,
+            rx,
+            is_terminated,
+            entries,
+        } = self;
+
+        let mut timeline = Timeline::new(entries, Instant::now());
+
+        let mut last_status = None;
+
+        while !is_terminated.load(Ordering::Relaxed) {
+            let now = Instant::now();
+
+            for entry in timeline.take_due(now) {
+                match entry.message() {
+                    Ok(message) => tx.send(message.into())?,
+                    Err(err) => logger!(error, "Failed to replay message: {:?}", err),
+                }
+            }
+
+            let status = timeline.status(now);
+
+            if last_status != Some(status) {
+                tx.send(ReplayResponse::Status(status).into())?;
+
+                last_status = Some(status);
+            }
+
+            let timeout = timeline
+                .next_timeout(now)
+                .map_or(POLL_INTERVAL, |timeout| timeout.min(POLL_INTERVAL));
+
+            match rx.recv_timeout(timeout) {
+                Ok(Message::Kube(Kube::Replay(ReplayMessage::Request(req)))) => match req {
+                    ReplayRequest::TogglePause => timeline.toggle_pause(Instant::now()),
+                    ReplayRequest::Seek(secs) => {
+                        if timeline.seek(secs, Instant::now()) {
+                            tx.send(ReplayResponse::Rewind.into())?;
+                        }
+                    }
+                },
+                Ok(_) => {}
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// 再生位置の管理
+#[derive(Debug)]
+struct Timeline {
+    entries: Vec<RecordEntry>,
+    /// 次に送信するエントリーのインデックス
+    index: usize,
+    /// `resumed_at`時点の再生位置
+    position: Duration,
+    /// 再生を開始（再開）した時刻、一時停止中は`None`
+    resumed_at: Option<Instant>,
+}
+
+impl Timeline {
+    fn new(entries: Vec<RecordEntry>, now: Instant) -> Self {
+        Self {
+            entries,
+            index: 0,
+            position: Duration::ZERO,
+            resumed_at: Some(now),
+        }
+    }
+
+    fn duration(&self) -> Duration {
+        self.entries
+            .last()
+            .map(|entry| entry.elapsed)
+            .unwrap_or_default()
+    }
+
+    fn position(&self, now: Instant) -> Duration {
+        let position = match self.resumed_at {
+            Some(resumed_at) => self.position + now.saturating_duration_since(resumed_at),
+            None => self.position,
+        };
+
+        position.min(self.duration())
+    }
+
+    fn status(&self, now: Instant) -> ReplayStatus {
+        ReplayStatus {
+            position: self.position(now).as_secs(),
+            duration: self.duration().as_secs(),
+            paused: self.resumed_at.is_none(),
+        }
+    }
+
+    /// 再生位置までに送信すべきエントリー
+    fn take_due(&mut self, now: Instant) -> &[RecordEntry] {
+        let position = self.position(now);
+
+        let start = self.index;
+
+        while self
+            .entries
+            .get(self.index)
+            .is_some_and(|entry| entry.elapsed <= position)
+        {
+            self.index += 1;
+        }
+
+        &self.entries[start..self.index]
+    }
+
+    /// 次のエントリーを送信するまでの時間
+    ///
+    /// 一時停止中またはすべて送信済みの場合は`None`を返す
+    fn next_timeout(&self, now: Instant) -> Option<Duration> {
+        self.resumed_at?;
+
+        let next = self.entries.get(self.index)?;
+
+        Some(next.elapsed.saturating_sub(self.position(now)))
+    }
+
+    fn toggle_pause(&mut self, now: Instant) {
+        self.position = self.position(now);
+
+        self.resumed_at = match self.resumed_at {
+            Some(_) => None,
+            None => Some(now),
+        };
+    }
+
+    /// 再生位置を移動する
+    ///
+    /// 巻き戻した場合は先頭から送信し直すため`true`を返す
+    fn seek(&mut self, secs: i64, now: Instant) -> bool {
+        let current = self.position(now);
+
+        let offset = Duration::from_secs(secs.unsigned_abs());
+
+        let target = if secs < 0 {
+            current.saturating_sub(offset)
+        } else {
+            (current + offset).min(self.duration())
+        };
+
+        self.position = target;
+
+        if self.resumed_at.is_some() {
+            self.resumed_at = Some(now);
+        }
+
+        let rewound = target < current;
+
+        if rewound {
+            self.index = 0;
+        }
+
+        rewound
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn entries(secs: &[u64]) -> Vec<RecordEntry> {
+        secs.iter()
+            .map(|secs| RecordEntry {
+                elapsed: Duration::from_secs(*secs),
+                message: serde_json::Value::Null,
+            })
+            .collect()
+    }
+
+    fn elapsed(entries: &[RecordEntry]) -> Vec<u64> {
+        entries
+            .iter()
+            .map(|entry| entry.elapsed.as_secs())
+            .collect()
+    }
+
+    #[test]
+    fn 経過時間までのエントリーを順に返す() {
+        let now = Instant::now();
+
+        let mut timeline = Timeline::new(entries(&[0, 1, 3, 10]), now);
+
+        assert_eq!(elapsed(timeline.take_due(now)), vec![0]);
+        assert_eq!(timeline.next_timeout(now), Some(Duration::from_secs(1)));
+
+        let later = now + Duration::from_secs(5);
+
+        assert_eq!(elapsed(timeline.take_due(later)), vec![1, 3]);
+        assert_eq!(timeline.next_timeout(later), Some(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn 一時停止中は再生位置が進まない() {
+        let now = Instant::now();
+
+        let mut timeline = Timeline::new(entries(&[0, 1, 3, 10]), now);
+
+        timeline.toggle_pause(now + Duration::from_secs(2));
+
+        let later = now + Duration::from_secs(5);
+
+        assert_eq!(elapsed(timeline.take_due(later)), vec![0, 1]);
+        assert_eq!(timeline.next_timeout(later), None);
+        assert_eq!(
+            timeline.status(later),
+            ReplayStatus {
+                position: 2,
+                duration: 10,
+                paused: true
+            }
+        );
+
+        timeline.toggle_pause(later);
+
+        assert_eq!(
+            elapsed(timeline.take_due(later + Duration::from_secs(1))),
+            vec![3]
+        );
+    }
+
+    #[test]
+    fn 早送りすると間のエントリーをまとめて返し末尾を超えない() {
+        let now = Instant::now();
+
+        let mut timeline = Timeline::new(entries(&[0, 1, 3, 10]), now);
+
+        assert!(!timeline.seek(4, now));
+        assert_eq!(elapsed(timeline.take_due(now)), vec![0, 1, 3]);
+
+        assert!(!timeline.seek(100, now));
+        assert_eq!(timeline.status(now).position, 10);
+        assert_eq!(elapsed(timeline.take_due(now)), vec![10]);
+    }
+
+    #[test]
+    fn 巻き戻すと先頭から送信し直す() {
+        let now = Instant::now();
+
+        let mut timeline = Timeline::new(entries(&[0, 1, 3, 10]), now);
+
+        let later = now + Duration::from_secs(5);
+
+        assert_eq!(elapsed(timeline.take_due(later)), vec![0, 1, 3]);
+
+        assert!(timeline.seek(-3, later));
+        assert_eq!(timeline.status(later).position, 2);
+        assert_eq!(elapsed(timeline.take_due(later)), vec![0, 1]);
+    }
+}